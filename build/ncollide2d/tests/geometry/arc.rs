@@ -0,0 +1,124 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Arc, Ball, Cuboid};
+use std::f64::consts::FRAC_PI_2;
+
+// A quarter of a circle of radius 2 with a thickness of 0.2.
+fn quarter_arc() -> Arc<f64> {
+    Arc::new(Point2::origin(), 2.0, 0.0, FRAC_PI_2, 0.2)
+}
+
+fn diagonal() -> Vector2<f64> {
+    Vector2::new(1.0, 1.0).normalize()
+}
+
+#[test]
+fn arc_point_projection() {
+    let arc = quarter_arc();
+    let id = Isometry2::identity();
+
+    let outside = Point2::from(diagonal() * 3.0);
+    let proj = arc.project_point(&id, &outside, true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::from(diagonal() * 2.1), epsilon = 1.0e-7);
+
+    let inside = Point2::from(diagonal() * 1.95);
+    assert!(arc.contains_point(&id, &inside));
+    assert!(!arc.contains_point(&id, &Point2::origin()));
+
+    // Past the end of the arc, the closest point is on its rounded end.
+    let past_end = Point2::new(2.0, -1.0);
+    let proj = arc.project_point(&id, &past_end, true);
+    assert_relative_eq!(proj.point, Point2::new(2.0, -0.1), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        arc.distance_to_point(&id, &past_end, true),
+        0.9,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn arc_ray_cast() {
+    let arc = quarter_arc();
+    let id = Isometry2::identity();
+
+    // From the center toward the inner side.
+    let ray = Ray::new(Point2::origin(), diagonal());
+    let inter = arc.toi_and_normal_with_ray(&id, &ray, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 1.9, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, -diagonal(), epsilon = 1.0e-7);
+
+    // From the outside toward the outer side.
+    let ray = Ray::new(Point2::from(diagonal() * 5.0), -diagonal());
+    let inter = arc.toi_and_normal_with_ray(&id, &ray, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.9, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, diagonal(), epsilon = 1.0e-7);
+
+    // Through the rounded end located at (2, 0).
+    let ray = Ray::new(Point2::new(2.0, -5.0), Vector2::y());
+    let inter = arc.toi_and_normal_with_ray(&id, &ray, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.9, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, -Vector2::y(), epsilon = 1.0e-7);
+
+    // From inside of the arc.
+    let ray = Ray::new(Point2::from(diagonal() * 2.0), diagonal());
+    assert_eq!(arc.toi_with_ray(&id, &ray, 10.0, true), Some(0.0));
+    let toi = arc.toi_with_ray(&id, &ray, 10.0, false).unwrap();
+    assert_relative_eq!(toi, 0.1, epsilon = 1.0e-7);
+
+    // Missing the arc, and hitting it beyond `max_toi`.
+    let ray = Ray::new(Point2::new(3.0, -5.0), Vector2::y());
+    assert!(!arc.intersects_ray(&id, &ray, 10.0));
+    let ray = Ray::new(Point2::origin(), diagonal());
+    assert!(!arc.intersects_ray(&id, &ray, 1.0));
+}
+
+#[test]
+fn arc_ball_contact() {
+    let arc = quarter_arc();
+    let id = Isometry2::identity();
+    let ball = Ball::new(0.5);
+
+    // Outer side.
+    let m = Isometry2::new(diagonal() * 2.55, 0.0);
+    let contact = query::contact(&id, &arc, &m, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, diagonal(), epsilon = 1.0e-6);
+
+    // Inner side.
+    let m = Isometry2::new(diagonal() * 1.45, 0.0);
+    let contact = query::contact(&id, &arc, &m, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, -diagonal(), epsilon = 1.0e-6);
+
+    // Past the rounded end at (2, 0), with the arc as the second shape.
+    let m = Isometry2::new(Vector2::new(2.0, -0.55), 0.0);
+    let contact = query::contact(&m, &ball, &id, &arc, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector2::y(), epsilon = 1.0e-6);
+
+    // Near the center, far from the arc.
+    let m = Isometry2::new(Vector2::new(0.1, 0.1), 0.0);
+    assert!(query::contact(&id, &arc, &m, &ball, 0.0).is_none());
+}
+
+#[test]
+fn arc_cuboid_contact() {
+    let arc = quarter_arc();
+    let id = Isometry2::identity();
+
+    // The corner (1.3, 1.3) of the cuboid is close to the inner side of the arc.
+    let cuboid = Cuboid::new(Vector2::new(1.3, 1.3));
+    let contact = query::contact(&id, &arc, &id, &cuboid, 0.1).unwrap();
+    let expected_depth = 1.3 * 2.0f64.sqrt() - 1.9;
+    assert_relative_eq!(contact.depth, expected_depth, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, -diagonal(), epsilon = 1.0e-6);
+    assert!(query::contact(&id, &arc, &id, &cuboid, 0.0).is_none());
+
+    // A cuboid resting on the outer side of the arc, at its top.
+    let cuboid = Cuboid::new(Vector2::new(0.5, 0.5));
+    let m = Isometry2::new(Vector2::new(0.8, 2.55), 0.0);
+    let contact = query::contact(&id, &arc, &m, &cuboid, 0.0).unwrap();
+    let expected_depth = 2.1 - (0.3f64 * 0.3 + 2.05 * 2.05).sqrt();
+    assert_relative_eq!(contact.depth, expected_depth, epsilon = 1.0e-6);
+}
@@ -1,6 +1,7 @@
 // Issue #35
 
-use na::{self, Isometry2, Vector2};
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::interpolation::ConstantVelocityRigidMotion;
 use ncollide2d::query;
 use ncollide2d::shape::Ball;
 
@@ -25,3 +26,64 @@ fn test_ball_ball_toi() {
 
     assert_eq!(cast.unwrap().toi, 0.9);
 }
+
+#[test]
+fn test_rotating_ball_ball_toi() {
+    let b = Ball::new(0.1f64);
+    // A pendulum of length 1 attached to the origin, and a ball at rest right above the origin.
+    let motion1 = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry2::translation(1.0, 0.0),
+        Point2::new(-1.0, 0.0),
+        na::zero(),
+        1.0,
+    );
+    let motion2 = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry2::translation(0.0, 1.0),
+        Point2::origin(),
+        na::zero(),
+        0.0,
+    );
+
+    let toi = query::nonlinear_time_of_impact_rotating_ball_ball(&motion1, &b, &motion2, &b, 2.0)
+        .unwrap();
+    let expected = std::f64::consts::FRAC_PI_2 - 2.0 * 0.1f64.asin();
+    assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-6);
+
+    // The pendulum swings the other way.
+    let mut motion1 = motion1;
+    motion1.angvel = -1.0;
+    assert!(
+        query::nonlinear_time_of_impact_rotating_ball_ball(&motion1, &b, &motion2, &b, 2.0)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_rotating_ball_ball_near_miss() {
+    let b = Ball::new(0.1f64);
+    let motion1 = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry2::translation(1.0, 0.0),
+        Point2::new(-1.0, 0.0),
+        na::zero(),
+        1.0,
+    );
+
+    for gap in [1.0e-3, 1.0e-5, 1.0e-7].iter() {
+        // The pendulum passes right below a ball at rest, without touching it.
+        let motion2 = ConstantVelocityRigidMotion::new(
+            0.0,
+            Isometry2::translation(0.0, 1.2 + gap),
+            Point2::origin(),
+            na::zero(),
+            0.0,
+        );
+
+        assert!(query::nonlinear_time_of_impact_rotating_ball_ball(
+            &motion1, &b, &motion2, &b, 2.0
+        )
+        .is_none());
+    }
+}
@@ -1,6 +1,10 @@
+mod arc;
 mod ball_ball_toi;
 mod ball_cuboid_contact;
 mod compound_penetration;
 mod epa2;
+mod polyline_polyline_closest_points;
 mod ray_cast;
+mod segment_plane;
+mod solid_point_projection;
 mod time_of_impact2;
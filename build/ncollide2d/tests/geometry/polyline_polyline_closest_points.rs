@@ -0,0 +1,106 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, ClosestPoints};
+use ncollide2d::shape::Polyline;
+
+// A polyline sampling the curve `f` with `n` segments on `[0, 10]`.
+fn curve(n: usize, f: impl Fn(f64) -> f64) -> Polyline<f64> {
+    let points = (0..=n)
+        .map(|i| {
+            let x = i as f64 * 10.0 / n as f64;
+            Point2::new(x, f(x))
+        })
+        .collect();
+    Polyline::new(points, None)
+}
+
+#[test]
+fn crossing_polylines_closest_points() {
+    let zigzag = Polyline::new(
+        vec![
+            Point2::new(0.0, 2.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(4.0, 2.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(8.0, 2.0),
+        ],
+        None,
+    );
+    let hook = Polyline::new(
+        vec![
+            Point2::new(8.0, 3.0),
+            Point2::new(4.0, 3.0),
+            Point2::new(4.0, -1.0),
+        ],
+        None,
+    );
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::translation(1.0, 0.0);
+
+    match query::closest_points(&m1, &zigzag, &m2, &hook, 0.1) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_eq!(p1, p2);
+            assert_relative_eq!(p1, Point2::new(5.0, 1.0), epsilon = 1.0e-9);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+
+    // Moved up, the end of the hook is closest to a descending segment of the zigzag.
+    let m2 = Isometry2::translation(1.0, 2.5);
+    match query::closest_points(&m1, &zigzag, &m2, &hook, 1.0) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(p1, Point2::new(4.75, 1.25), epsilon = 1.0e-9);
+            assert_relative_eq!(p2, Point2::new(5.0, 1.5), epsilon = 1.0e-9);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+}
+
+#[test]
+fn polyline_polyline_closest_points_global_minimum() {
+    let polyline1 = curve(40, |x| (x * 1.3).sin());
+    let polyline2 = curve(25, |x| (x * 0.9).cos() * 0.5);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(0.3, 2.2), 0.1);
+
+    // Brute-force minimum over all pairs of segments.
+    let mut expected = f64::MAX;
+    for seg1 in polyline1.segments() {
+        for seg2 in polyline2.segments() {
+            let seg2 = seg2.transformed(&m2);
+            let (loc1, loc2) = query::closest_points_segment_segment_with_locations_nD(
+                (&seg1.a, &seg1.b),
+                (&seg2.a, &seg2.b),
+            );
+            let dist = na::distance(&seg1.point_at(&loc1), &seg2.point_at(&loc2));
+            expected = expected.min(dist);
+        }
+    }
+
+    match query::closest_points(&m1, &polyline1, &m2, &polyline2, 10.0) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(na::distance(&p1, &p2), expected, epsilon = 1.0e-9);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+
+    let res = query::closest_points(&m1, &polyline1, &m2, &polyline2, expected * 0.9);
+    assert_eq!(res, ClosestPoints::Disjoint);
+}
+
+#[test]
+fn close_polylines_are_not_crossing_in_single_precision() {
+    let polyline1 = Polyline::new(vec![Point2::new(0.0f32, 0.0), Point2::new(1.0, 0.0)], None);
+    let polyline2 = Polyline::new(
+        vec![Point2::new(0.5f32, 2.0e-4), Point2::new(0.5, 1.0)],
+        None,
+    );
+    let m = Isometry2::identity();
+
+    match query::closest_points(&m, &polyline1, &m, &polyline2, 0.1) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(p1, Point2::new(0.5, 0.0), epsilon = 1.0e-6);
+            assert_relative_eq!(p2, Point2::new(0.5, 2.0e-4), epsilon = 1.0e-6);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+}
@@ -0,0 +1,64 @@
+use na::{Point2, Vector2};
+use ncollide2d::query::{self, SegmentPlaneResult};
+use ncollide2d::shape::Segment;
+
+fn classify(a: Point2<f64>, b: Point2<f64>) -> SegmentPlaneResult<f64> {
+    query::segment_plane(&Segment::new(a, b), &Point2::origin(), &Vector2::y())
+}
+
+#[test]
+fn segment_plane_sides() {
+    assert_eq!(
+        classify(Point2::new(0.0, 1.0), Point2::new(3.0, 2.0)),
+        SegmentPlaneResult::InFront
+    );
+    assert_eq!(
+        classify(Point2::new(0.0, -1.0), Point2::new(3.0, -2.0)),
+        SegmentPlaneResult::Behind
+    );
+    assert_eq!(
+        classify(Point2::new(-1.0, 0.0), Point2::new(3.0, 0.0)),
+        SegmentPlaneResult::OnPlane
+    );
+}
+
+#[test]
+fn segment_plane_crossing() {
+    let a = Point2::new(0.0, 3.0);
+    let b = Point2::new(4.0, -1.0);
+
+    assert_eq!(
+        classify(a, b),
+        SegmentPlaneResult::Crossing {
+            t: 0.75,
+            point: Point2::new(3.0, 0.0),
+            behind: Segment::new(Point2::new(3.0, 0.0), b),
+        }
+    );
+}
+
+#[test]
+fn segment_plane_touching_endpoint() {
+    let a = Point2::new(1.0, 0.0);
+
+    // Touching from the front: the part behind the plane is a single point.
+    assert_eq!(
+        classify(a, Point2::new(1.0, 2.0)),
+        SegmentPlaneResult::Crossing {
+            t: 0.0,
+            point: a,
+            behind: Segment::new(a, a),
+        }
+    );
+
+    // Touching from behind: the whole segment is behind the plane.
+    let b = Point2::new(2.0, -2.0);
+    assert_eq!(
+        classify(b, a),
+        SegmentPlaneResult::Crossing {
+            t: 1.0,
+            point: a,
+            behind: Segment::new(b, a),
+        }
+    );
+}
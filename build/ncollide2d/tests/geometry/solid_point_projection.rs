@@ -0,0 +1,41 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::query::PointQuery;
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid};
+
+#[test]
+fn solid_and_hollow_convex_polygon_projection() {
+    let m = Isometry2::new(Vector2::new(1.0, -2.0), 0.3);
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    let polygon = ConvexPolygon::try_from_points(&[
+        Point2::new(-1.0, -2.0),
+        Point2::new(1.0, -2.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(-1.0, 2.0),
+    ])
+    .unwrap();
+
+    for pt in &[Point2::origin(), Point2::new(0.7, -0.5)] {
+        let pt = m * pt;
+        assert_eq!(polygon.project_point(&m, &pt, true).point, pt);
+
+        let proj = polygon.project_point(&m, &pt, false);
+        let expected = cuboid.project_point(&m, &pt, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn solid_and_hollow_ball_center_projection() {
+    let m = Isometry2::new(Vector2::new(1.0, -2.0), 0.3);
+    let center = Point2::from(m.translation.vector);
+    let ball = Ball::new(1.5);
+
+    assert_eq!(ball.distance_to_point(&m, &center, true), 0.0);
+    assert_eq!(ball.project_point(&m, &center, true).point, center);
+
+    let proj = ball.project_point(&m, &center, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(na::distance(&proj.point, &center), 1.5, epsilon = 1.0e-7);
+    assert_relative_eq!(ball.distance_to_point(&m, &center, false), -1.5);
+}
@@ -0,0 +1,55 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide2d::query::ContactPrediction;
+use ncollide2d::shape::{Arc, Ball};
+use std::f64::consts::PI;
+
+#[test]
+fn ball_inside_half_circle_arc() {
+    let dispatcher = DefaultContactDispatcher::new();
+    // A bowl: the lower half of a circle of radius 2.
+    let arc = Arc::new(Point2::origin(), 2.0, PI, 2.0 * PI, 0.2);
+    let ball = Ball::new(0.5);
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+
+    for (arc_first, expected_normal) in [(true, Vector2::y()), (false, -Vector2::y())].iter() {
+        let mball = Isometry2::new(Vector2::new(0.0, -1.45), 0.0);
+        let mut generator = if *arc_first {
+            dispatcher.get_contact_algorithm(&arc, &ball).unwrap()
+        } else {
+            dispatcher.get_contact_algorithm(&ball, &arc).unwrap()
+        };
+        let mut manifold = generator.init_manifold();
+
+        let _ = if *arc_first {
+            generator.generate_contacts(
+                &dispatcher,
+                &Isometry2::identity(),
+                &arc,
+                None,
+                &mball,
+                &ball,
+                None,
+                &prediction,
+                &mut manifold,
+            )
+        } else {
+            generator.generate_contacts(
+                &dispatcher,
+                &mball,
+                &ball,
+                None,
+                &Isometry2::identity(),
+                &arc,
+                None,
+                &prediction,
+                &mut manifold,
+            )
+        };
+
+        assert_eq!(manifold.len(), 1);
+        let contact = manifold.deepest_contact().unwrap();
+        assert_relative_eq!(contact.contact.depth, 0.05, epsilon = 1.0e-6);
+        assert_relative_eq!(*contact.contact.normal, *expected_normal, epsilon = 1.0e-6);
+    }
+}
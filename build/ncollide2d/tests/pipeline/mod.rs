@@ -1,2 +1,4 @@
+mod arc_ball_contact;
 mod is_send_sync;
+mod polyline_polyline_contact;
 mod world_remove;
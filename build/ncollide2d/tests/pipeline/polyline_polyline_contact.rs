@@ -0,0 +1,79 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{
+    ContactManifoldGenerator, DefaultContactDispatcher, PolylinePolylineManifoldGenerator,
+};
+use ncollide2d::query::{ContactManifold, ContactPrediction};
+use ncollide2d::shape::{FeatureId, Polyline};
+
+#[test]
+fn crossing_polylines_contact() {
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = PolylinePolylineManifoldGenerator::new();
+    let polyline1 = Polyline::new(
+        vec![
+            Point2::new(-2.0, 0.0),
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+        ],
+        None,
+    );
+    let polyline2 = Polyline::new(vec![Point2::new(0.5, 0.1), Point2::new(0.5, 2.0)], None);
+    let prediction = ContactPrediction::new(0.2, 0.0, 0.0);
+    let mut manifold = generator.init_manifold();
+
+    let _ = generator.generate_contacts(
+        &dispatcher,
+        &Isometry2::identity(),
+        &polyline1,
+        None,
+        &Isometry2::identity(),
+        &polyline2,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    assert_eq!(manifold.len(), 1);
+    let contact = manifold.deepest_contact().unwrap();
+    assert_relative_eq!(contact.contact.depth, -0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        contact.contact.normal.into_inner(),
+        Vector2::y(),
+        epsilon = 1.0e-6
+    );
+    assert_eq!(
+        polyline1.edge_containing_feature(contact.kinematic.feature1()),
+        1
+    );
+    assert_eq!(contact.kinematic.feature2(), FeatureId::Vertex(0));
+}
+
+#[test]
+fn folded_polyline_self_contact() {
+    let mut generator = PolylinePolylineManifoldGenerator::new();
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 0.5),
+            Point2::new(0.0, 0.1),
+        ],
+        None,
+    );
+    let prediction = ContactPrediction::new(0.2, 0.0, 0.0);
+    let mut manifold: ContactManifold<f64> = generator.init_manifold();
+
+    generator.generate_self_contacts(
+        &Isometry2::identity(),
+        &polyline,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    // Only the first and last segments are both close and non-adjacent.
+    assert_eq!(manifold.len(), 1);
+    let contact = manifold.deepest_contact().unwrap();
+    assert_relative_eq!(contact.contact.depth, -0.1, epsilon = 1.0e-6);
+    assert_eq!(contact.kinematic.feature2(), FeatureId::Vertex(3));
+}
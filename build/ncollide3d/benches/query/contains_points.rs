@@ -0,0 +1,83 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Cuboid};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use test::Bencher;
+
+// Points scattered around a shape of unit size.
+fn points() -> Vec<Point3<f32>> {
+    let mut rng = IsaacRng::seed_from_u64(0);
+
+    (0..1024)
+        .map(|_| {
+            Point3::new(
+                rng.gen_range(-2.0, 2.0),
+                rng.gen_range(-2.0, 2.0),
+                rng.gen_range(-2.0, 2.0),
+            )
+        })
+        .collect()
+}
+
+fn pose() -> Isometry3<f32> {
+    Isometry3::new(Vector3::new(0.1, -0.2, 0.3), Vector3::new(0.4, 0.5, 0.6))
+}
+
+#[bench]
+fn bench_contains_point_cuboid(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.8));
+    let m = pose();
+    let pts = points();
+    let mut out = vec![false; pts.len()];
+
+    bh.iter(|| {
+        for (pt, inside) in pts.iter().zip(out.iter_mut()) {
+            *inside = cuboid.contains_point(&m, pt);
+        }
+
+        test::black_box(&out);
+    })
+}
+
+#[bench]
+fn bench_contains_points_cuboid(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.8));
+    let m = pose();
+    let pts = points();
+    let mut out = vec![false; pts.len()];
+
+    bh.iter(|| {
+        cuboid.contains_points(&m, &pts, &mut out);
+        test::black_box(&out);
+    })
+}
+
+#[bench]
+fn bench_contains_point_ball(bh: &mut Bencher) {
+    let ball = Ball::new(1.0f32);
+    let m = pose();
+    let pts = points();
+    let mut out = vec![false; pts.len()];
+
+    bh.iter(|| {
+        for (pt, inside) in pts.iter().zip(out.iter_mut()) {
+            *inside = ball.contains_point(&m, pt);
+        }
+
+        test::black_box(&out);
+    })
+}
+
+#[bench]
+fn bench_contains_points_ball(bh: &mut Bencher) {
+    let ball = Ball::new(1.0f32);
+    let m = pose();
+    let pts = points();
+    let mut out = vec![false; pts.len()];
+
+    bh.iter(|| {
+        ball.contains_points(&m, &pts, &mut out);
+        test::black_box(&out);
+    })
+}
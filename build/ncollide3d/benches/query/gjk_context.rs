@@ -0,0 +1,53 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query;
+use ncollide3d::query::algorithms::gjk::GJKContext;
+use ncollide3d::shape::{Cone, Cuboid};
+use test::Bencher;
+
+// Poses of a shape moving by small steps around another one.
+fn poses() -> Vec<Isometry3<f32>> {
+    (0usize..128)
+        .map(|i| {
+            let t = i as f32 * 0.005;
+            Isometry3::new(
+                Vector3::new(3.0 + t, 0.5 - t, 0.2 + t),
+                Vector3::new(0.1 + t, 0.2 - t, 0.3 + t),
+            )
+        })
+        .collect()
+}
+
+#[bench]
+fn bench_distance_small_moves(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+    let cone = Cone::new(1.0f32, 0.5);
+    let m1 = Isometry3::identity();
+    let poses = poses();
+    let mut i = 0;
+
+    bh.iter(|| {
+        i = (i + 1) & (poses.len() - 1);
+        test::black_box(query::distance(&m1, &cuboid, &poses[i], &cone))
+    })
+}
+
+#[bench]
+fn bench_distance_with_context_small_moves(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+    let cone = Cone::new(1.0f32, 0.5);
+    let m1 = Isometry3::identity();
+    let poses = poses();
+    let mut context = GJKContext::new();
+    let mut i = 0;
+
+    bh.iter(|| {
+        i = (i + 1) & (poses.len() - 1);
+        test::black_box(query::distance_with_context(
+            &m1,
+            &cuboid,
+            &poses[i],
+            &cone,
+            &mut context,
+        ))
+    })
+}
@@ -0,0 +1,68 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Cone, Cuboid};
+use test::Bencher;
+
+fn overlapping_pair() -> (Isometry3<f32>, Isometry3<f32>) {
+    let m1 = Isometry3::new(Vector3::zeros(), Vector3::new(0.1, 0.2, 0.3));
+    let m2 = Isometry3::new(Vector3::new(0.5, 0.2, 0.1), Vector3::new(0.3, 0.2, 0.1));
+    (m1, m2)
+}
+
+fn disjoint_pair() -> (Isometry3<f32>, Isometry3<f32>) {
+    let m1 = Isometry3::new(Vector3::zeros(), Vector3::new(0.1, 0.2, 0.3));
+    let m2 = Isometry3::new(Vector3::new(5.0, 2.0, 1.0), Vector3::new(0.3, 0.2, 0.1));
+    (m1, m2)
+}
+
+#[bench]
+fn bench_intersection_test_overlapping(bh: &mut Bencher) {
+    let (m1, m2) = overlapping_pair();
+    let g1 = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.7));
+    let g2 = Cone::new(1.0f32, 0.5);
+
+    bh.iter(|| {
+        test::black_box(query::intersection_test_support_map_support_map(
+            &m1, &g1, &m2, &g2,
+        ))
+    })
+}
+
+#[bench]
+fn bench_proximity_overlapping(bh: &mut Bencher) {
+    let (m1, m2) = overlapping_pair();
+    let g1 = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.7));
+    let g2 = Cone::new(1.0f32, 0.5);
+
+    bh.iter(|| {
+        test::black_box(query::proximity_support_map_support_map(
+            &m1, &g1, &m2, &g2, 0.0,
+        ))
+    })
+}
+
+#[bench]
+fn bench_intersection_test_disjoint(bh: &mut Bencher) {
+    let (m1, m2) = disjoint_pair();
+    let g1 = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.7));
+    let g2 = Cone::new(1.0f32, 0.5);
+
+    bh.iter(|| {
+        test::black_box(query::intersection_test_support_map_support_map(
+            &m1, &g1, &m2, &g2,
+        ))
+    })
+}
+
+#[bench]
+fn bench_proximity_disjoint(bh: &mut Bencher) {
+    let (m1, m2) = disjoint_pair();
+    let g1 = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.7));
+    let g2 = Cone::new(1.0f32, 0.5);
+
+    bh.iter(|| {
+        test::black_box(query::proximity_support_map_support_map(
+            &m1, &g1, &m2, &g2, 0.0,
+        ))
+    })
+}
@@ -1,3 +1,6 @@
 mod algorithm;
 mod contacts;
+mod contains_points;
+mod gjk_context;
+mod intersection_test;
 mod ray;
@@ -0,0 +1,34 @@
+use na::Point3;
+use ncollide3d::shape::TriMesh;
+
+// The vertices and triangles of a flat `n x n` grid of unit squares on the plane `y = 0`, each
+// split into two triangles.
+pub fn flat_grid(n: usize) -> (Vec<Point3<f64>>, Vec<Point3<usize>>) {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            points.push(Point3::new(i as f64, 0.0, j as f64));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + 1;
+            let c = a + n + 1;
+            let d = c + 1;
+            indices.push(Point3::new(a, b, d));
+            indices.push(Point3::new(a, d, c));
+        }
+    }
+
+    (points, indices)
+}
+
+// A flat `n x n` grid of unit squares on the plane `y = 0`, each split into two triangles.
+pub fn flat_floor(n: usize) -> TriMesh<f64> {
+    let (points, indices) = flat_grid(n);
+    TriMesh::new(points, indices, None)
+}
@@ -0,0 +1,3 @@
+pub use self::generators::{flat_floor, flat_grid};
+
+mod generators;
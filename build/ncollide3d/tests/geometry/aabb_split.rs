@@ -0,0 +1,83 @@
+use na::Point3;
+use ncollide3d::bounding_volume::{BoundingVolume, AABB};
+
+fn volume(aabb: &AABB<f64>) -> f64 {
+    let extents = aabb.extents();
+    extents.x * extents.y * extents.z
+}
+
+// Checks that `children` tile `parent` exactly, without gaps nor overlaps.
+fn assert_tiles(parent: &AABB<f64>, children: &[AABB<f64>]) {
+    let mut merged = children[0];
+
+    for (i, child) in children.iter().enumerate() {
+        assert!(parent.contains(child));
+        merged.merge(child);
+
+        for other in &children[i + 1..] {
+            if let Some(inter) = child.intersection(other) {
+                assert_eq!(volume(&inter), 0.0);
+            }
+        }
+    }
+
+    assert_eq!(merged, *parent);
+    let total: f64 = children.iter().map(volume).sum();
+    assert_relative_eq!(total, volume(parent), epsilon = 1.0e-12);
+}
+
+#[test]
+fn split_at_tiles_the_parent() {
+    let aabb = AABB::new(Point3::new(-1.0, 0.5, 2.0), Point3::new(3.0, 1.5, 7.0));
+
+    for axis in 0..3 {
+        let value = aabb.mins[axis] * 0.3 + aabb.maxs[axis] * 0.7;
+        let (below, above) = aabb.split_at(axis, value).unwrap();
+
+        assert_eq!(below.maxs[axis], value);
+        assert_eq!(above.mins[axis], value);
+        assert_tiles(&aabb, &[below, above]);
+    }
+
+    // Splitting at a face gives a flat part.
+    let (below, above) = aabb.split_at(0, -1.0).unwrap();
+    assert_eq!(volume(&below), 0.0);
+    assert_eq!(above, aabb);
+}
+
+#[test]
+fn split_at_out_of_range() {
+    let aabb = AABB::new(Point3::new(-1.0, 0.5, 2.0), Point3::new(3.0, 1.5, 7.0));
+
+    assert!(aabb.split_at(0, -1.1).is_none());
+    assert!(aabb.split_at(1, 1.6).is_none());
+    assert!(aabb.split_at(2, 700.0).is_none());
+}
+
+#[test]
+#[should_panic]
+fn split_at_invalid_axis() {
+    let aabb = AABB::new(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+    let _ = aabb.split_at(3, 0.5);
+}
+
+#[test]
+fn center_split_tiles_the_parent() {
+    let aabb = AABB::new(Point3::new(-1.0, 0.5, 2.0), Point3::new(3.0, 1.5, 7.0));
+    let children = aabb.center_split();
+    let center = aabb.center();
+
+    assert_tiles(&aabb, &children);
+
+    for (i, child) in children.iter().enumerate() {
+        assert_relative_eq!(volume(child), volume(&aabb) / 8.0, epsilon = 1.0e-12);
+
+        for axis in 0..3 {
+            if i & (1 << axis) == 0 {
+                assert_eq!(child.maxs[axis], center[axis]);
+            } else {
+                assert_eq!(child.mins[axis], center[axis]);
+            }
+        }
+    }
+}
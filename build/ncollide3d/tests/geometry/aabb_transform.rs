@@ -0,0 +1,44 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume};
+use ncollide3d::shape::ConvexHull;
+
+#[test]
+fn transformed_local_aabb_is_conservative() {
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let local_aabb = bounding_volume::local_aabb(&hull);
+
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.7, 1.1));
+    let transformed = local_aabb.transform_by(&m);
+    let fresh = bounding_volume::aabb(&hull, &m);
+
+    assert!(transformed.contains(&fresh));
+    assert!(transformed.half_extents().norm() > fresh.half_extents().norm());
+
+    // `transform_by` fits the transformed corners of the local AABB.
+    let (mins, maxs) = (local_aabb.mins(), local_aabb.maxs());
+    let mut fitted = bounding_volume::AABB::new_invalid();
+    for i in 0..8 {
+        let corner = Point3::new(
+            if i & 1 == 0 { mins.x } else { maxs.x },
+            if i & 2 == 0 { mins.y } else { maxs.y },
+            if i & 4 == 0 { mins.z } else { maxs.z },
+        );
+        fitted.take_point(m * corner);
+    }
+    assert_relative_eq!(transformed.mins(), fitted.mins(), epsilon = 1.0e-6);
+    assert_relative_eq!(transformed.maxs(), fitted.maxs(), epsilon = 1.0e-6);
+
+    // Translations do not loosen the AABB.
+    let t = Isometry3::translation(4.0, 5.0, 6.0);
+    let translated = local_aabb.transform_by(&t);
+    let fresh = bounding_volume::aabb(&hull, &t);
+    assert_relative_eq!(translated.mins(), fresh.mins(), epsilon = 1.0e-6);
+    assert_relative_eq!(translated.maxs(), fresh.maxs(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,40 @@
+use na::{Point3, Vector3};
+use ncollide3d::query;
+
+#[test]
+fn overlapping_balls_intersection_circle() {
+    let c1 = Point3::new(1.0, 2.0, 3.0);
+    let c2 = Point3::new(1.0, 2.0, 7.0);
+
+    // A 3-4-5 triangle: the circle is at distance 3 from c1 and has radius 4.
+    let (center, radius, normal) =
+        query::ball_ball_intersection_circle(&c1, 5.0, &c2, 17.0f64.sqrt()).unwrap();
+    assert_relative_eq!(center, Point3::new(1.0, 2.0, 6.0), epsilon = 1.0e-7);
+    assert_relative_eq!(radius, 4.0, epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector3::z(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn tangent_balls_intersection_circle() {
+    let c1 = Point3::origin();
+    let c2 = Point3::new(3.0, 0.0, 0.0);
+
+    let (center, radius, _) = query::ball_ball_intersection_circle(&c1, 1.0, &c2, 2.0).unwrap();
+    assert_eq!(radius, 0.0);
+    assert_relative_eq!(center, Point3::new(1.0, 0.0, 0.0));
+
+    // Tangent from the inside.
+    let (center, radius, _) = query::ball_ball_intersection_circle(&c1, 4.0, &c2, 1.0).unwrap();
+    assert_eq!(radius, 0.0);
+    assert_relative_eq!(center, Point3::new(4.0, 0.0, 0.0));
+}
+
+#[test]
+fn disjoint_or_nested_balls_have_no_intersection_circle() {
+    let c1 = Point3::origin();
+    let c2 = Point3::new(3.0, 0.0, 0.0);
+
+    assert!(query::ball_ball_intersection_circle(&c1, 1.0, &c2, 1.5).is_none());
+    assert!(query::ball_ball_intersection_circle(&c1, 5.0, &c2, 1.0).is_none());
+    assert!(query::ball_ball_intersection_circle(&c1, 1.0, &c1, 1.0).is_none());
+}
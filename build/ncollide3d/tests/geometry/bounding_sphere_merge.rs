@@ -0,0 +1,70 @@
+use na::Point3;
+use ncollide3d::bounding_volume::{BoundingSphere, BoundingVolume};
+
+#[test]
+fn merge_contained_sphere_is_unchanged() {
+    let large = BoundingSphere::new(Point3::new(0.3, -1.2, 0.7), 2.5);
+    let small = BoundingSphere::new(Point3::new(1.1, -0.4, 0.2), 0.9);
+    let tangent = BoundingSphere::new(Point3::new(0.3, -1.2, 2.2), 1.0);
+
+    assert_eq!(large.merged(&small), large);
+    assert_eq!(small.merged(&large), large);
+    assert_eq!(large.merged(&tangent), large);
+    assert_eq!(tangent.merged(&large), large);
+    assert_eq!(large.merged(&large), large);
+}
+
+#[test]
+fn merge_is_the_smallest_enclosing_sphere() {
+    let a = BoundingSphere::new(Point3::new(1.0, 2.0, 3.0), 1.0);
+    let b = BoundingSphere::new(Point3::new(1.0, 2.0, 7.0), 2.0);
+    let expected = BoundingSphere::new(Point3::new(1.0, 2.0, 5.5), 3.5);
+
+    for merged in &[a.merged(&b), b.merged(&a)] {
+        assert_relative_eq!(merged.center(), expected.center(), epsilon = 1.0e-12);
+        assert_relative_eq!(merged.radius(), expected.radius(), epsilon = 1.0e-12);
+        assert!(merged.loosened(1.0e-9).contains(&a));
+        assert!(merged.loosened(1.0e-9).contains(&b));
+    }
+
+    // Partially overlapping spheres.
+    let c = BoundingSphere::new(Point3::new(2.0, 2.0, 3.0), 1.5);
+    let merged = a.merged(&c);
+    assert_relative_eq!(merged.radius(), 1.75, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        *merged.center(),
+        Point3::new(1.75, 2.0, 3.0),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn take_point() {
+    let mut sphere = BoundingSphere::new(Point3::origin(), 1.0);
+
+    sphere.take_point(Point3::new(0.5, 0.0, 0.0));
+    assert_eq!(sphere, BoundingSphere::new(Point3::origin(), 1.0));
+
+    sphere.take_point(Point3::new(3.0, 0.0, 0.0));
+    assert_relative_eq!(sphere.radius(), 2.0, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        *sphere.center(),
+        Point3::new(1.0, 0.0, 0.0),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn loosen_and_tighten() {
+    let sphere = BoundingSphere::new(Point3::new(1.0, 0.0, -1.0), 1.0);
+    let loosened = sphere.loosened(0.5);
+
+    assert_eq!(*loosened.center(), *sphere.center());
+    assert_relative_eq!(loosened.radius(), 1.5);
+    assert!(loosened.contains(&sphere));
+    assert_eq!(loosened.tightened(0.5), sphere);
+
+    let mut loosened_in_place = sphere;
+    loosened_in_place.loosen(0.5);
+    assert_eq!(loosened_in_place, loosened);
+}
@@ -0,0 +1,59 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::partitioning::{BVH, BVT};
+use ncollide3d::query::visitors::BoundingVolumeInterferencesCollector;
+
+// A tree of unit boxes on a 4x4x4 grid, with the index of each box and a counter as leaf data.
+fn box_grid() -> BVT<(usize, u32), AABB<f64>> {
+    let mut leaves = Vec::new();
+
+    for i in 0..4 {
+        for j in 0..4 {
+            for k in 0..4 {
+                let mins = Point3::new(i as f64, j as f64, k as f64) * 2.0;
+                let aabb = AABB::new(mins, mins + Vector3::repeat(1.0));
+                leaves.push(((leaves.len(), 0), aabb));
+            }
+        }
+    }
+
+    BVT::new_balanced(leaves)
+}
+
+fn interferences(bvt: &BVT<(usize, u32), AABB<f64>>, aabb: &AABB<f64>) -> Vec<(usize, u32)> {
+    let mut out = Vec::new();
+    bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+        aabb, &mut out,
+    ));
+    out.sort();
+    out
+}
+
+#[test]
+fn bvt_leaf_data_update_keeps_queries() {
+    let mut bvt = box_grid();
+    let aabb = AABB::new(Point3::new(1.5, 1.5, 1.5), Point3::new(4.5, 4.5, 4.5));
+    let before = interferences(&bvt, &aabb);
+    let root_bv = bvt.root_bounding_volume().cloned();
+    assert_eq!(before.len(), 8);
+
+    for data in bvt.leaf_data_mut() {
+        data.1 += 1;
+    }
+
+    let old = bvt.set_leaf_data(0, (0, 10));
+    assert_eq!(old, (0, 1));
+
+    // The same leaves are found, with their updated data.
+    let after = interferences(&bvt, &aabb);
+    let expected: Vec<_> = before.iter().map(|(i, n)| (*i, n + 1)).collect();
+    assert_eq!(after, expected);
+    assert_eq!(bvt.root_bounding_volume().cloned(), root_bv);
+
+    let origin = AABB::new(Point3::origin(), Point3::new(0.5, 0.5, 0.5));
+    assert_eq!(interferences(&bvt, &origin), vec![(0, 10)]);
+    assert!(bvt
+        .leaves()
+        .iter()
+        .all(|leaf| leaf.data().0 == 0 || leaf.data().1 == 1));
+}
@@ -0,0 +1,90 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume, AABB};
+use ncollide3d::partitioning::BVT;
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::Triangle;
+
+// Layers of triangles parallel to the `xy` plane, with one triangle per cell of a 4x4 grid.
+fn triangle_grid() -> Vec<(Triangle<f32>, AABB<f32>)> {
+    let mut res = Vec::new();
+
+    for k in 0..5 {
+        for i in 0..4 {
+            for j in 0..4 {
+                let z = k as f32 * 2.0;
+                let a = Point3::new(i as f32, j as f32, z);
+                let b = a + Vector3::new(1.0, 0.0, 0.2);
+                let c = a + Vector3::new(0.0, 1.0, 0.2);
+                let tri = Triangle::new(a, b, c);
+                let aabb = bounding_volume::aabb(&tri, &Isometry3::identity());
+                res.push((tri, aabb));
+            }
+        }
+    }
+
+    res
+}
+
+#[test]
+fn bvt_ray_iter_front_to_back() {
+    let elements = triangle_grid();
+    let bvt = BVT::new_balanced(elements.clone());
+    let ray = Ray::new(Point3::new(0.1, 0.1, -1.0), Vector3::new(0.04, 0.03, 1.0));
+
+    let hits: Vec<_> = bvt.leaves_along_ray(&ray, 10.0).collect();
+    let expected = elements
+        .iter()
+        .filter(|(_, aabb)| {
+            aabb.toi_with_ray(&Isometry3::identity(), &ray, 10.0, true)
+                .is_some()
+        })
+        .count();
+    assert_eq!(hits.len(), expected);
+    assert!(hits.len() >= 5);
+
+    for w in hits.windows(2) {
+        assert!(w[0].1 <= w[1].1);
+    }
+
+    for (i, toi) in &hits {
+        let aabb = bvt.leaf(*i).bounding_volume();
+        let expected = aabb
+            .toi_with_ray(&Isometry3::identity(), &ray, 10.0, true)
+            .unwrap();
+        assert_relative_eq!(*toi, expected, epsilon = 1.0e-5);
+    }
+
+    // The triangles themselves are hit front-to-back.
+    let tri_hits: Vec<_> = hits
+        .iter()
+        .filter_map(|(i, _)| {
+            bvt.leaf(*i)
+                .data()
+                .toi_with_ray(&Isometry3::identity(), &ray, 10.0, true)
+        })
+        .collect();
+    assert_eq!(tri_hits.len(), 5);
+
+    for w in tri_hits.windows(2) {
+        assert!(w[0] < w[1]);
+    }
+
+    // Leaves entered after `max_toi` are ignored.
+    assert!(bvt.leaves_along_ray(&ray, 3.0).all(|(_, toi)| toi <= 3.0));
+    assert!(bvt.leaves_along_ray(&ray, 3.0).count() < hits.len());
+}
+
+#[test]
+fn bvt_ray_iter_origin_inside_aabb() {
+    let elements = triangle_grid();
+    let bvt = BVT::new_balanced(elements);
+    let ray = Ray::new(Point3::new(0.2, 0.2, 4.1), Vector3::z());
+
+    let (first, toi) = bvt.leaves_along_ray(&ray, 100.0).next().unwrap();
+    assert_eq!(toi, 0.0);
+    assert!(bvt
+        .leaf(first)
+        .bounding_volume()
+        .contains(&AABB::new(ray.origin, ray.origin)));
+    assert_eq!(bvt.leaves_along_ray(&ray, 100.0).count(), 3);
+}
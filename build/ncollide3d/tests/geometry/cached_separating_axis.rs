@@ -0,0 +1,50 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query::{self, Proximity};
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn proximity_with_cached_axis() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.5, 0.0);
+
+    let (prox, axis) = query::proximity_with_cached_axis(&m1, &cuboid, &m2, &cuboid, 0.1, None);
+    assert_eq!(prox, Proximity::Disjoint);
+    let axis = axis.expect("The GJK should yield a separating axis.");
+    assert!(axis.x > 0.0);
+
+    // The cached axis still separates the shapes.
+    let m2 = Isometry3::translation(2.9, 0.6, 0.0);
+    let (prox, new_axis) =
+        query::proximity_with_cached_axis(&m1, &cuboid, &m2, &cuboid, 0.1, Some(axis));
+    assert_eq!(prox, Proximity::Disjoint);
+    assert_eq!(new_axis, Some(axis));
+
+    // The cached axis no longer separates the shapes.
+    let m2 = Isometry3::translation(1.5, 0.0, 0.0);
+    let (prox, _) = query::proximity_with_cached_axis(&m1, &cuboid, &m2, &cuboid, 0.1, Some(axis));
+    assert_eq!(prox, query::proximity(&m1, &cuboid, &m2, &cuboid, 0.1));
+    assert_eq!(prox, Proximity::Intersecting);
+}
+
+#[test]
+fn contact_with_cached_axis() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.0, 3.0, 0.0);
+
+    let (contact, axis) = query::contact_with_cached_axis(&m1, &cuboid, &m2, &ball, 0.0, None);
+    assert!(contact.is_none());
+    let axis = axis.unwrap();
+
+    let m2 = Isometry3::translation(0.0, 1.2, 0.0);
+    let (contact, axis) =
+        query::contact_with_cached_axis(&m1, &cuboid, &m2, &ball, 0.0, Some(axis));
+    let contact = contact.expect("The shapes should be penetrating.");
+    let expected = query::contact(&m1, &cuboid, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, expected.depth, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.depth, 0.3, epsilon = 1.0e-6);
+    assert_eq!(axis, Some(contact.normal));
+    assert_relative_eq!(*contact.normal, Vector3::y(), epsilon = 1.0e-6);
+}
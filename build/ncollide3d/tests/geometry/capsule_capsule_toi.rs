@@ -0,0 +1,100 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query::{self, TOIStatus};
+use ncollide3d::shape::Capsule;
+
+fn check_toi(m1: Isometry3<f64>, vel1: Vector3<f64>, m2: Isometry3<f64>, vel2: Vector3<f64>) {
+    let c1 = Capsule::new(1.0, 0.5);
+    let c2 = Capsule::new(0.5, 0.25);
+
+    let exact = query::time_of_impact_capsule_capsule(&m1, &vel1, &c1, &m2, &vel2, &c2, 10.0, 0.0)
+        .expect("No impact found.");
+    let gjk =
+        query::time_of_impact_support_map_support_map(&m1, &vel1, &c1, &m2, &vel2, &c2, 10.0, 0.0)
+            .expect("No impact found by the generic algorithm.");
+
+    assert_eq!(exact.status, TOIStatus::Converged);
+    assert!(exact.toi.is_finite());
+    assert_relative_eq!(exact.toi, gjk.toi, epsilon = 1.0e-6);
+
+    // The witness points touch at the time of impact, with opposite normals.
+    let m1 = Isometry3::from_parts(
+        (m1.translation.vector + vel1 * exact.toi).into(),
+        m1.rotation,
+    );
+    let m2 = Isometry3::from_parts(
+        (m2.translation.vector + vel2 * exact.toi).into(),
+        m2.rotation,
+    );
+    assert_relative_eq!(m1 * exact.witness1, m2 * exact.witness2, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        m1 * *exact.normal1,
+        -(m2 * *exact.normal2),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn capsule_capsule_toi_crossing_axes() {
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.1, 0.2), Vector3::x() * 1.2);
+    check_toi(m1, Vector3::zeros(), m2, Vector3::new(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn capsule_capsule_toi_cap_on_cylinder() {
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.3, 0.0);
+    check_toi(
+        m1,
+        Vector3::new(0.5, 0.0, 0.0),
+        m2,
+        Vector3::new(-0.5, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn capsule_capsule_toi_cap_on_cap() {
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.1, 5.0, -0.1), Vector3::z() * 0.3);
+    check_toi(m1, Vector3::zeros(), m2, Vector3::new(0.0, -2.0, 0.0));
+}
+
+#[test]
+fn capsule_capsule_toi_parallel_axes() {
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.4, 1.0);
+    check_toi(m1, Vector3::zeros(), m2, Vector3::new(-1.0, 0.0, -0.4));
+}
+
+#[test]
+fn capsule_capsule_toi_penetrating_and_missing() {
+    let c = Capsule::new(1.0, 0.5);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.5, 0.0, 0.0);
+    let toi = query::time_of_impact_capsule_capsule(
+        &m1,
+        &Vector3::zeros(),
+        &c,
+        &m2,
+        &Vector3::x(),
+        &c,
+        1.0,
+        0.0,
+    )
+    .unwrap();
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+
+    let m2 = Isometry3::translation(3.0, 0.0, 0.0);
+    assert!(query::time_of_impact_capsule_capsule(
+        &m1,
+        &Vector3::zeros(),
+        &c,
+        &m2,
+        &Vector3::y(),
+        &c,
+        10.0,
+        0.0,
+    )
+    .is_none());
+}
@@ -0,0 +1,36 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn cuboid_in_corner() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m = Isometry3::translation(1.5, 1.2, 3.0);
+    let planes = [
+        (Point3::origin(), Vector3::x_axis()),
+        (Point3::origin(), Vector3::y_axis()),
+        (Point3::origin(), Vector3::z_axis()),
+    ];
+
+    let (i, clearance) = query::clearance_from_planes(&m, &cuboid, &planes);
+    assert_eq!(i, 1);
+    assert_relative_eq!(clearance, 0.2, epsilon = 1.0e-7);
+}
+
+#[test]
+fn ball_penetrating_tilted_wall() {
+    let ball = Ball::new(1.0);
+    let m = Isometry3::translation(0.0, 0.0, 0.0);
+    let planes = [
+        (Point3::new(0.0, -5.0, 0.0), Vector3::y_axis()),
+        (
+            Point3::new(0.5, 0.0, 0.0),
+            Unit::new_normalize(Vector3::new(-1.0, 1.0, 0.0)),
+        ),
+    ];
+
+    let (i, clearance) = query::clearance_from_planes(&m, &ball, &planes);
+    let expected = 0.5 / 2.0f64.sqrt() - 1.0;
+    assert_eq!(i, 1);
+    assert_relative_eq!(clearance, expected, epsilon = 1.0e-7);
+}
@@ -0,0 +1,68 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query::{self, ClosestPoints};
+use ncollide3d::shape::{Ball, Capsule, Cuboid, Shape};
+
+fn assert_well_defined_contact(
+    g1: &dyn Shape<f64>,
+    g2: &dyn Shape<f64>,
+    expected_depth: f64,
+) -> ncollide3d::query::Contact<f64> {
+    let m = Isometry3::translation(1.0, -2.0, 3.0);
+
+    assert_eq!(query::distance(&m, g1, &m, g2), 0.0);
+    assert_eq!(
+        query::closest_points(&m, g1, &m, g2, 1.0),
+        ClosestPoints::Intersecting
+    );
+
+    let contact = query::contact(&m, g1, &m, g2, 0.0).expect("The shapes should be in contact.");
+    assert!(contact.world1.coords.iter().all(|e| e.is_finite()));
+    assert!(contact.world2.coords.iter().all(|e| e.is_finite()));
+    assert!(contact.normal.iter().all(|e| e.is_finite()));
+    assert_relative_eq!(contact.depth, expected_depth, epsilon = 1.0e-6);
+
+    // The result must be deterministic.
+    assert_eq!(query::contact(&m, g1, &m, g2, 0.0), Some(contact));
+    contact
+}
+
+#[test]
+fn coincident_balls() {
+    let ball = Ball::new(1.0);
+    let _ = assert_well_defined_contact(&ball, &ball, 2.0);
+}
+
+#[test]
+fn coincident_cuboids() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 1.0, 3.0));
+    let contact = assert_well_defined_contact(&cuboid, &cuboid, 2.0);
+    assert_relative_eq!(contact.normal.y.abs(), 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn nested_cuboids() {
+    let outer = Cuboid::new(Vector3::new(2.0, 2.0, 2.0));
+    let inner = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let _ = assert_well_defined_contact(&outer, &inner, 3.0);
+    let _ = assert_well_defined_contact(&inner, &outer, 3.0);
+}
+
+#[test]
+fn coincident_capsules() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let contact = assert_well_defined_contact(&capsule, &capsule, 1.0);
+    assert_relative_eq!(contact.normal.y, 0.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn rotated_cuboids_with_degenerate_simplex() {
+    // The GJK ends on a degenerate simplex here, which must still be handled by the EPA.
+    let g1 = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+    let g2 = Cuboid::new(Vector3::repeat(0.5));
+    let m1 = Isometry3::new(Vector3::zeros(), Vector3::z() * std::f64::consts::FRAC_PI_6);
+    let m2 = m1 * Isometry3::translation(1.3, 0.0, 0.0);
+
+    let contact = query::contact(&m1, &g1, &m2, &g2, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.2, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, m1 * Vector3::x(), epsilon = 1.0e-6);
+}
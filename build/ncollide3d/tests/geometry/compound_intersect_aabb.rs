@@ -0,0 +1,32 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::shape::{Compound, Cuboid, ShapeHandle};
+
+#[test]
+fn compound_intersect_aabb() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::repeat(0.25)));
+    let mut shapes = Vec::new();
+
+    // 1000 cuboids laid out on a 10x10x10 grid with a spacing of 2.
+    for i in 0..10 {
+        for j in 0..10 {
+            for k in 0..10 {
+                let pos = Isometry3::translation(i as f64 * 2.0, j as f64 * 2.0, k as f64 * 2.0);
+                shapes.push((pos, cuboid.clone()));
+            }
+        }
+    }
+
+    let compound = Compound::new(shapes);
+    let aabb = AABB::new(Point3::new(3.0, 3.0, 3.0), Point3::new(7.0, 5.0, 5.0));
+    let mut found = compound.intersect_aabb(&aabb);
+    found.sort();
+
+    // Cuboids centered at x in {4, 6}, y = 4 and z = 4.
+    assert_eq!(found, vec![222, 322]);
+
+    for i in found {
+        let center = compound.shapes()[i].0.translation.vector;
+        assert!(center.x >= 3.0 && center.x <= 7.0);
+    }
+}
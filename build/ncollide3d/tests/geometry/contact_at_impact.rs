@@ -0,0 +1,63 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::DefaultContactDispatcher;
+use ncollide3d::query::{self, ContactPrediction, DefaultTOIDispatcher};
+use ncollide3d::shape::Cuboid;
+
+#[test]
+fn box_landing_flat_on_ground() {
+    let ground = Cuboid::new(Vector3::new(10.0f64, 1.0, 10.0));
+    let cube = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let m_ground = Isometry3::translation(0.0, -1.0, 0.0);
+    let m_start = Isometry3::translation(0.0, 3.0, 0.0);
+    let m_end = Isometry3::translation(0.0, -1.0, 0.0);
+    let prediction = ContactPrediction::new(0.01, 0.1, 0.1);
+
+    let (toi, manifold) = query::contact_at_impact(
+        &DefaultTOIDispatcher,
+        &DefaultContactDispatcher::new(),
+        &m_ground,
+        &m_ground,
+        &ground,
+        &m_start,
+        &m_end,
+        &cube,
+        &prediction,
+    )
+    .unwrap()
+    .expect("The box should hit the ground.");
+
+    assert_relative_eq!(toi, 0.625, epsilon = 1.0e-3);
+    assert_eq!(manifold.len(), 4);
+
+    for c in manifold.contacts() {
+        assert!(c.contact.depth.abs() <= 1.0e-3);
+        assert_relative_eq!(
+            c.contact.normal.into_inner(),
+            Vector3::y(),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn contact_at_impact_no_hit() {
+    let g = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let m1 = Isometry3::translation(0.0, 0.0, 0.0);
+    let m2_start = Isometry3::translation(3.0, 0.0, 0.0);
+    let m2_end = Isometry3::translation(3.0, 5.0, 0.0);
+
+    let res = query::contact_at_impact(
+        &DefaultTOIDispatcher,
+        &DefaultContactDispatcher::new(),
+        &m1,
+        &m1,
+        &g,
+        &m2_start,
+        &m2_end,
+        &g,
+        &ContactPrediction::new(0.01, 0.1, 0.1),
+    )
+    .unwrap();
+
+    assert!(res.is_none());
+}
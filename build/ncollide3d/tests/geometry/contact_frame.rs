@@ -0,0 +1,44 @@
+use na::{Point3, Unit, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, ContactTrackingMode};
+
+#[test]
+fn contact_frame_is_aligned_with_normal() {
+    let normal = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    let tangent = Vector3::new(-1.0, 1.0, 0.0).normalize();
+    let bitangent = Vector3::z();
+    let center = Point3::new(1.0, 2.0, 3.0);
+
+    let mut manifold = ContactManifold::<f64>::new();
+    manifold.set_tracking_mode(ContactTrackingMode::DistanceBased(0.0));
+
+    for (i, (u, v)) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+        .iter()
+        .enumerate()
+    {
+        let depth = 0.1 * (i + 1) as f64;
+        let mid = center + tangent * *u + bitangent * *v;
+        let world1 = mid + *normal * (depth * 0.5);
+        let world2 = mid - *normal * (depth * 0.5);
+        let contact = Contact::new(world1, world2, normal, depth);
+        let _ = manifold.push(contact, ContactKinematic::new(), world1, None, None);
+    }
+
+    let (frame, contacts) = manifold.in_contact_frame();
+    assert_eq!(contacts.len(), 4);
+    assert_relative_eq!(frame.translation.vector, center.coords, epsilon = 1.0e-7);
+    assert_relative_eq!(frame * Vector3::z(), *normal, epsilon = 1.0e-7);
+
+    for c in &contacts {
+        assert_relative_eq!(c.point1.z, c.depth * 0.5, epsilon = 1.0e-7);
+        assert_relative_eq!(c.point2.z, -c.depth * 0.5, epsilon = 1.0e-7);
+        assert_relative_eq!(c.point1.xy().coords.norm(), 2.0f64.sqrt(), epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn empty_manifold_contact_frame() {
+    let manifold = ContactManifold::<f64>::new();
+    let (frame, contacts) = manifold.in_contact_frame();
+    assert!(contacts.is_empty());
+    assert_eq!(frame, na::Isometry3::identity());
+}
@@ -0,0 +1,53 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, VelocityHint};
+
+fn manifold() -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+    let world1 = Point3::new(1.0, 0.0, 0.0);
+    let world2 = Point3::new(0.9, 0.0, 0.0);
+    let contact = Contact::new(world1, world2, Vector3::x_axis(), 0.1);
+    let _ = manifold.push(contact, ContactKinematic::new(), world1, None, None);
+    manifold
+}
+
+#[test]
+fn approach_speed_without_hints() {
+    assert_eq!(manifold().approach_speed(), 0.0);
+
+    let mut empty = ContactManifold::<f64>::new();
+    empty.set_velocity_hints(Some((
+        VelocityHint::linear(Vector3::x()),
+        VelocityHint::linear(-Vector3::x()),
+    )));
+    assert_eq!(empty.approach_speed(), 0.0);
+}
+
+#[test]
+fn approach_speed_with_linear_hints() {
+    let mut manifold = manifold();
+    manifold.set_velocity_hints(Some((
+        VelocityHint::linear(Vector3::new(2.0, 5.0, 0.0)),
+        VelocityHint::linear(Vector3::new(-1.0, 0.0, 3.0)),
+    )));
+    assert_relative_eq!(manifold.approach_speed(), 3.0);
+
+    manifold.set_velocity_hints(Some((
+        VelocityHint::linear(-Vector3::x()),
+        VelocityHint::linear(Vector3::zeros()),
+    )));
+    assert_relative_eq!(manifold.approach_speed(), -1.0);
+}
+
+#[test]
+fn approach_speed_with_angular_hints() {
+    let mut manifold = manifold();
+    // The first object spins around the z axis, so its contact point, located below its center,
+    // moves toward the second object.
+    let spinning = VelocityHint::new(
+        Vector3::zeros(),
+        Vector3::z() * 2.0,
+        Point3::new(1.0, 1.0, 0.0),
+    );
+    manifold.set_velocity_hints(Some((spinning, VelocityHint::linear(Vector3::zeros()))));
+    assert_relative_eq!(manifold.approach_speed(), 2.0);
+}
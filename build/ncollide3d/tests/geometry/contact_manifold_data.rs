@@ -0,0 +1,37 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold};
+
+fn contact_at(pt: Point3<f64>) -> Contact<f64> {
+    Contact::new(pt, pt, Vector3::y_axis(), 0.0)
+}
+
+#[test]
+fn contact_data_survives_matching() {
+    let mut manifold = ContactManifold::<f64, f64>::new();
+    let pt1 = Point3::new(1.0, 0.0, 0.0);
+    let pt2 = Point3::new(-1.0, 0.0, 0.0);
+
+    let _ = manifold.push(contact_at(pt1), ContactKinematic::new(), pt1, None, None);
+    let _ = manifold.push(contact_at(pt2), ContactKinematic::new(), pt2, None, None);
+
+    for c in manifold.contacts_mut() {
+        c.data = c.contact.world1.x * 10.0;
+    }
+
+    manifold.save_cache_and_clear();
+
+    // The first contact moved slightly, the second one is replaced by a new one.
+    let pt1 = Point3::new(1.001, 0.0, 0.0);
+    let pt3 = Point3::new(0.0, 0.0, 5.0);
+    assert!(manifold.push(contact_at(pt1), ContactKinematic::new(), pt1, None, None));
+    assert!(!manifold.push(contact_at(pt3), ContactKinematic::new(), pt3, None, None));
+
+    assert_eq!(manifold.len(), 2);
+    for c in manifold.contacts() {
+        if c.contact.world1 == pt1 {
+            assert_eq!(c.data, 10.0);
+        } else {
+            assert_eq!(c.data, 0.0);
+        }
+    }
+}
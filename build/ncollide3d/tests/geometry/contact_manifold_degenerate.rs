@@ -0,0 +1,51 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, ContactTrackingMode};
+
+fn manifold_with(pts: &[(Point3<f64>, f64)]) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+
+    for (pt, depth) in pts {
+        let contact = Contact::new(*pt, *pt, Vector3::y_axis(), *depth);
+        let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None);
+    }
+
+    manifold
+}
+
+#[test]
+fn degenerate_manifolds() {
+    let square = [
+        (Point3::new(0.0, 0.0, 0.0), 0.1),
+        (Point3::new(1.0, 0.0, 0.0), 0.1),
+        (Point3::new(1.0, 0.0, 1.0), 0.1),
+        (Point3::new(0.0, 0.0, 1.0), 0.1),
+    ];
+    assert!(!manifold_with(&square).is_degenerate(1.0e-3));
+
+    let collinear = [
+        (Point3::new(0.0, 0.0, 0.0), 0.1),
+        (Point3::new(1.0, 0.0, 1.0e-5), 0.1),
+        (Point3::new(2.0, 0.0, 0.0), 0.1),
+    ];
+    assert!(manifold_with(&collinear).is_degenerate(1.0e-3));
+    assert!(!manifold_with(&collinear[..2]).is_degenerate(1.0e-3));
+
+    let mut manifold = ContactManifold::<f64>::new();
+    manifold.set_tracking_mode(ContactTrackingMode::DistanceBased(0.0));
+    for (pt, depth) in &square {
+        let contact = Contact::new(*pt, *pt, Vector3::y_axis(), *depth);
+        let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None);
+    }
+    let pt = Point3::new(1.0, 0.0, 1.0e-4);
+    let contact = Contact::new(pt, pt, Vector3::y_axis(), 0.5);
+    let _ = manifold.push(contact, ContactKinematic::new(), pt, None, None);
+    assert_eq!(manifold.len(), 5);
+    assert!(manifold.is_degenerate(1.0e-3));
+
+    // The coincident contacts are merged into the deepest one.
+    assert_eq!(manifold.sanitize(1.0e-3), 1);
+    assert_eq!(manifold.len(), 4);
+    assert_eq!(manifold.contacts().count(), 4);
+    assert!(!manifold.is_degenerate(1.0e-3));
+    assert_eq!(manifold.deepest_contact().unwrap().contact.world1, pt);
+}
@@ -0,0 +1,76 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, ContactTrackingMode};
+
+fn manifold_with_contacts(points: &[Point3<f64>]) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::<f64>::new();
+    manifold.set_tracking_mode(ContactTrackingMode::DistanceBased(0.0));
+
+    for pt in points {
+        let depth = 2.0 + pt.x;
+        let world2 = *pt - Vector3::z() * depth;
+        let contact = Contact::new(*pt, world2, -Vector3::z_axis(), depth);
+        let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None);
+    }
+
+    manifold.canonicalize();
+    manifold
+}
+
+#[test]
+fn canonical_order_does_not_depend_on_insertion_order() {
+    let points = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.5, 1.0, 0.0),
+        Point3::new(-1.0, 0.5, 0.0),
+        Point3::new(-0.5, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.7, -0.7, 0.0),
+    ];
+    let expected: Vec<_> = manifold_with_contacts(&points)
+        .contacts()
+        .map(|c| c.contact.world1)
+        .collect();
+    assert_eq!(expected.len(), points.len());
+
+    let mut permuted = points;
+    for i in 0..points.len() {
+        permuted.rotate_left(1);
+        permuted.swap(0, i);
+
+        let manifold = manifold_with_contacts(&permuted);
+        let result: Vec<_> = manifold.contacts().map(|c| c.contact.world1).collect();
+        assert_eq!(result, expected);
+        assert_eq!(
+            manifold.deepest_contact().unwrap().contact.world1,
+            points[0]
+        );
+    }
+}
+
+#[test]
+fn canonicalize_preserves_contact_tracking() {
+    let points = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(-1.0, 1.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+    ];
+    let mut manifold = ContactManifold::<f64>::new();
+    manifold.set_tracking_mode(ContactTrackingMode::DistanceBased(0.1));
+
+    for pt in points.iter().rev() {
+        let contact = Contact::new(*pt, *pt, -Vector3::z_axis(), 0.0);
+        let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None);
+    }
+
+    manifold.canonicalize();
+    manifold.save_cache_and_clear();
+
+    // The same contacts, slightly moved, are matched with the cached ones.
+    for pt in &points {
+        let pt = *pt + Vector3::x() * 0.01;
+        let contact = Contact::new(pt, pt, -Vector3::z_axis(), 0.0);
+        assert!(manifold.push(contact, ContactKinematic::new(), pt, None, None));
+    }
+
+    assert_eq!(manifold.len(), 3);
+}
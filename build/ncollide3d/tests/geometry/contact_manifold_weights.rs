@@ -0,0 +1,55 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, ContactTrackingMode};
+
+fn manifold_with_contacts(points: &[Point3<f64>]) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::<f64>::new();
+    manifold.set_tracking_mode(ContactTrackingMode::DistanceBased(0.0));
+
+    for pt in points {
+        let world2 = *pt - Vector3::z() * 0.1;
+        let contact = Contact::new(*pt, world2, -Vector3::z_axis(), 0.1);
+        let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None);
+    }
+
+    manifold.update_weights();
+    manifold
+}
+
+#[test]
+fn central_contacts_have_larger_weights() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+    let mut points: Vec<_> = corners
+        .iter()
+        .map(|(u, v)| center + Vector3::new(*u, *v, 0.0))
+        .collect();
+    points.push(center);
+
+    let manifold = manifold_with_contacts(&points);
+    let mut total = 0.0;
+
+    for c in manifold.contacts() {
+        if c.contact.world1 == center {
+            assert_relative_eq!(c.contact.weight, 1.0 / 0.6, epsilon = 1.0e-7);
+        } else {
+            assert_relative_eq!(c.contact.weight, 0.5 / 0.6, epsilon = 1.0e-7);
+        }
+
+        total += c.contact.weight;
+    }
+
+    assert_relative_eq!(total, 5.0, epsilon = 1.0e-7);
+
+    // Without the central contact, all the contacts are equally central.
+    let manifold = manifold_with_contacts(&points[..4]);
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.weight, 1.0, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn single_contact_has_unit_weight() {
+    let manifold = manifold_with_contacts(&[Point3::new(1.0, 2.0, 3.0)]);
+    assert_eq!(manifold.len(), 1);
+    assert_eq!(manifold.contacts().next().unwrap().contact.weight, 1.0);
+}
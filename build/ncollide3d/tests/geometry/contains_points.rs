@@ -0,0 +1,51 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Capsule, Cuboid, ShapeHandle};
+
+// The spacing avoids points lying exactly on the boundaries, where rounding errors may differ.
+fn grid() -> Vec<Point3<f64>> {
+    let mut pts = Vec::new();
+
+    for i in -10..=10 {
+        for j in -10..=10 {
+            for k in -10..=10 {
+                pts.push(Point3::new(i as f64, j as f64, k as f64) * 0.2537);
+            }
+        }
+    }
+
+    pts
+}
+
+fn check_contains_points<G: PointQuery<f64> + ?Sized>(shape: &G, m: &Isometry3<f64>) {
+    let pts = grid();
+    let mut out = vec![false; pts.len()];
+    shape.contains_points(m, &pts, &mut out);
+
+    for (pt, res) in pts.iter().zip(out.iter()) {
+        assert_eq!(*res, shape.contains_point(m, pt));
+    }
+
+    // Make sure the test is not trivial.
+    assert!(out.iter().any(|res| *res));
+    assert!(out.iter().any(|res| !*res));
+}
+
+#[test]
+fn contains_points_matches_contains_point() {
+    let m = Isometry3::new(Vector3::new(0.3, -0.2, 0.1), Vector3::new(0.2, 0.5, -0.4));
+
+    check_contains_points(&Ball::new(1.3), &m);
+    check_contains_points(&Cuboid::new(Vector3::new(1.0, 0.6, 1.7)), &m);
+    check_contains_points(&Capsule::new(0.8, 0.5), &m);
+
+    let shape = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 1.5, 1.0)));
+    check_contains_points(&*shape, &m);
+}
+
+#[test]
+#[should_panic]
+fn contains_points_different_lengths() {
+    let mut out = vec![false; 1];
+    Ball::new(1.0).contains_points(&Isometry3::identity(), &grid(), &mut out);
+}
@@ -0,0 +1,111 @@
+use na::{Isometry3, Point3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{ConvexHull, TriMesh};
+use ncollide3d::transformation;
+
+// The L-shaped polygon `(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)` extruded from `z = 0` to
+// `z = 1`.
+fn l_shape() -> TriMesh<f64> {
+    let polygon = [
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 2.0),
+    ];
+    let n = polygon.len();
+    let mut points: Vec<_> = polygon
+        .iter()
+        .map(|&(x, y)| Point3::new(x, y, 0.0))
+        .collect();
+    points.extend(polygon.iter().map(|&(x, y)| Point3::new(x, y, 1.0)));
+
+    let mut indices = Vec::new();
+
+    // Both caps, as a fan around the reflex vertex 3.
+    for &(a, b) in &[(4, 5), (5, 0), (0, 1), (1, 2)] {
+        indices.push(Point3::new(3, b, a));
+        indices.push(Point3::new(3 + n, a + n, b + n));
+    }
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        indices.push(Point3::new(i, j, j + n));
+        indices.push(Point3::new(i, j + n, i + n));
+    }
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn l_shape_decomposition_covers_the_mesh() {
+    let mesh = l_shape();
+    let compound = transformation::convex_decomposition(&mesh, 8, 0.01).unwrap();
+    let parts = compound.shapes();
+
+    assert!(parts.len() >= 2 && parts.len() <= 8);
+    assert!(parts
+        .iter()
+        .all(|(_, part)| part.as_shape::<ConvexHull<f64>>().is_some()));
+
+    let m = Isometry3::identity();
+
+    for i in 0..20 {
+        for j in 0..20 {
+            for k in 1..5 {
+                let pt = Point3::new(
+                    (i as f64 + 0.5) / 10.0,
+                    (j as f64 + 0.5) / 10.0,
+                    k as f64 / 5.0,
+                );
+                let inside_l = pt.x < 1.0 || pt.y < 1.0;
+                let contained = parts
+                    .iter()
+                    .any(|(pos, part)| part.contains_point(&(m * pos), &pt));
+
+                // The union of the parts approximates the L shape.
+                if inside_l {
+                    assert!(contained, "{:?} is not covered", pt);
+                } else if pt.x > 1.1 && pt.y > 1.1 {
+                    assert!(!contained, "{:?} is covered", pt);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn single_hull_decomposition() {
+    let compound = transformation::convex_decomposition(&l_shape(), 1, 0.01).unwrap();
+    assert_eq!(compound.shapes().len(), 1);
+}
+
+#[test]
+fn decomposition_of_mesh_with_ridges_along_voxel_lines() {
+    // An octahedron with its vertices on the coordinate axes. Some lines of voxel centers only
+    // graze its edges, e.g., the line `y = -31/32, z = -1/32` along `x`.
+    let points = vec![
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let mut indices = Vec::new();
+
+    for &(x, y, z) in &[(0, 2, 4), (0, 4, 3), (0, 3, 5), (0, 5, 2)] {
+        indices.push(Point3::new(x, y, z));
+        indices.push(Point3::new(1, z, y));
+    }
+
+    let mesh = TriMesh::new(points, indices, None);
+    let compound = transformation::convex_decomposition(&mesh, 1, 1.0).unwrap();
+    let (pos, hull) = &compound.shapes()[0];
+    let m = Isometry3::identity() * pos;
+
+    assert!(hull.contains_point(&m, &Point3::new(0.1, -0.2, 0.3)));
+    assert!(!hull.contains_point(&m, &Point3::new(0.9, -0.9, -0.03)));
+    assert!(!hull.contains_point(&m, &Point3::new(-0.6, 0.6, 0.6)));
+}
@@ -0,0 +1,48 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::ConvexHull;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+// Deterministic pseudo-random points in the cube `[-scale, scale]^3`.
+fn random_points(n: usize, scale: f64, seed: u64) -> Vec<Point3<f64>> {
+    let mut rng = IsaacRng::seed_from_u64(seed);
+    let mut next = move || rng.gen_range(-scale, scale);
+
+    (0..n)
+        .map(|_| Point3::new(next(), next(), next()))
+        .collect()
+}
+
+#[test]
+fn contains_point_agrees_with_projection() {
+    let hull = ConvexHull::try_from_points(&random_points(50, 1.0, 0)).unwrap();
+    let m = Isometry3::new(Vector3::new(0.3, -0.2, 1.0), Vector3::new(0.5, 0.1, -0.7));
+    let mut num_inside = 0;
+
+    for pt in random_points(2000, 1.5, 1) {
+        let pt = m * pt;
+        let proj = hull.project_point(&m, &pt, false);
+
+        // Skip the points too close to the boundary for the projection to be reliable.
+        if na::distance(&proj.point, &pt) < 1.0e-6 {
+            continue;
+        }
+
+        assert_eq!(hull.contains_point(&m, &pt), proj.is_inside, "{:?}", pt);
+        num_inside += proj.is_inside as usize;
+    }
+
+    // Both cases have been tested.
+    assert!(num_inside > 100 && num_inside < 1900);
+}
+
+#[test]
+fn contains_vertices() {
+    let hull = ConvexHull::try_from_points(&random_points(50, 1.0, 0)).unwrap();
+
+    for pt in hull.points() {
+        assert!(hull.contains_local_point(&(pt * 0.999)));
+        assert!(!hull.contains_local_point(&(pt * 1.001)));
+    }
+}
@@ -0,0 +1,81 @@
+use na::{Point3, Vector3};
+use ncollide3d::shape::{ConvexHull, SupportMap};
+
+fn cube() -> ConvexHull<f64> {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        points.push(Point3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        ));
+    }
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+#[test]
+fn insert_inner_point() {
+    let mut hull = cube();
+    let expected = hull.clone();
+
+    assert!(!hull.insert_point(Point3::new(0.5, -0.2, 0.3)));
+    assert!(!hull.insert_point(Point3::new(1.0, 1.0, 1.0)));
+    assert_eq!(hull, expected);
+}
+
+#[test]
+fn insert_outer_point() {
+    let mut hull = cube();
+
+    assert!(hull.insert_point(Point3::new(0.0, 0.0, 3.0)));
+    hull.check_geometry();
+    assert_eq!(hull.points().len(), 9);
+    assert_eq!(
+        hull.local_support_point(&Vector3::z()),
+        Point3::new(0.0, 0.0, 3.0)
+    );
+
+    // This point hides the corner (1, 1, 1) of the cube.
+    assert!(hull.insert_point(Point3::new(3.0, 3.0, 3.0)));
+    hull.check_geometry();
+    assert_eq!(hull.points().len(), 9);
+}
+
+#[test]
+fn insert_coplanar_point() {
+    let mut hull = cube();
+
+    assert!(hull.insert_point(Point3::new(2.0, 0.0, 1.0)));
+    hull.check_geometry();
+    assert_eq!(hull.points().len(), 9);
+}
+
+#[test]
+fn insert_points_matches_full_rebuild() {
+    let mut hull = cube();
+    let mut all_points = hull.points().to_vec();
+    let mut seed = 12345u32;
+    let mut rand = || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        ((seed >> 8) as f64 / (1 << 24) as f64) * 2.0 - 1.0
+    };
+
+    for _ in 0..50 {
+        let dir = Vector3::new(rand(), rand(), rand()).normalize();
+        let p = Point3::from(dir * 2.0);
+
+        let _ = hull.insert_point(p);
+        hull.check_geometry();
+        all_points.push(p);
+    }
+
+    // The points of the full rebuild are slightly perturbed when computing the convex hull.
+    let expected = ConvexHull::try_from_points(&all_points).unwrap();
+    assert_eq!(hull.points().len(), expected.points().len());
+
+    for p in expected.points() {
+        assert!(hull.points().iter().any(|q| na::distance(p, q) < 1.0e-6));
+    }
+}
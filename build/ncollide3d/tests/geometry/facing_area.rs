@@ -0,0 +1,144 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cone, ConvexHull, Cuboid, Cylinder, Shape, TriMesh};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use std::f64::consts::PI;
+
+#[test]
+fn facing_area_analytic_shapes() {
+    let m = Isometry3::identity();
+    let diag = Unit::new_normalize(Vector3::new(1.0, 1.0, 1.0));
+
+    let ball = Ball::new(2.0);
+    assert_relative_eq!(query::facing_area(&m, &ball, &diag), 4.0 * PI);
+
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(query::facing_area(&m, &cuboid, &Vector3::x_axis()), 24.0);
+    assert_relative_eq!(query::facing_area(&m, &cuboid, &-Vector3::z_axis()), 8.0);
+    assert_relative_eq!(
+        query::facing_area(&m, &cuboid, &diag),
+        (24.0 + 12.0 + 8.0) / 3.0f64.sqrt(),
+        epsilon = 1.0e-7
+    );
+
+    // A rotated cuboid facing one of its faces toward the direction.
+    let rot = Isometry3::rotation(Vector3::z() * PI / 2.0);
+    assert_relative_eq!(
+        query::facing_area(&rot, &cuboid, &Vector3::y_axis()),
+        24.0,
+        epsilon = 1.0e-7
+    );
+
+    let cylinder = Cylinder::new(1.0, 0.5);
+    assert_relative_eq!(
+        query::facing_area(&m, &cylinder, &Vector3::y_axis()),
+        PI * 0.25
+    );
+    assert_relative_eq!(query::facing_area(&m, &cylinder, &Vector3::x_axis()), 2.0);
+}
+
+#[test]
+fn facing_area_polyhedral_shapes() {
+    let dir = Unit::new_normalize(Vector3::new(1.0, -2.0, 0.5));
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let expected = query::facing_area(&m, &cuboid, &dir);
+
+    let mesh = TriMesh::from(procedural::unit_cuboid());
+    assert_relative_eq!(
+        query::facing_area(&m, &mesh, &dir),
+        expected,
+        epsilon = 1.0e-7
+    );
+
+    let hull = ConvexHull::try_from_points(mesh.points()).unwrap();
+    assert_relative_eq!(
+        query::facing_area(&m, &hull, &dir),
+        expected,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn facing_area_sampled_silhouette() {
+    let cone = Cone::new(1.0, 1.0);
+    let area = query::facing_area(&Isometry3::identity(), &cone, &Vector3::y_axis());
+    assert_relative_eq!(area, PI, epsilon = 1.0e-2);
+}
+
+#[test]
+fn swept_frontal_area_translation_only() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let start = Isometry3::translation(1.0, 0.0, 0.0);
+    let end = Isometry3::translation(5.0, 0.0, 0.0);
+    assert_relative_eq!(query::swept_frontal_area(&cuboid, &start, &end), 24.0);
+    assert_relative_eq!(query::swept_frontal_area(&cuboid, &end, &start), 24.0);
+
+    let ball = Ball::new(2.0);
+    let end = Isometry3::new(Vector3::new(0.0, 3.0, 4.0), Vector3::x() * PI / 3.0);
+    // The rotation only enlarges the result by the safety margin.
+    let area = query::swept_frontal_area(&ball, &start, &end);
+    assert!(area >= 4.0 * PI);
+    assert!(area <= 4.0 * PI * 1.04);
+
+    // No motion.
+    assert_eq!(query::swept_frontal_area(&cuboid, &start, &start), 0.0);
+}
+
+#[test]
+fn swept_frontal_area_rotating_cuboid() {
+    // The frontal area is 4 * (6 * cos(a) + 3 * sin(a)) where `a` is the rotation angle. It is
+    // maximal for `a = atan(0.5)`, between the initial and final orientations.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let start = Isometry3::identity();
+    let end = Isometry3::new(Vector3::x() * 10.0, Vector3::z() * PI / 2.0);
+    let area = query::swept_frontal_area(&cuboid, &start, &end);
+
+    assert!(area >= 4.0 * 45.0f64.sqrt());
+    assert!(area <= 4.0 * 45.0f64.sqrt() * 1.06);
+}
+
+#[test]
+fn swept_frontal_area_bounds_dense_sampling() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let points: Vec<_> = (0..20)
+        .map(|_| {
+            Point3::new(
+                rng.gen_range(-1.0, 1.0),
+                rng.gen_range(-2.0, 2.0),
+                rng.gen_range(-0.5, 0.5),
+            )
+        })
+        .collect();
+    let shapes: Vec<Box<dyn Shape<f64>>> = vec![
+        Box::new(Cuboid::new(Vector3::new(0.2, 1.0, 3.0))),
+        Box::new(Cylinder::new(2.0, 0.3)),
+        Box::new(ConvexHull::try_from_points(&points).unwrap()),
+    ];
+
+    for _ in 0..20 {
+        let start = Isometry3::new(
+            Vector3::new(rng.gen_range(-1.0, 1.0), 0.0, 0.0),
+            Vector3::new(rng.gen_range(-PI, PI), rng.gen_range(-PI, PI), 0.0),
+        );
+        let end = Isometry3::new(
+            Vector3::new(rng.gen_range(-1.0, 1.0), 5.0, rng.gen_range(-1.0, 1.0)),
+            Vector3::new(0.0, rng.gen_range(-PI, PI), rng.gen_range(-PI, PI)),
+        );
+        let dir = Unit::new_normalize(end.translation.vector - start.translation.vector);
+
+        for shape in &shapes {
+            let swept = query::swept_frontal_area(shape.as_ref(), &start, &end);
+            let dense = (0..=1000)
+                .map(|i| {
+                    let m = start.lerp_slerp(&end, i as f64 / 1000.0);
+                    query::facing_area(&m, shape.as_ref(), &dir)
+                })
+                .fold(0.0, f64::max);
+
+            assert!(swept >= dense, "{} < {}", swept, dense);
+        }
+    }
+}
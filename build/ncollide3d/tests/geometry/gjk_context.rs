@@ -0,0 +1,96 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query;
+use ncollide3d::query::algorithms::gjk::GJKContext;
+#[cfg(feature = "diagnostics")]
+use ncollide3d::shape::Cone;
+use ncollide3d::shape::Cuboid;
+
+fn pose(i: usize) -> Isometry3<f64> {
+    let t = i as f64 * 0.01;
+    Isometry3::new(
+        Vector3::new(3.0 + t, 0.5 - t, 0.2 + t),
+        Vector3::new(0.1 + t, 0.2 - t, 0.3 + t),
+    )
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn distance_with_context_reduces_iterations() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cone = Cone::new(1.0, 0.5);
+    let m1 = Isometry3::identity();
+    let mut context = GJKContext::new();
+
+    let (_, cold) = query::with_diagnostics(|| {
+        for i in 0..20 {
+            let _ = query::distance(&m1, &cuboid, &pose(i), &cone);
+        }
+    });
+    let (dists, warm) = query::with_diagnostics(|| {
+        (0..20)
+            .map(|i| query::distance_with_context(&m1, &cuboid, &pose(i), &cone, &mut context))
+            .collect::<Vec<_>>()
+    });
+
+    for (i, dist) in dists.into_iter().enumerate() {
+        let expected = query::distance(&m1, &cuboid, &pose(i), &cone);
+        assert_relative_eq!(dist, expected, epsilon = 1.0e-6);
+    }
+
+    assert!(context.is_warm());
+    assert_eq!(warm.gjk_runs, cold.gjk_runs);
+    assert!(warm.gjk_iterations < cold.gjk_iterations);
+}
+
+#[test]
+fn contact_with_context_matches_contact() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let mut context = GJKContext::new();
+
+    for i in 0..20 {
+        // Penetrating, then separated.
+        let m2 = Isometry3::new(
+            Vector3::new(1.8 + i as f64 * 0.02, 0.5, 0.2),
+            Vector3::new(0.1, 0.2, 0.3),
+        );
+        let expected = query::contact(&m1, &cuboid, &m2, &cuboid, 0.1);
+        let contact = query::contact_with_context(&m1, &cuboid, &m2, &cuboid, 0.1, &mut context);
+
+        assert_eq!(contact.is_some(), expected.is_some());
+
+        if let (Some(contact), Some(expected)) = (contact, expected) {
+            assert_relative_eq!(contact.depth, expected.depth, epsilon = 1.0e-6);
+            assert_relative_eq!(*contact.normal, *expected.normal, epsilon = 1.0e-6);
+        }
+    }
+
+    // A large relative rotation makes the context stale.
+    assert!(context.warm_start(&m1, &pose(0)));
+    let flipped = Isometry3::new(Vector3::new(-3.0, 0.5, 0.2), Vector3::new(0.1, 0.2, 0.3));
+    assert!(!context.warm_start(&m1, &flipped));
+    assert!(!context.is_warm());
+
+    let expected = query::contact(&m1, &cuboid, &flipped, &cuboid, 0.1);
+    let contact = query::contact_with_context(&m1, &cuboid, &flipped, &cuboid, 0.1, &mut context);
+    assert_eq!(contact.is_some(), expected.is_some());
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn unchanged_poses_reuse_the_last_simplex() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cone = Cone::new(1.0, 0.5);
+    let m1 = Isometry3::identity();
+    let mut context = GJKContext::new();
+    let first = query::distance_with_context(&m1, &cuboid, &pose(3), &cone, &mut context);
+
+    // The recorded simplex is already the final one, so GJK stops without adding any vertex.
+    let (second, diagnostics) = query::with_diagnostics(|| {
+        query::distance_with_context(&m1, &cuboid, &pose(3), &cone, &mut context)
+    });
+
+    assert_relative_eq!(first, second, epsilon = 1.0e-9);
+    assert_eq!(diagnostics.gjk_runs, 1);
+    assert_eq!(diagnostics.gjk_iterations, 0);
+}
@@ -0,0 +1,68 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
+use ncollide3d::query::{self, ClosestPoints};
+use ncollide3d::shape::{Cone, Cuboid};
+
+#[test]
+fn witness_points_from_gjk_simplex() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let cone = Cone::new(1.0, 0.5);
+    let m1 = Isometry3::new(Vector3::new(0.3, -0.2, 0.1), Vector3::new(0.4, 0.1, -0.3));
+    let poses = [
+        Isometry3::new(Vector3::new(3.0, 0.5, 0.2), Vector3::new(0.1, 0.2, 0.3)),
+        Isometry3::new(Vector3::new(0.2, 3.5, -0.4), Vector3::new(1.2, -0.5, 0.0)),
+        Isometry3::new(Vector3::new(-1.0, 0.3, 4.0), Vector3::new(0.0, 0.0, 1.5)),
+    ];
+
+    for m2 in poses.iter() {
+        // Initialize the simplex like `query::closest_points` does.
+        let mut simplex = VoronoiSimplex::new();
+        let dir = m1.translation.vector - m2.translation.vector;
+        simplex.reset(CSOPoint::from_shapes(&m1, &cuboid, m2, &cone, &dir));
+
+        let (dist, vertices) =
+            gjk::closest_points_with_simplex(&m1, &cuboid, m2, &cone, 100.0, &mut simplex).unwrap();
+
+        assert!(!vertices.is_empty() && vertices.len() <= 4);
+
+        let mut local1 = Point3::origin();
+        let mut local2 = Point3::origin();
+        let mut total_weight = 0.0;
+
+        for v in &vertices {
+            assert_relative_eq!(
+                v.point,
+                m1 * v.local1 - (m2 * v.local2).coords,
+                epsilon = 1.0e-9
+            );
+            local1 += v.local1.coords * v.weight;
+            local2 += v.local2.coords * v.weight;
+            total_weight += v.weight;
+        }
+
+        assert_relative_eq!(total_weight, 1.0, epsilon = 1.0e-9);
+
+        match query::closest_points(&m1, &cuboid, m2, &cone, 100.0) {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                assert_relative_eq!(m1 * local1, p1, epsilon = 1.0e-6);
+                assert_relative_eq!(m2 * local2, p2, epsilon = 1.0e-6);
+                assert_relative_eq!(na::distance(&p1, &p2), dist, epsilon = 1.0e-6);
+            }
+            _ => panic!("The shapes should be disjoint."),
+        }
+    }
+
+    // Intersecting shapes have no closest points.
+    let mut simplex = VoronoiSimplex::new();
+    let m2 = Isometry3::translation(0.5, 0.0, 0.0);
+    simplex.reset(CSOPoint::from_shapes(
+        &m1,
+        &cuboid,
+        &m2,
+        &cone,
+        &Vector3::x(),
+    ));
+    assert!(
+        gjk::closest_points_with_simplex(&m1, &cuboid, &m2, &cone, 100.0, &mut simplex).is_none()
+    );
+}
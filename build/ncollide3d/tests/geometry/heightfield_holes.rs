@@ -0,0 +1,65 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{HeightField, HeightFieldCellStatus};
+
+// A flat 10x10 heightfield with unit cells and a raised first row of vertices.
+fn terrain() -> HeightField<f64> {
+    let heights = DMatrix::from_fn(11, 11, |i, _| if i == 0 { 3.0 } else { 0.0 });
+    HeightField::new(heights, Vector3::new(10.0, 1.0, 10.0))
+}
+
+#[test]
+fn ray_passes_through_heightfield_hole() {
+    let mut heightfield = terrain();
+    let m = Isometry3::identity();
+    let down = Ray::new(Point3::new(0.5, 10.0, 0.5), -Vector3::y());
+    let slanted = Ray::new(Point3::new(-0.5, 10.0, 0.5), Vector3::new(0.1, -1.0, 0.0));
+
+    assert_relative_eq!(
+        heightfield.toi_with_ray(&m, &down, 100.0, true).unwrap(),
+        10.0
+    );
+
+    heightfield.set_cell_status(5, 5, HeightFieldCellStatus::CELL_REMOVED);
+    assert_eq!(
+        heightfield.cell_status(5, 5),
+        HeightFieldCellStatus::CELL_REMOVED
+    );
+    assert!(heightfield.toi_with_ray(&m, &down, 100.0, true).is_none());
+    assert!(heightfield
+        .toi_with_ray(&m, &slanted, 100.0, true)
+        .is_none());
+    assert_eq!(heightfield.triangles().count(), 198);
+
+    // The neighbor cells are still there.
+    let next = Ray::new(Point3::new(1.5, 10.0, 0.5), -Vector3::y());
+    assert_relative_eq!(
+        heightfield.toi_with_ray(&m, &next, 100.0, true).unwrap(),
+        10.0
+    );
+
+    // The projection of a point in the hole lies on its boundary.
+    let proj = heightfield.project_point(&m, &Point3::new(0.5, 0.1, 0.3), false);
+    assert_relative_eq!(proj.point, Point3::new(0.5, 0.0, 0.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn heightfield_aabb_ignores_removed_cells() {
+    let mut heightfield = terrain();
+    assert_relative_eq!(heightfield.aabb().maxs().y, 3.0);
+
+    for j in 0..10 {
+        heightfield.set_cell_status(0, j, HeightFieldCellStatus::CELL_REMOVED);
+    }
+
+    // Removing triangles does not shrink the AABB until it is refitted.
+    assert_relative_eq!(heightfield.aabb().maxs().y, 3.0);
+    heightfield.refit_aabb();
+    assert_relative_eq!(*heightfield.aabb().mins(), Point3::new(-5.0, 0.0, -4.0));
+    assert_relative_eq!(*heightfield.aabb().maxs(), Point3::new(5.0, 0.0, 5.0));
+
+    // Restoring one triangle makes the AABB grow back.
+    heightfield.set_cell_status(0, 3, HeightFieldCellStatus::LEFT_TRIANGLE_REMOVED);
+    assert_relative_eq!(heightfield.aabb().maxs().y, 3.0);
+    assert_relative_eq!(heightfield.aabb().mins().z, -5.0);
+}
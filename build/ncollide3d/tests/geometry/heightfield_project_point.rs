@@ -0,0 +1,55 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::HeightField;
+
+fn cliff() -> HeightField<f64> {
+    // A flat ground with a tall cliff on its last columns.
+    let heights = DMatrix::from_fn(10, 10, |_, j| if j >= 7 { 5.0 } else { 0.0 });
+    HeightField::new(heights, Vector3::new(9.0, 1.0, 9.0))
+}
+
+#[test]
+fn heightfield_project_point_and_cell_matches_brute_force() {
+    let heightfield = cliff();
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::y() * 0.3);
+    let points = [
+        Point3::new(1.5, 1.0, 0.5),
+        Point3::new(3.8, 0.3, 0.0),
+        Point3::new(-6.0, 2.0, 7.0),
+        Point3::new(10.0, 10.0, -10.0),
+    ];
+
+    for pt in points.iter() {
+        let pt = m * pt;
+        let (proj, (i, j)) = heightfield.project_point_and_cell(&m, &pt);
+
+        let mut expected = f64::MAX;
+        for tri in heightfield.triangles() {
+            let tri_proj = tri.project_point(&m, &pt, false);
+            expected = expected.min(na::distance(&pt, &tri_proj.point));
+        }
+
+        assert_relative_eq!(na::distance(&pt, &proj.point), expected, epsilon = 1.0e-7);
+
+        let (tri1, tri2) = heightfield.triangles_at(i, j);
+        let on_cell = tri1
+            .iter()
+            .chain(tri2.iter())
+            .any(|tri| tri.distance_to_point(&m, &proj.point, false) < 1.0e-7);
+        assert!(on_cell);
+    }
+}
+
+#[test]
+fn heightfield_project_point_near_cliff() {
+    let heightfield = cliff();
+    // Close to the cliff wall: the closest point is on the wall, not below the point.
+    let pt = Point3::new(0.9, 1.0, 0.0);
+    let (proj, (_, j)) = heightfield.project_point_and_cell(&Isometry3::identity(), &pt);
+
+    // The wall goes from (1.5, 0.0) to (2.5, 5.0) in the `xy` plane.
+    let expected = 4.0 / 26.0f64.sqrt();
+    assert_relative_eq!(na::distance(&pt, &proj.point), expected, epsilon = 1.0e-7);
+    assert!(proj.point.x > 1.5);
+    assert_eq!(j, 6);
+}
@@ -0,0 +1,115 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::{Ray, RayCast, RayIntersection};
+use ncollide3d::shape::HeightField;
+
+// A 40x40 terrain with a ridge along the z axis and small bumps everywhere.
+fn ridge() -> HeightField<f64> {
+    let heights = DMatrix::from_fn(41, 41, |i, j| {
+        let ridge = 4.0 - (j as f64 - 20.0).abs() * 0.5;
+        let bumps = ((i * 7 + j * 13) % 5) as f64 * 0.05;
+        ridge.max(0.0) + bumps
+    });
+    HeightField::new(heights, Vector3::new(40.0, 1.0, 40.0))
+}
+
+// Casts the ray on every triangle of the heightfield, keeping the closest hit.
+fn brute_force_cast(
+    heightfield: &HeightField<f64>,
+    m: &Isometry3<f64>,
+    ray: &Ray<f64>,
+    max_toi: f64,
+) -> Option<RayIntersection<f64>> {
+    let mut best: Option<RayIntersection<f64>> = None;
+
+    for i in 0..heightfield.nrows() {
+        for j in 0..heightfield.ncols() {
+            let (tri1, tri2) = heightfield.triangles_at(i, j);
+
+            for tri in tri1.iter().chain(tri2.iter()) {
+                if let Some(inter) = tri.toi_and_normal_with_ray(m, ray, max_toi, true) {
+                    if best.as_ref().map(|b| inter.toi < b.toi).unwrap_or(true) {
+                        best = Some(inter);
+                    }
+                }
+            }
+        }
+    }
+
+    best
+}
+
+fn assert_same_hit(
+    heightfield: &HeightField<f64>,
+    m: &Isometry3<f64>,
+    ray: &Ray<f64>,
+    max_toi: f64,
+) -> Option<f64> {
+    let expected = brute_force_cast(heightfield, m, ray, max_toi);
+    let actual = heightfield.toi_and_normal_with_ray(m, ray, max_toi, true);
+
+    match (expected, actual) {
+        (Some(expected), Some(actual)) => {
+            assert_relative_eq!(actual.toi, expected.toi, epsilon = 1.0e-9);
+            assert_relative_eq!(actual.normal, expected.normal, epsilon = 1.0e-9);
+            Some(actual.toi)
+        }
+        (None, None) => None,
+        (expected, actual) => panic!(
+            "Ray cast mismatch: expected {:?}, found {:?}",
+            expected, actual
+        ),
+    }
+}
+
+#[test]
+fn grazing_ray_across_ridge_matches_brute_force() {
+    let heightfield = ridge();
+    let m = Isometry3::identity();
+
+    // Starts outside of the footprint, skims the top of the ridge, then hits the far slope.
+    let grazing = Ray::new(
+        Point3::new(-30.0, 13.3, -25.0),
+        Vector3::new(1.0, -0.3, 0.7),
+    );
+    let toi = assert_same_hit(&heightfield, &m, &grazing, 1000.0).unwrap();
+    assert!(grazing.point_at(toi).x > 0.0);
+
+    // A slightly lower ray hits the near slope of the ridge instead.
+    let lower = Ray::new(
+        Point3::new(-30.0, 13.0, -25.0),
+        Vector3::new(1.0, -0.3, 0.7),
+    );
+    let toi = assert_same_hit(&heightfield, &m, &lower, 1000.0).unwrap();
+    assert!(lower.point_at(toi).x < 0.0);
+
+    // A ray passing above the whole terrain.
+    let above = Ray::new(Point3::new(-30.0, 6.0, -25.0), Vector3::new(1.0, 0.0, 0.7));
+    assert!(assert_same_hit(&heightfield, &m, &above, 1000.0).is_none());
+
+    // The hit is ignored if it is farther than `max_toi`.
+    assert!(assert_same_hit(&heightfield, &m, &grazing, 40.0).is_none());
+}
+
+#[test]
+fn rays_parallel_to_grid_axes_match_brute_force() {
+    let heightfield = ridge();
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.0, 0.4, 0.0));
+    let rays = [
+        // Parallel to the x axis, across the ridge.
+        Ray::new(Point3::new(-30.0, 2.0, 3.3), Vector3::x()),
+        Ray::new(Point3::new(30.0, 2.0, -7.1), -Vector3::x()),
+        // Parallel to the z axis, along the ridge.
+        Ray::new(Point3::new(0.2, 5.0, -30.0), Vector3::new(0.0, -0.05, 1.0)),
+        Ray::new(
+            Point3::new(-10.7, 1.0, 30.0),
+            Vector3::new(0.0, -0.05, -1.0),
+        ),
+        // Vertical.
+        Ray::new(Point3::new(5.3, 10.0, -2.6), -Vector3::y()),
+    ];
+
+    for ray in rays.iter() {
+        let ray = ray.transform_by(&m);
+        assert!(assert_same_hit(&heightfield, &m, &ray, 1000.0).is_some());
+    }
+}
@@ -0,0 +1,36 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Capsule, ConvexHull, Cuboid};
+
+#[test]
+fn inscribed_sphere_simple_shapes() {
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let (center, radius) = query::inscribed_sphere(&m, &Ball::new(0.5f64));
+    assert_relative_eq!(center, Point3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(radius, 0.5);
+
+    let (center, radius) = query::inscribed_sphere(&m, &Cuboid::new(Vector3::new(2.0, 0.5, 1.0)));
+    assert_relative_eq!(center, Point3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(radius, 0.5);
+
+    let (center, radius) = query::inscribed_sphere(&m, &Capsule::new(2.0, 0.25));
+    assert_relative_eq!(center, Point3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(radius, 0.25);
+}
+
+#[test]
+fn inscribed_sphere_convex_hull() {
+    let points = [
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+    ];
+    let tetrahedron = ConvexHull::try_from_points(&points).unwrap();
+    let m = Isometry3::new(Vector3::new(0.0, 5.0, 0.0), Vector3::y() * 0.5);
+
+    let (center, radius) = query::inscribed_sphere(&m, &tetrahedron);
+    // The inradius of a regular tetrahedron is a third of its circumradius.
+    assert_relative_eq!(center, Point3::new(0.0, 5.0, 0.0), epsilon = 1.0e-5);
+    assert_relative_eq!(radius, 3.0f64.sqrt() / 3.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,28 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query::{self, Proximity};
+use ncollide3d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn intersection_test_matches_proximity() {
+    let shapes: Vec<Box<dyn Shape<f64>>> = vec![
+        Box::new(Ball::new(0.5)),
+        Box::new(Cuboid::new(Vector3::new(1.0, 0.5, 0.25))),
+    ];
+
+    for g1 in &shapes {
+        for g2 in &shapes {
+            for i in 0..50 {
+                let t = i as f64 * 0.1;
+                let m1 = Isometry3::new(Vector3::zeros(), Vector3::new(0.1, 0.2, 0.3) * t);
+                let m2 = Isometry3::new(
+                    Vector3::new(t * 0.5, 0.2 * t, 0.1),
+                    Vector3::new(0.3, 0.2, 0.1) * t,
+                );
+
+                let expected =
+                    query::proximity(&m1, &**g1, &m2, &**g2, 0.0) == Proximity::Intersecting;
+                assert_eq!(query::intersection_test(&m1, &**g1, &m2, &**g2), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use na::Point3;
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::partitioning::{DBVTLeaf, BVH, BVT, DBVT};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+// Deterministic pseudo-random points in the cube `[-10, 10]^3`.
+fn scattered_points(n: usize) -> Vec<Point3<f64>> {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let mut next = move || rng.gen_range(-10.0, 10.0);
+
+    (0..n)
+        .map(|_| Point3::new(next(), next(), next()))
+        .collect()
+}
+
+// The indices of the `k` points closest to `query`, with their distances.
+fn brute_force(points: &[Point3<f64>], query: &Point3<f64>, k: usize) -> Vec<(usize, f64)> {
+    let mut all: Vec<_> = points
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| (i, na::distance(pt, query)))
+        .collect();
+    all.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    all.truncate(k);
+    all
+}
+
+fn check(result: Vec<(&usize, f64)>, expected: &[(usize, f64)]) {
+    assert_eq!(result.len(), expected.len());
+
+    for ((i, dist), (expected_i, expected_dist)) in result.into_iter().zip(expected.iter()) {
+        assert_eq!(i, expected_i);
+        assert_relative_eq!(dist, *expected_dist, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn k_nearest_matches_brute_force() {
+    let points = scattered_points(500);
+    let leaves: Vec<_> = points
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| (i, AABB::new(*pt, *pt)))
+        .collect();
+    let bvt = BVT::new_balanced(leaves.clone());
+    let mut dbvt = DBVT::new();
+
+    for (i, aabb) in leaves {
+        let _ = dbvt.insert(DBVTLeaf::new(aabb, i));
+    }
+
+    let queries = [
+        Point3::origin(),
+        Point3::new(3.2, -7.5, 1.1),
+        Point3::new(-9.9, 9.9, -9.9),
+        Point3::new(30.0, 0.0, 0.0),
+    ];
+
+    for query in queries.iter() {
+        for k in [1, 5, 32].iter() {
+            let expected = brute_force(&points, query, *k);
+            check(bvt.k_nearest(query, *k), &expected);
+            check(dbvt.k_nearest(query, *k), &expected);
+        }
+    }
+}
+
+#[test]
+fn k_nearest_with_few_leaves() {
+    let points = scattered_points(3);
+    let leaves: Vec<_> = points
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| (i, AABB::new(*pt, *pt)))
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    assert_eq!(bvt.k_nearest(&Point3::origin(), 10).len(), 3);
+    assert!(bvt.k_nearest(&Point3::origin(), 0).is_empty());
+
+    let empty: DBVT<f64, usize, AABB<f64>> = DBVT::new();
+    assert!(empty.k_nearest(&Point3::origin(), 10).is_empty());
+}
@@ -0,0 +1,46 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid, MinkowskiSum, Shape, ShapeHandle};
+
+fn inflated_cuboid(radius: f64) -> MinkowskiSum<f64> {
+    MinkowskiSum::new(
+        ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 2.0, 0.5))),
+        ShapeHandle::new(Ball::new(radius)),
+    )
+}
+
+#[test]
+fn minkowski_sum_distance_matches_inflated_distance() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 0.5));
+    let sum = inflated_cuboid(0.3);
+    let obstacle = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let m1 = Isometry3::new(Vector3::new(0.2, -0.1, 0.3), Vector3::new(0.1, 0.4, -0.2));
+    let m2 = Isometry3::new(Vector3::new(3.0, 1.5, 1.0), Vector3::new(-0.3, 0.2, 0.5));
+
+    let expected = query::distance(&m1, &cuboid, &m2, &obstacle) - 0.3;
+    let actual = query::distance(&m1, &sum, &m2, &obstacle);
+    // GJK converges slowly on curved shapes.
+    assert_relative_eq!(actual, expected, epsilon = 1.0e-4);
+
+    // The inflated cuboid penetrates obstacles closer than the ball radius.
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.7, 0.0, 0.0);
+    let contact = query::contact(&m1, &sum, &m2, &obstacle, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-4);
+    assert!(query::contact(&m1, &cuboid, &m2, &obstacle, 0.0).is_none());
+}
+
+#[test]
+fn minkowski_sum_aabb() {
+    let sum = inflated_cuboid(0.3);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.2, 0.3, 0.4));
+    let aabb = sum.aabb(&m);
+    let cuboid_aabb = Cuboid::new(Vector3::new(1.0, 2.0, 0.5)).aabb(&m);
+
+    assert_relative_eq!(aabb.mins(), &(cuboid_aabb.mins() - Vector3::repeat(0.3)));
+    assert_relative_eq!(aabb.maxs(), &(cuboid_aabb.maxs() + Vector3::repeat(0.3)));
+
+    let local = sum.local_aabb();
+    assert_relative_eq!(local.mins(), &Point3::new(-1.3, -2.3, -0.8));
+    assert_relative_eq!(local.maxs(), &Point3::new(1.3, 2.3, 0.8));
+}
@@ -1,11 +1,69 @@
+mod aabb_split;
+mod aabb_transform;
+mod ball_ball_intersection_circle;
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod bounding_sphere_merge;
+mod bvt_leaf_data;
+mod bvt_ray_iter;
+mod cached_separating_axis;
+mod capsule_capsule_toi;
+mod clearance_from_planes;
+mod coincident_shapes;
+mod compound_intersect_aabb;
 mod contact;
+mod contact_at_impact;
+mod contact_frame;
+mod contact_manifold_approach_speed;
+mod contact_manifold_data;
+mod contact_manifold_degenerate;
+mod contact_manifold_order;
+mod contact_manifold_weights;
+mod contains_points;
+mod convex_decomposition;
+mod convex_hull_contains_point;
+mod convex_hull_insert_point;
 mod cuboid_ray_cast;
 mod cylinder_cuboid_contact;
 mod epa3;
+mod facing_area;
 mod first_interference_with_ray;
+mod gjk_context;
+mod gjk_simplex;
+mod heightfield_holes;
+mod heightfield_project_point;
+mod heightfield_ray_cast;
+mod inscribed_sphere;
 mod interferences_with_ray;
+mod intersection_test;
+mod k_nearest;
+mod minkowski_sum;
+mod morph_shape;
+mod obb;
+mod on_surface;
+mod overlap_volume;
+mod polyline_segments;
+mod project_point_with_normal;
+mod project_to_plane;
+mod proximity_and_direction;
+#[cfg(feature = "diagnostics")]
+mod query_diagnostics;
+mod ray_closest_points;
+mod rotating_support_map_toi;
+mod rotational_depenetration;
+mod round_shape;
+mod scaled_shape;
+mod shape_validation;
+mod similarity;
+mod slab;
+mod solid_point_projection;
 mod still_objects_toi;
+mod surface_distance;
 mod time_of_impact3;
+mod time_to_enter_aabb;
+#[cfg(feature = "diagnostics")]
+mod traversal_stats;
+mod trimesh_deformations;
+mod trimesh_ray_barycentric;
 mod trimesh_trimesh_toi;
+mod wedge;
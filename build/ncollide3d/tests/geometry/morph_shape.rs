@@ -0,0 +1,53 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{self, PointQuery};
+use ncollide3d::shape::{Ball, Cuboid, MorphShape, ShapeHandle, SupportMap};
+
+fn cube_to_ball(t: f64) -> MorphShape<f64> {
+    MorphShape::new(
+        ShapeHandle::new(Cuboid::new(Vector3::repeat(1.0))),
+        ShapeHandle::new(Ball::new(1.0)),
+        t,
+    )
+}
+
+#[test]
+fn morph_shape_interpolates_support_points() {
+    let diag = Vector3::repeat(1.0);
+    let cube_support = Point3::new(1.0, 1.0, 1.0);
+    let ball_support = Point3::from(diag.normalize());
+
+    let shape = cube_to_ball(0.0);
+    assert_relative_eq!(shape.local_support_point(&diag), cube_support);
+    let shape = cube_to_ball(1.0);
+    assert_relative_eq!(shape.local_support_point(&diag), ball_support);
+    let shape = cube_to_ball(0.25);
+    assert_relative_eq!(
+        shape.local_support_point(&diag),
+        cube_support * 0.75 + ball_support.coords * 0.25
+    );
+
+    // The parameter is clamped.
+    let mut shape = cube_to_ball(2.0);
+    assert_eq!(shape.t(), 1.0);
+    shape.set_t(-1.0);
+    assert_eq!(shape.t(), 0.0);
+}
+
+#[test]
+fn morph_shape_convex_queries() {
+    let ball = Ball::new(0.5);
+    let m = Isometry3::identity();
+    let pos = Isometry3::translation(3.0, 3.0, 3.0);
+    let corner_dist = 3.0f64.sqrt() * 3.0 - 0.5;
+
+    let dist_cube = query::distance(&m, &cube_to_ball(0.0), &pos, &ball);
+    let dist_half = query::distance(&m, &cube_to_ball(0.5), &pos, &ball);
+    let dist_ball = query::distance(&m, &cube_to_ball(1.0), &pos, &ball);
+    assert_relative_eq!(dist_cube, corner_dist - 3.0f64.sqrt(), epsilon = 1.0e-6);
+    assert_relative_eq!(dist_ball, corner_dist - 1.0, epsilon = 1.0e-6);
+    assert!(dist_cube < dist_half && dist_half < dist_ball);
+
+    // Faces are unaffected since both shapes have the same extent along the axes.
+    let proj = cube_to_ball(0.5).project_point(&m, &Point3::new(3.0, 0.0, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-6);
+}
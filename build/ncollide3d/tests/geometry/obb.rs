@@ -0,0 +1,167 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume, OBB};
+use ncollide3d::shape::Cuboid;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+fn corners(obb: &OBB<f64>) -> Vec<Point3<f64>> {
+    let he = obb.half_extents();
+    let pos = obb.position();
+
+    (0..8)
+        .map(|i| {
+            let corner = Point3::new(
+                if i & 1 == 0 { -he.x } else { he.x },
+                if i & 2 == 0 { -he.y } else { he.y },
+                if i & 4 == 0 { -he.z } else { he.z },
+            );
+            pos * corner
+        })
+        .collect()
+}
+
+// Checks if the projections of the corners of both OBBs on `axis` are disjoint.
+fn separates(a: &[Point3<f64>], b: &[Point3<f64>], axis: &Vector3<f64>) -> bool {
+    let range = |pts: &[Point3<f64>]| {
+        pts.iter()
+            .map(|pt| pt.coords.dot(axis))
+            .fold((std::f64::MAX, -std::f64::MAX), |(min, max), d| {
+                (min.min(d), max.max(d))
+            })
+    };
+    let (min1, max1) = range(a);
+    let (min2, max2) = range(b);
+
+    max1 < min2 || max2 < min1
+}
+
+// Reference SAT test on the 15 candidate axes, using the corners of both OBBs.
+//
+// Returns whether the face axes, and whether the edge-cross axes, separate both OBBs.
+fn reference_separation(a: &OBB<f64>, b: &OBB<f64>) -> (bool, bool) {
+    let (ca, cb) = (corners(a), corners(b));
+    let axes_a = a.rotation().to_rotation_matrix().into_inner();
+    let axes_b = b.rotation().to_rotation_matrix().into_inner();
+    let mut by_face = false;
+    let mut by_edges = false;
+
+    for i in 0..3 {
+        by_face |= separates(&ca, &cb, &axes_a.column(i).into_owned());
+        by_face |= separates(&ca, &cb, &axes_b.column(i).into_owned());
+
+        for j in 0..3 {
+            let axis = axes_a.column(i).cross(&axes_b.column(j));
+
+            if axis.norm() > 1.0e-6 {
+                by_edges |= separates(&ca, &cb, &axis);
+            }
+        }
+    }
+
+    (by_face, by_edges)
+}
+
+#[test]
+fn obb_intersects_matches_sat_reference() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let mut rand = move || rng.gen::<f64>();
+    let random_obb = |rand: &mut dyn FnMut() -> f64| {
+        let half_extents = Vector3::new(0.1 + rand() * 2.0, 0.1 + rand() * 0.5, 0.1 + rand());
+        let pos = Isometry3::new(
+            Vector3::new(rand() * 3.0, rand() * 3.0, rand() * 3.0),
+            Vector3::new(rand() * 6.0, rand() * 6.0, rand() * 6.0),
+        );
+        bounding_volume::obb(&Cuboid::new(half_extents), &pos)
+    };
+
+    let mut num_edge_separations = 0;
+    let mut num_intersections = 0;
+
+    for _ in 0..5000 {
+        let a = random_obb(&mut rand);
+        let b = random_obb(&mut rand);
+        let (by_face, by_edges) = reference_separation(&a, &b);
+        let expected = !by_face && !by_edges;
+
+        if !by_face && by_edges {
+            num_edge_separations += 1;
+        }
+
+        if expected {
+            num_intersections += 1;
+        }
+
+        assert_eq!(a.intersects(&b), expected);
+        assert_eq!(b.intersects(&a), expected);
+    }
+
+    // Make sure the scenario exercises all the outcomes.
+    assert!(num_edge_separations > 0);
+    assert!(num_intersections > 0);
+    assert!(num_intersections < 5000);
+}
+
+#[test]
+fn obb_separated_only_by_edge_cross_axis() {
+    // Two long bars crossing each other at a right angle. Seen along the z axis they overlap,
+    // but they are one above the other. Tilting both bars makes the only separating axis the
+    // cross product of their main axes.
+    let he = Vector3::new(2.0, 0.1, 0.1);
+    let tilt = Vector3::new(0.0, 0.6, 0.0);
+    let a = OBB::from_aabb(
+        &bounding_volume::AABB::from_half_extents(Point3::origin(), he),
+        &(Isometry3::translation(0.0, 0.0, -0.5) * Isometry3::new(Vector3::zeros(), tilt)),
+    );
+    let b = OBB::from_aabb(
+        &bounding_volume::AABB::from_half_extents(Point3::origin(), he),
+        &(Isometry3::new(
+            Vector3::new(0.0, 0.0, 0.5),
+            Vector3::z() * std::f64::consts::FRAC_PI_2,
+        ) * Isometry3::new(Vector3::zeros(), tilt)),
+    );
+
+    assert_eq!(reference_separation(&a, &b), (false, true));
+    assert!(!a.intersects(&b));
+    assert!(!b.intersects(&a));
+
+    // Their AABBs overlap.
+    assert!(a.aabb().intersects(&b.aabb()));
+
+    let closer = b.transform_by(&Isometry3::translation(0.0, 0.0, -0.9));
+    assert!(a.intersects(&closer));
+}
+
+#[test]
+fn obb_merge_and_contains() {
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.7, 1.1));
+    let bar = Cuboid::new(Vector3::new(3.0, 0.2, 0.2));
+    let obb = bounding_volume::obb(&bar, &pos);
+
+    // Much tighter than the AABB of the rotated bar.
+    let aabb = bounding_volume::aabb(&bar, &pos);
+    assert!(obb.aabb().loosened(1.0e-9).contains(&aabb));
+    assert!(
+        obb.half_extents().x * obb.half_extents().y * obb.half_extents().z * 8.0 < {
+            let e = aabb.extents();
+            e.x * e.y * e.z
+        }
+    );
+
+    let other = bounding_volume::obb(&bar, &(pos * Isometry3::translation(0.5, 0.0, 0.0)));
+    let merged = obb.merged(&other);
+    assert_eq!(merged.rotation(), obb.rotation());
+    assert_relative_eq!(merged.half_extents().x, 3.25, epsilon = 1.0e-9);
+    assert!(merged.loosened(1.0e-9).contains(&obb));
+    assert!(merged.loosened(1.0e-9).contains(&other));
+    assert!(!obb.contains(&merged));
+
+    let loosened = obb.loosened(0.5);
+    assert!(loosened.contains(&obb));
+    assert!(!obb.contains(&loosened));
+    let tightened = loosened.tightened(0.5);
+    assert_relative_eq!(
+        tightened.half_extents(),
+        obb.half_extents(),
+        epsilon = 1.0e-12
+    );
+}
@@ -0,0 +1,33 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn ball_on_surface() {
+    let ball = Ball::new(1.0);
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    assert!(ball.on_surface(&m, &Point3::new(2.0, 2.0, 3.0), 1.0e-7));
+    assert!(ball.on_surface(&m, &Point3::new(1.0, 2.0, 3.995), 1.0e-2));
+    assert!(!ball.on_surface(&m, &Point3::new(1.0, 2.0, 3.5), 1.0e-2));
+    assert!(!ball.on_surface(&m, &Point3::new(1.0, 2.0, 4.5), 1.0e-2));
+}
+
+#[test]
+fn cuboid_on_surface() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let m = Isometry3::identity();
+
+    // On a face, an edge, and a vertex.
+    assert!(cuboid.on_surface(&m, &Point3::new(1.0, 0.5, 0.5), 1.0e-7));
+    assert!(cuboid.on_surface(&m, &Point3::new(1.0, 2.0, 0.5), 1.0e-7));
+    assert!(cuboid.on_surface(&m, &Point3::new(-1.0, 2.0, -3.0), 1.0e-7));
+
+    // Inside and outside, closer than the tolerance.
+    assert!(cuboid.on_surface(&m, &Point3::new(0.0, 1.99, 0.0), 1.0e-1));
+    assert!(cuboid.on_surface(&m, &Point3::new(0.0, 0.0, 3.01), 1.0e-1));
+
+    // Inside and outside, further than the tolerance.
+    assert!(!cuboid.on_surface(&m, &Point3::origin(), 1.0e-1));
+    assert!(!cuboid.on_surface(&m, &Point3::new(0.0, 0.0, 3.5), 1.0e-1));
+}
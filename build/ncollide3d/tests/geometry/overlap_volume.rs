@@ -0,0 +1,109 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, Plane, TriMesh, Wedge};
+use std::f64::consts::PI;
+
+#[test]
+fn cuboid_aabb_overlap_volume() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    // The cell contains the cuboid.
+    let cell = AABB::new(Point3::new(-5.0, -5.0, -5.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    assert_relative_eq!(volume, 48.0, epsilon = 1.0e-7);
+
+    // The cell contains a quarter of the cuboid.
+    let cell = AABB::new(Point3::new(1.0, 0.0, -5.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    assert_relative_eq!(volume, 12.0, epsilon = 1.0e-7);
+
+    // The cell does not intersect the cuboid.
+    let cell = AABB::new(Point3::new(3.0, 0.0, -5.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    assert_eq!(volume, 0.0);
+}
+
+#[test]
+fn ball_aabb_overlap_volume() {
+    let ball = Ball::new(1.0);
+    let m = Isometry3::identity();
+
+    let cell = AABB::new(Point3::new(-2.0, -2.0, -2.0), Point3::new(2.0, 2.0, 2.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &ball, &cell);
+    assert_relative_eq!(volume, 4.0 / 3.0 * PI, max_relative = 1.0e-2);
+
+    let cell = AABB::new(Point3::new(-2.0, 0.0, -2.0), Point3::new(2.0, 2.0, 2.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &ball, &cell);
+    assert_relative_eq!(volume, 2.0 / 3.0 * PI, max_relative = 1.0e-2);
+}
+
+#[test]
+fn plane_aabb_overlap_volume() {
+    let plane = Plane::new(Vector3::y_axis());
+    let m = Isometry3::translation(0.0, 0.5, 0.0);
+
+    let cell = AABB::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &plane, &cell);
+    assert_relative_eq!(volume, 0.5, epsilon = 1.0e-7);
+}
+
+#[test]
+fn rotated_cuboid_aabb_overlap_volume_is_exact() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m = Isometry3::new(Vector3::zeros(), Vector3::y() * PI / 4.0);
+
+    // Half of the cuboid.
+    let cell = AABB::new(Point3::new(-5.0, -5.0, 0.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    assert_relative_eq!(volume, 4.0, epsilon = 1.0e-9);
+
+    // A prism with a triangular cross-section, cut near the edge of the cuboid along `y`.
+    let cell = AABB::new(Point3::new(0.5, -5.0, -5.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    let expected = 2.0 * (2.0f64.sqrt() - 0.5).powi(2);
+    assert_relative_eq!(volume, expected, epsilon = 1.0e-9);
+
+    // The cell is inside of the cuboid.
+    let cell = AABB::new(Point3::new(-0.2, -0.3, -0.2), Point3::new(0.2, 0.3, 0.2));
+    let volume = query::shape_aabb_overlap_volume(&m, &cuboid, &cell);
+    assert_relative_eq!(volume, 0.4 * 0.6 * 0.4, epsilon = 1.0e-9);
+}
+
+#[test]
+fn convex_polyhedra_aabb_overlap_volume_is_exact() {
+    let tetrahedron = ConvexHull::try_from_points(&[
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ])
+    .unwrap();
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    // The corner of the tetrahedron with `x > 0.5` is cut out.
+    let cell = AABB::new(Point3::new(-5.0, -5.0, -5.0), Point3::new(1.5, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &tetrahedron, &cell);
+    assert_relative_eq!(volume, 7.0 / 48.0, epsilon = 1.0e-9);
+
+    // The cell is one of the faces of the tetrahedron.
+    let cell = AABB::new(Point3::new(-5.0, -5.0, -5.0), Point3::new(1.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&m, &tetrahedron, &cell);
+    assert_relative_eq!(volume, 0.0, epsilon = 1.0e-9);
+
+    // Half of a wedge, split along its triangular cross-section.
+    let wedge = Wedge::new(Vector3::new(1.0, 2.0, 3.0));
+    let cell = AABB::new(Point3::new(-5.0, -5.0, -5.0), Point3::new(5.0, 5.0, 0.0));
+    let volume = query::shape_aabb_overlap_volume(&Isometry3::identity(), &wedge, &cell);
+    assert_relative_eq!(volume, 12.0, epsilon = 1.0e-9);
+}
+
+#[test]
+fn trimesh_aabb_overlap_volume_is_zero() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let trimesh = TriMesh::from(ncollide3d::procedural::cuboid(&(cuboid.half_extents * 2.0)));
+    let cell = AABB::new(Point3::new(-5.0, -5.0, -5.0), Point3::new(5.0, 5.0, 5.0));
+    let volume = query::shape_aabb_overlap_volume(&Isometry3::identity(), &trimesh, &cell);
+    assert_eq!(volume, 0.0);
+}
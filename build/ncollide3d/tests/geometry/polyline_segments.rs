@@ -0,0 +1,38 @@
+use na::Point3;
+use ncollide3d::shape::Polyline;
+
+fn square() -> Vec<Point3<f64>> {
+    vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ]
+}
+
+#[test]
+fn open_polyline_segments() {
+    let polyline = Polyline::new(square(), None);
+    let segments: Vec<_> = polyline.segments().collect();
+
+    assert_eq!(segments.len(), 3);
+    assert_eq!(segments[2].a, Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(segments[2].b, Point3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn closed_polyline_segments() {
+    let indices = (0..4).map(|i| na::Point2::new(i, (i + 1) % 4)).collect();
+    let polyline = Polyline::new(square(), Some(indices));
+    let segments: Vec<_> = polyline.segments().collect();
+
+    assert_eq!(segments.len(), 4);
+    // The last segment wraps around to the first point.
+    assert_eq!(segments[3].a, Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(segments[3].b, Point3::new(0.0, 0.0, 0.0));
+
+    for (i, segment) in polyline.segments().enumerate() {
+        assert_eq!(segment.a, polyline.segment_at(i).a);
+        assert_eq!(segment.b, polyline.segment_at(i).b);
+    }
+}
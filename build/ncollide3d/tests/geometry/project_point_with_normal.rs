@@ -0,0 +1,73 @@
+use na::{Isometry3, Point3, UnitQuaternion, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Capsule, Cuboid, TriMesh};
+
+#[test]
+fn ball_project_point_with_normal() {
+    let ball = Ball::new(1.0);
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    let (proj, normal) = ball.project_point_with_normal(&m, &Point3::new(1.0, 2.0, 5.0));
+    assert_relative_eq!(proj.point, Point3::new(1.0, 2.0, 4.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector3::z(), epsilon = 1.0e-7);
+
+    // The normal is outward even if the point is inside.
+    let (proj, normal) = ball.project_point_with_normal(&m, &Point3::new(0.5, 2.0, 3.0));
+    assert!(proj.is_inside);
+    assert_relative_eq!(*normal, -Vector3::x(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn cuboid_project_point_with_normal() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 0.3);
+    let m = Isometry3::from_parts(Vector3::new(1.0, 0.0, 0.0).into(), rot);
+
+    // Face.
+    let (_, normal) = cuboid.project_point_with_normal(&m, &(m * Point3::new(0.0, 5.0, 0.0)));
+    assert_relative_eq!(*normal, Vector3::y(), epsilon = 1.0e-7);
+
+    // Edge.
+    let (_, normal) = cuboid.project_point_with_normal(&m, &(m * Point3::new(2.0, 3.0, 0.0)));
+    let expected = rot * Vector3::new(1.0, 1.0, 0.0).normalize();
+    assert_relative_eq!(*normal, expected, epsilon = 1.0e-7);
+
+    // Vertex.
+    let (_, normal) = cuboid.project_point_with_normal(&m, &(m * Point3::new(-2.0, 3.0, -4.0)));
+    let expected = rot * Vector3::new(-1.0, 1.0, -1.0).normalize();
+    assert_relative_eq!(*normal, expected, epsilon = 1.0e-7);
+
+    // Inside.
+    let (_, normal) = cuboid.project_point_with_normal(&m, &(m * Point3::new(0.0, 0.0, 2.5)));
+    assert_relative_eq!(*normal, rot * Vector3::z(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn trimesh_project_point_with_normal() {
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    let mesh = TriMesh::new(points, vec![Point3::new(0, 1, 2)], None);
+    let m = Isometry3::identity();
+
+    // Even on the outside of an edge, the normal is the one of the triangle.
+    let (proj, normal) = mesh.project_point_with_normal(&m, &Point3::new(2.0, 1.0, 0.5));
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector3::y(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn capsule_project_point_with_normal() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry3::identity();
+
+    let (_, normal) = capsule.project_point_with_normal(&m, &Point3::new(0.0, 3.0, 0.0));
+    assert_relative_eq!(*normal, Vector3::y(), epsilon = 1.0e-7);
+
+    // The point already lies on the surface.
+    let (proj, normal) = capsule.project_point_with_normal(&m, &Point3::new(0.5, 0.0, 0.0));
+    assert_relative_eq!(proj.point, Point3::new(0.5, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector3::x(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,61 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid, Polyline};
+
+fn check_outline(outline: &Polyline<f64>, plane_point: &Point3<f64>, normal: &Unit<Vector3<f64>>) {
+    let pts = outline.points();
+    assert_eq!(outline.edges().len(), pts.len());
+
+    for i in 0..pts.len() {
+        let a = pts[i];
+        let b = pts[(i + 1) % pts.len()];
+        let c = pts[(i + 2) % pts.len()];
+
+        assert_relative_eq!((a - plane_point).dot(normal), 0.0, epsilon = 1.0e-7);
+        // Counter-clockwise and convex.
+        assert!((b - a).cross(&(c - b)).dot(normal) > 0.0);
+    }
+}
+
+#[test]
+fn project_cuboid_to_plane() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let m = Isometry3::translation(1.0, 0.0, 4.0);
+    let plane_point = Point3::new(0.0, 0.0, -5.0);
+    let normal = Vector3::z_axis();
+
+    let outline = query::project_to_plane(&m, &cuboid, &plane_point, &normal);
+    check_outline(&outline, &plane_point, &normal);
+    assert_eq!(outline.points().len(), 4);
+
+    for pt in outline.points() {
+        assert_relative_eq!((pt.x - 1.0).abs(), 1.0, epsilon = 1.0e-7);
+        assert_relative_eq!(pt.y.abs(), 2.0, epsilon = 1.0e-7);
+    }
+
+    // Seen along one of its diagonals, the cube projects to a hexagon.
+    let normal = Unit::new_normalize(Vector3::new(1.0, 1.0, 1.0));
+    let outline = query::project_to_plane(&m, &cuboid, &plane_point, &normal);
+    check_outline(&outline, &plane_point, &normal);
+    assert_eq!(outline.points().len(), 6);
+}
+
+#[test]
+fn project_ball_to_plane() {
+    let ball = Ball::new(2.0);
+    let m = Isometry3::translation(1.0, 3.0, 0.0);
+    let plane_point = Point3::origin();
+    let normal = Vector3::y_axis();
+
+    let outline = query::project_to_plane(&m, &ball, &plane_point, &normal);
+    check_outline(&outline, &plane_point, &normal);
+    assert!(outline.points().len() > 16);
+
+    for pt in outline.points() {
+        assert_relative_eq!(
+            na::distance(pt, &Point3::new(1.0, 0.0, 0.0)),
+            2.0,
+            epsilon = 1.0e-7
+        );
+    }
+}
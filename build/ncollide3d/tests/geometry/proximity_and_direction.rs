@@ -0,0 +1,45 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::query::{self, Proximity};
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn disjoint_balls_direction() {
+    let ball = Ball::new(1.0);
+    let m1 = Isometry3::translation(1.0, 1.0, 1.0);
+    let m2 = Isometry3::translation(4.0, 5.0, 1.0);
+
+    let (proximity, dir) = query::proximity_and_direction(&m1, &ball, &m2, &ball, 0.1);
+    assert_eq!(proximity, Proximity::Disjoint);
+    assert_relative_eq!(*dir.unwrap(), Vector3::new(0.6, 0.8, 0.0), epsilon = 1.0e-7);
+
+    // The direction is reversed when the shapes are swapped.
+    let (_, dir) = query::proximity_and_direction(&m2, &ball, &m1, &ball, 0.1);
+    assert_relative_eq!(
+        *dir.unwrap(),
+        Vector3::new(-0.6, -0.8, 0.0),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn cuboids_within_margin_direction() {
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.2, 1.05, -0.1);
+
+    let (proximity, dir) = query::proximity_and_direction(&m1, &cuboid, &m2, &cuboid, 0.1);
+    assert_eq!(proximity, Proximity::WithinMargin);
+    assert_relative_eq!(*dir.unwrap(), Vector3::y(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn intersecting_direction_is_penetration_normal() {
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.2, 0.1, 0.0);
+
+    let (proximity, dir) = query::proximity_and_direction(&m1, &cuboid, &m2, &ball, 0.0);
+    assert_eq!(proximity, Proximity::Intersecting);
+    assert_relative_eq!(*dir.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,96 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::algorithms::{CSOPoint, VoronoiSimplex, EPA};
+use ncollide3d::query::{self, Proximity};
+use ncollide3d::shape::{Ball, Cone, Cuboid};
+
+#[test]
+fn diagnostics_do_not_change_results() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let far = Isometry3::new(Vector3::new(3.0, 0.5, 0.2), Vector3::new(0.1, 0.2, 0.3));
+    let close = Isometry3::new(Vector3::new(1.5, 0.5, 0.2), Vector3::new(0.1, 0.2, 0.3));
+
+    let (dist, diag) = query::distance_with_diagnostics(&m1, &cuboid, &far, &cuboid);
+    assert_eq!(dist, query::distance(&m1, &cuboid, &far, &cuboid));
+    assert!(diag.gjk_runs > 0);
+    assert!(diag.gjk_iterations > 0);
+    assert!(!diag.epa_invoked);
+    assert!(!diag.convergence_failure);
+
+    let (contact, diag) = query::contact_with_diagnostics(&m1, &cuboid, &close, &cuboid, 0.0);
+    assert_eq!(contact, query::contact(&m1, &cuboid, &close, &cuboid, 0.0));
+    assert!(contact.unwrap().depth > 0.0);
+    assert!(diag.gjk_runs > 0);
+    assert!(diag.epa_invoked);
+    assert!(diag.epa_iterations > 0);
+    assert!(diag.epa_max_polytope_size >= 4);
+    assert_eq!(diag.epa_unconverged_runs, 0);
+    assert!(!diag.convergence_failure);
+
+    let (prox, diag) = query::proximity_with_diagnostics(&m1, &cuboid, &far, &cuboid, 0.1);
+    assert_eq!(prox, Proximity::Disjoint);
+    assert!(diag.gjk_runs > 0);
+    assert!(!diag.epa_invoked);
+}
+
+#[test]
+fn analytic_queries_have_empty_diagnostics() {
+    let ball = Ball::new(1.0);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.5, 0.0, 0.0);
+
+    let (contact, diag) = query::contact_with_diagnostics(&m1, &ball, &m2, &ball, 0.0);
+    assert!(contact.is_some());
+    assert_eq!(diag, query::QueryDiagnostics::default());
+}
+
+#[test]
+fn unconverged_epa_on_degenerate_overlap() {
+    // Two almost flat cones overlapping deeply: the EPA polytope becomes too degenerate for the
+    // gap between the bounds of the penetration depth to reach its tolerance.
+    let cone = Cone::new(1.0e-6, 1.0);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.01, 0.0, 0.0), Vector3::new(-0.5, 0.0, 0.5));
+
+    let (contact, diag) = query::contact_with_diagnostics(&m1, &cone, &m2, &cone, 0.0);
+    assert_eq!(contact, query::contact(&m1, &cone, &m2, &cone, 0.0));
+    assert!(contact.is_some());
+    assert!(diag.epa_invoked);
+    assert!(diag.epa_iterations > 0);
+    assert!(diag.epa_max_polytope_size > diag.epa_iterations);
+    assert_eq!(diag.epa_unconverged_runs, 1);
+}
+
+#[test]
+fn touching_contact_converges() {
+    // The simplex is reduced to the origin when the shapes touch exactly.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(2.0, 2.0, 2.0);
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::new(
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ));
+
+    let (res, diag) =
+        query::with_diagnostics(|| EPA::new().closest_points(&m1, &cuboid, &m2, &cuboid, &simplex));
+    assert!(res.is_some());
+    assert!(diag.epa_invoked);
+    assert_eq!(diag.epa_unconverged_runs, 0);
+}
+
+#[test]
+fn nested_diagnostics() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.5, 0.2);
+
+    let ((_, inner), outer) = query::with_diagnostics(|| {
+        let _ = query::distance(&m1, &cuboid, &m2, &cuboid);
+        query::distance_with_diagnostics(&m1, &cuboid, &m2, &cuboid)
+    });
+
+    assert_eq!(outer.gjk_runs, 2 * inner.gjk_runs);
+    assert_eq!(outer.gjk_iterations, 2 * inner.gjk_iterations);
+}
@@ -0,0 +1,91 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{self, Ray};
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn ray_closest_points_ball() {
+    let ray = Ray::new(Point3::new(-5.0, 2.0, 0.0), Vector3::x());
+    let ball = Ball::new(1.0);
+    let m = Isometry3::identity();
+
+    // The ray passes above the ball.
+    let (pt1, pt2, dist) = query::ray_closest_points(&ray, 10.0, &m, &ball);
+    assert_relative_eq!(pt1, Point3::new(0.0, 2.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(pt2, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(dist, 1.0, epsilon = 1.0e-7);
+
+    // The ray stops before reaching the ball.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let (pt1, pt2, dist) = query::ray_closest_points(&ray, 2.0, &m, &ball);
+    assert_relative_eq!(pt1, Point3::new(-3.0, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(pt2, Point3::new(-1.0, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-7);
+
+    // The ray hits the ball.
+    let (pt1, pt2, dist) = query::ray_closest_points(&ray, 10.0, &m, &ball);
+    assert_relative_eq!(pt1, Point3::new(-1.0, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_eq!(pt1, pt2);
+    assert_eq!(dist, 0.0);
+}
+
+#[test]
+fn ray_closest_points_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m = Isometry3::translation(0.0, 0.0, 10.0);
+
+    // The ray passes diagonally next to an edge of the cuboid.
+    let ray = Ray::new(Point3::new(3.0, -10.0, 10.0), Vector3::y());
+    let (pt1, pt2, dist) = query::ray_closest_points(&ray, 20.0, &m, &cuboid);
+    assert_relative_eq!(pt1.x, 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(pt1.z, 10.0, epsilon = 1.0e-5);
+    assert_relative_eq!(pt2.x, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-5);
+
+    // The ray hits the cuboid.
+    let ray = Ray::new(Point3::new(0.0, -10.0, 10.0), Vector3::y());
+    let (pt1, pt2, dist) = query::ray_closest_points(&ray, 20.0, &m, &cuboid);
+    assert_relative_eq!(pt1, Point3::new(0.0, -1.0, 10.0), epsilon = 1.0e-5);
+    assert_eq!(pt1, pt2);
+    assert_eq!(dist, 0.0);
+}
+
+#[test]
+fn closest_points_unbounded_ray_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m = Isometry3::translation(0.0, 0.0, 10.0);
+
+    // The ray misses the cuboid, passing next to one of its edges.
+    let ray = Ray::new(Point3::new(3.0, -100.0, 10.0), Vector3::y());
+    let (pt1, pt2, sq_dist) = query::closest_points_ray_shape(&ray, &m, &cuboid);
+    assert_relative_eq!(pt1.x, 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(pt1.z, 10.0, epsilon = 1.0e-5);
+    assert_relative_eq!(pt2.x, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(sq_dist, 4.0, epsilon = 1.0e-5);
+
+    // The ray points away from the cuboid: its origin is the closest point.
+    let ray = Ray::new(Point3::new(0.0, -5.0, 10.0), -Vector3::y());
+    let (pt1, pt2, sq_dist) = query::closest_points_ray_shape(&ray, &m, &cuboid);
+    assert_relative_eq!(pt1, ray.origin, epsilon = 1.0e-5);
+    assert_relative_eq!(pt2, Point3::new(0.0, -1.0, 10.0), epsilon = 1.0e-5);
+    assert_relative_eq!(sq_dist, 16.0, epsilon = 1.0e-5);
+
+    // The ray origin is inside of the cuboid.
+    let ray = Ray::new(Point3::new(0.5, 0.0, 10.0), Vector3::x());
+    let (pt1, pt2, sq_dist) = query::closest_points_ray_shape(&ray, &m, &cuboid);
+    assert_eq!(pt1, ray.origin);
+    assert_eq!(pt2, ray.origin);
+    assert_eq!(sq_dist, 0.0);
+}
+
+#[test]
+fn closest_points_unbounded_ray_ball() {
+    let ball = Ball::new(1.0);
+    let m = Isometry3::translation(50.0, 0.0, 0.0);
+
+    // The closest point is far along the ray.
+    let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), Vector3::x());
+    let (pt1, pt2, sq_dist) = query::closest_points_ray_shape(&ray, &m, &ball);
+    assert_relative_eq!(pt1, Point3::new(50.0, 3.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(pt2, Point3::new(50.0, 1.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(sq_dist, 4.0, epsilon = 1.0e-7);
+}
@@ -0,0 +1,85 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
+use ncollide3d::query::{self, DefaultTOIDispatcher, TOIStatus};
+use ncollide3d::shape::Cuboid;
+
+// A long thin rod along the `y` axis, spinning about the `z` axis toward a wall at `x = 1.2`.
+fn rod_and_wall(
+    angvel: f64,
+) -> (
+    ConstantVelocityRigidMotion<f64>,
+    Cuboid<f64>,
+    ConstantVelocityRigidMotion<f64>,
+    Cuboid<f64>,
+) {
+    let rod = Cuboid::new(Vector3::new(0.05, 1.5, 0.05));
+    let wall = Cuboid::new(Vector3::new(0.1, 5.0, 5.0));
+    let motion1 = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        Point3::origin(),
+        Vector3::zeros(),
+        Vector3::z() * -angvel,
+    );
+    let motion2 = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::translation(1.3, 0.0, 0.0),
+        Point3::origin(),
+        Vector3::zeros(),
+        Vector3::zeros(),
+    );
+
+    (motion1, rod, motion2, wall)
+}
+
+#[test]
+fn spinning_rod_hits_wall() {
+    let (motion1, rod, motion2, wall) = rod_and_wall(2.0);
+
+    // The linear-only time of impact does not see the rotation.
+    let linear = query::time_of_impact(
+        &DefaultTOIDispatcher,
+        &motion1.start,
+        &motion1.linvel,
+        &rod,
+        &motion2.start,
+        &motion2.linvel,
+        &wall,
+        1.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(linear.is_none());
+
+    let toi = query::nonlinear_time_of_impact_rotating_support_map_support_map(
+        &motion1, &rod, &motion2, &wall, 1.0, 0.0,
+    )
+    .expect("The spinning rod should hit the wall.");
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert!(toi.toi > 0.3 && toi.toi < 0.5, "{}", toi.toi);
+
+    // The shapes touch at the time of impact, and were separated just before.
+    let dist = |t: f64| {
+        query::distance(
+            &motion1.position_at_time(t),
+            &rod,
+            &motion2.position_at_time(t),
+            &wall,
+        )
+    };
+    assert!(dist(toi.toi) < 1.0e-6);
+    assert!(dist(toi.toi - 0.01) > 0.0);
+
+    // The witness point on the rod is at its tip.
+    assert_relative_eq!(toi.witness1.y.abs(), 1.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn slowly_spinning_rod_misses_wall() {
+    let (motion1, rod, motion2, wall) = rod_and_wall(0.5);
+
+    let toi = query::nonlinear_time_of_impact_rotating_support_map_support_map(
+        &motion1, &rod, &motion2, &wall, 1.0, 0.0,
+    );
+    assert!(toi.is_none());
+}
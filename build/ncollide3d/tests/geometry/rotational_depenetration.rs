@@ -0,0 +1,78 @@
+use na::{Isometry3, UnitQuaternion, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn rotational_depenetration_of_a_bar() {
+    let bar = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.5, 0.4, 0.0);
+    let axis = Vector3::z_axis();
+
+    let angle = query::rotational_depenetration(&m1, &bar, &m2, &ball, &axis).unwrap();
+    // Lowering the end of the bar below the ball is the shortest way to separate them.
+    assert!(angle < 0.0);
+
+    let rotated = |angle: f64| {
+        Isometry3::from_parts(
+            m1.translation,
+            UnitQuaternion::from_axis_angle(&axis, angle) * m1.rotation,
+        )
+    };
+    let depth = |angle: f64| {
+        query::contact(&rotated(angle), &bar, &m2, &ball, 0.0)
+            .map(|c| c.depth)
+            .unwrap_or(0.0)
+    };
+
+    assert_eq!(depth(angle), 0.0);
+    assert!(depth(angle * 0.99) > 0.0);
+    assert!(depth(-angle) > 0.0);
+}
+
+#[test]
+fn rotational_depenetration_trivial_cases() {
+    let ball = Ball::new(0.5);
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let axis = Vector3::y_axis();
+
+    // Not penetrating.
+    let m2 = Isometry3::translation(3.0, 0.0, 0.0);
+    assert_eq!(
+        query::rotational_depenetration(&Isometry3::identity(), &ball, &m2, &cuboid, &axis),
+        Some(0.0)
+    );
+
+    // A ball rotating around its center stays at the same place.
+    let m2 = Isometry3::translation(1.2, 0.0, 0.0);
+    assert_eq!(
+        query::rotational_depenetration(&Isometry3::identity(), &ball, &m2, &cuboid, &axis),
+        None
+    );
+}
+
+#[test]
+fn rotational_depenetration_of_a_shallow_tangential_penetration() {
+    // The ball barely penetrates the end of a long bar, so rotating the bar moves its end almost
+    // tangentially to the ball and the penetration depth decreases very slowly.
+    let bar = Cuboid::new(Vector3::new(10.0, 0.1, 0.1));
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(10.5 - 1.0e-6, 0.0, 0.0);
+    let axis = Vector3::z_axis();
+
+    let angle = query::rotational_depenetration(&m1, &bar, &m2, &ball, &axis).unwrap();
+    let rotated = Isometry3::from_parts(
+        m1.translation,
+        UnitQuaternion::from_axis_angle(&axis, angle) * m1.rotation,
+    );
+    let almost_rotated = Isometry3::from_parts(
+        m1.translation,
+        UnitQuaternion::from_axis_angle(&axis, angle * 0.99) * m1.rotation,
+    );
+
+    assert!(angle.abs() < 0.1);
+    assert!(query::contact(&rotated, &bar, &m2, &ball, 0.0).is_none());
+    assert!(query::contact(&almost_rotated, &bar, &m2, &ball, 0.0).is_some());
+}
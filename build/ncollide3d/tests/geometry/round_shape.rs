@@ -0,0 +1,63 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, RoundShape, Segment, Shape, ShapeHandle, SupportMap, Triangle};
+
+fn triangle() -> Triangle<f64> {
+    Triangle::new(
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(2.0, 0.0, -0.5),
+        Point3::new(0.0, 0.0, 1.5),
+    )
+}
+
+#[test]
+fn round_triangle_distance_to_ball() {
+    let thin = triangle();
+    let thick = RoundShape::new(ShapeHandle::new(triangle()), 0.2);
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::new(Vector3::new(0.3, -0.2, 0.1), Vector3::new(0.2, -0.1, 0.4));
+    let positions = [
+        // Facing the triangle face.
+        Isometry3::translation(0.5, 2.0, 0.0),
+        // Beyond an edge.
+        Isometry3::translation(0.5, 1.0, -3.0),
+        // Beyond a vertex.
+        Isometry3::translation(4.0, -1.0, -1.5),
+    ];
+
+    for m2 in positions.iter() {
+        let expected = query::distance(&m1, &thin, m2, &ball) - 0.2;
+        let actual = query::distance(&m1, &thick, m2, &ball);
+        assert!(expected > 0.0);
+        // GJK converges slowly on curved shapes.
+        assert_relative_eq!(actual, expected, epsilon = 1.0e-4);
+    }
+
+    // The thick triangle behaves like a slab: a ball resting close to the face penetrates it.
+    let m2 = m1 * Isometry3::translation(0.3, 0.6, 0.0);
+    let contact = query::contact(&m1, &thick, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-4);
+    assert_relative_eq!(*contact.normal, m1 * Vector3::y(), epsilon = 1.0e-4);
+    assert!(query::contact(&m1, &thin, &m2, &ball, 0.0).is_none());
+}
+
+#[test]
+fn round_segment_support_point_and_aabb() {
+    let segment = Segment::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0));
+    let round = RoundShape::new(ShapeHandle::new(segment), 0.5);
+    let dir = Vector3::new(1.0, 0.0, 1.0);
+
+    assert_relative_eq!(
+        round.local_support_point(&dir),
+        Point3::new(2.0, 1.0, 0.0) + dir.normalize() * 0.5
+    );
+    // A zero direction does not inflate the support point.
+    assert_eq!(
+        round.local_support_point(&Vector3::zeros()),
+        segment.local_support_point(&Vector3::zeros())
+    );
+
+    let local = round.local_aabb();
+    assert_relative_eq!(local.mins(), &Point3::new(-0.5, -0.5, -0.5));
+    assert_relative_eq!(local.maxs(), &Point3::new(2.5, 1.5, 0.5));
+}
@@ -0,0 +1,105 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{self, PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, InvalidShape, ScaledShape, Shape, ShapeHandle, TriMesh};
+
+#[test]
+fn scaled_shape_rejects_invalid_scales() {
+    let cube = ShapeHandle::new(Cuboid::new(Vector3::repeat(1.0f64)));
+
+    assert_eq!(
+        ScaledShape::new(cube.clone(), Vector3::new(1.0, 0.0, 1.0)).err(),
+        Some(InvalidShape::NonPositiveDimension)
+    );
+    assert_eq!(
+        ScaledShape::new(cube.clone(), Vector3::new(1.0, 2.0, -1.0)).err(),
+        Some(InvalidShape::NonPositiveDimension)
+    );
+    assert_eq!(
+        ScaledShape::new(cube.clone(), Vector3::new(std::f64::NAN, 1.0, 1.0)).err(),
+        Some(InvalidShape::NonFiniteDimension)
+    );
+    assert!(ScaledShape::new(cube, Vector3::new(1.0, 2.0, 3.0)).is_ok());
+}
+
+#[test]
+fn scaled_cube_matches_cuboid() {
+    let cube = ShapeHandle::new(Cuboid::new(Vector3::repeat(1.0f64)));
+    let scaled = ScaledShape::new(cube, Vector3::new(2.0, 3.0, 0.5)).unwrap();
+    let cuboid = Cuboid::new(Vector3::new(2.0, 3.0, 0.5));
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.2, 0.7));
+
+    let aabb1 = scaled.aabb(&m);
+    let aabb2 = cuboid.aabb(&m);
+    assert_relative_eq!(aabb1.mins(), aabb2.mins(), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb1.maxs(), aabb2.maxs(), epsilon = 1.0e-6);
+
+    let sphere = scaled.bounding_sphere(&m);
+    assert!(sphere.radius() >= cuboid.bounding_sphere(&m).radius() - 1.0e-6);
+
+    for i in -3..=3 {
+        for j in -3..=3 {
+            for k in -3..=3 {
+                let v = Vector3::new(i as f64, j as f64, k as f64) * 1.3 + Vector3::x() * 0.1;
+                let pt = m * Point3::from(v);
+
+                assert_relative_eq!(
+                    scaled.distance_to_point(&m, &pt, true),
+                    cuboid.distance_to_point(&m, &pt, true),
+                    epsilon = 1.0e-6
+                );
+
+                let ray = Ray::new(pt, m * -v);
+                let inter1 = scaled.toi_and_normal_with_ray(&m, &ray, 10.0, true);
+                let inter2 = cuboid.toi_and_normal_with_ray(&m, &ray, 10.0, true);
+                assert_eq!(inter1.is_some(), inter2.is_some());
+
+                if let (Some(inter1), Some(inter2)) = (inter1, inter2) {
+                    assert_relative_eq!(inter1.toi, inter2.toi, epsilon = 1.0e-6);
+
+                    if inter2.toi > 0.0 {
+                        assert_relative_eq!(inter1.normal, inter2.normal, epsilon = 1.0e-6);
+                    }
+                }
+            }
+        }
+    }
+
+    // The narrow phase can use the scaled shape as a support map.
+    let ball = Ball::new(1.0);
+    let m2 = m * Isometry3::translation(0.3, 3.5, 0.1);
+    let contact1 = query::contact(&m, &scaled, &m2, &ball, 0.0).unwrap();
+    let contact2 = query::contact_support_map_support_map(&m, &cuboid, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact1.depth, 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(contact1.depth, contact2.depth, epsilon = 1.0e-5);
+    assert_relative_eq!(contact1.normal, contact2.normal, epsilon = 1.0e-5);
+}
+
+#[test]
+fn scaled_trimesh_ray_cast() {
+    let points = vec![
+        Point3::new(-1.0f64, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = ShapeHandle::new(TriMesh::new(points, indices, None));
+    let scaled = ScaledShape::new(mesh, Vector3::new(4.0, 1.0, 1.0)).unwrap();
+    let m = Isometry3::translation(0.0, 1.0, 0.0);
+
+    assert!(!scaled.is_support_map());
+    assert!(scaled.as_point_query().is_none());
+
+    let aabb = scaled.aabb(&m);
+    assert_relative_eq!(*aabb.mins(), Point3::new(-4.0, 1.0, -1.0));
+    assert_relative_eq!(*aabb.maxs(), Point3::new(4.0, 1.0, 1.0));
+
+    let hit = Ray::new(Point3::new(3.5, 3.0, 0.0), -Vector3::y());
+    let miss = Ray::new(Point3::new(4.5, 3.0, 0.0), -Vector3::y());
+    let inter = scaled
+        .toi_and_normal_with_ray(&m, &hit, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal.y.abs(), 1.0, epsilon = 1.0e-6);
+    assert!(scaled.toi_with_ray(&m, &miss, 10.0, true).is_none());
+}
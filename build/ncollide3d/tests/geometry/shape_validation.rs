@@ -0,0 +1,99 @@
+use na::{Point2, Point3, Vector3};
+use ncollide3d::shape::{Ball, Capsule, ConvexHull, Cuboid, InvalidShape, TriMesh};
+
+#[test]
+fn reject_invalid_dimensions() {
+    assert_eq!(Ball::try_new(1.0f64).unwrap().radius, 1.0);
+    assert_eq!(
+        Ball::try_new(f64::NAN),
+        Err(InvalidShape::NonFiniteDimension)
+    );
+    assert_eq!(
+        Ball::try_new(0.0f64),
+        Err(InvalidShape::NonPositiveDimension)
+    );
+
+    assert!(Cuboid::try_new(Vector3::new(1.0f64, 2.0, 3.0)).is_ok());
+    assert_eq!(
+        Cuboid::try_new(Vector3::new(1.0, f64::INFINITY, 3.0)),
+        Err(InvalidShape::NonFiniteDimension)
+    );
+    assert_eq!(
+        Cuboid::try_new(Vector3::new(1.0f64, 2.0, -3.0)),
+        Err(InvalidShape::NonPositiveDimension)
+    );
+
+    assert!(Capsule::try_new(1.0f64, 0.5).is_ok());
+    assert_eq!(
+        Capsule::try_new(1.0, f64::NAN),
+        Err(InvalidShape::NonFiniteDimension)
+    );
+}
+
+#[test]
+fn reject_non_finite_vertices() {
+    let points = vec![
+        Point3::origin(),
+        Point3::new(1.0f64, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 2, 1),
+        Point3::new(0, 1, 3),
+        Point3::new(0, 3, 2),
+        Point3::new(1, 2, 3),
+    ];
+
+    assert!(TriMesh::try_new(points.clone(), indices.clone(), None).is_ok());
+    assert!(ConvexHull::try_from_points(&points).is_ok());
+
+    let mut bad_points = points;
+    bad_points[2].y = f64::NAN;
+
+    assert_eq!(
+        TriMesh::try_new(bad_points.clone(), indices, None).err(),
+        Some(InvalidShape::NonFiniteVertex(2))
+    );
+    assert_eq!(
+        ConvexHull::try_from_points(&bad_points).err(),
+        Some(InvalidShape::NonFiniteVertex(2))
+    );
+}
+
+#[test]
+fn reject_invalid_faces() {
+    let points = vec![
+        Point3::origin(),
+        Point3::new(1.0f64, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)];
+
+    assert_eq!(
+        TriMesh::try_new(points.clone(), indices, None).err(),
+        Some(InvalidShape::InvalidIndex(1))
+    );
+
+    let uvs = vec![Point2::origin(); 2];
+    assert_eq!(
+        TriMesh::try_new(points, vec![Point3::new(0, 1, 2)], Some(uvs)).err(),
+        Some(InvalidShape::InvalidUVs)
+    );
+}
+
+#[test]
+fn reject_degenerate_convex_hull() {
+    let points = vec![
+        Point3::origin(),
+        Point3::origin(),
+        Point3::new(0.0f64, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let indices = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+
+    assert_eq!(
+        ConvexHull::try_new(points, &indices).err(),
+        Some(InvalidShape::Degenerate)
+    );
+}
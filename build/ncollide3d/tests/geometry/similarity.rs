@@ -0,0 +1,39 @@
+use na::{Isometry3, Point3, Similarity3, Vector3};
+use ncollide3d::bounding_volume;
+use ncollide3d::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn scaled_cuboid_queries() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball = Ball::new(0.5);
+    let m1 = Similarity3::from_isometry(Isometry3::translation(1.0, 0.0, 0.0), 2.0);
+    let m2 = Similarity3::from_isometry(Isometry3::translation(6.0, 0.0, 0.0), 2.0);
+
+    // The cuboid spans [-1, 3] along `x`, and the ball has a radius of 1.
+    let dist = query::distance_with_similarity(&m1, &cuboid, &m2, &ball);
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-6);
+    assert_eq!(
+        query::proximity_with_similarity(&m1, &cuboid, &m2, &ball, 1.0),
+        Proximity::Disjoint
+    );
+
+    let contact = query::contact_with_similarity(&m1, &cuboid, &m2, &ball, 3.0).unwrap();
+    assert_relative_eq!(contact.depth, -2.0, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.world1, Point3::new(3.0, 0.0, 0.0), epsilon = 1.0e-3);
+    assert_relative_eq!(contact.world2, Point3::new(5.0, 0.0, 0.0), epsilon = 1.0e-3);
+
+    let proj = cuboid.project_point_with_similarity(&m1, &Point3::new(5.0, 0.5, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(3.0, 0.5, 0.0), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(10.0, 0.0, 0.0), -Vector3::x());
+    let inter = cuboid
+        .toi_and_normal_with_ray_and_similarity(&m1, &ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 7.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, Vector3::x(), epsilon = 1.0e-6);
+
+    let aabb = bounding_volume::aabb_with_similarity(&cuboid, &m1);
+    assert_relative_eq!(aabb.mins, Point3::new(-1.0, -2.0, -2.0), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs, Point3::new(3.0, 2.0, 2.0), epsilon = 1.0e-6);
+}
@@ -0,0 +1,115 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, FeatureId, Slab};
+
+// The region between y = 0 and y = 2.
+fn slab() -> Slab<f64> {
+    Slab::new(Vector3::y_axis(), 1.0, 2.0)
+}
+
+#[test]
+fn slab_point_query() {
+    let slab = slab();
+    let m = Isometry3::translation(0.0, -1.0, 0.0);
+
+    assert!(slab.contains_point(&m, &Point3::new(10.0, 0.5, -4.0)));
+    assert!(!slab.contains_point(&m, &Point3::new(10.0, 1.5, -4.0)));
+    assert!(!slab.contains_point(&m, &Point3::new(10.0, -1.5, -4.0)));
+
+    let (proj, feature) = slab.project_point_with_feature(&m, &Point3::new(3.0, 0.5, 2.0));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(3.0, 1.0, 2.0));
+    assert_eq!(feature, FeatureId::Face(0));
+
+    let (proj, feature) = slab.project_point_with_feature(&m, &Point3::new(3.0, -4.0, 2.0));
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(3.0, -1.0, 2.0));
+    assert_eq!(feature, FeatureId::Face(1));
+
+    let pt = Point3::new(3.0, -0.5, 2.0);
+    assert_eq!(slab.project_point(&m, &pt, true).point, pt);
+    assert_relative_eq!(slab.distance_to_point(&m, &pt, false), -0.5);
+    assert_relative_eq!(
+        slab.distance_to_point(&m, &Point3::new(0.0, 3.0, 0.0), true),
+        2.0
+    );
+}
+
+#[test]
+fn slab_ray_cast() {
+    let slab = slab();
+    let m = Isometry3::identity();
+
+    // From above.
+    let ray = Ray::new(Point3::new(1.0, 5.0, 0.0), -Vector3::y());
+    let inter = slab
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 3.0);
+    assert_relative_eq!(inter.normal, Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    // From below, going away.
+    let ray = Ray::new(Point3::new(1.0, -5.0, 0.0), -Vector3::y());
+    assert!(slab.toi_with_ray(&m, &ray, f64::MAX, true).is_none());
+
+    // From inside.
+    let ray = Ray::new(Point3::new(1.0, 0.5, 0.0), Vector3::new(0.0, -1.0, 1.0));
+    assert_eq!(slab.toi_with_ray(&m, &ray, f64::MAX, true), Some(0.0));
+    let inter = slab
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 0.5);
+    assert_relative_eq!(inter.normal, Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    // Parallel to the slab.
+    let ray = Ray::new(Point3::new(1.0, 5.0, 0.0), Vector3::x());
+    assert!(slab.toi_with_ray(&m, &ray, f64::MAX, true).is_none());
+}
+
+#[test]
+fn slab_contact() {
+    let slab = slab();
+    let m = Isometry3::identity();
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+
+    // Penetrating the top plane.
+    let pos = Isometry3::translation(0.0, 2.5, 0.0);
+    let c = query::contact(&m, &slab, &pos, &cuboid, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.5);
+    assert_relative_eq!(*c.normal, Vector3::y());
+
+    let c = query::contact(&pos, &cuboid, &m, &slab, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.5);
+    assert_relative_eq!(*c.normal, -Vector3::y());
+
+    // Completely above or below.
+    let pos = Isometry3::translation(0.0, 3.5, 0.0);
+    assert!(query::contact(&m, &slab, &pos, &cuboid, 0.0).is_none());
+    assert!(query::contact(&m, &slab, &pos, &cuboid, 1.0).is_some());
+    let pos = Isometry3::translation(0.0, -1.5, 0.0);
+    assert!(query::contact(&m, &slab, &pos, &cuboid, 0.0).is_none());
+    assert_eq!(
+        query::proximity(&m, &slab, &pos, &cuboid, 0.0),
+        Proximity::Disjoint
+    );
+}
+
+#[test]
+fn shape_inside_slab_is_contained() {
+    let slab = slab();
+    let m = Isometry3::identity();
+    let ball = Ball::new(0.5);
+
+    // The ball is closer to the bottom plane.
+    let pos = Isometry3::translation(4.0, 0.7, 0.0);
+    assert_eq!(
+        query::proximity(&m, &slab, &pos, &ball, 0.0),
+        Proximity::Intersecting
+    );
+
+    let c = query::contact(&m, &slab, &pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 1.2);
+    assert_relative_eq!(*c.normal, -Vector3::y());
+}
@@ -0,0 +1,66 @@
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, Shape};
+
+// Checks the projections of `pt`, inside of `shape`, on a solid and on a hollow `shape`.
+//
+// Returns the distance from `pt` to its projection on the hollow shape.
+fn check_inside_projections(shape: &dyn Shape<f64>, m: &Isometry3<f64>, pt: &Point3<f64>) -> f64 {
+    let solid = shape.project_point(m, pt, true);
+    assert!(solid.is_inside);
+    assert_eq!(solid.point, *pt);
+    assert_eq!(shape.distance_to_point(m, pt, true), 0.0);
+
+    let hollow = shape.project_point(m, pt, false);
+    let dist = na::distance(pt, &hollow.point);
+    assert!(hollow.is_inside);
+    assert_relative_eq!(
+        shape.distance_to_point(m, pt, false),
+        -dist,
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        shape.distance_to_point(m, &hollow.point, false),
+        0.0,
+        epsilon = 1.0e-7
+    );
+    dist
+}
+
+#[test]
+fn solid_and_hollow_ball_center_projection() {
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.2, 0.1));
+    let center = Point3::from(m.translation.vector);
+    let dist = check_inside_projections(&Ball::new(1.5), &m, &center);
+    assert_relative_eq!(dist, 1.5, epsilon = 1.0e-7);
+
+    let inner = m * Point3::new(0.2, 0.3, -0.1);
+    let dist = check_inside_projections(&Ball::new(1.5), &m, &inner);
+    assert_relative_eq!(
+        dist,
+        1.5 - inner.coords.metric_distance(&center.coords),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn solid_and_hollow_cuboid_and_convex_hull_projection() {
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.2, 0.1));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let mut points = Vec::new();
+    for x in &[-1.0, 1.0] {
+        for y in &[-2.0, 2.0] {
+            for z in &[-3.0, 3.0] {
+                points.push(Point3::new(*x, *y, *z));
+            }
+        }
+    }
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+
+    for pt in &[Point3::origin(), Point3::new(0.7, 0.5, -0.2)] {
+        let pt = m * pt;
+        let dist_cuboid = check_inside_projections(&cuboid, &m, &pt);
+        let dist_hull = check_inside_projections(&hull, &m, &pt);
+        assert_relative_eq!(dist_cuboid, dist_hull, epsilon = 1.0e-6);
+    }
+}
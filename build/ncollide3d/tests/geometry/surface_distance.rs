@@ -0,0 +1,35 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid};
+use std::f64::consts::PI;
+
+#[test]
+fn ball_surface_distance_is_great_circle_distance() {
+    let ball = Ball::new(2.0);
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+    let top = Point3::new(1.0, 4.0, 3.0);
+    let equator = Point3::new(3.0, 2.0, 3.0);
+    let bottom = Point3::new(1.0, 0.0, 3.0);
+
+    assert_relative_eq!(query::surface_distance(&m, &ball, &top, &equator), PI);
+    assert_relative_eq!(query::surface_distance(&m, &ball, &top, &bottom), 2.0 * PI);
+    assert_relative_eq!(query::surface_distance(&m, &ball, &top, &top), 0.0);
+}
+
+#[test]
+fn cuboid_surface_distance() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    // On the same face.
+    let a = Point3::new(-0.5, 1.0, 0.2);
+    let b = Point3::new(0.5, 1.0, -0.3);
+    let dist = query::surface_distance(&m, &cuboid, &(m * a), &(m * b));
+    assert_relative_eq!(dist, na::distance(&a, &b), epsilon = 1.0e-6);
+
+    // Across an edge.
+    let a = Point3::new(0.0, 1.0, 0.0);
+    let b = Point3::new(1.0, 0.0, 0.0);
+    let dist = query::surface_distance(&m, &cuboid, &(m * a), &(m * b));
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-6);
+}
@@ -0,0 +1,61 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{BoundingVolume, AABB};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Cuboid, HeightField};
+
+#[test]
+fn ball_time_to_enter_aabb() {
+    let ball = Ball::new(0.5);
+    let region = AABB::new(Point3::new(2.0, -1.0, -1.0), Point3::new(4.0, 1.0, 1.0));
+    let m_start = Isometry3::translation(-2.0, 0.0, 0.0);
+    let m_end = Isometry3::translation(6.0, 0.0, 0.0);
+
+    // The ball touches the region after traveling 3.5 out of 8.
+    let toi = query::time_to_enter_aabb(&m_start, &m_end, &ball, &region).unwrap();
+    assert_relative_eq!(toi, 3.5 / 8.0, epsilon = 1.0e-5);
+
+    // Already inside.
+    let toi = query::time_to_enter_aabb(&m_end, &m_start, &ball, &region.loosened(4.0));
+    assert_eq!(toi, Some(0.0));
+
+    // Never enters.
+    let m_end = Isometry3::translation(-2.0, 5.0, 0.0);
+    assert_eq!(
+        query::time_to_enter_aabb(&m_start, &m_end, &ball, &region),
+        None
+    );
+}
+
+#[test]
+fn rotating_cuboid_time_to_enter_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let region = AABB::new(Point3::new(1.5, 1.0, -1.0), Point3::new(2.5, 2.0, 1.0));
+
+    // The cuboid does not move but rotates until its tip sweeps through the region.
+    let m_start = Isometry3::identity();
+    let m_end = Isometry3::new(Vector3::zeros(), Vector3::z() * 1.0);
+    let toi = query::time_to_enter_aabb(&m_start, &m_end, &cuboid, &region).unwrap();
+    assert!(toi > 0.0 && toi < 1.0);
+
+    let m = m_start.lerp_slerp(&m_end, toi);
+    let dist = query::distance(
+        &m,
+        &cuboid,
+        &Isometry3::translation(2.0, 1.5, 0.0),
+        &Cuboid::new(Vector3::new(0.5, 0.5, 1.0)),
+    );
+    assert!(dist < 1.0e-3);
+}
+
+#[test]
+fn unsupported_time_to_enter_aabb() {
+    let heightfield = HeightField::new(DMatrix::zeros(3, 3), Vector3::new(4.0, 1.0, 4.0));
+    let region = AABB::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let m_start = Isometry3::translation(0.0, 5.0, 0.0);
+    let m_end = Isometry3::identity();
+
+    assert_eq!(
+        query::time_to_enter_aabb(&m_start, &m_end, &heightfield, &region),
+        None
+    );
+}
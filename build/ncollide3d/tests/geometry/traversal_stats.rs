@@ -0,0 +1,45 @@
+use crate::common::flat_floor;
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::partitioning;
+use ncollide3d::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn traversal_stats_do_not_change_results() {
+    let mesh = flat_floor(32);
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(10.3, 5.0, 20.7), -Vector3::y());
+
+    let (hit, stats) =
+        query::toi_and_normal_with_ray_with_traversal_stats(&m, &mesh, &ray, 100.0, true);
+    let hit = hit.unwrap();
+    let expected = mesh.toi_and_normal_with_ray(&m, &ray, 100.0, true).unwrap();
+    assert_eq!(hit.toi, expected.toi);
+    assert_eq!(hit.feature, expected.feature);
+    assert_relative_eq!(hit.toi, 5.0);
+    assert!(stats.leaves_tested >= 1);
+    assert!(stats.leaves_tested < mesh.faces().len() / 10);
+    assert!(stats.nodes_visited > stats.leaves_tested);
+    assert!(stats.max_depth > 0 && stats.max_depth < 32);
+
+    let point = Point3::new(3.2, 1.0, 7.9);
+    let (proj, stats) = query::project_point_with_traversal_stats(&m, &mesh, &point, true);
+    assert_eq!(proj.point, mesh.project_point(&m, &point, true).point);
+    assert!(stats.leaves_tested >= 1);
+    assert!(stats.nodes_visited > stats.leaves_tested);
+}
+
+#[test]
+fn nested_traversal_stats() {
+    let mesh = flat_floor(8);
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(2.5, 5.0, 2.5), -Vector3::y());
+
+    let ((_, inner), outer) = partitioning::with_traversal_stats(|| {
+        let _ = mesh.toi_with_ray(&m, &ray, 100.0, true);
+        partitioning::with_traversal_stats(|| mesh.toi_with_ray(&m, &ray, 100.0, true))
+    });
+
+    assert_eq!(outer.nodes_visited, inner.nodes_visited * 2);
+    assert_eq!(outer.leaves_tested, inner.leaves_tested * 2);
+    assert_eq!(outer.max_depth, inner.max_depth);
+}
@@ -0,0 +1,62 @@
+use crate::common::flat_grid;
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{BoundingVolume, AABB};
+use ncollide3d::partitioning::BVH;
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{DeformableShape, TriMesh};
+
+// Checks that the bounding volume of each node contains the bounding volumes of its children.
+fn assert_valid_bvt(bvt: &impl BVH<usize, AABB<f64>>) {
+    let mut stack = vec![bvt.root().unwrap()];
+
+    while let Some(node) = stack.pop() {
+        let bv = bvt.content(node).0;
+
+        for i in 0..bvt.num_children(node) {
+            let child = bvt.child(i, node);
+            assert!(bv.contains(bvt.content(child).0));
+            stack.push(child);
+        }
+    }
+}
+
+#[test]
+fn deformed_trimesh_matches_rebuilt_trimesh() {
+    let n = 24;
+    let (points, indices) = flat_grid(n);
+    let mut mesh = TriMesh::new(points.clone(), indices.clone(), None);
+
+    for step in 1..=4 {
+        // Fold and stretch the cloth a bit more at each step, moving some vertices far away from
+        // their original position.
+        let t = step as f64;
+        let deformed: Vec<_> = points
+            .iter()
+            .map(|p| {
+                let y = (p.x * 0.4 * t).sin() * 3.0 * t + (p.z * 0.01 * t * t).powi(3);
+                Point3::new(p.x * (1.0 + 0.3 * t), y, p.z - (p.x * 0.2).cos() * t)
+            })
+            .collect();
+        let coords: Vec<f64> = deformed.iter().flat_map(|p| p.iter().cloned()).collect();
+
+        mesh.set_deformations(&coords);
+        assert_valid_bvt(mesh.bvt());
+
+        let rebuilt = TriMesh::new(deformed, indices.clone(), None);
+        let m = Isometry3::identity();
+
+        for i in 0..20 {
+            for j in 0..20 {
+                let origin = Point3::new(i as f64 * 3.0 - 5.0, 100.0, j as f64 * 1.6 - 5.0);
+                let ray = Ray::new(origin, -Vector3::y());
+                let toi = mesh.toi_with_ray(&m, &ray, 1000.0, true);
+                let expected = rebuilt.toi_with_ray(&m, &ray, 1000.0, true);
+
+                assert_eq!(toi.is_some(), expected.is_some());
+                if let (Some(toi), Some(expected)) = (toi, expected) {
+                    assert_relative_eq!(toi, expected, epsilon = 1.0e-9);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use na::{Isometry3, Point2, Point3, Vector3};
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{FeatureId, TriMesh};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+// A bumpy `n x n` grid of unit squares around the plane `y = 0`, each split into two triangles.
+fn bumpy_floor(n: usize, reversed: bool) -> TriMesh<f64> {
+    let mut points = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            let y = ((i * 7 + j * 3) % 5) as f64 * 0.1;
+            points.push(Point3::new(i as f64, y, j as f64));
+            uvs.push(Point2::new(i as f64 / n as f64, j as f64 / n as f64));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + 1;
+            let c = a + n + 1;
+            let d = c + 1;
+            indices.push(Point3::new(a, b, d));
+            indices.push(Point3::new(a, d, c));
+        }
+    }
+
+    if reversed {
+        indices.reverse();
+    }
+
+    TriMesh::new(points, indices, Some(uvs))
+}
+
+// The point with the barycentric coordinates `bcoords` on the `i`-th face of `mesh`.
+fn reconstruct(mesh: &TriMesh<f64>, i: usize, bcoords: &Vector3<f64>) -> Point3<f64> {
+    let idx = mesh.faces()[i].indices;
+    let pts = mesh.points();
+    let coords = pts[idx[0]].coords * bcoords.x
+        + pts[idx[1]].coords * bcoords.y
+        + pts[idx[2]].coords * bcoords.z;
+
+    Point3::from(coords)
+}
+
+#[test]
+fn barycentric_coordinates_reconstruct_the_hit_point() {
+    let mesh = bumpy_floor(8, false);
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.1, 0.7, -0.2));
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let mut rand = move || rng.gen::<f64>();
+
+    for _ in 0..200 {
+        let target = m * Point3::new(rand() * 8.0, 0.2, rand() * 8.0);
+        let origin = target + Vector3::new(rand() - 0.5, 1.0, rand() - 0.5) * 5.0;
+        let ray = Ray::new(origin, target - origin);
+
+        let hit = mesh
+            .toi_and_normal_and_barycentric_coordinates_with_ray(&m, &ray, 10.0)
+            .unwrap();
+        let bcoords = hit.barycentric_coordinates;
+
+        assert_relative_eq!(bcoords.x + bcoords.y + bcoords.z, 1.0, epsilon = 1.0e-9);
+        assert!(bcoords.min() >= -1.0e-9);
+        assert_relative_eq!(
+            m * reconstruct(&mesh, hit.face, &bcoords),
+            ray.point_at(hit.intersection.toi),
+            epsilon = 1.0e-7
+        );
+        assert_eq!(hit.intersection.feature, FeatureId::Face(hit.face));
+
+        let expected = mesh.toi_and_normal_with_ray(&m, &ray, 10.0, true).unwrap();
+        assert_relative_eq!(hit.intersection.toi, expected.toi, epsilon = 1.0e-9);
+        assert_relative_eq!(hit.intersection.normal, expected.normal, epsilon = 1.0e-9);
+
+        // The texture coordinates are interpolated with the same barycentric coordinates.
+        let uv = mesh
+            .toi_and_normal_and_uv_with_ray(&m, &ray, 10.0, true)
+            .unwrap()
+            .uvs
+            .unwrap();
+        let ls_hit = reconstruct(&mesh, hit.face, &bcoords);
+        assert_relative_eq!(
+            uv,
+            Point2::new(ls_hit.x / 8.0, ls_hit.z / 8.0),
+            epsilon = 1.0e-7
+        );
+    }
+}
+
+#[test]
+fn shared_edges_and_vertices_pick_the_smallest_face_index() {
+    for reversed in &[false, true] {
+        let mesh = bumpy_floor(4, *reversed);
+        let m = Isometry3::identity();
+
+        // A shared diagonal, a shared edge between two squares, and a vertex shared by six
+        // triangles.
+        for target in &[
+            Point3::new(1.25, 0.0, 2.25),
+            Point3::new(2.0, 0.0, 1.5),
+            Point3::new(2.0, 0.0, 2.0),
+        ] {
+            let origin = Point3::new(target.x, 5.0, target.z);
+            let ray = Ray::new(origin, -Vector3::y());
+            let hit = mesh
+                .toi_and_normal_and_barycentric_coordinates_with_ray(&m, &ray, 10.0)
+                .unwrap();
+            let pt = ray.point_at(hit.intersection.toi);
+
+            // All the faces containing the hit point.
+            let containing: Vec<_> = (0..mesh.faces().len())
+                .filter(|i| {
+                    let tri = mesh.triangle_at(*i);
+                    tri.toi_with_ray(&m, &ray, 10.0, true)
+                        .map(|toi| (toi - hit.intersection.toi).abs() < 1.0e-9)
+                        .unwrap_or(false)
+                })
+                .collect();
+
+            assert!(containing.len() >= 2);
+            assert_eq!(hit.face, containing[0]);
+            assert_relative_eq!(
+                reconstruct(&mesh, hit.face, &hit.barycentric_coordinates),
+                pt,
+                epsilon = 1.0e-9
+            );
+        }
+    }
+}
@@ -56,3 +56,106 @@ fn trimesh_trimesh_toi() {
     let toi = do_toi_test();
     assert_eq!(toi, Some(0.00998));
 }
+
+// A flat square mesh on the plane `z = 0`, with `n * n` cells of unit size.
+fn build_grid(n: usize) -> TriMesh<f64> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            points.push(Point3::new(i as f64, j as f64, 0.0));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + n + 1;
+            indices.push(Point3::new(a, b, b + 1));
+            indices.push(Point3::new(a, b + 1, a + 1));
+        }
+    }
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn thin_trimeshes_toi() {
+    let floor = build_grid(10);
+    let sheet = build_grid(2);
+    let m1 = Isometry3::identity();
+    let vel1 = Vector3::zeros();
+    let vel2 = Vector3::new(0.0, 0.0, -100.0);
+
+    // The sheet falls flat on the floor within a single step.
+    let m2 = Isometry3::translation(3.5, 4.5, 5.0);
+    let toi = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &m1,
+        &vel1,
+        &floor,
+        &m2,
+        &vel2,
+        &sheet,
+        1.0,
+        0.0,
+    )
+    .unwrap()
+    .expect("The sheet should hit the floor.");
+    assert_relative_eq!(toi.toi, 0.05, epsilon = 1.0e-6);
+
+    // Tilted sheet: only one of its corners hits the floor first.
+    let m2 = Isometry3::new(Vector3::new(3.5, 4.5, 5.0), Vector3::new(0.3, 0.2, 0.0));
+    let lowest = sheet
+        .points()
+        .iter()
+        .map(|pt| (m2 * pt).z)
+        .fold(f64::MAX, f64::min);
+    let toi = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &m1,
+        &vel1,
+        &floor,
+        &m2,
+        &vel2,
+        &sheet,
+        1.0,
+        0.0,
+    )
+    .unwrap()
+    .expect("The sheet should hit the floor.");
+    assert_relative_eq!(toi.toi, lowest / 100.0, epsilon = 1.0e-6);
+    assert_relative_eq!((m1 * toi.witness1).z, 0.0, epsilon = 1.0e-6);
+
+    // The sheet moves too slowly to reach the floor before `max_toi`.
+    let toi = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &m1,
+        &vel1,
+        &floor,
+        &m2,
+        &Vector3::new(0.0, 0.0, -1.0),
+        &sheet,
+        1.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+
+    // The sheet falls next to the floor.
+    let m2 = Isometry3::translation(20.0, 4.5, 5.0);
+    let toi = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &m1,
+        &vel1,
+        &floor,
+        &m2,
+        &vel2,
+        &sheet,
+        1.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+}
@@ -0,0 +1,60 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume;
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{ConvexHull, SupportMap, Wedge};
+
+#[test]
+fn wedge_matches_its_convex_hull() {
+    let wedge = Wedge::new(Vector3::new(2.0, 1.0, 0.5));
+    let hull = ConvexHull::try_from_points(&wedge.vertices()).unwrap();
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.2, 0.7));
+
+    for i in -3..=3 {
+        for j in -3..=3 {
+            for k in -3..=3 {
+                let v = Vector3::new(i as f64, j as f64, k as f64) * 0.7 + Vector3::x() * 0.1;
+                let dir = v.normalize();
+
+                assert_relative_eq!(
+                    wedge.support_point(&m, &dir).coords.dot(&dir),
+                    hull.support_point(&m, &dir).coords.dot(&dir),
+                    epsilon = 1.0e-6
+                );
+
+                let pt = Point3::from(v);
+                assert_relative_eq!(
+                    wedge.distance_to_point(&m, &pt, true),
+                    hull.distance_to_point(&m, &pt, true),
+                    epsilon = 1.0e-6
+                );
+            }
+        }
+    }
+
+    let aabb = bounding_volume::aabb(&wedge, &m);
+    let hull_aabb = bounding_volume::aabb(&hull, &m);
+    assert_relative_eq!(aabb.mins, hull_aabb.mins, epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs, hull_aabb.maxs, epsilon = 1.0e-6);
+
+    let sphere = bounding_volume::bounding_sphere(&wedge, &m);
+    for pt in &wedge.vertices() {
+        assert_relative_eq!(
+            na::distance(&(m * pt), sphere.center()),
+            sphere.radius(),
+            epsilon = 1.0e-6
+        );
+    }
+}
+
+#[test]
+fn wedge_ray_cast_on_slope() {
+    let wedge = Wedge::new(Vector3::new(2.0, 1.0, 0.5));
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    let inter = wedge
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+
+    // The slope passes through the origin.
+    assert_relative_eq!(inter.toi, 5.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, *wedge.slope_normal(), epsilon = 1.0e-6);
+}
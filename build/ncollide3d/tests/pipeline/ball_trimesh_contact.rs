@@ -0,0 +1,103 @@
+use crate::common::flat_floor;
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::narrow_phase::{
+    BallTriMeshManifoldGenerator, ContactManifoldGenerator, DefaultContactDispatcher,
+};
+use ncollide3d::query::ContactPrediction;
+use ncollide3d::shape::{Ball, FeatureId, TriMesh};
+
+fn contacts(
+    mesh: &TriMesh<f64>,
+    ball: &Ball<f64>,
+    center: Point3<f64>,
+) -> Vec<(Vector3<f64>, f64, FeatureId)> {
+    let mut generator = BallTriMeshManifoldGenerator::new(false);
+    let mut manifold = generator.init_manifold();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let m1 = Isometry3::translation(center.x, center.y, center.z);
+
+    assert!(generator.generate_contacts(
+        &DefaultContactDispatcher::new(),
+        &m1,
+        ball,
+        None,
+        &Isometry3::identity(),
+        mesh,
+        None,
+        &prediction,
+        &mut manifold,
+    ));
+
+    manifold
+        .contacts()
+        .map(|c| (*c.contact.normal, c.contact.depth, c.kinematic.feature2()))
+        .collect()
+}
+
+#[test]
+fn ball_rolling_on_flat_triangulated_floor() {
+    let mesh = flat_floor(4);
+    let ball = Ball::new(0.5);
+
+    for k in 0..=60 {
+        // Roll diagonally across the floor so the ball passes over vertices, internal edges,
+        // and diagonals of the quads.
+        let t = 0.5 + k as f64 * 0.05;
+        let center = Point3::new(t, 0.4, 0.5 + k as f64 * 0.04);
+        let contacts = contacts(&mesh, &ball, center);
+
+        assert!(!contacts.is_empty());
+
+        for (normal, depth, _) in contacts {
+            assert_relative_eq!(normal, -Vector3::y(), epsilon = 1.0e-7);
+            assert_relative_eq!(depth, 0.1, epsilon = 1.0e-7);
+        }
+    }
+
+    // Exactly above an internal vertex: a single upward contact.
+    let on_vertex = contacts(&mesh, &ball, Point3::new(2.0, 0.4, 2.0));
+    assert_eq!(on_vertex.len(), 1);
+    assert_eq!(on_vertex[0].2, FeatureId::Vertex(2 * 5 + 2));
+    assert_relative_eq!(on_vertex[0].0, -Vector3::y(), epsilon = 1.0e-7);
+
+    // Exactly above an internal edge: the faces on both sides may be reported, but all with
+    // the same upward normal.
+    let on_edge = contacts(&mesh, &ball, Point3::new(2.0, 0.4, 2.5));
+    assert!(!on_edge.is_empty());
+
+    for (normal, depth, _) in on_edge {
+        assert_relative_eq!(normal, -Vector3::y(), epsilon = 1.0e-7);
+        assert_relative_eq!(depth, 0.1, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn ball_against_convex_ridge() {
+    // Two triangles forming a roof with a ridge along the z axis.
+    let points = vec![
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, 1.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 1, 3),
+        Point3::new(0, 3, 2),
+        Point3::new(2, 3, 5),
+        Point3::new(2, 5, 4),
+    ];
+    let mesh = TriMesh::new(points, indices, None);
+    let ball = Ball::new(0.5);
+
+    // Right above the ridge, slightly to the right: the ridge edge is hit.
+    let contacts = contacts(&mesh, &ball, Point3::new(0.1, 0.45, 0.0));
+    assert_eq!(contacts.len(), 1);
+    match contacts[0].2 {
+        FeatureId::Edge(_) => {}
+        f => panic!("Expected an edge contact, found {:?}.", f),
+    }
+    let expected = -Vector3::new(0.1, 0.45, 0.0).normalize();
+    assert_relative_eq!(contacts[0].0, expected, epsilon = 1.0e-7);
+}
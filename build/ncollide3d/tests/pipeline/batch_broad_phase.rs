@@ -0,0 +1,75 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{BroadPhase, BroadPhaseInterferenceHandler, DBVTBroadPhase};
+use std::collections::BTreeSet;
+
+struct PairsCollector(BTreeSet<(usize, usize)>);
+
+impl BroadPhaseInterferenceHandler<usize> for PairsCollector {
+    fn is_interference_allowed(&mut self, a: &usize, b: &usize) -> bool {
+        (a + b) % 7 != 0
+    }
+
+    fn interference_started(&mut self, a: &usize, b: &usize) {
+        assert!(self.0.insert((*a.min(b), *a.max(b))));
+    }
+
+    fn interference_stopped(&mut self, a: &usize, b: &usize) {
+        assert!(self.0.remove(&(*a.min(b), *a.max(b))));
+    }
+}
+
+fn aabb(seed: &mut u64) -> AABB<f64> {
+    let mut rand = || {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*seed >> 33) as f64 / (1u64 << 31) as f64
+    };
+
+    let center = Point3::new(rand() * 30.0, rand() * 30.0, rand() * 30.0);
+    AABB::from_half_extents(center, Vector3::repeat(0.5 + rand()))
+}
+
+#[test]
+fn batch_insertion_matches_incremental_insertion() {
+    let mut incremental = DBVTBroadPhase::new(0.1);
+    let mut batch = DBVTBroadPhase::new(0.1);
+    let mut incremental_pairs = PairsCollector(BTreeSet::new());
+    let mut batch_pairs = PairsCollector(BTreeSet::new());
+
+    let mut seed = 42;
+    let mut handles = Vec::new();
+
+    for round in 0..3 {
+        // The second batch is inserted into a non-empty tree.
+        let bvs: Vec<_> = (0..300).map(|_| aabb(&mut seed)).collect();
+        let first = round * bvs.len();
+        let hs = batch.create_proxies_batch(
+            bvs.iter()
+                .cloned()
+                .enumerate()
+                .map(|(i, bv)| (bv, first + i)),
+        );
+        assert_eq!(hs.len(), bvs.len());
+
+        for (i, (bv, h2)) in bvs.into_iter().zip(hs.into_iter()).enumerate() {
+            let h1 = incremental.create_proxy(bv, first + i);
+            assert_eq!(batch.proxy(h2).map(|p| *p.1), Some(first + i));
+            handles.push((h1, h2));
+        }
+
+        for step in 0..3 {
+            incremental.update(&mut incremental_pairs);
+            batch.update(&mut batch_pairs);
+            assert!(!incremental_pairs.0.is_empty());
+            assert_eq!(incremental_pairs.0, batch_pairs.0);
+
+            for (h1, h2) in handles.iter().skip(step % 3).step_by(3) {
+                let bv = aabb(&mut seed);
+                incremental.deferred_set_bounding_volume(*h1, bv.clone());
+                batch.deferred_set_bounding_volume(*h2, bv);
+            }
+        }
+    }
+}
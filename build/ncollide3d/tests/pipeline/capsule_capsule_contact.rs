@@ -0,0 +1,83 @@
+use na::{Isometry3, UnitQuaternion, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Capsule, ShapeHandle};
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn parallel_capsules_two_point_contact() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let capsule = ShapeHandle::new(Capsule::new(1.0, 0.5));
+    let horizontal = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+
+    // Two horizontal capsules lying side by side, the second one shifted along their axes.
+    let h1 = world
+        .add(
+            Isometry3::from_parts(Vector3::zeros().into(), horizontal),
+            capsule.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::from_parts(Vector3::new(0.5, 0.0, 0.9).into(), horizontal),
+            capsule,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+    assert_eq!(manifold.len(), 2);
+
+    // The contacts are at both ends of the overlapping part of the capsules.
+    let mut xs: Vec<_> = manifold.contacts().map(|c| c.contact.world1.x).collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(xs[0], -0.5, epsilon = 1.0e-7);
+    assert_relative_eq!(xs[1], 1.0, epsilon = 1.0e-7);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.1, epsilon = 1.0e-7);
+        assert_relative_eq!(c.contact.normal.z.abs(), 1.0, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn crossing_capsules_single_contact() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let capsule = ShapeHandle::new(Capsule::new(1.0, 0.5));
+    let horizontal = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+    let crossing = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), FRAC_PI_2);
+
+    let h1 = world
+        .add(
+            Isometry3::from_parts(Vector3::zeros().into(), horizontal),
+            capsule.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::from_parts(Vector3::new(0.0, 0.9, 0.0).into(), crossing),
+            capsule,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+    assert_eq!(manifold.len(), 1);
+    let c = &manifold.deepest_contact().unwrap().contact;
+    assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-7);
+}
@@ -0,0 +1,84 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType,
+};
+use ncollide3d::query::TOIStatus;
+use ncollide3d::shape::{Capsule, Cuboid, ShapeHandle};
+
+fn world_with_walls() -> (CollisionWorld<f64, ()>, Vec<CollisionObjectSlabHandle>) {
+    let mut world = CollisionWorld::new(0.0);
+    let wall = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 5.0, 5.0)));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let handles = [5.0, 10.0]
+        .iter()
+        .map(|x| {
+            world
+                .add(
+                    Isometry3::translation(*x, 0.0, 0.0),
+                    wall.clone(),
+                    groups,
+                    query,
+                    (),
+                )
+                .0
+        })
+        .collect();
+    world.update();
+
+    (world, handles)
+}
+
+#[test]
+fn capsule_cast_first_hit() {
+    let (world, walls) = world_with_walls();
+    let capsule = Capsule::new(1.0, 0.5);
+    let start = Isometry3::identity();
+    let motion = Vector3::new(20.0, 0.0, 0.0);
+    let groups = CollisionGroups::new();
+
+    let (handle, toi) = world
+        .capsule_cast(&capsule, &start, &motion, &groups, &|_, _| true)
+        .expect("The capsule should hit the first wall.");
+    assert_eq!(handle, walls[0]);
+    // The capsule surface reaches the wall face at x = 4.5 after moving by 4.0.
+    assert_relative_eq!(toi.toi, 4.0 / 20.0, epsilon = 1.0e-6);
+    assert_relative_eq!(*toi.normal1, Vector3::x(), epsilon = 1.0e-6);
+
+    // Skip the first wall with the filter.
+    let skip_first = |h, _: &_| h != walls[0];
+    let (handle, toi) = world
+        .capsule_cast(&capsule, &start, &motion, &groups, &skip_first)
+        .unwrap();
+    assert_eq!(handle, walls[1]);
+    assert_relative_eq!(toi.toi, 9.0 / 20.0, epsilon = 1.0e-6);
+
+    // Too short to reach anything.
+    let short = Vector3::new(3.0, 0.0, 0.0);
+    assert!(world
+        .capsule_cast(&capsule, &start, &short, &groups, &|_, _| true)
+        .is_none());
+}
+
+#[test]
+fn capsule_cast_initial_overlap() {
+    let (world, walls) = world_with_walls();
+    let capsule = Capsule::new(1.0, 0.5);
+    let start = Isometry3::translation(4.2, 0.0, 0.0);
+    let motion = Vector3::new(-1.0, 0.0, 0.0);
+    let groups = CollisionGroups::new();
+
+    let (handle, toi) = world
+        .capsule_cast(&capsule, &start, &motion, &groups, &|_, _| true)
+        .unwrap();
+    assert_eq!(handle, walls[0]);
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_relative_eq!(*toi.normal1, Vector3::x(), epsilon = 1.0e-6);
+
+    // Moving along `-normal1` by the penetration depth separates the capsule from the wall.
+    let wall_pos = world.collision_object(handle).unwrap().position();
+    let depth = (start * toi.witness1 - wall_pos * toi.witness2).norm();
+    assert_relative_eq!(depth, 0.2, epsilon = 1.0e-6);
+}
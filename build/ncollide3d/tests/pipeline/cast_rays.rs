@@ -0,0 +1,57 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn cast_rays_matches_individual_casts() {
+    let mut world = CollisionWorld::new(0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut handles = Vec::new();
+
+    for i in 0..10 {
+        for j in 0..10 {
+            let pos = Isometry3::translation(i as f64 * 2.0, j as f64 * 2.0, 10.0);
+            handles.push(world.add(pos, ball.clone(), groups, query, i).0);
+        }
+    }
+    world.update();
+
+    let mut rays = Vec::new();
+    for i in 0..40 {
+        for j in 0..40 {
+            let origin = Point3::new(i as f64 * 0.5, j as f64 * 0.5, 0.0);
+            rays.push(Ray::new(origin, Vector3::z()));
+        }
+    }
+
+    let hits = world.cast_rays(&rays, &groups, &|_, _| true);
+    assert_eq!(hits.len(), rays.len());
+    assert!(hits.iter().any(|hit| hit.is_some()));
+    assert!(hits.iter().any(|hit| hit.is_none()));
+
+    for (ray, hit) in rays.iter().zip(hits.iter()) {
+        let expected = world.first_interference_with_ray(ray, f64::MAX, &groups);
+        assert_eq!(hit.map(|h| h.0), expected.as_ref().map(|e| e.handle));
+
+        if let (Some(hit), Some(expected)) = (hit, expected) {
+            assert_eq!(hit.1.toi, expected.inter.toi);
+        }
+    }
+
+    // Balls behind the first ones are hit if the first ones are filtered out.
+    let pos = Isometry3::translation(0.0, 0.0, 20.0);
+    let behind = world.add(pos, ball.clone(), groups, query, 100).0;
+    world.update();
+
+    let ray = [Ray::new(Point3::origin(), Vector3::z())];
+    let hits = world.cast_rays(&ray, &groups, &|_, co| *co.data() >= 100);
+    assert_eq!(hits[0].unwrap().0, behind);
+    assert_relative_eq!(hits[0].unwrap().1.toi, 19.5);
+    assert_eq!(
+        world.cast_rays(&ray, &groups, &|_, _| true)[0].unwrap().0,
+        handles[0]
+    );
+}
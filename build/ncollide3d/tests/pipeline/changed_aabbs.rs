@@ -0,0 +1,46 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::bounding_volume;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn changed_aabbs_reports_moved_objects_only() {
+    let mut world = CollisionWorld::new(0.1);
+    let ball = Ball::new(0.5);
+    let shape = ShapeHandle::new(ball);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.02, 0.0);
+    let handles: Vec<_> = (0..20)
+        .map(|i| {
+            let pos = Isometry3::translation(i as f64 * 2.0, 0.0, 0.0);
+            world.add(pos, shape.clone(), groups, query, ()).0
+        })
+        .collect();
+
+    // All the added objects are reported by the first update.
+    world.update();
+    let mut added: Vec<_> = world.changed_aabbs().map(|(h, _)| h).collect();
+    added.sort();
+    assert_eq!(added, handles);
+
+    world.update();
+    assert_eq!(world.changed_aabbs().count(), 0);
+
+    let pos = Isometry3::new(Vector3::new(7.0, 1.0, 0.0), Vector3::new(0.1, 0.2, 0.3));
+    world.set_position(handles[3], pos);
+    world.update();
+
+    let changed: Vec<_> = world.changed_aabbs().collect();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].0, handles[3]);
+
+    let mut expected = bounding_volume::aabb(&ball, &pos);
+    expected.mins -= Vector3::repeat(0.02);
+    expected.maxs += Vector3::repeat(0.02);
+    assert_relative_eq!(changed[0].1.mins, expected.mins, epsilon = 1.0e-9);
+    assert_relative_eq!(changed[0].1.maxs, expected.maxs, epsilon = 1.0e-9);
+
+    // Changes are cleared by the next update.
+    world.update();
+    assert_eq!(world.changed_aabbs().count(), 0);
+}
@@ -0,0 +1,39 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn contacts_with_breaking_distance() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    // New contacts closer than 0.2, tracked contacts kept up to 0.6.
+    let query = GeometricQueryType::ContactsWithBreakingDistance(0.1, 0.0, 0.3);
+    let (h1, _) = world.add(Isometry3::identity(), shape.clone(), groups, query, ());
+    let (h2, _) = world.add(
+        Isometry3::translation(2.4, 0.0, 0.0),
+        shape,
+        groups,
+        query,
+        (),
+    );
+
+    let mut num_contacts_at = |gap: f64| {
+        world
+            .get_mut(h2)
+            .unwrap()
+            .set_position(Isometry3::translation(2.0 + gap, 0.0, 0.0));
+        world.update();
+        world
+            .contact_pair(h1, h2, false)
+            .map(|p| p.3.len())
+            .unwrap_or(0)
+    };
+
+    assert_eq!(num_contacts_at(0.4), 0);
+    assert_eq!(num_contacts_at(0.1), 1);
+    // The tracked contact persists beyond the linear prediction.
+    assert_eq!(num_contacts_at(0.4), 1);
+    assert_eq!(num_contacts_at(0.7), 0);
+    assert_eq!(num_contacts_at(0.4), 0);
+}
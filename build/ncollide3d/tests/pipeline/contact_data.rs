@@ -0,0 +1,50 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Cuboid, Plane, ShapeHandle};
+
+#[test]
+fn contact_data_moves_with_the_world_contacts() {
+    let mut world = CollisionWorld::<f64, (), f64>::with_contact_data(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let ground = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(Plane::new(Vector3::y_axis())),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let cuboid = world
+        .add(
+            Isometry3::translation(0.0, 0.49, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5))),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let manifold = world.contact_pair_mut(ground, cuboid).unwrap().3;
+    assert_eq!(manifold.len(), 4);
+
+    for c in manifold.contacts_mut() {
+        assert_eq!(c.data, 0.0);
+        c.data = c.contact.world2.x + 10.0 * c.contact.world2.z;
+    }
+
+    // The cuboid slides slightly, so each contact is matched with the one at the same corner.
+    world.set_position(cuboid, Isometry3::translation(0.01, 0.49, 0.0));
+    world.update();
+
+    let manifold = world.contact_pair(ground, cuboid, true).unwrap().3;
+    assert_eq!(manifold.len(), 4);
+
+    for c in manifold.contacts() {
+        let expected = (c.contact.world2.x - 0.01) + 10.0 * c.contact.world2.z;
+        assert_relative_eq!(c.data, expected, epsilon = 1.0e-6);
+    }
+}
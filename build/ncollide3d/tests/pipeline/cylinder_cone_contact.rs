@@ -0,0 +1,145 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::query::{self, ContactManifold, ContactPrediction};
+use ncollide3d::shape::{Ball, Cone, Cuboid, Cylinder, Plane, Shape, SupportMap};
+
+fn contacts(
+    m1: &Isometry3<f64>,
+    g1: &dyn Shape<f64>,
+    m2: &Isometry3<f64>,
+    g2: &dyn Shape<f64>,
+) -> ContactManifold<f64> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+    let mut generator = dispatcher.get_contact_algorithm(g1, g2).unwrap();
+    let mut manifold = generator.init_manifold();
+
+    let _ = generator.generate_contacts(
+        &dispatcher,
+        m1,
+        g1,
+        None,
+        m2,
+        g2,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    manifold
+}
+
+// The extents, along the `x` and `z` axis, of the points of the contacts on the first shape.
+fn horizontal_extents(manifold: &ContactManifold<f64>) -> (f64, f64) {
+    let mut mins = Vector3::repeat(f64::MAX);
+    let mut maxs = Vector3::repeat(-f64::MAX);
+
+    for c in manifold.contacts() {
+        mins = mins.inf(&c.contact.world1.coords);
+        maxs = maxs.sup(&c.contact.world1.coords);
+    }
+
+    (maxs.x - mins.x, maxs.z - mins.z)
+}
+
+fn assert_resting(manifold: &ContactManifold<f64>, radius: f64) {
+    assert!(manifold.len() >= 3);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+        assert_relative_eq!(c.contact.normal.y.abs(), 1.0, epsilon = 1.0e-6);
+    }
+
+    let (dx, dz) = horizontal_extents(manifold);
+    assert!(dx > radius);
+    assert!(dz > radius);
+}
+
+#[test]
+fn stacked_cylinders() {
+    let small = Cylinder::new(0.5, 1.0);
+    let large = Cylinder::new(0.5, 2.0);
+    let bottom = Isometry3::translation(0.1, 0.0, -0.2);
+    let top = Isometry3::translation(0.0, 0.99, 0.0);
+
+    // The rim of the small cylinder is used whichever shape comes first.
+    assert_resting(&contacts(&bottom, &large, &top, &small), 1.0);
+    assert_resting(&contacts(&top, &small, &bottom, &large), 1.0);
+    assert_resting(&contacts(&bottom, &small, &top, &large), 1.0);
+    assert_resting(&contacts(&top, &large, &bottom, &small), 1.0);
+}
+
+#[test]
+fn cone_base_on_cuboid() {
+    let cone = Cone::new(1.0, 0.5);
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let m_cone = Isometry3::translation(0.3, 1.49, 0.0);
+    let m_cuboid = Isometry3::identity();
+
+    assert_resting(&contacts(&m_cone, &cone, &m_cuboid, &cuboid), 0.5);
+    assert_resting(&contacts(&m_cuboid, &cuboid, &m_cone, &cone), 0.5);
+}
+
+#[test]
+fn cylinder_overhanging_cuboid_edge() {
+    let cylinder = Cylinder::new(0.5, 1.0);
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let m_cylinder = Isometry3::translation(2.5, 0.99, 0.0);
+    let manifold = contacts(&m_cylinder, &cylinder, &Isometry3::identity(), &cuboid);
+
+    // Only the part of the rim above the cuboid is in contact.
+    assert!(manifold.len() >= 3);
+    for c in manifold.contacts() {
+        assert!(c.contact.world1.x <= 2.0 + 1.0e-6);
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn tilted_cylinder_has_a_single_contact() {
+    let cylinder = Cylinder::new(0.5, 1.0);
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let rot = Vector3::new(0.0, 0.0, 0.5);
+    let lowest = cylinder.support_point(&Isometry3::new(na::zero(), rot), &-Vector3::y());
+    let m_cylinder = Isometry3::new(Vector3::new(0.0, 0.49 - lowest.y, 0.0), rot);
+    let manifold = contacts(&m_cylinder, &cylinder, &Isometry3::identity(), &cuboid);
+
+    assert_eq!(manifold.len(), 1);
+    assert_relative_eq!(
+        manifold.deepest_contact().unwrap().contact.depth,
+        0.01,
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn cylinder_cap_on_plane() {
+    let cylinder = Cylinder::new(0.5, 1.0);
+    let plane = Plane::new(Vector3::y_axis());
+    let m_cylinder = Isometry3::translation(0.0, 0.49, 0.0);
+    let manifold = contacts(&m_cylinder, &cylinder, &Isometry3::identity(), &plane);
+
+    assert_resting(&manifold, 1.0);
+}
+
+#[test]
+fn large_ball_on_cylinder_cap() {
+    let cylinder = Cylinder::new(1.0, 1.0);
+    let ball = Ball::new(10.0);
+    let m_cylinder = Isometry3::identity();
+    let m_ball = Isometry3::translation(0.0, 10.99, 0.0);
+    let expected = query::contact(&m_cylinder, &cylinder, &m_ball, &ball, 0.0).unwrap();
+    assert_relative_eq!(expected.depth, 0.01, epsilon = 1.0e-6);
+
+    // The rim samples do not replace the actual penetration.
+    for manifold in [
+        contacts(&m_cylinder, &cylinder, &m_ball, &ball),
+        contacts(&m_ball, &ball, &m_cylinder, &cylinder),
+    ]
+    .iter()
+    {
+        let deepest = manifold.deepest_contact().unwrap();
+        assert_relative_eq!(deepest.contact.depth, expected.depth, epsilon = 1.0e-6);
+        assert_relative_eq!(deepest.contact.normal.y.abs(), 1.0, epsilon = 1.0e-6);
+    }
+}
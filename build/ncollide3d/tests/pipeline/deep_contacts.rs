@@ -0,0 +1,58 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn deep_contacts_filter_by_depth() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.1, 0.0);
+
+    let h1 = world
+        .add(Isometry3::identity(), shape.clone(), groups, query, ())
+        .0;
+    // Penetration depth of 0.3.
+    let h2 = world
+        .add(
+            Isometry3::translation(1.7, 0.0, 0.0),
+            shape.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    // Penetration depth of 0.05 with the first ball.
+    let _ = world
+        .add(
+            Isometry3::translation(0.0, -1.95, 0.0),
+            shape.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    // Separated by a gap of 0.1 from the second ball.
+    let _ = world
+        .add(
+            Isometry3::translation(1.7, 2.1, 0.0),
+            shape,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let deep: Vec<_> = world.deep_contacts(0.1).collect();
+    assert_eq!(deep.len(), 1);
+    assert!((deep[0].0, deep[0].1) == (h1, h2) || (deep[0].0, deep[0].1) == (h2, h1));
+    assert_relative_eq!(deep[0].2.contact.depth, 0.3, epsilon = 1.0e-7);
+
+    assert_eq!(world.deep_contacts(0.0).count(), 2);
+    assert_eq!(world.deep_contacts(-0.2).count(), 3);
+
+    let manifold = world.contact_pair(h1, h2, true).unwrap().3;
+    assert_eq!(manifold.deep_contacts(0.1).count(), 1);
+    assert_eq!(manifold.deep_contacts(0.4).count(), 0);
+}
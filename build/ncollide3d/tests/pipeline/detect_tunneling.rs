@@ -0,0 +1,78 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+use std::collections::HashMap;
+
+#[test]
+fn detect_tunneling_reports_missed_collisions() {
+    let mut world = CollisionWorld::new(0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Proximity(0.0);
+
+    let fixed = world
+        .add(Isometry3::identity(), ball.clone(), groups, query, ())
+        .0;
+    let bullet = world
+        .add(
+            Isometry3::translation(5.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let slow = world
+        .add(
+            Isometry3::translation(-3.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let crossing1 = world
+        .add(
+            Isometry3::translation(0.0, 5.0, 10.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let crossing2 = world
+        .add(
+            Isometry3::translation(5.0, 0.0, 10.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let mut prev_positions = HashMap::new();
+    // Went through `fixed`.
+    prev_positions.insert(bullet, Isometry3::translation(-5.0, 0.0, 0.0));
+    // Did not reach `fixed`.
+    prev_positions.insert(slow, Isometry3::translation(-5.0, 0.0, 0.0));
+    // Went through each other.
+    prev_positions.insert(crossing1, Isometry3::translation(0.0, -5.0, 10.0));
+    prev_positions.insert(crossing2, Isometry3::translation(-5.0, 0.0, 10.0));
+
+    let pairs = world.detect_tunneling(&prev_positions);
+    assert_eq!(pairs, vec![(fixed, bullet), (crossing1, crossing2)]);
+
+    // No tunneling is reported if the objects are still touching.
+    world.set_position(bullet, Isometry3::translation(0.5, 0.0, 0.0));
+    world.update();
+    let pairs = world.detect_tunneling(&prev_positions);
+    assert!(!pairs.contains(&(fixed, bullet)));
+
+    // Nor if they were already penetrating at their previous positions.
+    world.set_position(bullet, Isometry3::translation(5.0, 0.0, 0.0));
+    world.update();
+    prev_positions.insert(bullet, Isometry3::translation(0.5, 0.0, 0.0));
+    let pairs = world.detect_tunneling(&prev_positions);
+    assert!(!pairs.contains(&(fixed, bullet)));
+}
@@ -0,0 +1,106 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionGroupsRule, CollisionWorld, GeometricQueryType, PairInteractionStatus,
+};
+use ncollide3d::query::Proximity;
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn explain_intersecting_cuboids() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let (h1, _) = world.add(Isometry3::identity(), shape.clone(), groups, query, ());
+    let (h2, _) = world.add(
+        Isometry3::translation(1.5, 0.0, 0.0),
+        shape,
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let diag = world.explain_pair(h1, h2).unwrap();
+    assert!(diag.aabbs_overlap);
+    assert_eq!(diag.groups_blocker, None);
+    assert!(diag.pair_filter_accepts);
+    assert!(diag.is_effective());
+
+    match diag.interaction {
+        PairInteractionStatus::Contact {
+            algorithm,
+            num_contacts,
+            deepest_depth,
+        } => {
+            assert!(algorithm.contains("ManifoldGenerator"));
+            assert!(num_contacts > 0);
+            assert_relative_eq!(deepest_depth.unwrap(), 0.5, epsilon = 1.0e-6);
+        }
+        _ => panic!("A contact algorithm should have been dispatched."),
+    }
+}
+
+#[test]
+fn explain_pair_blocked_by_groups() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let query = GeometricQueryType::Proximity(0.0);
+    let groups1 = CollisionGroups::new().with_membership(&[1]);
+    let groups2 = CollisionGroups::new()
+        .with_membership(&[2])
+        .with_blacklist(&[1]);
+    let (h1, _) = world.add(Isometry3::identity(), shape.clone(), groups1, query, ());
+    let (h2, _) = world.add(Isometry3::identity(), shape, groups2, query, ());
+    world.update();
+
+    let diag = world.explain_pair(h1, h2).unwrap();
+    assert!(diag.aabbs_overlap);
+    assert_eq!(
+        diag.groups_blocker,
+        Some(CollisionGroupsRule::BlacklistedBySecond)
+    );
+    assert!(!diag.is_effective());
+    assert!(matches!(diag.interaction, PairInteractionStatus::None));
+
+    let diag = world.explain_pair(h2, h1).unwrap();
+    assert_eq!(
+        diag.groups_blocker,
+        Some(CollisionGroupsRule::BlacklistedByFirst)
+    );
+}
+
+#[test]
+fn explain_distant_proximity_pair() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Proximity(0.0);
+    let (h1, _) = world.add(Isometry3::identity(), shape.clone(), groups, query, ());
+    let (h2, _) = world.add(
+        Isometry3::translation(1.5, 0.0, 0.0),
+        shape.clone(),
+        groups,
+        query,
+        (),
+    );
+    let (h3, _) = world.add(
+        Isometry3::translation(10.0, 0.0, 0.0),
+        shape,
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    match world.explain_pair(h1, h2).unwrap().interaction {
+        PairInteractionStatus::Proximity { proximity, .. } => {
+            assert_eq!(proximity, Proximity::Intersecting)
+        }
+        _ => panic!("A proximity algorithm should have been dispatched."),
+    }
+
+    let diag = world.explain_pair(h1, h3).unwrap();
+    assert!(!diag.aabbs_overlap);
+    assert!(matches!(diag.interaction, PairInteractionStatus::None));
+}
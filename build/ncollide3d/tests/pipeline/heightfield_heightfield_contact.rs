@@ -0,0 +1,38 @@
+use na::{DMatrix, Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{HeightField, ShapeHandle};
+
+#[test]
+fn heightfield_heightfield_seam_contacts() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.1, 0.0);
+    let heights = DMatrix::<f64>::zeros(5, 5);
+    let tile = ShapeHandle::new(HeightField::new(heights, Vector3::new(10.0, 1.0, 10.0)));
+
+    // The second tile overlaps the first one on a seam of width 0.5, slightly below it.
+    let h1 = world
+        .add(Isometry3::identity(), tile.clone(), groups, query, ())
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::translation(9.5, -0.1, 0.0),
+            tile,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    // The contacts are only predicted so the pair is not effective.
+    let (_, _, _, manifold) = world.contact_pair(h1, h2, false).unwrap();
+    assert!(manifold.len() > 0);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, -0.1, epsilon = 1.0e-5);
+        assert_relative_eq!(c.contact.normal.y.abs(), 1.0, epsilon = 1.0e-5);
+        assert!(c.contact.world1.x >= 4.5 - 1.0e-5 && c.contact.world1.x <= 5.0 + 1.0e-5);
+        assert!(c.contact.world2.x >= 4.5 - 1.0e-5 && c.contact.world2.x <= 5.0 + 1.0e-5);
+    }
+}
@@ -0,0 +1,52 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn interaction_graph_to_dot() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.0);
+
+    let h1 = world
+        .add(Isometry3::identity(), shape.clone(), groups, contacts, ())
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::translation(1.5, 0.0, 0.0),
+            shape.clone(),
+            groups,
+            contacts,
+            (),
+        )
+        .0;
+    let h3 = world
+        .add(
+            Isometry3::translation(-1.5, 0.0, 0.0),
+            shape.clone(),
+            groups,
+            proximity,
+            (),
+        )
+        .0;
+    world.update();
+
+    let dot = world.to_dot();
+    assert!(dot.starts_with("graph interactions {\n"));
+    assert!(dot.ends_with("}\n"));
+
+    for h in &[h1, h2, h3] {
+        assert!(dot.contains(&format!("[label=\"{}\"];", h.uid())));
+    }
+
+    let edges: Vec<_> = dot.lines().filter(|l| l.contains(" -- ")).collect();
+    assert_eq!(edges.len(), 2);
+    assert!(edges
+        .iter()
+        .any(|e| e.contains("style=solid, color=red, label=\"1\"")));
+    assert!(edges
+        .iter()
+        .any(|e| e.contains("style=dashed, color=red, label=\"Intersecting\"")));
+}
@@ -0,0 +1,46 @@
+use na::{Isometry3, Point3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn interferences_along_bent_path() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let mut add = |x, y| {
+        world
+            .add(
+                Isometry3::translation(x, y, 0.0),
+                shape.clone(),
+                groups,
+                query,
+                (),
+            )
+            .0
+    };
+
+    let on_first_segment = add(5.0, 0.0);
+    let on_corner = add(10.0, 0.0);
+    let on_second_segment = add(10.0, 5.0);
+    // Inside the AABB of the whole path, but far from both segments.
+    let inside_bend = add(5.0, 5.0);
+    let far_away = add(0.0, 20.0);
+    world.update();
+
+    let path = [
+        Point3::origin(),
+        Point3::new(10.0, 0.0, 0.0),
+        Point3::new(10.0, 10.0, 0.0),
+    ];
+    let mut out = Vec::new();
+    world.interferences_along_path(&path, 0.5, &groups, &mut out);
+
+    assert_eq!(out.len(), 3);
+    assert!(out.contains(&on_first_segment));
+    assert!(out.contains(&on_corner));
+    assert!(out.contains(&on_second_segment));
+    assert!(!out.contains(&inside_bend));
+    assert!(!out.contains(&far_away));
+}
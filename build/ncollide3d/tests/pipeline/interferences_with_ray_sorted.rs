@@ -0,0 +1,95 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Cuboid, ShapeHandle};
+
+#[test]
+fn boxes_along_ray_nearest_first() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5)));
+    let groups = CollisionGroups::new().with_membership(&[0]);
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut add = |x: f64, groups: CollisionGroups, query: GeometricQueryType<f64>| {
+        world
+            .add(
+                Isometry3::translation(x, 0.0, 0.0),
+                shape.clone(),
+                groups,
+                query,
+                (),
+            )
+            .0
+    };
+
+    // Added out of order.
+    let far = add(9.0, groups, contacts);
+    let near = add(3.0, groups, contacts);
+    let middle = add(6.0, groups, contacts);
+    let sensor = add(1.5, groups, GeometricQueryType::Proximity(0.0));
+    let _ = add(4.5, CollisionGroups::new().with_membership(&[1]), contacts);
+    world.update();
+
+    let ray = Ray::new(Point3::new(-1.0, 0.1, -0.2), Vector3::x());
+    // Ignores the object in the group 1.
+    let query_groups = CollisionGroups::new()
+        .with_membership(&[0])
+        .with_blacklist(&[1]);
+
+    let hits: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 100.0, &query_groups, true, false)
+        .collect();
+    let handles: Vec<_> = hits.iter().map(|hit| hit.0).collect();
+    assert_eq!(handles, vec![near, middle, far]);
+    assert_relative_eq!(hits[0].1.toi, 3.5);
+    assert_relative_eq!(hits[1].1.toi, 6.5);
+    assert_relative_eq!(hits[2].1.toi, 9.5);
+
+    let handles: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 100.0, &query_groups, true, true)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(handles, vec![sensor, near, middle, far]);
+
+    // Only the objects hit before `max_toi`.
+    let handles: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 7.0, &query_groups, true, false)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(handles, vec![near, middle]);
+}
+
+#[test]
+fn ray_starting_inside_a_box() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5)));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let inner = world
+        .add(Isometry3::identity(), shape.clone(), groups, query, ())
+        .0;
+    let outer = world
+        .add(
+            Isometry3::translation(2.0, 0.0, 0.0),
+            shape,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+
+    let solid: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 100.0, &groups, true, false)
+        .collect();
+    assert_eq!(solid.len(), 2);
+    assert_eq!((solid[0].0, solid[0].1.toi), (inner, 0.0));
+    assert_eq!(solid[1].0, outer);
+
+    let hollow: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 100.0, &groups, false, false)
+        .collect();
+    assert_eq!(hollow[0].0, inner);
+    assert_relative_eq!(hollow[0].1.toi, 0.5);
+}
@@ -0,0 +1,58 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn is_pose_free_against_world() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)));
+
+    let h_cuboid = world
+        .add(Isometry3::identity(), cuboid, groups, query, ())
+        .0;
+    world.update();
+
+    let ball = Ball::new(0.5);
+    let nb_objects = world.collision_objects().count();
+
+    // Overlapping the cuboid.
+    assert!(!world.is_pose_free(
+        &ball,
+        &Isometry3::translation(1.4, 0.0, 0.0),
+        &groups,
+        &|_, _| true
+    ));
+    // The AABBs intersect but not the shapes.
+    assert!(world.is_pose_free(
+        &ball,
+        &Isometry3::translation(1.4, 1.4, 0.0),
+        &groups,
+        &|_, _| true
+    ));
+    // Far away.
+    assert!(world.is_pose_free(
+        &ball,
+        &Isometry3::translation(5.0, 0.0, 0.0),
+        &groups,
+        &|_, _| true
+    ));
+    // The overlapped object is filtered out.
+    assert!(world.is_pose_free(
+        &ball,
+        &Isometry3::translation(1.4, 0.0, 0.0),
+        &groups,
+        &|h, _| h != h_cuboid
+    ));
+    // The overlapped object is rejected by the collision groups.
+    let other_groups = CollisionGroups::new().with_blacklist(&[0]);
+    assert!(world.is_pose_free(
+        &ball,
+        &Isometry3::translation(1.4, 0.0, 0.0),
+        &other_groups,
+        &|_, _| true
+    ));
+
+    assert_eq!(world.collision_objects().count(), nb_objects);
+}
@@ -0,0 +1,73 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Cuboid, Plane, ShapeHandle};
+
+#[test]
+fn sliding_box_keeps_corner_ids() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let ground = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(Plane::new(Vector3::y_axis())),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let cuboid = world
+        .add(
+            Isometry3::translation(0.0, 0.49, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5))),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let mut prev = world.contact_pair(ground, cuboid, true).unwrap().3.clone();
+    assert_eq!(prev.len(), 4);
+
+    for i in 1..6 {
+        let x = i as f64 * 0.3;
+        world.set_position(cuboid, Isometry3::translation(x, 0.49, 0.0));
+        world.update();
+
+        let manifold = world.contact_pair(ground, cuboid, true).unwrap().3;
+        let matches = manifold.match_previous(&prev);
+        assert_eq!(manifold.len(), 4);
+        assert_eq!(matches.len(), 4);
+
+        let curr: Vec<_> = manifold.contacts().collect();
+        let old: Vec<_> = prev.contacts().collect();
+
+        for (i, j) in matches {
+            assert_eq!(curr[i].features(), old[j].features());
+            assert_eq!(curr[i].id, old[j].id);
+        }
+
+        prev = manifold.clone();
+    }
+}
+
+#[test]
+fn unknown_features_are_not_matched() {
+    use na::Point3;
+    use ncollide3d::query::{Contact, ContactKinematic, ContactManifold};
+
+    let contact = Contact::new(Point3::origin(), Point3::origin(), Vector3::y_axis(), 0.0);
+    let mut manifold: ContactManifold<f64> = ContactManifold::new();
+    let _ = manifold.push(
+        contact,
+        ContactKinematic::new(),
+        Point3::origin(),
+        None,
+        None,
+    );
+
+    assert_eq!(manifold.contacts().count(), 1);
+    assert!(manifold.match_previous(&manifold.clone()).is_empty());
+}
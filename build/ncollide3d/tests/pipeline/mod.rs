@@ -1,3 +1,41 @@
+mod ball_trimesh_contact;
+mod batch_broad_phase;
+mod capsule_capsule_contact;
+mod capsule_cast;
+mod cast_rays;
+mod changed_aabbs;
+mod contact_breaking_distance;
+mod contact_data;
 mod contact_pairs;
+mod cylinder_cone_contact;
+mod deep_contacts;
+mod detect_tunneling;
 mod duplicate_trimesh_on_world;
+mod explain_pair;
+mod heightfield_heightfield_contact;
+mod interaction_graph_dot;
+mod interferences_along_path;
+mod interferences_with_ray_sorted;
+mod is_pose_free;
 mod is_send_sync;
+mod manifold_feature_matching;
+mod morph_shape_update;
+mod nearest_surface;
+mod object_lifecycle_callbacks;
+mod pair_snapshot;
+mod pairs_within_distance;
+mod parallel_broad_phase;
+mod plane_cone_contact;
+mod plane_convex_hull_contact;
+mod plane_cylinder_contact;
+mod proximity_hysteresis;
+mod proxy_tags;
+mod round_shape_contact;
+mod sap_broad_phase;
+mod slab_contact;
+mod sorted_broad_phase_queries;
+mod sorted_contact_pairs;
+mod surface_normals_near;
+mod trimesh_trimesh_contact;
+mod wedge_cuboid_contact;
+mod world_snapshot;
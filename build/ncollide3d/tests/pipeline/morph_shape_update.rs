@@ -0,0 +1,34 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Proximity;
+use ncollide3d::shape::{Ball, MorphShape, ShapeHandle};
+
+#[test]
+fn morph_parameter_updates_bounding_volume() {
+    let mut world = CollisionWorld::new(0.0);
+    let small = ShapeHandle::new(Ball::new(0.5));
+    let large = ShapeHandle::new(Ball::new(2.0));
+    let morph = ShapeHandle::new(MorphShape::new(small.clone(), large, 0.0));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Proximity(0.0);
+    let (h1, _) = world.add(Isometry3::identity(), morph, groups, query, ());
+    let (h2, _) = world.add(
+        Isometry3::translation(2.0, 0.0, 0.0),
+        small,
+        groups,
+        query,
+        (),
+    );
+    world.update();
+    assert!(world.proximity_pair(h1, h2, true).is_none());
+
+    // The morph shape is now the large ball.
+    world.get_mut(h1).unwrap().set_morph_parameter(1.0);
+    world.update();
+    let (_, _, _, proximity) = world.proximity_pair(h1, h2, true).unwrap();
+    assert_eq!(proximity, Proximity::Intersecting);
+
+    world.get_mut(h1).unwrap().set_morph_parameter(0.0);
+    world.update();
+    assert!(world.proximity_pair(h1, h2, true).is_none());
+}
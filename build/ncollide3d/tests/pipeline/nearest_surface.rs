@@ -0,0 +1,115 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+fn brute_force_nearest(
+    world: &CollisionWorld<f64, ()>,
+    pt: &Point3<f64>,
+    skip: &dyn Fn(usize) -> bool,
+) -> (usize, f64) {
+    world
+        .collision_objects()
+        .filter(|(handle, _)| !skip(handle.uid()))
+        .map(|(handle, co)| {
+            let proj = co.shape().project_point(co.position(), pt, false);
+            (handle.uid(), na::distance(pt, &proj.point))
+        })
+        .fold((usize::max_value(), f64::MAX), |best, curr| {
+            if curr.1 < best.1 {
+                curr
+            } else {
+                best
+            }
+        })
+}
+
+#[test]
+fn nearest_surface_matches_brute_force() {
+    let mut world = CollisionWorld::new(0.0);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.4));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.3, 0.6, 0.2)));
+
+    for i in 0..10 {
+        for j in 0..10 {
+            let pos = Isometry3::translation(i as f64 * 2.0, (i * j % 3) as f64, j as f64 * 2.0);
+            let shape = if (i + j) % 2 == 0 { &ball } else { &cuboid };
+            let _ = world.add(pos, shape.clone(), CollisionGroups::new(), query, ());
+        }
+    }
+
+    // Let the objects settle on the static tree of the broad phase, then add a moving one.
+    for _ in 0..150 {
+        world.update();
+    }
+
+    let _ = world.add(
+        Isometry3::translation(7.1, 5.0, 7.3),
+        ball.clone(),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+
+    let groups = CollisionGroups::new();
+
+    for k in 0..30 {
+        let pt = Point3::new(
+            k as f64 * 0.61,
+            3.0 + (k % 4) as f64,
+            19.0 - k as f64 * 0.57,
+        );
+        let (handle, proj, normal) = world
+            .nearest_surface(&pt, 100.0, &groups, &|_, _| true)
+            .unwrap();
+        let (expected, dist) = brute_force_nearest(&world, &pt, &|_| false);
+
+        assert_eq!(handle.uid(), expected);
+        assert_relative_eq!(na::distance(&pt, &proj.point), dist, epsilon = 1.0e-7);
+        assert!((pt - proj.point).dot(&normal) > 0.0);
+
+        // Skipping the nearest object through the filter yields the next one.
+        let (handle, _, _) = world
+            .nearest_surface(&pt, 100.0, &groups, &|h, _| h.uid() != expected)
+            .unwrap();
+        assert_eq!(
+            handle.uid(),
+            brute_force_nearest(&world, &pt, &|i| i == expected).0
+        );
+    }
+}
+
+#[test]
+fn nearest_surface_respects_max_dist_and_groups() {
+    let mut world = CollisionWorld::new(0.0);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let near_groups = CollisionGroups::new().with_membership(&[1]);
+
+    let far = world
+        .add(
+            Isometry3::translation(5.0, 0.0, 0.0),
+            ball.clone(),
+            CollisionGroups::new(),
+            query,
+            (),
+        )
+        .0;
+    let _ = world.add(Isometry3::identity(), ball, near_groups, query, ());
+    world.update();
+
+    let pt = Point3::new(0.0, 2.0, 0.0);
+    let groups = CollisionGroups::new().with_whitelist(&[0]);
+
+    assert!(world
+        .nearest_surface(&pt, 4.0, &groups, &|_, _| true)
+        .is_none());
+
+    let (handle, proj, _) = world
+        .nearest_surface(&pt, 10.0, &groups, &|_, _| true)
+        .unwrap();
+    assert_eq!(handle, far);
+    assert_relative_eq!(na::distance(&pt, &proj.point), 29.0f64.sqrt() - 1.0);
+}
@@ -0,0 +1,59 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType,
+};
+use ncollide3d::shape::{Ball, ShapeHandle};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn object_added_and_removed_callbacks() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let added: Arc<Mutex<Vec<CollisionObjectSlabHandle>>> = Arc::new(Mutex::new(Vec::new()));
+    let removed: Arc<Mutex<Vec<CollisionObjectSlabHandle>>> = Arc::new(Mutex::new(Vec::new()));
+
+    {
+        let added = added.clone();
+        let removed = removed.clone();
+        world.on_object_added(move |h| added.lock().unwrap().push(h));
+        world.on_object_removed(move |h| removed.lock().unwrap().push(h));
+    }
+
+    let h1 = world
+        .add(Isometry3::identity(), ball.clone(), groups, query, ())
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::translation(5.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+
+    // The proxies are inserted by the update.
+    assert!(added.lock().unwrap().is_empty());
+    world.update();
+    assert_eq!(*added.lock().unwrap(), vec![h1, h2]);
+
+    world.update();
+    assert_eq!(added.lock().unwrap().len(), 2);
+
+    world.remove(&[h1]);
+    assert_eq!(*removed.lock().unwrap(), vec![h1]);
+
+    // An object removed before its proxy is inserted triggers no event.
+    let h3 = world
+        .add(Isometry3::identity(), ball.clone(), groups, query, ())
+        .0;
+    world.remove(&[h3]);
+    world.update();
+    assert_eq!(added.lock().unwrap().len(), 2);
+    assert_eq!(removed.lock().unwrap().len(), 1);
+
+    world.remove(&[h2]);
+    assert_eq!(*removed.lock().unwrap(), vec![h1, h2]);
+}
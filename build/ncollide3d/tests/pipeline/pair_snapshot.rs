@@ -0,0 +1,67 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Proximity;
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn pair_snapshot_is_not_affected_by_updates() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.0);
+
+    let h1 = world
+        .add(Isometry3::identity(), shape.clone(), groups, contacts, ())
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::translation(1.5, 0.0, 0.0),
+            shape.clone(),
+            groups,
+            contacts,
+            (),
+        )
+        .0;
+    let h3 = world
+        .add(
+            Isometry3::translation(-1.5, 0.0, 0.0),
+            shape.clone(),
+            groups,
+            proximity,
+            (),
+        )
+        .0;
+    world.update();
+
+    let snapshot = world.snapshot_pairs();
+    assert_eq!(snapshot.len(), 2);
+
+    // Separate everything.
+    world
+        .get_mut(h2)
+        .unwrap()
+        .set_position(Isometry3::translation(10.0, 0.0, 0.0));
+    world
+        .get_mut(h3)
+        .unwrap()
+        .set_position(Isometry3::translation(-10.0, 0.0, 0.0));
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 0);
+
+    for _ in 0..2 {
+        let contacts: Vec<_> = snapshot.contact_pairs().collect();
+        assert_eq!(contacts.len(), 1);
+        let (a, b, manifold) = contacts[0];
+        assert!((a, b) == (h1, h2) || (a, b) == (h2, h1));
+        assert_eq!(manifold.len(), 1);
+        assert_relative_eq!(manifold.deepest_contact().unwrap().contact.depth, 0.5);
+
+        let proximities: Vec<_> = snapshot.proximity_pairs().collect();
+        assert_eq!(proximities.len(), 1);
+        assert!(proximities[0].0 == h3 || proximities[0].1 == h3);
+        assert_eq!(proximities[0].2, Proximity::Intersecting);
+    }
+
+    assert!(world.snapshot_pairs().is_empty());
+}
@@ -0,0 +1,86 @@
+use na::{DMatrix, Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Cuboid, HeightField, Plane, ShapeHandle};
+
+#[test]
+fn pairs_within_distance_reports_close_pairs() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+    let mut add = |x: f64, shape: &ShapeHandle<f64>, groups: CollisionGroups| {
+        let pos = Isometry3::translation(x, 0.0, 0.0);
+        world.add(pos, shape.clone(), groups, query, ()).0
+    };
+
+    let h0 = add(0.0, &ball, groups);
+    // At a distance of 0.2 from `h0`.
+    let h1 = add(1.2, &cuboid, groups);
+    // At a distance of 0.4 from `h1`.
+    let h2 = add(2.6, &ball, groups);
+    // Overlapping `h2`.
+    let h3 = add(3.2, &ball, groups);
+    // Far from everything.
+    let _ = add(10.0, &ball, groups);
+    // At a distance of 0.1 from `h0`, but not allowed to interact with it.
+    let blocked = CollisionGroups::new()
+        .with_membership(&[1])
+        .with_blacklist(&[0]);
+    let _ = add(-1.1, &ball, blocked);
+
+    world.update();
+
+    let mut pairs = world.pairs_within_distance(0.3);
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(pairs.len(), 2);
+    assert_eq!((pairs[0].0, pairs[0].1), (h0, h1));
+    assert_relative_eq!(pairs[0].2, 0.2, epsilon = 1.0e-6);
+    assert_eq!((pairs[1].0, pairs[1].1), (h2, h3));
+    assert_eq!(pairs[1].2, 0.0);
+
+    // A larger distance includes the pair `(h1, h2)`.
+    let mut pairs = world.pairs_within_distance(0.5);
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    let handles: Vec<_> = pairs.iter().map(|p| (p.0, p.1)).collect();
+    assert_eq!(handles, vec![(h0, h1), (h1, h2), (h2, h3)]);
+    assert_relative_eq!(pairs[1].2, 0.4, epsilon = 1.0e-6);
+}
+
+#[test]
+fn pairs_within_distance_with_heightfield_and_planes() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    // Flat around the origin, with a ridge along one of its sides.
+    let heights = DMatrix::from_fn(11, 11, |i, _| if i < 3 { 1.0 } else { 0.0 });
+    let terrain = ShapeHandle::new(HeightField::new(heights, Vector3::new(10.0, 1.0, 10.0)));
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let plane = ShapeHandle::new(Plane::new(Vector3::y_axis()));
+    let mut add = |y: f64, shape: &ShapeHandle<f64>| {
+        let pos = Isometry3::translation(0.0, y, 0.0);
+        world.add(pos, shape.clone(), groups, query, ()).0
+    };
+
+    let h_terrain = add(0.0, &terrain);
+    // At a distance of 0.1 from the terrain.
+    let h_ball = add(0.6, &ball);
+    let _ = add(5.0, &ball);
+    // The distance between two planes cannot be computed, so they are skipped.
+    let h_plane = add(-1.0, &plane);
+    let _ = add(-2.0, &plane);
+
+    world.update();
+
+    let pairs = world.pairs_within_distance(0.3);
+    assert_eq!(pairs.len(), 1);
+    assert_eq!((pairs[0].0, pairs[0].1), (h_terrain, h_ball));
+    assert_relative_eq!(pairs[0].2, 0.1, epsilon = 1.0e-6);
+
+    let mut pairs = world.pairs_within_distance(1.05);
+    pairs.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    let handles: Vec<_> = pairs.iter().map(|p| (p.0, p.1)).collect();
+    assert_eq!(handles, vec![(h_terrain, h_ball), (h_terrain, h_plane)]);
+    assert_relative_eq!(pairs[1].2, 1.0, epsilon = 1.0e-6);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "parallel")]
+
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{BroadPhase, BroadPhaseInterferenceHandler, DBVTBroadPhase};
+use std::collections::BTreeSet;
+
+struct PairsCollector(BTreeSet<(usize, usize)>);
+
+impl BroadPhaseInterferenceHandler<usize> for PairsCollector {
+    fn is_interference_allowed(&mut self, a: &usize, b: &usize) -> bool {
+        // Exercise the filter as well.
+        (a + b) % 7 != 0
+    }
+
+    fn interference_started(&mut self, a: &usize, b: &usize) {
+        let _ = self.0.insert((*a.min(b), *a.max(b)));
+    }
+
+    fn interference_stopped(&mut self, a: &usize, b: &usize) {
+        let _ = self.0.remove(&(*a.min(b), *a.max(b)));
+    }
+}
+
+fn aabb(seed: &mut u64) -> AABB<f64> {
+    let mut rand = || {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*seed >> 33) as f64 / (1u64 << 31) as f64
+    };
+
+    let center = Point3::new(rand() * 20.0, rand() * 20.0, rand() * 20.0);
+    AABB::from_half_extents(center, Vector3::repeat(0.5 + rand()))
+}
+
+#[test]
+fn parallel_update_matches_serial_update() {
+    let mut serial = DBVTBroadPhase::new(0.1);
+    let mut parallel = DBVTBroadPhase::new(0.1);
+    parallel.set_parallel_update(true);
+    let mut serial_pairs = PairsCollector(BTreeSet::new());
+    let mut parallel_pairs = PairsCollector(BTreeSet::new());
+
+    let mut seed = 42;
+    let mut handles = Vec::new();
+
+    for i in 0..500 {
+        let bv = aabb(&mut seed);
+        let h1 = serial.create_proxy(bv.clone(), i);
+        let h2 = parallel.create_proxy(bv, i);
+        handles.push((h1, h2));
+    }
+
+    for step in 0..10 {
+        serial.update(&mut serial_pairs);
+        parallel.update(&mut parallel_pairs);
+        assert!(!serial_pairs.0.is_empty());
+        assert_eq!(serial_pairs.0, parallel_pairs.0);
+
+        for (h1, h2) in handles.iter().skip(step % 3).step_by(3) {
+            let bv = aabb(&mut seed);
+            serial.deferred_set_bounding_volume(*h1, bv.clone());
+            parallel.deferred_set_bounding_volume(*h2, bv);
+        }
+    }
+}
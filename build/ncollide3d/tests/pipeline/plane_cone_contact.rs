@@ -0,0 +1,64 @@
+use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::pipeline::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::query::ContactPrediction;
+use ncollide3d::shape::{Cone, Plane};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn num_contacts(cone_rot: UnitQuaternion<f64>) -> usize {
+    let dispatcher = DefaultContactDispatcher::new();
+    let plane = Plane::new(Vector3::y_axis());
+    let cone = Cone::new(1.0, 1.0);
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+
+    // Place the cone so that its lowest point is slightly below the plane.
+    let lowest = [
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(0.0, -1.0, 1.0),
+        Point3::new(0.0, -1.0, -1.0),
+    ]
+    .iter()
+    .map(|pt| (cone_rot * pt).y)
+    .fold(f64::MAX, f64::min);
+    let m2 = Isometry3::from_parts(Translation3::new(0.0, -lowest - 0.01, 0.0), cone_rot);
+
+    let mut generator = dispatcher.get_contact_algorithm(&plane, &cone).unwrap();
+    let mut manifold = generator.init_manifold();
+    let _ = generator.generate_contacts(
+        &dispatcher,
+        &Isometry3::identity(),
+        &plane,
+        None,
+        &m2,
+        &cone,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+    }
+
+    manifold.len()
+}
+
+#[test]
+fn cone_resting_on_base() {
+    assert!(num_contacts(UnitQuaternion::identity()) >= 3);
+}
+
+#[test]
+fn cone_resting_on_side() {
+    // Rotate the side normal of the generatrix along `+x` toward `-y`.
+    let angle = -FRAC_PI_2 - 1.0f64.atan2(2.0);
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angle);
+    assert_eq!(num_contacts(rot), 2);
+}
+
+#[test]
+fn cone_resting_on_apex() {
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), PI);
+    assert_eq!(num_contacts(rot), 1);
+}
@@ -0,0 +1,179 @@
+use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionWorld, ContactDispatcher, DefaultContactDispatcher,
+    GeometricQueryType,
+};
+use ncollide3d::query::{self, ContactManifold, ContactPrediction};
+use ncollide3d::shape::{ConvexHull, FeatureId, Plane, ShapeHandle, SupportMap};
+use std::f64::consts::PI;
+
+// A hexagonal prism with its axis along `y`, with an edge of its bottom face along `z` at `x < 0`.
+fn hexagonal_prism() -> ConvexHull<f64> {
+    let mut points = Vec::new();
+
+    for i in 0..6 {
+        let angle = PI / 6.0 + i as f64 * PI / 3.0;
+        points.push(Point3::new(angle.cos(), -0.5, angle.sin()));
+        points.push(Point3::new(angle.cos(), 0.5, angle.sin()));
+    }
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+// Contacts between the plane `y = 0` and the prism rotated by `rot`, with its lowest point at
+// the height `height`.
+fn contacts(rot: UnitQuaternion<f64>, height: f64, flip: bool) -> ContactManifold<f64> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let plane = Plane::new(Vector3::y_axis());
+    let prism = hexagonal_prism();
+    let prediction = ContactPrediction::new(0.001, 0.0, 0.0);
+    let lowest = prism.support_point(
+        &Isometry3::from_parts(Translation3::identity(), rot),
+        &-Vector3::y(),
+    );
+    let m2 = Isometry3::from_parts(Vector3::new(0.0, height - lowest.y, 0.0).into(), rot);
+    let m1 = Isometry3::identity();
+
+    let mut generator = if flip {
+        dispatcher.get_contact_algorithm(&prism, &plane).unwrap()
+    } else {
+        dispatcher.get_contact_algorithm(&plane, &prism).unwrap()
+    };
+    let mut manifold = generator.init_manifold();
+
+    let _ = if flip {
+        generator.generate_contacts(
+            &dispatcher,
+            &m2,
+            &prism,
+            None,
+            &m1,
+            &plane,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    } else {
+        generator.generate_contacts(
+            &dispatcher,
+            &m1,
+            &plane,
+            None,
+            &m2,
+            &prism,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    };
+
+    for c in manifold.contacts() {
+        let normal = if flip { -Vector3::y() } else { Vector3::y() };
+        assert_relative_eq!(*c.contact.normal, normal);
+    }
+
+    manifold
+}
+
+// The features of the prism the contacts of `manifold` lie on, with their depth.
+fn prism_features(manifold: &ContactManifold<f64>, flip: bool) -> Vec<(FeatureId, f64)> {
+    manifold
+        .contacts()
+        .map(|c| {
+            let feature = if flip { c.features().0 } else { c.features().1 };
+            (feature, c.contact.depth)
+        })
+        .collect()
+}
+
+#[test]
+fn tilted_prism_resting_on_edge() {
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), PI / 6.0);
+
+    for flip in [false, true].iter() {
+        let manifold = contacts(rot, -0.01, *flip);
+        let features = prism_features(&manifold, *flip);
+
+        // The two ends of the bottom edge along `z`.
+        assert_eq!(features.len(), 2);
+
+        for (feature, depth) in features {
+            assert!(match feature {
+                FeatureId::Vertex(_) => true,
+                _ => false,
+            });
+            assert_relative_eq!(depth, 0.01, epsilon = 1.0e-9);
+        }
+    }
+}
+
+#[test]
+fn nearly_flat_prism_face_is_clipped() {
+    // Rotated by less than one degree, so the whole bottom face is considered.
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.5f64.to_radians());
+    let manifold = contacts(rot, -0.01, false);
+    let features = prism_features(&manifold, false);
+
+    // The four lowest vertices of the bottom face, and the points where the two edges rising
+    // toward the two highest vertices leave the contact region.
+    assert_eq!(features.len(), 6);
+
+    let mut num_vertices = 0;
+
+    for (feature, depth) in features {
+        match feature {
+            FeatureId::Vertex(_) => {
+                num_vertices += 1;
+                assert!(depth > 0.0);
+            }
+            FeatureId::Edge(_) => assert_relative_eq!(depth, -0.001, epsilon = 1.0e-9),
+            _ => panic!("Unexpected feature {:?}", feature),
+        }
+    }
+
+    assert_eq!(num_vertices, 4);
+
+    // A flat face generates one contact per vertex.
+    let manifold = contacts(UnitQuaternion::identity(), -0.01, true);
+    assert_eq!(manifold.len(), 6);
+    assert!(manifold
+        .contacts()
+        .all(|c| (c.contact.depth - 0.01).abs() < 1.0e-9));
+}
+
+#[test]
+fn prism_above_plane_has_no_contact() {
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.3);
+    assert_eq!(contacts(rot, 0.01, false).len(), 0);
+    assert_eq!(contacts(rot, 0.01, true).len(), 0);
+
+    // Within the linear prediction.
+    assert_eq!(contacts(rot, 0.0005, false).len(), 1);
+}
+
+#[test]
+fn prism_on_plane_in_collision_world() {
+    let mut world = CollisionWorld::new(0.01);
+    let groups = CollisionGroups::new();
+    let query_type = GeometricQueryType::Contacts(0.0, 0.0);
+    let plane = ShapeHandle::new(Plane::new(Vector3::y_axis()));
+    let prism = ShapeHandle::new(hexagonal_prism());
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), PI / 6.0);
+    // The lowest edge lies `cos(30°)` below the center of the prism.
+    let pos = Isometry3::from_parts(Vector3::new(0.0, 0.86, 0.0).into(), rot);
+
+    let _ = world.add(Isometry3::identity(), plane.clone(), groups, query_type, ());
+    let _ = world.add(pos, prism.clone(), groups, query_type, ());
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(manifold.len(), 2);
+
+    let raised = Isometry3::from_parts(Vector3::new(0.0, 2.0, 0.0).into(), rot);
+    let lowest = prism
+        .as_support_map()
+        .unwrap()
+        .support_point(&raised, &-Vector3::y());
+    let dist = query::distance(&Isometry3::identity(), &*plane, &raised, &*prism);
+    assert_relative_eq!(dist, lowest.y, epsilon = 1.0e-9);
+}
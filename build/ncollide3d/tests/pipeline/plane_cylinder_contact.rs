@@ -0,0 +1,116 @@
+use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::pipeline::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::query::{ContactManifold, ContactPrediction};
+use ncollide3d::shape::{Ball, Cylinder, Plane, ScaledShape, Shape, ShapeHandle};
+use std::f64::consts::FRAC_PI_2;
+
+// Contacts between a plane and a shape with its lowest point at the depth 0.01.
+fn contacts(shape: &dyn Shape<f64>, rot: UnitQuaternion<f64>, flip: bool) -> ContactManifold<f64> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let plane = Plane::new(Vector3::y_axis());
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+    let lowest = shape.as_support_map().unwrap().support_point(
+        &Isometry3::from_parts(Translation3::identity(), rot),
+        &-Vector3::y(),
+    );
+    let m2 = Isometry3::from_parts(Vector3::new(0.0, -lowest.y - 0.01, 0.0).into(), rot);
+    let m1 = Isometry3::identity();
+
+    let mut generator = if flip {
+        dispatcher.get_contact_algorithm(shape, &plane).unwrap()
+    } else {
+        dispatcher.get_contact_algorithm(&plane, shape).unwrap()
+    };
+    let mut manifold = generator.init_manifold();
+
+    let _ = if flip {
+        generator.generate_contacts(
+            &dispatcher,
+            &m2,
+            shape,
+            None,
+            &m1,
+            &plane,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    } else {
+        generator.generate_contacts(
+            &dispatcher,
+            &m1,
+            &plane,
+            None,
+            &m2,
+            shape,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    };
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+    }
+
+    manifold
+}
+
+fn x_range(manifold: &ContactManifold<f64>, flip: bool) -> (f64, f64) {
+    manifold
+        .contacts()
+        .map(|c| {
+            if flip {
+                c.contact.world1.x
+            } else {
+                c.contact.world2.x
+            }
+        })
+        .fold((f64::MAX, -f64::MAX), |(min, max), x| {
+            (min.min(x), max.max(x))
+        })
+}
+
+#[test]
+fn cylinder_lying_on_its_side() {
+    let on_side = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+    let short = Cylinder::new(1.0, 0.5);
+    let long = Cylinder::new(5.0, 0.5);
+
+    for flip in &[false, true] {
+        let short_contacts = contacts(&short, on_side, *flip);
+        let long_contacts = contacts(&long, on_side, *flip);
+
+        // The contacts span the whole generatrix.
+        let (min, max) = x_range(&short_contacts, *flip);
+        assert_relative_eq!(min, -1.0, epsilon = 1.0e-6);
+        assert_relative_eq!(max, 1.0, epsilon = 1.0e-6);
+        let (min, max) = x_range(&long_contacts, *flip);
+        assert_relative_eq!(min, -5.0, epsilon = 1.0e-6);
+        assert_relative_eq!(max, 5.0, epsilon = 1.0e-6);
+
+        assert!(short_contacts.len() >= 2);
+        assert!(long_contacts.len() > short_contacts.len());
+    }
+}
+
+#[test]
+fn cylinder_resting_on_its_cap() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    assert!(contacts(&cylinder, UnitQuaternion::identity(), false).len() >= 3);
+}
+
+#[test]
+fn ellipsoid_touches_plane_at_a_single_point() {
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let ellipsoid = ScaledShape::new(ball, Vector3::new(3.0, 1.0, 2.0)).unwrap();
+    let manifold = contacts(&ellipsoid, UnitQuaternion::identity(), false);
+
+    assert_eq!(manifold.len(), 1);
+    let c = manifold.deepest_contact().unwrap();
+    assert_relative_eq!(
+        c.contact.world2,
+        Point3::new(0.0, -0.01, 0.0),
+        epsilon = 1.0e-6
+    );
+}
@@ -0,0 +1,40 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Proximity;
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn proximity_with_hysteresis() {
+    let mut world = CollisionWorld::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let groups = CollisionGroups::new();
+    // Enter at a distance of 0.2, exit at a distance of 0.6.
+    let query = GeometricQueryType::ProximityWithHysteresis(0.1, 0.3);
+    let (h1, _) = world.add(Isometry3::identity(), shape.clone(), groups, query, ());
+    let (h2, _) = world.add(
+        Isometry3::translation(2.4, 0.0, 0.0),
+        shape,
+        groups,
+        query,
+        (),
+    );
+
+    let mut proximity_at = |gap: f64| {
+        world
+            .get_mut(h2)
+            .unwrap()
+            .set_position(Isometry3::translation(2.0 + gap, 0.0, 0.0));
+        world.update();
+        world
+            .proximity_pair(h1, h2, false)
+            .map(|p| p.3)
+            .unwrap_or(Proximity::Disjoint)
+    };
+
+    assert_eq!(proximity_at(0.4), Proximity::Disjoint);
+    assert_eq!(proximity_at(0.1), Proximity::WithinMargin);
+    // Moving back to the same distance does not make the pair disjoint again.
+    assert_eq!(proximity_at(0.4), Proximity::WithinMargin);
+    assert_eq!(proximity_at(0.7), Proximity::Disjoint);
+    assert_eq!(proximity_at(0.4), Proximity::Disjoint);
+}
@@ -0,0 +1,63 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{BroadPhase, BroadPhaseInterferenceHandler, DBVTBroadPhase};
+use ncollide3d::query::Ray;
+
+struct NoopHandler;
+
+impl BroadPhaseInterferenceHandler<usize> for NoopHandler {
+    fn is_interference_allowed(&mut self, _: &usize, _: &usize) -> bool {
+        true
+    }
+
+    fn interference_started(&mut self, _: &usize, _: &usize) {}
+
+    fn interference_stopped(&mut self, _: &usize, _: &usize) {}
+}
+
+#[test]
+fn filter_interferences_by_proxy_tag() {
+    let mut broad_phase = DBVTBroadPhase::new(0.0);
+    let mut handles = Vec::new();
+
+    for i in 0..10 {
+        let center = Point3::new(i as f64 * 3.0, 0.0, 0.0);
+        let bv = AABB::from_half_extents(center, Vector3::repeat(1.0));
+        handles.push(broad_phase.create_proxy(bv, i));
+    }
+
+    assert_eq!(broad_phase.proxy_tag(handles[3]), Some(0));
+
+    for (i, handle) in handles.iter().enumerate() {
+        broad_phase.set_proxy_tag(*handle, i as u32 % 3);
+    }
+
+    broad_phase.update(&mut NoopHandler);
+    assert_eq!(broad_phase.proxy_tag(handles[4]), Some(1));
+
+    let mut out = Vec::new();
+    let everything = AABB::from_half_extents(Point3::origin(), Vector3::repeat(100.0));
+    broad_phase.interferences_with_bounding_volume_with_filter(
+        &everything,
+        &mut |tag| tag == 1,
+        &mut out,
+    );
+    let mut found: Vec<_> = out.iter().map(|i| **i).collect();
+    found.sort();
+    assert_eq!(found, vec![1, 4, 7]);
+
+    out.clear();
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    broad_phase.interferences_with_ray_with_filter(&ray, 20.0, &mut |tag| tag != 0, &mut out);
+    let mut found: Vec<_> = out.iter().map(|i| **i).collect();
+    found.sort();
+    assert_eq!(found, vec![1, 2, 4, 5]);
+
+    out.clear();
+    let point = Point3::new(6.5, 0.0, 0.0);
+    broad_phase.interferences_with_point_with_filter(&point, &mut |tag| tag == 2, &mut out);
+    assert_eq!(out, vec![&2]);
+    out.clear();
+    broad_phase.interferences_with_point_with_filter(&point, &mut |tag| tag == 0, &mut out);
+    assert!(out.is_empty());
+}
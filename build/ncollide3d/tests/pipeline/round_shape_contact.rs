@@ -0,0 +1,58 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::{self, PointQuery};
+use ncollide3d::shape::{Ball, Cuboid, RoundShape, ShapeHandle, Triangle};
+
+fn thick_triangle() -> RoundShape<f64> {
+    let triangle = Triangle::new(
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(2.0, 0.0, -0.5),
+        Point3::new(0.0, 0.0, 1.5),
+    );
+    RoundShape::new(ShapeHandle::new(triangle), 0.2)
+}
+
+#[test]
+fn round_shape_contacts_on_world() {
+    // Each shape penetrates the thick triangle by 0.1.
+    let others = [
+        (ShapeHandle::new(Ball::new(0.5)), 0.6),
+        (ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5))), 0.6),
+        (ShapeHandle::new(thick_triangle()), 0.3),
+    ];
+    let m1 = Isometry3::new(Vector3::new(0.3, -0.2, 0.1), Vector3::new(0.2, -0.1, 0.4));
+    let thick = ShapeHandle::new(thick_triangle());
+    let groups = CollisionGroups::new();
+    let query_type = GeometricQueryType::Contacts(0.0, 0.0);
+
+    for (other, height) in others.iter() {
+        let m2 = m1 * Isometry3::translation(0.3, *height, 0.0);
+        let mut world = CollisionWorld::new(0.0);
+        let h1 = world.add(m1, thick.clone(), groups, query_type, ()).0;
+        let h2 = world.add(m2, other.clone(), groups, query_type, ()).0;
+        world.update();
+
+        let (first, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+        let mut deepest = manifold.deepest_contact().unwrap().contact;
+        let expected = query::contact(&m1, &*thick, &m2, &**other, 0.0).unwrap();
+
+        // The contact manifold is expressed from the first object of the pair.
+        if first != h1 {
+            deepest.flip();
+        }
+
+        assert_relative_eq!(deepest.depth, 0.1, epsilon = 1.0e-4);
+        assert_relative_eq!(deepest.depth, expected.depth, epsilon = 1.0e-4);
+        assert_relative_eq!(*deepest.normal, *expected.normal, epsilon = 1.0e-4);
+        assert_relative_eq!(
+            thick.distance_to_point(&m1, &deepest.world1, false),
+            0.0,
+            epsilon = 1.0e-4
+        );
+        assert_relative_eq!(
+            other.distance_to_point(&m2, &deepest.world2, false),
+            0.0,
+            epsilon = 1.0e-4
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType, SAPBroadPhase,
+};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+type Pairs = Vec<(CollisionObjectSlabHandle, CollisionObjectSlabHandle)>;
+
+// The contact pairs of `world`, and its pairs with contacts.
+//
+// The handles of each pair are sorted because their order depends on the order in which the
+// broad phase reported the interferences.
+fn pairs(world: &CollisionWorld<f64, ()>) -> (Pairs, Pairs) {
+    let collect = |effective_only| {
+        let mut pairs: Pairs = world
+            .contact_pairs(effective_only)
+            .map(|(h1, h2, _, _)| if h1 < h2 { (h1, h2) } else { (h2, h1) })
+            .collect();
+        pairs.sort();
+        pairs
+    };
+    (collect(false), collect(true))
+}
+
+// Runs the same scenario on a world with the default broad phase, and on a world with the
+// sweep-and-prune broad phase, checking they detect the same pairs after each update.
+#[test]
+fn sap_broad_phase_matches_dbvt() {
+    let mut dbvt = CollisionWorld::new(0.02);
+    let mut sap = CollisionWorld::new(0.02);
+    sap.broad_phase = Box::new(SAPBroadPhase::new(0.02));
+
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let mut rand = move || rng.gen::<f64>();
+
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.6, 0.3, 0.4)));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut handles = Vec::new();
+
+    for i in 0..60 {
+        let pos = Isometry3::translation(rand() * 8.0, rand() * 8.0, rand() * 2.0);
+        let shape = if i % 2 == 0 { &ball } else { &cuboid };
+        let h1 = dbvt.add(pos, shape.clone(), groups, query, ()).0;
+        let h2 = sap.add(pos, shape.clone(), groups, query, ()).0;
+        assert_eq!(h1, h2);
+        handles.push(h1);
+    }
+
+    for step in 0..20 {
+        for h in &handles {
+            // Mostly small moves, with a few large jumps.
+            let scale = if rand() < 0.1 { 4.0 } else { 0.2 };
+            let delta = Vector3::new(rand() - 0.5, rand() - 0.5, rand() - 0.5) * scale;
+            let pos = Isometry3::new(
+                dbvt.collision_object(*h)
+                    .unwrap()
+                    .position()
+                    .translation
+                    .vector
+                    + delta,
+                Vector3::new(0.0, rand(), 0.0),
+            );
+            dbvt.set_position(*h, pos);
+            sap.set_position(*h, pos);
+        }
+
+        if step == 5 {
+            let removed: Vec<_> = handles.drain(..10).collect();
+            dbvt.remove(&removed);
+            sap.remove(&removed);
+        }
+
+        if step == 8 {
+            let mut blacklisted = CollisionGroups::new();
+            blacklisted.set_membership(&[1]);
+            blacklisted.set_blacklist(&[1]);
+
+            for h in handles.iter().step_by(2) {
+                dbvt.set_collision_groups(*h, blacklisted);
+                sap.set_collision_groups(*h, blacklisted);
+            }
+        }
+
+        if step == 12 {
+            let pos = Isometry3::translation(4.0, 4.0, 1.0);
+            let h1 = dbvt.add(pos, ball.clone(), groups, query, ()).0;
+            let h2 = sap.add(pos, ball.clone(), groups, query, ()).0;
+            assert_eq!(h1, h2);
+            handles.push(h1);
+        }
+
+        dbvt.update();
+        sap.update();
+
+        let (contacts, effective) = pairs(&sap);
+        assert!(!contacts.is_empty());
+        assert_eq!(pairs(&dbvt), (contacts, effective), "step {}", step);
+    }
+}
+
+#[test]
+fn sap_broad_phase_queries() {
+    let mut world = CollisionWorld::new(0.0);
+    world.broad_phase = Box::new(SAPBroadPhase::new(0.0));
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let handles: Vec<_> = (0..5)
+        .map(|i| {
+            let pos = Isometry3::translation(i as f64 * 2.0, 0.0, 0.0);
+            world.add(pos, ball.clone(), groups, query, ()).0
+        })
+        .collect();
+    world.update();
+
+    let aabb = AABB::new(Point3::new(1.6, -1.0, -1.0), Point3::new(4.6, 1.0, 1.0));
+    let found: Vec<_> = world
+        .interferences_with_aabb(&aabb, &groups)
+        .map(|(h, _)| h)
+        .collect();
+    assert_eq!(found, vec![handles[1], handles[2]]);
+
+    let found: Vec<_> = world
+        .interferences_with_point(&Point3::new(6.2, 0.1, 0.0), &groups)
+        .map(|(h, _)| h)
+        .collect();
+    assert_eq!(found, vec![handles[3]]);
+
+    let ray = Ray::new(Point3::new(9.0, 0.0, 0.0), -Vector3::x());
+    let first = world
+        .first_interference_with_ray(&ray, 100.0, &groups)
+        .unwrap();
+    assert_eq!(first.handle, handles[4]);
+    assert!((first.inter.toi - 0.5).abs() < 1.0e-6);
+    assert_eq!(
+        world.interferences_with_ray(&ray, 100.0, &groups).count(),
+        5
+    );
+}
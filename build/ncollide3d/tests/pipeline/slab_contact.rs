@@ -0,0 +1,58 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle, Slab};
+
+#[test]
+fn slab_contacts_in_world() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let slab = ShapeHandle::new(Slab::new(Vector3::y_axis(), 0.0, 2.0));
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+
+    let h_slab = world.add(Isometry3::identity(), slab, groups, query, ()).0;
+    let h_ball = world
+        .add(
+            Isometry3::translation(0.0, 1.4, 0.0),
+            ball,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let h_cuboid = world
+        .add(
+            Isometry3::translation(5.0, -1.4, 0.0),
+            cuboid,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    // The contact normals point from the first object of the pair toward the second one.
+    let (h1, _, _, manifold) = world.contact_pair(h_slab, h_ball, true).unwrap();
+    let c = &manifold.deepest_contact().unwrap().contact;
+    let normal = if h1 == h_slab {
+        Vector3::y()
+    } else {
+        -Vector3::y()
+    };
+    assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-7);
+    assert_relative_eq!(*c.normal, normal, epsilon = 1.0e-7);
+
+    let (h1, _, _, manifold) = world.contact_pair(h_slab, h_cuboid, true).unwrap();
+    assert!(manifold.len() >= 4);
+
+    for c in manifold.contacts() {
+        let normal = if h1 == h_slab {
+            -Vector3::y()
+        } else {
+            Vector3::y()
+        };
+        assert_relative_eq!(c.contact.depth, 0.1, epsilon = 1.0e-7);
+        assert_relative_eq!(*c.contact.normal, normal, epsilon = 1.0e-7);
+    }
+}
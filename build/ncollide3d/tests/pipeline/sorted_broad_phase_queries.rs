@@ -0,0 +1,66 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{BroadPhase, BroadPhaseInterferenceHandler, DBVTBroadPhase};
+use ncollide3d::query::Ray;
+
+struct NoopHandler;
+
+impl BroadPhaseInterferenceHandler<usize> for NoopHandler {
+    fn is_interference_allowed(&mut self, _: &usize, _: &usize) -> bool {
+        true
+    }
+
+    fn interference_started(&mut self, _: &usize, _: &usize) {}
+
+    fn interference_stopped(&mut self, _: &usize, _: &usize) {}
+}
+
+fn aabbs() -> Vec<AABB<f64>> {
+    (0..200)
+        .map(|i| {
+            let x = (i * 7 % 20) as f64;
+            let y = (i * 13 % 10) as f64;
+            AABB::from_half_extents(Point3::new(x, y, 0.0), Vector3::repeat(0.75))
+        })
+        .collect()
+}
+
+#[test]
+fn query_outputs_do_not_depend_on_the_tree_structure() {
+    // The same proxies, inserted one by one into one broad phase, and by batch into the other,
+    // so the two trees have different structures.
+    let mut incremental = DBVTBroadPhase::new(0.1);
+    let mut batch = DBVTBroadPhase::new(0.1);
+
+    for (i, aabb) in aabbs().into_iter().enumerate() {
+        let _ = incremental.create_proxy(aabb, i);
+    }
+
+    let _ = batch.create_proxies_batch(aabbs().into_iter().enumerate().map(|(i, bv)| (bv, i)));
+    incremental.update(&mut NoopHandler);
+    batch.update(&mut NoopHandler);
+
+    let region = AABB::new(Point3::new(2.0, 1.0, -1.0), Point3::new(15.0, 8.0, 1.0));
+    let ray = Ray::new(Point3::new(-1.0, 4.0, 0.0), Vector3::x());
+    let point = Point3::new(7.0, 3.0, 0.0);
+
+    let mut outputs = Vec::new();
+
+    for broad_phase in &[&incremental, &batch] {
+        let mut in_region = Vec::new();
+        let mut on_ray = Vec::new();
+        let mut at_point = Vec::new();
+        broad_phase.interferences_with_bounding_volume(&region, &mut in_region);
+        broad_phase.interferences_with_ray(&ray, 100.0, &mut on_ray);
+        broad_phase.interferences_with_point(&point, &mut at_point);
+
+        for found in &[&in_region, &on_ray, &at_point] {
+            assert!(!found.is_empty());
+            assert!(found.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        outputs.push((in_region, on_ray, at_point));
+    }
+
+    assert_eq!(outputs[0], outputs[1]);
+}
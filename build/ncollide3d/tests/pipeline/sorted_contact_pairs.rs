@@ -0,0 +1,82 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType,
+};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+// A world with a grid of overlapping balls, some of them removed and re-added.
+fn scene() -> CollisionWorld<f64, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    let ball = ShapeHandle::new(Ball::new(0.6));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut handles = Vec::new();
+
+    for i in 0..5 {
+        for j in 0..5 {
+            let pos = Isometry3::translation(i as f64, j as f64, 0.0);
+            handles.push(world.add(pos, ball.clone(), groups, query, ()).0);
+        }
+    }
+
+    world.update();
+
+    for h in handles.iter().step_by(3) {
+        let pos = *world.collision_object(*h).unwrap().position();
+        world.remove(&[*h]);
+        let _ = world.add(pos, ball.clone(), groups, query, ());
+    }
+
+    world.update();
+    world
+}
+
+fn pairs(
+    world: &CollisionWorld<f64, ()>,
+) -> Vec<(CollisionObjectSlabHandle, CollisionObjectSlabHandle)> {
+    world
+        .contact_pairs_sorted(false)
+        .map(|(h1, h2, _, _)| (h1, h2))
+        .collect()
+}
+
+#[test]
+fn contact_pairs_sorted_is_deterministic() {
+    let world1 = scene();
+    let world2 = scene();
+    let pairs1 = pairs(&world1);
+
+    assert!(pairs1.len() >= 40);
+    assert_eq!(pairs1, pairs(&world2));
+    assert!(pairs1.windows(2).all(|w| w[0] < w[1]));
+
+    // Same pairs as the unsorted iterator.
+    let mut unsorted: Vec<_> = world1
+        .contact_pairs(false)
+        .map(|(h1, h2, _, _)| (h1, h2))
+        .collect();
+    unsorted.sort();
+    assert_eq!(pairs1, unsorted);
+
+    let interactions: Vec<_> = world1
+        .interaction_pairs_sorted(false)
+        .map(|(h1, h2, _)| (h1, h2))
+        .collect();
+    assert_eq!(pairs1, interactions);
+}
+
+#[test]
+fn effective_contact_pairs_sorted() {
+    let world = scene();
+    let effective: Vec<_> = world
+        .contact_pairs_sorted(true)
+        .map(|(h1, h2, _, manifold)| {
+            assert!(manifold.len() > 0);
+            (h1, h2)
+        })
+        .collect();
+
+    // Only the balls at a distance 1 of each other are touching.
+    assert_eq!(effective.len(), 40);
+    assert!(effective.windows(2).all(|w| w[0] < w[1]));
+}
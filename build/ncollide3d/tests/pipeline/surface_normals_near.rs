@@ -0,0 +1,52 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn surface_normals_near_point() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let wall = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 5.0, 5.0)));
+    let ball = ShapeHandle::new(Ball::new(1.0));
+
+    let h_wall = world
+        .add(
+            Isometry3::translation(-1.5, 0.0, 0.0),
+            wall,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let h_ball = world
+        .add(
+            Isometry3::translation(0.0, 2.5, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    // Too far, even though its AABB is close.
+    let _ = world
+        .add(
+            Isometry3::translation(2.0, -2.0, 0.0),
+            ball,
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let mut normals = Vec::new();
+    world.surface_normals_near(&Point3::origin(), 1.6, &groups, &mut normals);
+    normals.sort_by_key(|(h, _)| *h != h_wall);
+
+    assert_eq!(normals.len(), 2);
+    assert_eq!(normals[0].0, h_wall);
+    assert_relative_eq!(*normals[0].1, Vector3::x(), epsilon = 1.0e-7);
+    assert_eq!(normals[1].0, h_ball);
+    assert_relative_eq!(*normals[1].1, -Vector3::y(), epsilon = 1.0e-7);
+}
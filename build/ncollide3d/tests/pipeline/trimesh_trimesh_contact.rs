@@ -0,0 +1,84 @@
+use crate::common::flat_floor;
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::narrow_phase::{
+    ContactManifoldGenerator, DefaultContactDispatcher, TriMeshTriMeshManifoldGenerator,
+};
+use ncollide3d::query::ContactPrediction;
+use ncollide3d::shape::TriMesh;
+
+// The contact points on the first mesh of the contacts between `mesh1` and `mesh2` moved by `m2`.
+fn contact_points(
+    mesh1: &TriMesh<f64>,
+    mesh2: &TriMesh<f64>,
+    m2: &Isometry3<f64>,
+) -> Vec<Point3<f64>> {
+    let mut generator = TriMeshTriMeshManifoldGenerator::new();
+    let mut manifold = generator.init_manifold();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+
+    assert!(generator.generate_contacts(
+        &DefaultContactDispatcher::new(),
+        &Isometry3::identity(),
+        mesh1,
+        None,
+        m2,
+        mesh2,
+        None,
+        &prediction,
+        &mut manifold,
+    ));
+
+    manifold.contacts().map(|c| c.contact.world1).collect()
+}
+
+fn assert_no_coincident_contacts(pts: &[Point3<f64>]) {
+    for (i, p1) in pts.iter().enumerate() {
+        for p2 in &pts[i + 1..] {
+            assert!(na::distance(p1, p2) > 1.0e-6);
+        }
+    }
+}
+
+fn x_range(pts: &[Point3<f64>]) -> (f64, f64) {
+    pts.iter()
+        .fold((std::f64::MAX, -std::f64::MAX), |(min, max), pt| {
+            (min.min(pt.x), max.max(pt.x))
+        })
+}
+
+#[test]
+fn crossing_trimeshes_contacts_along_shared_edges_are_merged() {
+    // The second mesh is tilted and crosses the first one right on top of its vertices and
+    // edges, each shared by several triangles.
+    let mesh1 = flat_floor(5);
+    let mesh2 = flat_floor(5);
+    let m2 = Isometry3::new(Vector3::new(0.0, -0.1, 0.0), Vector3::new(-0.05, 0.0, 0.0));
+    let pts = contact_points(&mesh1, &mesh2, &m2);
+
+    assert!(!pts.is_empty());
+    assert!(pts.len() < 32);
+    assert_no_coincident_contacts(&pts);
+
+    // The contacts are spread along the whole intersection line.
+    let (min_x, max_x) = x_range(&pts);
+    assert!(min_x < 0.5 && max_x > 4.5);
+}
+
+#[test]
+fn crossing_trimeshes_contacts_are_capped_and_spread() {
+    let mesh1 = flat_floor(16);
+    let mesh2 = flat_floor(16);
+    let m2 = Isometry3::new(Vector3::new(0.5, -0.37, 0.3), Vector3::new(-0.05, 0.0, 0.0));
+    let pts = contact_points(&mesh1, &mesh2, &m2);
+
+    // The meshes intersect along a line close to `z = 7.7`, on `x` from `0.5` to `16`.
+    assert_eq!(pts.len(), 32);
+    assert_no_coincident_contacts(&pts);
+
+    for pt in &pts {
+        assert!(pt.z > 6.5 && pt.z < 9.0);
+    }
+
+    let (min_x, max_x) = x_range(&pts);
+    assert!(min_x < 1.5 && max_x > 15.0);
+}
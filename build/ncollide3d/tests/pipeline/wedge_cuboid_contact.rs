@@ -0,0 +1,47 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Cuboid, ShapeHandle, Wedge};
+
+#[test]
+fn cuboid_resting_on_wedge_slope() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let wedge = Wedge::new(Vector3::new(2.0, 1.0, 2.0));
+    let normal = wedge.slope_normal();
+
+    // Rotate the cuboid so its bottom face lies on the slope, penetrating it by 0.01.
+    let angle = -(0.5f64).atan();
+    let center = *normal * (0.3 - 0.01);
+    let cuboid_pos = Isometry3::new(center, Vector3::z() * angle);
+
+    let h1 = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(wedge),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let h2 = world
+        .add(
+            cuboid_pos,
+            ShapeHandle::new(Cuboid::new(Vector3::repeat(0.3))),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let (first, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+    // The contact normals are oriented toward the second object of the pair.
+    let normal = if first == h1 { *normal } else { -*normal };
+    assert!(manifold.len() >= 4);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+        assert_relative_eq!(*c.contact.normal, normal, epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,158 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType,
+};
+use ncollide3d::query::{Contact, ContactId, Proximity};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+type Handle = CollisionObjectSlabHandle;
+
+// The contact pairs of `world` with a copy of their contacts, and its proximity pairs.
+fn pairs(
+    world: &CollisionWorld<f64, ()>,
+) -> (
+    Vec<(Handle, Handle, Vec<(Contact<f64>, ContactId)>)>,
+    Vec<(Handle, Handle, Proximity)>,
+) {
+    let contacts = world
+        .contact_pairs_sorted(false)
+        .map(|(h1, h2, _, manifold)| {
+            let contacts = manifold.contacts().map(|c| (c.contact, c.id)).collect();
+            (h1, h2, contacts)
+        })
+        .collect();
+    let mut proximities: Vec<_> = world
+        .proximity_pairs(false)
+        .map(|(h1, h2, _, prox)| (h1, h2, prox))
+        .collect();
+    proximities.sort_by_key(|(h1, h2, _)| (*h1, *h2));
+
+    (contacts, proximities)
+}
+
+#[test]
+fn restored_world_has_identical_pairs() {
+    let mut world = CollisionWorld::new(0.02);
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let mut rand = move || rng.gen::<f64>();
+
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.6, 0.3, 0.4)));
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.05, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.1);
+    let mut handles = Vec::new();
+
+    for i in 0..40 {
+        let pos = Isometry3::translation(rand() * 5.0, rand() * 5.0, rand() * 2.0);
+        let shape = if i % 2 == 0 { &ball } else { &cuboid };
+        let query = if i % 7 == 0 { proximity } else { contacts };
+        handles.push(world.add(pos, shape.clone(), groups, query, ()).0);
+    }
+
+    // Precomputed moves, so the steps following the snapshot can be replayed.
+    let moves: Vec<Vec<Isometry3<f64>>> = (0..15)
+        .map(|step| {
+            // Large moves after the snapshot, to break and create many pairs.
+            let scale = if step < 5 { 0.2 } else { 2.0 };
+            handles
+                .iter()
+                .map(|_| {
+                    Isometry3::new(
+                        Vector3::new(rand() - 0.5, rand() - 0.5, rand() - 0.5) * scale,
+                        Vector3::new(0.0, rand() - 0.5, 0.0),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    let step = |world: &mut CollisionWorld<f64, ()>, moves: &[Isometry3<f64>]| {
+        for (h, delta) in handles.iter().zip(moves.iter()) {
+            let co = world.get_mut(*h).unwrap();
+            let pos = delta * co.position();
+            co.set_position(pos);
+        }
+        world.update();
+    };
+
+    world.update();
+
+    for moves in &moves[..5] {
+        step(&mut world, moves);
+    }
+
+    let snapshot = world.snapshot();
+    let expected = pairs(&world);
+    assert_eq!(snapshot.num_objects(), 40);
+    assert!(expected.0.iter().filter(|p| !p.2.is_empty()).count() > 3);
+    assert!(expected.1.iter().any(|p| p.2 != Proximity::Disjoint));
+
+    step(&mut world, &moves[5]);
+    let next = pairs(&world);
+
+    for moves in &moves[6..] {
+        step(&mut world, moves);
+    }
+
+    assert_ne!(pairs(&world), expected);
+
+    world.restore(&snapshot.clone());
+    assert_eq!(pairs(&world), expected);
+    assert_eq!(world.contact_events().len(), 0);
+    assert_eq!(world.proximity_events().len(), 0);
+
+    // Replaying the step following the snapshot detects the same pairs again.
+    step(&mut world, &moves[5]);
+    let replayed = pairs(&world);
+    let effective = |pairs: &[(Handle, Handle, Vec<(Contact<f64>, ContactId)>)]| {
+        pairs
+            .iter()
+            .filter(|p| !p.2.is_empty())
+            .map(|p| (p.0, p.1, p.2.len()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(effective(&replayed.0), effective(&next.0));
+    assert_eq!(replayed.1, next.1);
+}
+
+#[test]
+fn restored_pair_keeps_its_orientation() {
+    let mut world = CollisionWorld::new(0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let pos = |x| Isometry3::translation(x, 0.0, 0.0);
+    let a = world.add(pos(0.0), ball.clone(), groups, query, ()).0;
+    let b = world.add(pos(0.9), ball.clone(), groups, query, ()).0;
+    world.update();
+
+    let snapshot = world.snapshot();
+    let expected = pairs(&world);
+    assert_eq!(expected.0.len(), 1);
+
+    // Separate both balls, and bring them back together in a way that makes the broad phase
+    // report their pair with the objects in the other order.
+    for (h, x) in [(a, 10.0), (b, 20.0), (a, 20.9), (b, 0.9), (a, 0.0)].iter() {
+        world.get_mut(*h).unwrap().set_position(pos(*x));
+        world.update();
+    }
+
+    let (h1, h2, _) = &pairs(&world).0[0];
+    assert_eq!((*h1, *h2), (expected.0[0].1, expected.0[0].0));
+
+    world.restore(&snapshot);
+    assert_eq!(pairs(&world), expected);
+
+    // The contact algorithm dispatched for the restored orientation keeps working.
+    world.get_mut(b).unwrap().set_position(pos(0.8));
+    world.update();
+    let (h1, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(h1, expected.0[0].0);
+    assert_relative_eq!(
+        manifold.deepest_contact().unwrap().contact.depth,
+        0.2,
+        epsilon = 1.0e-6
+    );
+}
@@ -1,7 +1,7 @@
 //! Axis Aligned Bounding Box.
 
 use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
-use crate::math::{Isometry, Point, Vector, DIM};
+use crate::math::{Isometry, Point, Similarity, Vector, DIM};
 use crate::utils::IsometryOps;
 use na::{self, RealField};
 
@@ -18,6 +18,20 @@ where
     g.bounding_volume(m)
 }
 
+/// Computes an axis-aligned bounding box of a shape `g` transformed by the similarity `m`.
+///
+/// This is the local AABB of `g`, scaled, then transformed by the isometric part of `m`.
+#[inline]
+pub fn aabb_with_similarity<N, G: ?Sized>(g: &G, m: &Similarity<N>) -> AABB<N>
+where
+    N: RealField,
+    G: HasBoundingVolume<N, AABB<N>>,
+{
+    let local = g.local_bounding_volume();
+    let scale = m.scaling();
+    AABB::new(local.mins * scale, local.maxs * scale).transform_by(&m.isometry)
+}
+
 // Seems useful to help type inference. See issue #84.
 /// Computes the axis-aligned bounding box of a shape `g`.
 ///
@@ -116,6 +130,12 @@ impl<N: RealField> AABB<N> {
     }
 
     /// Computes the AABB bounding `self` transformed by `m`.
+    ///
+    /// This is equivalent to fitting an AABB to the transformed corners of `self`, without
+    /// having to evaluate the shape `self` was computed from. Thus, transforming the local AABB of
+    /// a shape is much cheaper than computing its AABB from scratch when the shape is expensive to
+    /// bound, e.g., a `ConvexHull` with many vertices. The result is conservative: after a
+    /// rotation, it is generally looser than the AABB computed directly from the shape.
     #[inline]
     pub fn transform_by(&self, m: &Isometry<N>) -> Self {
         let ls_center = self.center();
@@ -125,6 +145,84 @@ impl<N: RealField> AABB<N> {
         AABB::new(center + (-ws_half_extents), center + ws_half_extents)
     }
 
+    /// Computes the intersection of this AABB with another one.
+    ///
+    /// Returns `None` if both AABBs do not intersect.
+    #[inline]
+    pub fn intersection(&self, other: &AABB<N>) -> Option<AABB<N>> {
+        let result = AABB::new(
+            self.mins.coords.sup(&other.mins.coords).into(),
+            self.maxs.coords.inf(&other.maxs.coords).into(),
+        );
+
+        for i in 0..DIM {
+            if result.mins[i] > result.maxs[i] {
+                return None;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Splits this AABB in two by the plane orthogonal to the `axis`-th axis at the coordinate
+    /// `value`.
+    ///
+    /// Returns the parts of `self` below and above this plane. Returns `None` if the plane does
+    /// not intersect `self`. Panics if `axis` is not smaller than the dimension.
+    #[inline]
+    pub fn split_at(&self, axis: usize, value: N) -> Option<(AABB<N>, AABB<N>)> {
+        assert!(axis < DIM, "The split axis is out of range.");
+
+        if value < self.mins[axis] || value > self.maxs[axis] {
+            return None;
+        }
+
+        let mut below = *self;
+        let mut above = *self;
+        below.maxs[axis] = value;
+        above.mins[axis] = value;
+
+        Some((below, above))
+    }
+
+    /// Splits this AABB into the four quadrants delimited by its center.
+    ///
+    /// The `i`-th AABB returned is above the center along the `j`-th axis if the `j`-th bit
+    /// of `i` is set, and below otherwise.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn center_split(&self) -> [AABB<N>; 4] {
+        let mut children = [*self; 4];
+        self.center_split_into(&mut children);
+        children
+    }
+
+    /// Splits this AABB into the eight octants delimited by its center.
+    ///
+    /// The `i`-th AABB returned is above the center along the `j`-th axis if the `j`-th bit
+    /// of `i` is set, and below otherwise.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn center_split(&self) -> [AABB<N>; 8] {
+        let mut children = [*self; 8];
+        self.center_split_into(&mut children);
+        children
+    }
+
+    fn center_split_into(&self, children: &mut [AABB<N>]) {
+        let center = self.center();
+
+        for (i, child) in children.iter_mut().enumerate() {
+            for axis in 0..DIM {
+                if i & (1 << axis) == 0 {
+                    child.maxs[axis] = center[axis];
+                } else {
+                    child.mins[axis] = center[axis];
+                }
+            }
+        }
+    }
+
     /// The smallest bounding sphere containing this AABB.
     #[inline]
     pub fn bounding_sphere(&self) -> BoundingSphere<N> {
@@ -0,0 +1,34 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::{Isometry, Vector};
+use crate::shape::Arc;
+use na::{self, RealField};
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Arc<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        let bv: AABB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let start = self.start_point();
+        let end = self.end_point();
+        let mut mins = start.inf(&end);
+        let mut maxs = start.sup(&end);
+
+        // The curve reaches its extremal coordinates at the angles multiple of `pi / 2` it spans.
+        for i in 0..4 {
+            let angle = N::frac_pi_2() * na::convert(i as f64);
+
+            if self.contains_angle(angle) {
+                let pt = self.point_at_angle(angle);
+                mins = mins.inf(&pt);
+                maxs = maxs.sup(&pt);
+            }
+        }
+
+        let half_thickness = Vector::repeat(self.half_thickness());
+        AABB::new(mins - half_thickness, maxs + half_thickness)
+    }
+}
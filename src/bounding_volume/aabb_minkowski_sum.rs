@@ -0,0 +1,31 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::MinkowskiSum;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for MinkowskiSum<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        // The translation of `m` must only be applied once.
+        let aabb1 = self.shape1().aabb(m);
+        let aabb2 = self
+            .shape2()
+            .aabb(&Isometry::from_parts(na::one(), m.rotation));
+
+        AABB::new(
+            aabb1.mins + aabb2.mins.coords,
+            aabb1.maxs + aabb2.maxs.coords,
+        )
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let aabb1 = self.shape1().local_aabb();
+        let aabb2 = self.shape2().local_aabb();
+
+        AABB::new(
+            aabb1.mins + aabb2.mins.coords,
+            aabb1.maxs + aabb2.maxs.coords,
+        )
+    }
+}
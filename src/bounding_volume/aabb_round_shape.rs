@@ -0,0 +1,16 @@
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::RoundShape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for RoundShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        self.shape().aabb(m).loosened(self.radius())
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        self.shape().local_aabb().loosened(self.radius())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::bounding_volume::{self, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::ScaledShape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for ScaledShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        if self.shape().is_support_map() {
+            bounding_volume::support_map_aabb(m, self)
+        } else {
+            let aabb: AABB<N> = self.local_bounding_volume();
+            aabb.transform_by(m)
+        }
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        // The scale is positive and aligned with the axes, so it maps the AABB of the wrapped
+        // shape to the AABB of the scaled shape.
+        let aabb = self.shape().local_aabb();
+        AABB::new(self.scale_point(&aabb.mins), self.scale_point(&aabb.maxs))
+    }
+}
@@ -0,0 +1,20 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point};
+use crate::num::Bounded;
+use crate::shape::Slab;
+use na::{self, RealField};
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Slab<N> {
+    #[inline]
+    fn bounding_volume(&self, _: &Isometry<N>) -> AABB<N> {
+        self.local_bounding_volume()
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        // Same as for planes: we divide by 2.0 so that the box can still be loosened.
+        let max = Point::max_value() * na::convert(0.5f64);
+
+        AABB::new(-max, max)
+    }
+}
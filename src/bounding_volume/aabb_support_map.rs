@@ -1,7 +1,7 @@
 use crate::bounding_volume;
 use crate::bounding_volume::{HasBoundingVolume, AABB};
 use crate::math::Isometry;
-use crate::shape::{Capsule, Segment};
+use crate::shape::{Capsule, MorphShape, Segment};
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, Cylinder};
 use na::RealField;
@@ -44,6 +44,18 @@ impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Capsule<N> {
     }
 }
 
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for MorphShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_support_map_aabb(self)
+    }
+}
+
 impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Segment<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
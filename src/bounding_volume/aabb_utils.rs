@@ -31,7 +31,7 @@ where
 }
 
 /// Computes the AABB of an support mapped shape.
-pub fn local_support_map_aabb<N, G>(i: &G) -> AABB<N>
+pub fn local_support_map_aabb<N, G: ?Sized>(i: &G) -> AABB<N>
 where
     N: RealField,
     G: SupportMap<N>,
@@ -0,0 +1,18 @@
+use crate::bounding_volume::{self, HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point};
+use crate::shape::Wedge;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Wedge<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let half_extents = Point::from(self.half_extents);
+
+        AABB::new(-half_extents, half_extents)
+    }
+}
@@ -1,7 +1,7 @@
 //! Bounding sphere.
 
 use crate::bounding_volume::{BoundingVolume, HasBoundingVolume};
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Similarity};
 use na::{self, RealField};
 
 // Seems useful to help type inference. See issue #84.
@@ -16,6 +16,16 @@ where
     g.bounding_volume(m)
 }
 
+/// Computes the bounding sphere of a shape `g` transformed by the similarity `m`.
+pub fn bounding_sphere_with_similarity<N, G: ?Sized>(g: &G, m: &Similarity<N>) -> BoundingSphere<N>
+where
+    N: RealField,
+    G: HasBoundingVolume<N, BoundingSphere<N>>,
+{
+    let local = g.local_bounding_volume();
+    BoundingSphere::new(m * local.center(), local.radius() * m.scaling())
+}
+
 // Seems useful to help type inference. See issue #84.
 /// Computes the bounding sphere of a shape `g`.
 ///
@@ -54,6 +64,14 @@ impl<N: RealField> BoundingSphere<N> {
         self.radius
     }
 
+    /// Enlarges this bounding sphere so it also contains the point `pt`.
+    ///
+    /// The result is the smallest sphere containing both `self` and `pt`.
+    #[inline]
+    pub fn take_point(&mut self, pt: Point<N>) {
+        self.merge(&BoundingSphere::new(pt, N::zero()))
+    }
+
     /// Transforms this bounding sphere by `m`.
     #[inline]
     pub fn transform_by(&self, m: &Isometry<N>) -> BoundingSphere<N> {
@@ -87,35 +105,24 @@ impl<N: RealField> BoundingVolume<N> for BoundingSphere<N> {
 
     #[inline]
     fn merge(&mut self, other: &BoundingSphere<N>) {
-        let mut dir = *other.center() - *self.center();
-        let norm = dir.normalize_mut();
-
-        if norm.is_zero() {
-            if other.radius > self.radius {
-                self.radius = other.radius
-            }
-        } else {
-            let s_center_dir = self.center.coords.dot(&dir);
-            let o_center_dir = other.center.coords.dot(&dir);
-
-            let right;
-            let left;
-
-            if s_center_dir + self.radius > o_center_dir + other.radius {
-                right = self.center + dir * self.radius;
-            } else {
-                right = other.center + dir * other.radius;
-            }
-
-            if -s_center_dir + self.radius > -o_center_dir + other.radius {
-                left = self.center - dir * self.radius;
-            } else {
-                left = other.center - dir * other.radius;
-            }
-
-            self.center = na::center(&left, &right);
-            self.radius = na::distance(&right, &self.center);
+        if self.contains(other) {
+            return;
+        }
+
+        if other.contains(self) {
+            *self = *other;
+            return;
         }
+
+        // The smallest sphere containing both spans the diameter joining their farthest points
+        // on the line passing through their centers. The centers are distinct since neither
+        // sphere contains the other.
+        let delta_pos = other.center - self.center;
+        let distance = delta_pos.norm();
+        let radius = (distance + self.radius + other.radius) * na::convert(0.5);
+
+        self.center += delta_pos * ((radius - self.radius) / distance);
+        self.radius = radius;
     }
 
     #[inline]
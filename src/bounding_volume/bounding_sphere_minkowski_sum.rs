@@ -0,0 +1,23 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::MinkowskiSum;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for MinkowskiSum<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bv1 = self.shape1().local_bounding_sphere();
+        let bv2 = self.shape2().local_bounding_sphere();
+
+        BoundingSphere::new(
+            bv1.center() + bv2.center().coords,
+            bv1.radius() + bv2.radius(),
+        )
+    }
+}
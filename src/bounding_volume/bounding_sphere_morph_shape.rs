@@ -0,0 +1,18 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::MorphShape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for MorphShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let aabb: AABB<N> = self.local_bounding_volume();
+        aabb.bounding_sphere()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::RoundShape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for RoundShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.shape().bounding_sphere(m).loosened(self.radius())
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.shape().local_bounding_sphere().loosened(self.radius())
+    }
+}
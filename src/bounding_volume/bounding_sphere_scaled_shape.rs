@@ -0,0 +1,19 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::ScaledShape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for ScaledShape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bv = self.shape().local_bounding_sphere();
+        let center = self.scale_point(bv.center());
+        BoundingSphere::new(center, bv.radius() * self.scale().amax())
+    }
+}
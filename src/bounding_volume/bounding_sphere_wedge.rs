@@ -0,0 +1,21 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::{Isometry, Point};
+use crate::shape::Wedge;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for Wedge<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        // The origin is the middle of the hypotenuse of the cross-section so all the vertices are
+        // at the same distance from it.
+        let radius = self.half_extents.norm();
+
+        BoundingSphere::new(Point::origin(), radius)
+    }
+}
@@ -3,26 +3,30 @@
 pub use self::circular_cone::CircularCone;
 pub use self::spatialized_normal_cone::SpatializedNormalCone;
 #[doc(inline)]
-pub use crate::bounding_volume::aabb::{aabb, local_aabb, AABB};
+pub use crate::bounding_volume::aabb::{aabb, aabb_with_similarity, local_aabb, AABB};
 pub use crate::bounding_volume::aabb_ball::ball_aabb;
 pub use crate::bounding_volume::aabb_utils::{
     local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb, support_map_aabb,
 };
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_sphere::{
-    bounding_sphere, local_bounding_sphere, BoundingSphere,
+    bounding_sphere, bounding_sphere_with_similarity, local_bounding_sphere, BoundingSphere,
 };
 pub use crate::bounding_volume::bounding_sphere_utils::{
     point_cloud_bounding_sphere, point_cloud_bounding_sphere_with_center,
 };
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_volume::{BoundingVolume, HasBoundingVolume};
+#[doc(inline)]
+pub use crate::bounding_volume::obb::{obb, OBB};
 
 #[doc(hidden)]
 pub mod bounding_volume;
 
 #[doc(hidden)]
 pub mod aabb;
+#[cfg(feature = "dim2")]
+mod aabb_arc;
 mod aabb_ball;
 mod aabb_compound;
 #[cfg(feature = "dim3")]
@@ -31,17 +35,25 @@ mod aabb_convex;
 mod aabb_convex_polygon;
 mod aabb_cuboid;
 mod aabb_heightfield;
+mod aabb_minkowski_sum;
 mod aabb_plane;
 mod aabb_polyline;
+mod aabb_round_shape;
+mod aabb_scaled_shape;
 mod aabb_shape;
+mod aabb_slab;
 mod aabb_support_map;
 mod aabb_triangle;
 #[cfg(feature = "dim3")]
 mod aabb_trimesh;
 mod aabb_utils;
+#[cfg(feature = "dim3")]
+mod aabb_wedge;
 
 #[doc(hidden)]
 pub mod bounding_sphere;
+#[cfg(feature = "dim2")]
+mod bounding_sphere_arc;
 mod bounding_sphere_ball;
 mod bounding_sphere_capsule;
 mod bounding_sphere_compound;
@@ -55,14 +67,24 @@ mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
 mod bounding_sphere_heightfield;
+mod bounding_sphere_minkowski_sum;
+mod bounding_sphere_morph_shape;
 mod bounding_sphere_plane;
 mod bounding_sphere_polyline;
+mod bounding_sphere_round_shape;
+mod bounding_sphere_scaled_shape;
 mod bounding_sphere_segment;
 mod bounding_sphere_shape;
+mod bounding_sphere_slab;
 mod bounding_sphere_triangle;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_wedge;
+
+#[doc(hidden)]
+pub mod obb;
 
 pub(crate) mod circular_cone;
 mod spatialized_normal_cone;
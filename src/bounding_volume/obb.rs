@@ -0,0 +1,253 @@
+//! Oriented Bounding Box.
+
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::{Isometry, Matrix, Point, Rotation, Vector, DIM};
+use na::{self, RealField};
+
+/// Computes an oriented bounding box of a shape `g` transformed by `m`.
+///
+/// This is the local AABB of `g` transformed by `m`. It is generally much tighter than the
+/// world-space AABB of `g` when `g` is elongated and rotated.
+#[inline]
+pub fn obb<N, G: ?Sized>(g: &G, m: &Isometry<N>) -> OBB<N>
+where
+    N: RealField,
+    G: HasBoundingVolume<N, AABB<N>>,
+{
+    OBB::from_aabb(&g.local_bounding_volume(), m)
+}
+
+/// An Oriented Bounding Box.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OBB<N: RealField> {
+    center: Point<N>,
+    rotation: Rotation<N>,
+    half_extents: Vector<N>,
+}
+
+impl<N: RealField> OBB<N> {
+    /// Creates a new OBB from its center, its orientation, and its half-extents along its local
+    /// axes.
+    #[inline]
+    pub fn new(center: Point<N>, rotation: Rotation<N>, half_extents: Vector<N>) -> OBB<N> {
+        OBB {
+            center,
+            rotation,
+            half_extents,
+        }
+    }
+
+    /// Creates the OBB equal to the AABB `aabb` transformed by `m`.
+    #[inline]
+    pub fn from_aabb(aabb: &AABB<N>, m: &Isometry<N>) -> OBB<N> {
+        OBB::new(m * aabb.center(), m.rotation, aabb.half_extents())
+    }
+
+    /// The center of this OBB.
+    #[inline]
+    pub fn center(&self) -> &Point<N> {
+        &self.center
+    }
+
+    /// The orientation of this OBB.
+    #[inline]
+    pub fn rotation(&self) -> &Rotation<N> {
+        &self.rotation
+    }
+
+    /// The half-extents of this OBB along its local axes.
+    #[inline]
+    pub fn half_extents(&self) -> &Vector<N> {
+        &self.half_extents
+    }
+
+    /// The transformation from the local space of this OBB to the world space.
+    ///
+    /// In its local space, this OBB is an AABB centered at the origin.
+    #[inline]
+    pub fn position(&self) -> Isometry<N> {
+        Isometry::from_parts(self.center.coords.into(), self.rotation)
+    }
+
+    /// This OBB expressed in its local space, i.e., an AABB centered at the origin.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB<N> {
+        AABB::from_half_extents(Point::origin(), self.half_extents)
+    }
+
+    /// The smallest AABB containing this OBB.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        self.local_aabb().transform_by(&self.position())
+    }
+
+    /// Transforms this OBB by `m`.
+    #[inline]
+    pub fn transform_by(&self, m: &Isometry<N>) -> OBB<N> {
+        OBB::new(
+            m * self.center,
+            m.rotation * self.rotation,
+            self.half_extents,
+        )
+    }
+
+    /// Checks if the segment joining the projections of `self` and `other` on `axis` overlap.
+    ///
+    /// The axis does not need to be normalized. `axes1` and `axes2` are the rotation matrices of
+    /// `self` and `other`, and `dcenter` is the vector from the center of `self` to the center of
+    /// `other`.
+    #[inline]
+    fn is_separating_axis(
+        &self,
+        other: &OBB<N>,
+        axes1: &Matrix<N>,
+        axes2: &Matrix<N>,
+        dcenter: &Vector<N>,
+        axis: &Vector<N>,
+    ) -> bool {
+        let mut radius1 = N::zero();
+        let mut radius2 = N::zero();
+
+        for i in 0..DIM {
+            radius1 += self.half_extents[i] * axes1.column(i).dot(axis).abs();
+            radius2 += other.half_extents[i] * axes2.column(i).dot(axis).abs();
+        }
+
+        dcenter.dot(axis).abs() > radius1 + radius2
+    }
+}
+
+impl<N: RealField> BoundingVolume<N> for OBB<N> {
+    #[inline]
+    fn center(&self) -> Point<N> {
+        self.center
+    }
+
+    /// Checks if this OBB intersects another one using the separating axis theorem.
+    ///
+    /// The candidate axes are the local axes of both OBBs and, in 3D, the cross products of each
+    /// axis of `self` with each axis of `other`.
+    fn intersects(&self, other: &OBB<N>) -> bool {
+        let axes1 = self.rotation.to_rotation_matrix().into_inner();
+        let axes2 = other.rotation.to_rotation_matrix().into_inner();
+        let dcenter = other.center - self.center;
+
+        for i in 0..DIM {
+            let axis1 = axes1.column(i).into_owned();
+            let axis2 = axes2.column(i).into_owned();
+
+            if self.is_separating_axis(other, &axes1, &axes2, &dcenter, &axis1)
+                || self.is_separating_axis(other, &axes1, &axes2, &dcenter, &axis2)
+            {
+                return false;
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            // Cross products of nearly parallel axes are too noisy to be meaningful. The face
+            // axes already cover those cases.
+            let eps = N::default_epsilon().sqrt();
+
+            for i in 0..DIM {
+                for j in 0..DIM {
+                    let axis = axes1.column(i).cross(&axes2.column(j));
+
+                    if axis.norm_squared() > eps
+                        && self.is_separating_axis(other, &axes1, &axes2, &dcenter, &axis)
+                    {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Checks if this OBB contains every vertex of `other`.
+    fn contains(&self, other: &OBB<N>) -> bool {
+        let axes1 = self.rotation.to_rotation_matrix().into_inner();
+        let axes2 = other.rotation.to_rotation_matrix().into_inner();
+        let dcenter = other.center - self.center;
+
+        for i in 0..DIM {
+            let axis = axes1.column(i);
+            let mut extent = dcenter.dot(&axis).abs();
+
+            for j in 0..DIM {
+                extent += other.half_extents[j] * axes2.column(j).dot(&axis).abs();
+            }
+
+            if extent > self.half_extents[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Merges this OBB with another one, keeping the orientation of `self`.
+    ///
+    /// The result is the smallest OBB with the orientation of `self` containing both OBBs.
+    fn merge(&mut self, other: &OBB<N>) {
+        let pos = self.position();
+        let other_local = other
+            .local_aabb()
+            .transform_by(&pos.inv_mul(&other.position()));
+        let merged = self.local_aabb().merged(&other_local);
+
+        self.center = pos * merged.center();
+        self.half_extents = merged.half_extents();
+    }
+
+    #[inline]
+    fn merged(&self, other: &OBB<N>) -> OBB<N> {
+        let mut res = *self;
+
+        res.merge(other);
+
+        res
+    }
+
+    #[inline]
+    fn loosen(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The loosening margin must be positive."
+        );
+        self.half_extents += Vector::repeat(amount);
+    }
+
+    #[inline]
+    fn loosened(&self, amount: N) -> OBB<N> {
+        let mut res = *self;
+
+        res.loosen(amount);
+
+        res
+    }
+
+    #[inline]
+    fn tighten(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The tightening margin must be positive."
+        );
+        self.half_extents -= Vector::repeat(amount);
+        assert!(
+            self.half_extents.iter().all(|e| *e >= N::zero()),
+            "The tightening margin is to large."
+        );
+    }
+
+    #[inline]
+    fn tightened(&self, amount: N) -> OBB<N> {
+        let mut res = *self;
+
+        res.tighten(amount);
+
+        res
+    }
+}
@@ -93,7 +93,10 @@ pub mod utils;
 /// Compilation flags dependent aliases for mathematical types.
 #[cfg(feature = "dim3")]
 pub mod math {
-    use na::{Isometry3, Matrix3, Point3, Translation3, UnitQuaternion, Vector3, Vector6, U3, U6};
+    use na::{
+        Isometry3, Matrix3, Point3, Similarity3, Translation3, UnitQuaternion, Vector3, Vector6,
+        U3, U6,
+    };
 
     /// The dimension of the space.
     pub const DIM: usize = 3;
@@ -128,6 +131,9 @@ pub mod math {
     /// The transformation matrix type.
     pub type Isometry<N> = Isometry3<N>;
 
+    /// The similarity transformation type, i.e., an isometry combined with a uniform scale.
+    pub type Similarity<N> = Similarity3<N>;
+
     /// The rotation matrix type.
     pub type Rotation<N> = UnitQuaternion<N>;
 
@@ -138,7 +144,9 @@ pub mod math {
 /// Compilation flags dependent aliases for mathematical types.
 #[cfg(feature = "dim2")]
 pub mod math {
-    use na::{Isometry2, Matrix2, Point2, Translation2, UnitComplex, Vector1, Vector2, U2};
+    use na::{
+        Isometry2, Matrix2, Point2, Similarity2, Translation2, UnitComplex, Vector1, Vector2, U2,
+    };
 
     /// The dimension of the space.
     pub const DIM: usize = 2;
@@ -161,6 +169,9 @@ pub mod math {
     /// The transformation matrix type.
     pub type Isometry<N> = Isometry2<N>;
 
+    /// The similarity transformation type, i.e., an isometry combined with a uniform scale.
+    pub type Similarity<N> = Similarity2<N>;
+
     /// The rotation matrix type.
     pub type Rotation<N> = UnitComplex<N>;
 
@@ -1,7 +1,9 @@
+use crate::math::{Isometry, Point};
 use crate::partitioning::{
     BVTNodeId, BestFirstVisitStatus, BestFirstVisitor, DBVTNodeId, SimultaneousVisitor,
-    VisitStatus, Visitor, BVT, DBVT,
+    TraversalRecorder, VisitStatus, Visitor, BVT, DBVT,
 };
+use crate::query::PointQuery;
 use na::RealField;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -24,17 +26,19 @@ pub trait BVH<T, BV> {
     fn visit(&self, visitor: &mut impl Visitor<T, BV>) {
         // FIXME: find a way to avoid the allocation.
         let mut stack = Vec::new();
+        let mut recorder = TraversalRecorder::default();
 
         if let Some(root) = self.root() {
-            stack.push(root);
+            stack.push((root, 0));
 
-            while let Some(node) = stack.pop() {
+            while let Some((node, depth)) = stack.pop() {
                 let content = self.content(node);
+                recorder.visit(depth, content.1.is_some());
 
                 match visitor.visit(content.0, content.1) {
                     VisitStatus::Continue => {
                         for i in 0..self.num_children(node) {
-                            stack.push(self.child(i, node))
+                            stack.push((self.child(i, node), depth + 1))
                         }
                     }
                     VisitStatus::ExitEarly => return,
@@ -103,13 +107,15 @@ pub trait BVH<T, BV> {
         N: RealField,
         BFS: BestFirstVisitor<N, T, BV>,
     {
-        let mut queue: BinaryHeap<WeightedValue<N, Self::Node>> = BinaryHeap::new();
+        let mut queue: BinaryHeap<WeightedValue<N, (Self::Node, usize)>> = BinaryHeap::new();
         // The lowest cost collision with actual scene geometry.
         let mut best_cost = N::max_value();
         let mut best_result = None;
+        let mut recorder = TraversalRecorder::default();
 
         if let Some(root) = self.root() {
             let (root_bv, root_data) = self.content(root);
+            recorder.visit(0, root_data.is_some());
 
             match visitor.visit(best_cost, root_bv, root_data) {
                 BestFirstVisitStatus::Continue { cost, result } => {
@@ -119,7 +125,7 @@ pub trait BVH<T, BV> {
                         best_result = Some((root, res));
                     }
 
-                    queue.push(WeightedValue::new(root, -cost))
+                    queue.push(WeightedValue::new((root, 0), -cost))
                 }
                 BestFirstVisitStatus::Stop => return None,
                 BestFirstVisitStatus::ExitEarly(result) => return result.map(|res| (root, res)),
@@ -131,9 +137,12 @@ pub trait BVH<T, BV> {
                     break; // Solution found.
                 }
 
-                for i in 0..self.num_children(entry.value) {
-                    let child = self.child(i, entry.value);
+                let (node, depth) = entry.value;
+
+                for i in 0..self.num_children(node) {
+                    let child = self.child(i, node);
                     let (child_bv, child_data) = self.content(child);
+                    recorder.visit(depth + 1, child_data.is_some());
 
                     match visitor.visit(best_cost, child_bv, child_data) {
                         BestFirstVisitStatus::Continue { cost, result } => {
@@ -144,7 +153,7 @@ pub trait BVH<T, BV> {
                                     best_result = result.map(|res| (child, res));
                                 }
                                 // BV may have a child with lower cost, evaluate it next.
-                                queue.push(WeightedValue::new(child, -cost))
+                                queue.push(WeightedValue::new((child, depth + 1), -cost))
                             }
                         }
                         BestFirstVisitStatus::ExitEarly(result) => {
@@ -158,6 +167,70 @@ pub trait BVH<T, BV> {
 
         best_result
     }
+
+    /// The data of the (at most) `k` leaves with the bounding volumes closest to `point`.
+    ///
+    /// Returns the data of each leaf with the distance from `point` to its bounding volume,
+    /// sorted by increasing distance. This distance is zero if `point` lies inside of the
+    /// bounding volume. The tree is traversed in a best-first order, pruning the subtrees further
+    /// than the `k`-th closest leaf found so far.
+    fn k_nearest<'a, N>(&'a self, point: &Point<N>, k: usize) -> Vec<(&'a T, N)>
+    where
+        N: RealField,
+        T: 'a,
+        BV: 'a + PointQuery<N>,
+    {
+        // Max-heap of the best leaves found so far, with the furthest one on top.
+        let mut best: BinaryHeap<WeightedValue<N, &'a T>> = BinaryHeap::with_capacity(k + 1);
+        // Max-heap of the nodes to visit, with the closest one on top.
+        let mut queue: BinaryHeap<WeightedValue<N, (Self::Node, usize)>> = BinaryHeap::new();
+        let mut recorder = TraversalRecorder::default();
+        let id = Isometry::identity();
+
+        if k == 0 {
+            return Vec::new();
+        }
+
+        if let Some(root) = self.root() {
+            let dist = self.content(root).0.distance_to_point(&id, point, true);
+            queue.push(WeightedValue::new((root, 0), -dist));
+        }
+
+        while let Some(entry) = queue.pop() {
+            let dist = -entry.cost;
+
+            if best.len() == k && best.peek().map(|worst| dist >= worst.cost) == Some(true) {
+                // No node left can contain a leaf closer than the k-th best one.
+                break;
+            }
+
+            let (node, depth) = entry.value;
+            let (_, data) = self.content(node);
+            recorder.visit(depth, data.is_some());
+
+            if let Some(data) = data {
+                best.push(WeightedValue::new(data, dist));
+
+                if best.len() > k {
+                    let _ = best.pop();
+                }
+            } else {
+                for i in 0..self.num_children(node) {
+                    let child = self.child(i, node);
+                    let child_dist = self.content(child).0.distance_to_point(&id, point, true);
+
+                    if best.len() < k || best.peek().map(|w| child_dist < w.cost) == Some(true) {
+                        queue.push(WeightedValue::new((child, depth + 1), -child_dist))
+                    }
+                }
+            }
+        }
+
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|entry| (entry.value, entry.cost))
+            .collect()
+    }
 }
 
 /// An enum grouping references to all the BVH implementations on ncollide.
@@ -248,7 +321,7 @@ impl<'a, N: RealField, T, BV> BVHImpl<'a, N, T, BV> {
     }
 }
 
-struct WeightedValue<N, T> {
+pub(crate) struct WeightedValue<N, T> {
     pub value: T,
     pub cost: N,
 }
@@ -1,11 +1,13 @@
 //! A read-only Bounding Volume Tree.
 
-use crate::bounding_volume::BoundingVolume;
+use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::{Point, DIM};
+use crate::partitioning::bvh::WeightedValue;
 use crate::partitioning::BVH;
+use crate::query::Ray;
 use crate::utils;
 use simba::scalar::RealField;
-use std::collections::VecDeque;
+use std::collections::{BinaryHeap, VecDeque};
 use std::iter;
 use std::usize;
 
@@ -136,6 +138,24 @@ impl<T, BV> BVT<T, BV> {
         &self.leaves[i]
     }
 
+    /// Mutable references to the user-data of the leaves of this BVT, in the same order as
+    /// `.leaves()`.
+    ///
+    /// The bounding volumes and the order of the leaves cannot be modified this way, so the tree
+    /// remains valid. Use `.set_leaf_bounding_volume` to modify the bounding volumes instead.
+    #[inline]
+    pub fn leaf_data_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.leaves.iter_mut().map(|leaf| &mut leaf.data)
+    }
+
+    /// Replaces the user-data of the i-th leaf of this BVT, and returns the previous one.
+    ///
+    /// The bounding volumes and the topology of the tree are left unchanged.
+    #[inline]
+    pub fn set_leaf_data(&mut self, i: usize, data: T) -> T {
+        std::mem::replace(&mut self.leaves[i].data, data)
+    }
+
     /// Reference to the bounding volume of the tree root.
     pub fn root_bounding_volume(&self) -> Option<&BV> {
         if self.leaves.is_empty() {
@@ -208,37 +228,41 @@ impl<T, BV> BVT<T, BV> {
         self.deformation_timestamp += 1;
 
         while let Some(curr) = self.parents_to_update.pop_front() {
-            let infos = &mut self.deformation_infos[curr];
-            if infos.timestamp < self.deformation_timestamp {
-                // This node has not been updated yet.
-                infos.timestamp = self.deformation_timestamp;
-
-                let mut new_bv = match (self.internals[curr].left, self.internals[curr].right) {
-                    (BVTNodeId::Internal(i), BVTNodeId::Internal(j)) => self.internals[i]
-                        .bounding_volume
-                        .merged(&self.internals[j].bounding_volume),
-                    (BVTNodeId::Internal(i), BVTNodeId::Leaf(j)) => self.internals[i]
-                        .bounding_volume
-                        .merged(&self.leaves[j].bounding_volume),
-                    (BVTNodeId::Leaf(i), BVTNodeId::Internal(j)) => self.leaves[i]
-                        .bounding_volume
-                        .merged(&self.internals[j].bounding_volume),
-                    (BVTNodeId::Leaf(i), BVTNodeId::Leaf(j)) => self.leaves[i]
-                        .bounding_volume
-                        .merged(&self.leaves[j].bounding_volume),
-                };
+            // A node may be updated several times if its children are updated after it, so its
+            // bounding volume is always recomputed after the ones of its children. The timestamp
+            // only identifies the nodes currently waiting on the queue.
+            self.deformation_infos[curr].timestamp = 0;
+
+            let mut new_bv = match (self.internals[curr].left, self.internals[curr].right) {
+                (BVTNodeId::Internal(i), BVTNodeId::Internal(j)) => self.internals[i]
+                    .bounding_volume
+                    .merged(&self.internals[j].bounding_volume),
+                (BVTNodeId::Internal(i), BVTNodeId::Leaf(j)) => self.internals[i]
+                    .bounding_volume
+                    .merged(&self.leaves[j].bounding_volume),
+                (BVTNodeId::Leaf(i), BVTNodeId::Internal(j)) => self.leaves[i]
+                    .bounding_volume
+                    .merged(&self.internals[j].bounding_volume),
+                (BVTNodeId::Leaf(i), BVTNodeId::Leaf(j)) => self.leaves[i]
+                    .bounding_volume
+                    .merged(&self.leaves[j].bounding_volume),
+            };
+
+            if !self.internals[curr].bounding_volume.contains(&new_bv) {
+                if !margin.is_zero() {
+                    new_bv.loosen(margin)
+                }
 
-                if !self.internals[curr].bounding_volume.contains(&new_bv) {
-                    if !margin.is_zero() {
-                        new_bv.loosen(margin)
-                    }
+                self.internals[curr].bounding_volume = new_bv;
 
-                    self.internals[curr].bounding_volume = new_bv;
+                let parent = self.deformation_infos[curr].parent;
 
-                    if infos.parent != usize::max_value() {
-                        // Push the parent if it is not the root.
-                        self.parents_to_update.push_back(infos.parent);
-                    }
+                if parent != usize::max_value()
+                    && self.deformation_infos[parent].timestamp != self.deformation_timestamp
+                {
+                    // Push the parent if it is not the root, and not already waiting.
+                    self.deformation_infos[parent].timestamp = self.deformation_timestamp;
+                    self.parents_to_update.push_back(parent);
                 }
             }
         }
@@ -400,6 +424,79 @@ impl<T, BV> BVT<T, BV> {
     }
 }
 
+impl<T, N: RealField> BVT<T, AABB<N>> {
+    /// Lazily iterates through the leaves with an AABB intersected by a ray, sorted by increasing
+    /// time of entry of the ray into their AABB.
+    ///
+    /// The iterator yields the index of each leaf, i.e., its position in `self.leaves()`, along
+    /// with the time of entry of the ray into its AABB. This time is zero if the ray origin lies
+    /// inside of the AABB. Leaves entered after `max_toi` are ignored. The subtrees of the AABBs
+    /// missed by the ray are pruned, and tree nodes are only visited as the iteration advances.
+    pub fn leaves_along_ray(&self, ray: &Ray<N>, max_toi: N) -> BVTRayIter<'_, T, N> {
+        let mut iter = BVTRayIter {
+            bvt: self,
+            ray: *ray,
+            max_toi,
+            queue: BinaryHeap::new(),
+        };
+
+        if !self.leaves.is_empty() {
+            iter.push(self.root);
+        }
+
+        iter
+    }
+}
+
+/// Iterator through the leaves of a BVT intersected by a ray, sorted by increasing time of entry.
+///
+/// Created by `BVT::leaves_along_ray`.
+pub struct BVTRayIter<'a, T, N: RealField> {
+    bvt: &'a BVT<T, AABB<N>>,
+    ray: Ray<N>,
+    max_toi: N,
+    queue: BinaryHeap<WeightedValue<N, BVTNodeId>>,
+}
+
+impl<'a, T, N: RealField> BVTRayIter<'a, T, N> {
+    fn push(&mut self, node: BVTNodeId) {
+        let aabb = match node {
+            BVTNodeId::Internal(i) => &self.bvt.internals[i].bounding_volume,
+            BVTNodeId::Leaf(i) => &self.bvt.leaves[i].bounding_volume,
+        };
+
+        if let Some((toi, _)) = aabb.clip_ray_parameters(&self.ray) {
+            if toi <= self.max_toi {
+                // The queue is a max-heap.
+                self.queue.push(WeightedValue::new(node, -toi))
+            }
+        }
+    }
+}
+
+impl<'a, T, N: RealField> Iterator for BVTRayIter<'a, T, N> {
+    type Item = (usize, N);
+
+    fn next(&mut self) -> Option<(usize, N)> {
+        while let Some(entry) = self.queue.pop() {
+            match entry.value {
+                BVTNodeId::Leaf(i) => return Some((i, -entry.cost)),
+                BVTNodeId::Internal(i) => {
+                    let (left, right) = {
+                        let node = &self.bvt.internals[i];
+                        (node.left, node.right)
+                    };
+
+                    self.push(left);
+                    self.push(right);
+                }
+            }
+        }
+
+        None
+    }
+}
+
 impl<'a, T, BV> BVH<T, BV> for BVT<T, BV> {
     type Node = BVTNodeId;
 
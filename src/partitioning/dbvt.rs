@@ -3,6 +3,7 @@ use crate::math::Point;
 use crate::partitioning::BVH;
 use na::{self, RealField};
 use slab::Slab;
+use std::cmp::Ordering;
 use std::ops::Index;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -254,6 +255,102 @@ impl<N: RealField, T, BV: BoundingVolume<N>> DBVT<N, T, BV> {
         }
     }
 
+    /// Inserts several leaves into this DBVT at once.
+    ///
+    /// A balanced subtree is built from the leaves, splitting them recursively at the median of
+    /// their centers along the axis where they are the most spread out. This subtree is then
+    /// attached to the root of this DBVT, so the new leaves do not each have to search for their
+    /// insertion point. The identifiers of the inserted leaves are returned in the same order as
+    /// `leaves`.
+    pub fn insert_batch(
+        &mut self,
+        leaves: impl IntoIterator<Item = DBVTLeaf<N, T, BV>>,
+    ) -> Vec<DBVTLeafId> {
+        let was_empty = self.is_empty();
+        let mut ids: Vec<usize> = leaves
+            .into_iter()
+            .map(|leaf| self.leaves.insert(leaf))
+            .collect();
+        let result = ids.iter().map(|id| DBVTLeafId(*id)).collect();
+
+        if ids.is_empty() {
+            return result;
+        }
+
+        let subtree = self.build_subtree(&mut ids);
+
+        if was_empty {
+            self.set_parent(subtree, DBVTInternalId::Root);
+            self.root = subtree;
+        } else {
+            // Create a common parent which is the new root.
+            let root_bv = self
+                .node_bounding_volume(self.root)
+                .merged(self.node_bounding_volume(subtree));
+            let root = DBVTInternal::new(root_bv, DBVTInternalId::Root, self.root, subtree);
+            let root_id = self.internals.insert(root);
+
+            self.set_parent(self.root, DBVTInternalId::LeftChildOf(root_id));
+            self.set_parent(subtree, DBVTInternalId::RightChildOf(root_id));
+            self.root = DBVTNodeId::Internal(root_id);
+        }
+
+        result
+    }
+
+    // Builds a balanced tree from the given leaves, and returns its root.
+    fn build_subtree(&mut self, ids: &mut [usize]) -> DBVTNodeId {
+        if ids.len() == 1 {
+            return DBVTNodeId::Leaf(ids[0]);
+        }
+
+        let mut mins = self.leaves[ids[0]].center;
+        let mut maxs = mins;
+
+        for id in &ids[1..] {
+            let center = &self.leaves[*id].center;
+            mins = mins.inf(center);
+            maxs = maxs.sup(center);
+        }
+
+        let axis = (maxs - mins).imax();
+        let mid = ids.len() / 2;
+        let leaves = &self.leaves;
+        let _ = ids.select_nth_unstable_by(mid, |a, b| {
+            leaves[*a].center[axis]
+                .partial_cmp(&leaves[*b].center[axis])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let (left_ids, right_ids) = ids.split_at_mut(mid);
+        let left = self.build_subtree(left_ids);
+        let right = self.build_subtree(right_ids);
+        let bv = self
+            .node_bounding_volume(left)
+            .merged(self.node_bounding_volume(right));
+        let id = self
+            .internals
+            .insert(DBVTInternal::new(bv, DBVTInternalId::Root, left, right));
+
+        self.set_parent(left, DBVTInternalId::LeftChildOf(id));
+        self.set_parent(right, DBVTInternalId::RightChildOf(id));
+        DBVTNodeId::Internal(id)
+    }
+
+    fn node_bounding_volume(&self, node: DBVTNodeId) -> &BV {
+        match node {
+            DBVTNodeId::Leaf(i) => &self.leaves[i].bounding_volume,
+            DBVTNodeId::Internal(i) => &self.internals[i].bounding_volume,
+        }
+    }
+
+    fn set_parent(&mut self, node: DBVTNodeId, parent: DBVTInternalId) {
+        match node {
+            DBVTNodeId::Leaf(i) => self.leaves[i].parent = parent,
+            DBVTNodeId::Internal(i) => self.internals[i].parent = parent,
+        }
+    }
+
     /// Removes a leaf from this DBVT.
     ///
     /// Panics if the provided leaf is not attached to this DBVT.
@@ -1,8 +1,12 @@
 //! Spatial partitioning tools.
 
+pub(crate) use self::bvh::WeightedValue;
 pub use self::bvh::{BVHImpl, BVH};
-pub use self::bvt::{BVTNodeId, BinaryPartition, BVT};
+pub use self::bvt::{BVTNodeId, BVTRayIter, BinaryPartition, BVT};
 pub use self::dbvt::{DBVTLeaf, DBVTLeafId, DBVTNodeId, DBVT};
+pub(crate) use self::traversal_stats::TraversalRecorder;
+#[cfg(feature = "diagnostics")]
+pub use self::traversal_stats::{with_traversal_stats, TraversalStats};
 pub use self::visitor::{
     BestFirstVisitStatus, BestFirstVisitor, SimultaneousVisitor, VisitStatus, Visitor,
 };
@@ -10,4 +14,5 @@ pub use self::visitor::{
 mod bvh;
 mod bvt;
 mod dbvt;
+mod traversal_stats;
 mod visitor;
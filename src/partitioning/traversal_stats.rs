@@ -0,0 +1,110 @@
+//! Statistics about the bounding volume hierarchy traversals.
+//!
+//! Those statistics are only collected if the `diagnostics` feature is enabled, since this adds
+//! a thread-local lookup to each traversal.
+
+#[cfg(feature = "diagnostics")]
+use std::cell::Cell;
+
+/// Statistics about the traversals of bounding volume hierarchies.
+#[cfg(feature = "diagnostics")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraversalStats {
+    /// The number of nodes visited, including leaves.
+    pub nodes_visited: usize,
+    /// The number of leaves visited.
+    pub leaves_tested: usize,
+    /// The maximum depth of the visited nodes, the root having a depth of zero.
+    pub max_depth: usize,
+}
+
+#[cfg(feature = "diagnostics")]
+impl TraversalStats {
+    fn merge(&mut self, other: &TraversalStats) {
+        self.nodes_visited += other.nodes_visited;
+        self.leaves_tested += other.leaves_tested;
+        self.max_depth = self.max_depth.max(other.max_depth);
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+thread_local! {
+    static TRAVERSAL_STATS: Cell<Option<TraversalStats>> = Cell::new(None);
+}
+
+// Counts the nodes visited by one traversal, and accounts for them when dropped.
+#[derive(Default)]
+pub(crate) struct TraversalRecorder {
+    #[cfg(feature = "diagnostics")]
+    stats: TraversalStats,
+}
+
+impl TraversalRecorder {
+    #[cfg(feature = "diagnostics")]
+    #[inline]
+    pub fn visit(&mut self, depth: usize, is_leaf: bool) {
+        self.stats.nodes_visited += 1;
+        self.stats.max_depth = self.stats.max_depth.max(depth);
+
+        if is_leaf {
+            self.stats.leaves_tested += 1;
+        }
+    }
+
+    #[cfg(not(feature = "diagnostics"))]
+    #[inline(always)]
+    pub fn visit(&mut self, _depth: usize, _is_leaf: bool) {}
+}
+
+#[cfg(feature = "diagnostics")]
+impl Drop for TraversalRecorder {
+    fn drop(&mut self) {
+        if self.stats.nodes_visited != 0 {
+            record(&self.stats)
+        }
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+fn record(stats: &TraversalStats) {
+    TRAVERSAL_STATS.with(|s| {
+        if let Some(mut collected) = s.get() {
+            collected.merge(stats);
+            s.set(Some(collected));
+        }
+    })
+}
+
+// Restores the statistics being collected before a call to `with_traversal_stats`, even if the
+// closure panics.
+#[cfg(feature = "diagnostics")]
+struct TraversalStatsGuard {
+    prev: Option<TraversalStats>,
+}
+
+#[cfg(feature = "diagnostics")]
+impl Drop for TraversalStatsGuard {
+    fn drop(&mut self) {
+        TRAVERSAL_STATS.with(|s| s.set(self.prev))
+    }
+}
+
+/// Calls `f` and collects statistics about the bounding volume hierarchy traversals it runs on
+/// this thread.
+///
+/// Only the traversals performed by `BVH::visit` and `BVH::best_first_search` are accounted for.
+///
+/// Calls to `with_traversal_stats` can be nested, in which case the statistics collected by the
+/// inner call are also accounted for by the outer one.
+#[cfg(feature = "diagnostics")]
+pub fn with_traversal_stats<R>(f: impl FnOnce() -> R) -> (R, TraversalStats) {
+    let prev = TRAVERSAL_STATS.with(|s| s.replace(Some(TraversalStats::default())));
+    let guard = TraversalStatsGuard { prev };
+    let res = f();
+    let stats = TRAVERSAL_STATS.with(|s| s.take()).unwrap_or_default();
+
+    drop(guard);
+    record(&stats);
+
+    (res, stats)
+}
@@ -1,8 +1,8 @@
-use na::RealField;
+use na::{RealField, Unit};
 use std::any::Any;
 
-use crate::math::Point;
-use crate::query::{Ray, RayIntersection};
+use crate::math::{Point, Vector};
+use crate::query::{PointProjection, Ray, RayIntersection};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BroadPhaseProxyHandle(pub usize);
@@ -38,6 +38,10 @@ pub trait BroadPhaseInterferenceHandler<T> {
 }
 
 /// Trait all broad phase must implement.
+///
+/// **Custom broad phases should override `nearest_interference_with_point`:** its default
+/// implementation always returns `None`, which makes `CollisionWorld::nearest_surface` never find
+/// anything.
 pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
     /// Tells the broad phase to add a bounding-volume at the next update.
     fn create_proxy(&mut self, bv: BV, data: T) -> BroadPhaseProxyHandle;
@@ -83,4 +87,29 @@ pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
         max_toi: N,
         cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
     ) -> Option<(T, RayIntersection<N>)>;
+
+    /// Returns the object with the boundary closest to a point, among those closer than
+    /// `max_dist`.
+    ///
+    /// The objects are visited by increasing distance between `point` and their bounding volume.
+    /// `cost_fn` projects the point on the given object and returns the projection with the
+    /// outward normal at the projected point, or `None` if the object has to be ignored.
+    ///
+    /// # Default implementation
+    ///
+    /// **The default implementation does not visit any object and always returns `None`.** It
+    /// only exists so that adding this method does not break the existing broad phases. Broad
+    /// phases used with `CollisionWorld::nearest_surface` have to override it.
+    fn nearest_interference_with_point<'a, 'b>(
+        &'a self,
+        _point: &'b Point<N>,
+        _max_dist: N,
+        _cost_fn: &'a dyn Fn(
+            T,
+            &'b Point<N>,
+            N,
+        ) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)>,
+    ) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)> {
+        None
+    }
 }
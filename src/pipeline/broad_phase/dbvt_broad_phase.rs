@@ -1,16 +1,16 @@
 use crate::bounding_volume::BoundingVolume;
-use crate::math::Point;
-use crate::partitioning::{DBVTLeaf, DBVTLeafId, BVH, DBVT};
+use crate::math::{Point, Vector};
+use crate::partitioning::{DBVTLeaf, DBVTLeafId, VisitStatus, Visitor, BVH, DBVT};
 use crate::pipeline::broad_phase::{
     BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
 };
 use crate::query::visitors::{
-    BoundingVolumeInterferencesCollector, PointInterferencesCollector, RayInterferencesCollector,
-    RayIntersectionCostFnVisitor,
+    BoundingVolumeInterferencesCollector, PointInterferencesCollector,
+    PointProjectionCostFnVisitor, RayInterferencesCollector, RayIntersectionCostFnVisitor,
 };
-use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::query::{PointProjection, PointQuery, Ray, RayCast, RayIntersection};
 use crate::utils::{DeterministicState, SortedPair};
-use na::RealField;
+use na::{RealField, Unit};
 use slab::Slab;
 use std::any::Any;
 use std::collections::hash_map::Entry;
@@ -27,6 +27,7 @@ enum ProxyStatus {
 
 struct DBVTBroadPhaseProxy<T> {
     data: T,
+    tag: u32,
     status: ProxyStatus,
     updated: bool,
 }
@@ -35,6 +36,7 @@ impl<T> DBVTBroadPhaseProxy<T> {
     fn new(data: T) -> DBVTBroadPhaseProxy<T> {
         DBVTBroadPhaseProxy {
             data,
+            tag: 0,
             status: ProxyStatus::Detached(None),
             updated: true,
         }
@@ -50,10 +52,33 @@ impl<T> DBVTBroadPhaseProxy<T> {
 
 const DEACTIVATION_THRESHOLD: usize = 100;
 
+// Forwards to `visitor` only the leaves of the proxies with a tag accepted by `filter`.
+struct TagFilterVisitor<'a, T, V> {
+    proxies: &'a Slab<DBVTBroadPhaseProxy<T>>,
+    filter: &'a mut dyn FnMut(u32) -> bool,
+    visitor: V,
+}
+
+impl<'a, T, BV, V> Visitor<BroadPhaseProxyHandle, BV> for TagFilterVisitor<'a, T, V>
+where
+    V: Visitor<BroadPhaseProxyHandle, BV>,
+{
+    #[inline]
+    fn visit(&mut self, bv: &BV, data: Option<&BroadPhaseProxyHandle>) -> VisitStatus {
+        match data {
+            Some(handle) if !(self.filter)(self.proxies[handle.uid()].tag) => VisitStatus::Continue,
+            _ => self.visitor.visit(bv, data),
+        }
+    }
+}
+
 /// Broad phase based on a Dynamic Bounding Volume Tree.
 ///
 /// It uses two separate trees: one for static objects and which is never updated, and one for
 /// moving objects.
+///
+/// The results of the `interferences_with_*` queries are ordered by increasing proxy handle, so
+/// identical scenes produce identical results regardless of how the trees have been rebalanced.
 pub struct DBVTBroadPhase<N: RealField, BV, T> {
     proxies: Slab<DBVTBroadPhaseProxy<T>>,
     // DBVT for moving objects.
@@ -65,11 +90,15 @@ pub struct DBVTBroadPhase<N: RealField, BV, T> {
     // The margin added to each bounding volume.
     margin: N,
     purge_all: bool,
+    #[cfg(feature = "parallel")]
+    parallel_update: bool,
 
     // Just to avoid dynamic allocations.
     collector: Vec<BroadPhaseProxyHandle>,
     leaves_to_update: Vec<DBVTLeaf<N, BroadPhaseProxyHandle, BV>>,
     proxies_to_update: VecDeque<(BroadPhaseProxyHandle, BV)>,
+    // Proxies inserted by batch, the interferences of which are collected on the next update.
+    proxies_to_collide: Vec<BroadPhaseProxyHandle>,
 }
 
 impl<N, BV, T> DBVTBroadPhase<N, BV, T>
@@ -85,9 +114,12 @@ where
             stree: DBVT::new(),
             pairs: HashMap::with_hasher(DeterministicState::new()),
             purge_all: false,
+            #[cfg(feature = "parallel")]
+            parallel_update: false,
             collector: Vec::new(),
             leaves_to_update: Vec::new(),
             proxies_to_update: VecDeque::new(),
+            proxies_to_collide: Vec::new(),
             margin,
         }
     }
@@ -98,6 +130,78 @@ where
         self.pairs.len()
     }
 
+    /// Enables or disables the parallel update of this broad phase.
+    ///
+    /// When enabled, the bounding volume tree traversals needed to find the new interferences of
+    /// each modified proxy are performed on the rayon thread pool. The detected pairs are identical
+    /// to the ones detected by the serial update, and the interference handler is still called
+    /// from the thread calling `.update()`. Disabled by default.
+    #[cfg(feature = "parallel")]
+    pub fn set_parallel_update(&mut self, enabled: bool) {
+        self.parallel_update = enabled
+    }
+
+    /// Whether the parallel update of this broad phase is enabled.
+    #[cfg(feature = "parallel")]
+    pub fn parallel_update(&self) -> bool {
+        self.parallel_update
+    }
+
+    /// Sets the user tag of the given proxy.
+    ///
+    /// Tags can be used to cheaply filter the results of the `*_with_filter` interference queries
+    /// without accessing the data of the proxies. The tag of a newly created proxy is zero.
+    pub fn set_proxy_tag(&mut self, handle: BroadPhaseProxyHandle, tag: u32) {
+        self.proxies
+            .get_mut(handle.uid())
+            .expect("Attempting to set the tag of an object that does not exist.")
+            .tag = tag
+    }
+
+    /// The user tag of the given proxy, if it exists.
+    pub fn proxy_tag(&self, handle: BroadPhaseProxyHandle) -> Option<u32> {
+        self.proxies.get(handle.uid()).map(|proxy| proxy.tag)
+    }
+
+    /// Creates several proxies at once.
+    ///
+    /// This is equivalent to calling `create_proxy` on each `(bounding volume, data)` pair,
+    /// except that instead of being inserted one by one into the dynamic tree during the next
+    /// update, these proxies are inserted right away as a single balanced subtree. Their interferences are still detected by the next call to
+    /// `update`. The handles of the new proxies are returned in the same order as `proxies`.
+    pub fn create_proxies_batch(
+        &mut self,
+        proxies: impl IntoIterator<Item = (BV, T)>,
+    ) -> Vec<BroadPhaseProxyHandle> {
+        let mut leaves = Vec::new();
+
+        for (bv, data) in proxies {
+            let handle = BroadPhaseProxyHandle(self.proxies.insert(DBVTBroadPhaseProxy::new(data)));
+            leaves.push(DBVTLeaf::new(bv, handle));
+        }
+
+        let handles: Vec<_> = leaves.iter().map(|leaf| leaf.data).collect();
+        let leaf_ids = self.tree.insert_batch(leaves);
+
+        for (handle, leaf) in handles.iter().zip(leaf_ids.into_iter()) {
+            self.proxies[handle.uid()].status =
+                ProxyStatus::OnDynamicTree(leaf, DEACTIVATION_THRESHOLD);
+        }
+
+        self.proxies_to_collide.extend_from_slice(&handles);
+        handles
+    }
+
+    fn push_sorted<'a>(&'a self, mut collector: Vec<BroadPhaseProxyHandle>, out: &mut Vec<&'a T>) {
+        // The traversal order depends on the shape of the trees, so sort the results to make
+        // them independent from the history of the broad phase.
+        collector.sort_unstable();
+
+        for l in collector.into_iter() {
+            out.push(&self.proxies[l.uid()].data)
+        }
+    }
+
     fn purge_some_contact_pairs(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
         let purge_all = self.purge_all;
         let proxies = &self.proxies;
@@ -165,56 +269,63 @@ where
     }
 }
 
-impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
+impl<N, BV, T> DBVTBroadPhase<N, BV, T>
 where
     N: RealField,
-    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    BV: BoundingVolume<N> + Any + Send + Sync + Clone,
     T: Any + Send + Sync + Clone,
 {
-    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+    fn collect_batch_interferences(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
         /*
-         * Remove from the trees all nodes that have been deleted or modified.
+         * Collect the interferences of the proxies inserted by batch, which are already on the
+         * dynamic tree.
          */
-        for (handle, bv) in self.proxies_to_update.drain(..) {
-            if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
-                let mut set_status = true;
-                match proxy.status {
-                    ProxyStatus::OnStaticTree(leaf) => {
-                        let mut leaf = self.stree.remove(leaf);
-                        leaf.bounding_volume = bv;
-                        self.leaves_to_update.push(leaf);
-                    }
-                    ProxyStatus::OnDynamicTree(leaf, _) => {
-                        let mut leaf = self.tree.remove(leaf);
-                        leaf.bounding_volume = bv;
-                        self.leaves_to_update.push(leaf);
-                    }
-                    ProxyStatus::Detached(None) => {
-                        let leaf = DBVTLeaf::new(bv, handle);
-                        self.leaves_to_update.push(leaf);
-                    }
-                    ProxyStatus::Detached(Some(id)) => {
-                        let leaf = DBVTLeaf::new(bv, handle);
-                        self.leaves_to_update[id] = leaf;
-                        set_status = false;
-                    }
-                    ProxyStatus::Deleted => {
-                        panic!("DBVT broad phase internal error: the proxy was deleted.")
-                    }
+        for handle in self.proxies_to_collide.drain(..) {
+            let proxy1 = match self.proxies.get(handle.uid()) {
+                Some(proxy) => proxy,
+                None => continue,
+            };
+            let bv = match proxy1.status {
+                ProxyStatus::OnDynamicTree(leaf, _) => &self.tree[leaf].bounding_volume,
+                // Removed, or modified and already handled by `reinsert_leaves`.
+                _ => continue,
+            };
+
+            {
+                let mut visitor =
+                    BoundingVolumeInterferencesCollector::new(bv, &mut self.collector);
+
+                self.tree.visit(&mut visitor);
+                self.stree.visit(&mut visitor);
+            }
+
+            // Event generation.
+            for proxy_key2 in self.collector.iter() {
+                if *proxy_key2 == handle {
+                    continue;
                 }
 
-                proxy.updated = true;
+                let proxy2 = &self.proxies[proxy_key2.uid()];
 
-                if set_status {
-                    proxy.status = ProxyStatus::Detached(Some(self.leaves_to_update.len() - 1));
+                if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
+                    match self.pairs.entry(SortedPair::new(handle, *proxy_key2)) {
+                        Entry::Occupied(entry) => *entry.into_mut() = true,
+                        Entry::Vacant(entry) => {
+                            handler.interference_started(&proxy1.data, &proxy2.data);
+                            let _ = entry.insert(true);
+                        }
+                    }
                 }
             }
+
+            self.collector.clear();
         }
+    }
 
+    fn reinsert_leaves(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
         /*
          * Re-insert outdated nodes one by one and collect interferences at the same time.
          */
-        let some_leaves_updated = self.leaves_to_update.len() != 0;
         for leaf in self.leaves_to_update.drain(..) {
             {
                 let proxy1 = &self.proxies[leaf.data.uid()];
@@ -251,6 +362,225 @@ where
             let leaf = self.tree.insert(leaf);
             proxy1.status = ProxyStatus::OnDynamicTree(leaf, DEACTIVATION_THRESHOLD);
         }
+    }
+
+    /// Same as `reinsert_leaves` but traverses the trees in parallel.
+    ///
+    /// All the leaves are re-inserted first. Then each leaf keeps only the interferences the
+    /// serial version would have found while re-inserting it: those with proxies that were not
+    /// modified, and those with modified proxies re-inserted before it.
+    #[cfg(feature = "parallel")]
+    fn reinsert_leaves_parallel(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        use rayon::prelude::*;
+
+        let mut ranks = HashMap::with_capacity_and_hasher(
+            self.leaves_to_update.len(),
+            DeterministicState::new(),
+        );
+        let mut bvs = Vec::with_capacity(self.leaves_to_update.len());
+
+        for (rank, leaf) in self.leaves_to_update.drain(..).enumerate() {
+            let _ = ranks.insert(leaf.data, rank);
+            bvs.push((leaf.data, leaf.bounding_volume.clone()));
+
+            let proxy = &mut self.proxies[leaf.data.uid()];
+            assert!(proxy.is_detached());
+            let leaf = self.tree.insert(leaf);
+            proxy.status = ProxyStatus::OnDynamicTree(leaf, DEACTIVATION_THRESHOLD);
+        }
+
+        let tree = &self.tree;
+        let stree = &self.stree;
+        let interferences: Vec<Vec<BroadPhaseProxyHandle>> = bvs
+            .par_iter()
+            .enumerate()
+            .map(|(rank, (_, bv))| {
+                let mut collector = Vec::new();
+
+                {
+                    let mut visitor = BoundingVolumeInterferencesCollector::new(bv, &mut collector);
+                    tree.visit(&mut visitor);
+                    stree.visit(&mut visitor);
+                }
+
+                collector.retain(|handle| match ranks.get(handle) {
+                    Some(other_rank) => *other_rank < rank,
+                    None => true,
+                });
+                collector
+            })
+            .collect();
+
+        // Event generation.
+        for ((proxy_key1, _), collector) in bvs.iter().zip(interferences.iter()) {
+            let proxy1 = &self.proxies[proxy_key1.uid()];
+
+            for proxy_key2 in collector {
+                let proxy2 = &self.proxies[proxy_key2.uid()];
+
+                if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
+                    match self.pairs.entry(SortedPair::new(*proxy_key1, *proxy_key2)) {
+                        Entry::Occupied(entry) => *entry.into_mut() = true,
+                        Entry::Vacant(entry) => {
+                            handler.interference_started(&proxy1.data, &proxy2.data);
+                            let _ = entry.insert(true);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<N, BV, T> DBVTBroadPhase<N, BV, T>
+where
+    N: RealField,
+    BV: 'static + BoundingVolume<N> + RayCast<N> + PointQuery<N> + Clone,
+{
+    /// Collects every object which might intersect a given bounding volume and with a tag
+    /// accepted by `filter`.
+    ///
+    /// The filter is called during the traversal of the trees on the tag of each proxy reached,
+    /// and the proxies it rejects are skipped before their bounding volume is tested.
+    pub fn interferences_with_bounding_volume_with_filter<'a>(
+        &'a self,
+        bv: &BV,
+        filter: &mut dyn FnMut(u32) -> bool,
+        out: &mut Vec<&'a T>,
+    ) {
+        let mut collector = Vec::new();
+
+        {
+            let mut visitor = TagFilterVisitor {
+                proxies: &self.proxies,
+                filter,
+                visitor: BoundingVolumeInterferencesCollector::new(bv, &mut collector),
+            };
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.push_sorted(collector, out)
+    }
+
+    /// Collects every object which might intersect a given ray and with a tag accepted by
+    /// `filter`.
+    ///
+    /// The filter is called during the traversal of the trees on the tag of each proxy reached,
+    /// and the proxies it rejects are skipped before their bounding volume is tested.
+    pub fn interferences_with_ray_with_filter<'a>(
+        &'a self,
+        ray: &Ray<N>,
+        max_toi: N,
+        filter: &mut dyn FnMut(u32) -> bool,
+        out: &mut Vec<&'a T>,
+    ) {
+        let mut collector = Vec::new();
+
+        {
+            let mut visitor = TagFilterVisitor {
+                proxies: &self.proxies,
+                filter,
+                visitor: RayInterferencesCollector::new(ray, max_toi, &mut collector),
+            };
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.push_sorted(collector, out)
+    }
+
+    /// Collects every object which might contain a given point and with a tag accepted by
+    /// `filter`.
+    ///
+    /// The filter is called during the traversal of the trees on the tag of each proxy reached,
+    /// and the proxies it rejects are skipped before their bounding volume is tested.
+    pub fn interferences_with_point_with_filter<'a>(
+        &'a self,
+        point: &Point<N>,
+        filter: &mut dyn FnMut(u32) -> bool,
+        out: &mut Vec<&'a T>,
+    ) {
+        let mut collector = Vec::new();
+
+        {
+            let mut visitor = TagFilterVisitor {
+                proxies: &self.proxies,
+                filter,
+                visitor: PointInterferencesCollector::new(point, &mut collector),
+            };
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.push_sorted(collector, out)
+    }
+}
+
+impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
+where
+    N: RealField,
+    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync + Clone,
+{
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        /*
+         * Remove from the trees all nodes that have been deleted or modified.
+         */
+        for (handle, bv) in self.proxies_to_update.drain(..) {
+            if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
+                let mut set_status = true;
+                match proxy.status {
+                    ProxyStatus::OnStaticTree(leaf) => {
+                        let mut leaf = self.stree.remove(leaf);
+                        leaf.bounding_volume = bv;
+                        self.leaves_to_update.push(leaf);
+                    }
+                    ProxyStatus::OnDynamicTree(leaf, _) => {
+                        let mut leaf = self.tree.remove(leaf);
+                        leaf.bounding_volume = bv;
+                        self.leaves_to_update.push(leaf);
+                    }
+                    ProxyStatus::Detached(None) => {
+                        let leaf = DBVTLeaf::new(bv, handle);
+                        self.leaves_to_update.push(leaf);
+                    }
+                    ProxyStatus::Detached(Some(id)) => {
+                        let leaf = DBVTLeaf::new(bv, handle);
+                        self.leaves_to_update[id] = leaf;
+                        set_status = false;
+                    }
+                    ProxyStatus::Deleted => {
+                        panic!("DBVT broad phase internal error: the proxy was deleted.")
+                    }
+                }
+
+                proxy.updated = true;
+
+                if set_status {
+                    proxy.status = ProxyStatus::Detached(Some(self.leaves_to_update.len() - 1));
+                }
+            }
+        }
+
+        let some_leaves_updated =
+            !self.leaves_to_update.is_empty() || !self.proxies_to_collide.is_empty();
+
+        #[cfg(feature = "parallel")]
+        {
+            if self.parallel_update {
+                self.reinsert_leaves_parallel(handler);
+            } else {
+                self.reinsert_leaves(handler);
+            }
+        }
+        #[cfg(not(feature = "parallel"))]
+        self.reinsert_leaves(handler);
+
+        self.collect_batch_interferences(handler);
 
         if some_leaves_updated {
             self.purge_some_contact_pairs(handler);
@@ -394,9 +724,7 @@ where
             self.stree.visit(&mut visitor);
         }
 
-        for l in collector.into_iter() {
-            out.push(&self.proxies[l.uid()].data)
-        }
+        self.push_sorted(collector, out)
     }
 
     fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>) {
@@ -409,9 +737,7 @@ where
             self.stree.visit(&mut visitor);
         }
 
-        for l in collector.into_iter() {
-            out.push(&self.proxies[l.uid()].data)
-        }
+        self.push_sorted(collector, out)
     }
 
     fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>) {
@@ -424,9 +750,7 @@ where
             self.stree.visit(&mut visitor);
         }
 
-        for l in collector.into_iter() {
-            out.push(&self.proxies[l.uid()].data)
-        }
+        self.push_sorted(collector, out)
     }
 
     /// Returns the first object that interferes with a ray.
@@ -458,4 +782,24 @@ where
             None
         }
     }
+
+    fn nearest_interference_with_point<'a, 'b>(
+        &'a self,
+        point: &'b Point<N>,
+        max_dist: N,
+        cost_fn: &'a dyn Fn(T, &'b Point<N>, N) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)>,
+    ) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)> {
+        let mut visitor = PointProjectionCostFnVisitor::new(point, max_dist, self, cost_fn);
+        let dynamic_hit = self.tree.best_first_search(&mut visitor).map(|res| res.1);
+
+        // Only the static objects closer than the best dynamic object need to be visited.
+        let max_dist = dynamic_hit
+            .as_ref()
+            .map(|hit| na::distance(point, &hit.1.point))
+            .unwrap_or(max_dist);
+        let mut visitor = PointProjectionCostFnVisitor::new(point, max_dist, self, cost_fn);
+        let static_hit = self.stree.best_first_search(&mut visitor).map(|res| res.1);
+
+        static_hit.or(dynamic_hit)
+    }
 }
@@ -0,0 +1,529 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::pipeline::broad_phase::{
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
+};
+use crate::query::{PointProjection, PointQuery, Ray, RayCast, RayIntersection};
+use crate::utils::{DeterministicState, SortedPair};
+use na::{RealField, Unit};
+use slab::Slab;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+struct SAPBroadPhaseProxy<N: RealField, T> {
+    data: T,
+    aabb: AABB<N>,
+    // Whether the endpoints of this proxy are on the sorted lists.
+    inserted: bool,
+    updated: bool,
+}
+
+// The lower or upper bound of the AABB of a proxy along one axis.
+#[derive(Copy, Clone)]
+struct Endpoint<N> {
+    value: N,
+    is_min: bool,
+    proxy: BroadPhaseProxyHandle,
+}
+
+impl<N: RealField> Endpoint<N> {
+    // Whether `self` is strictly before `other` on a sorted list.
+    //
+    // Lower bounds are sorted before upper bounds with the same value so that touching AABBs
+    // are considered intersecting, as with `AABB::intersects`.
+    #[inline]
+    fn is_before(&self, other: &Self) -> bool {
+        self.value < other.value || (self.value == other.value && self.is_min && !other.is_min)
+    }
+}
+
+/// Broad phase based on the Sweep and Prune algorithm.
+///
+/// The bounds of the AABBs of all the proxies are kept sorted along each axis. Because objects
+/// usually move only a little between two updates, the lists of bounds are re-sorted by
+/// insertion, and the pairs of proxies with bounds swapped during this sort are the only ones that
+/// may have started or stopped interfering. Thus, this broad phase outperforms the
+/// `DBVTBroadPhase` for scenes with many similarly-sized objects moving coherently, but may be
+/// slower when large objects move quickly.
+///
+/// The same margins as the `DBVTBroadPhase` are used, so both detect the same interferences. The
+/// results of the `interferences_with_*` queries are ordered by increasing proxy handle.
+pub struct SAPBroadPhase<N: RealField, T> {
+    proxies: Slab<SAPBroadPhaseProxy<N, T>>,
+    // The bounds of all the inserted proxies, sorted along each axis.
+    endpoints: Vec<Vec<Endpoint<N>>>,
+    // Pairs detected.
+    pairs: HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+    // The margin added to each bounding volume.
+    margin: N,
+    purge_all: bool,
+
+    proxies_to_update: Vec<(BroadPhaseProxyHandle, AABB<N>)>,
+    proxies_to_recompute: Vec<BroadPhaseProxyHandle>,
+    // Just to avoid dynamic allocations.
+    updated: Vec<BroadPhaseProxyHandle>,
+    inserted: Vec<BroadPhaseProxyHandle>,
+    pairs_to_check: Vec<SortedPair<BroadPhaseProxyHandle>>,
+}
+
+impl<N: RealField, T> SAPBroadPhase<N, T> {
+    /// Creates a new broad phase based on the Sweep and Prune algorithm.
+    pub fn new(margin: N) -> SAPBroadPhase<N, T> {
+        SAPBroadPhase {
+            proxies: Slab::new(),
+            endpoints: (0..DIM).map(|_| Vec::new()).collect(),
+            pairs: HashSet::with_hasher(DeterministicState::new()),
+            margin,
+            purge_all: false,
+            proxies_to_update: Vec::new(),
+            proxies_to_recompute: Vec::new(),
+            updated: Vec::new(),
+            inserted: Vec::new(),
+            pairs_to_check: Vec::new(),
+        }
+    }
+
+    /// Number of interferences detected by this broad phase.
+    #[inline]
+    pub fn num_interferences(&self) -> usize {
+        self.pairs.len()
+    }
+
+    // Calls `f` on each pair of inserted proxies with intersecting AABBs.
+    fn sweep(&self, mut f: impl FnMut(BroadPhaseProxyHandle, BroadPhaseProxyHandle)) {
+        let mut active: Vec<BroadPhaseProxyHandle> = Vec::new();
+
+        for endpoint in &self.endpoints[0] {
+            if endpoint.is_min {
+                let aabb = &self.proxies[endpoint.proxy.uid()].aabb;
+
+                for other in &active {
+                    if self.proxies[other.uid()].aabb.intersects(aabb) {
+                        f(*other, endpoint.proxy)
+                    }
+                }
+
+                active.push(endpoint.proxy);
+            } else if let Some(i) = active.iter().position(|h| *h == endpoint.proxy) {
+                let _ = active.swap_remove(i);
+            }
+        }
+    }
+
+    // The inserted proxies with a lower bound along the first axis smaller than `max`.
+    fn proxies_starting_before(&self, max: N) -> impl Iterator<Item = BroadPhaseProxyHandle> + '_ {
+        self.endpoints[0]
+            .iter()
+            .take_while(move |endpoint| endpoint.value <= max)
+            .filter(|endpoint| endpoint.is_min)
+            .map(|endpoint| endpoint.proxy)
+    }
+
+    fn push_sorted<'a>(&'a self, mut collector: Vec<BroadPhaseProxyHandle>, out: &mut Vec<&'a T>) {
+        collector.sort_unstable();
+
+        for handle in collector {
+            out.push(&self.proxies[handle.uid()].data)
+        }
+    }
+}
+
+// Starts or stops the interference between two proxies, depending on their current AABBs.
+fn check_pair<N: RealField, T>(
+    proxies: &Slab<SAPBroadPhaseProxy<N, T>>,
+    pairs: &mut HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+    pair: SortedPair<BroadPhaseProxyHandle>,
+    handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+) {
+    let proxy1 = &proxies[pair.0.uid()];
+    let proxy2 = &proxies[pair.1.uid()];
+
+    if proxy1.aabb.intersects(&proxy2.aabb)
+        && handler.is_interference_allowed(&proxy1.data, &proxy2.data)
+    {
+        if pairs.insert(pair) {
+            handler.interference_started(&proxy1.data, &proxy2.data);
+        }
+    } else if pairs.remove(&pair) {
+        handler.interference_stopped(&proxy1.data, &proxy2.data);
+    }
+}
+
+// Sorts `endpoints` by insertion, collecting the pairs of proxies with a lower bound and an
+// upper bound swapped.
+fn insertion_sort<N: RealField>(
+    endpoints: &mut [Endpoint<N>],
+    swapped: &mut Vec<SortedPair<BroadPhaseProxyHandle>>,
+) {
+    for i in 1..endpoints.len() {
+        let mut j = i;
+
+        while j > 0 && endpoints[j].is_before(&endpoints[j - 1]) {
+            let (moved, other) = (endpoints[j], endpoints[j - 1]);
+
+            if moved.is_min != other.is_min {
+                swapped.push(SortedPair::new(moved.proxy, other.proxy));
+            }
+
+            endpoints.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+// Inserts `endpoint` at its place on the sorted `endpoints` by moving it down from their end.
+//
+// If `overlaps` is given, it is updated with the pairs of proxies with intervals starting or
+// stopping to overlap because of this insertion: an interval overlaps those with an upper bound
+// crossed by its lower bound, except those with a lower bound also crossed by its upper bound.
+fn insert_sorted<N: RealField>(
+    endpoints: &mut Vec<Endpoint<N>>,
+    endpoint: Endpoint<N>,
+    mut overlaps: Option<&mut HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>>,
+) {
+    endpoints.push(endpoint);
+    let mut j = endpoints.len() - 1;
+
+    while j > 0 && endpoint.is_before(&endpoints[j - 1]) {
+        let other = endpoints[j - 1];
+
+        if let Some(overlaps) = overlaps.as_deref_mut() {
+            let pair = SortedPair::new(endpoint.proxy, other.proxy);
+
+            if endpoint.is_min && !other.is_min {
+                let _ = overlaps.insert(pair);
+            } else if !endpoint.is_min && other.is_min {
+                let _ = overlaps.remove(&pair);
+            }
+        }
+
+        endpoints.swap(j, j - 1);
+        j -= 1;
+    }
+}
+
+impl<N, T> BroadPhase<N, AABB<N>, T> for SAPBroadPhase<N, T>
+where
+    N: RealField,
+    T: Any + Send + Sync + Clone,
+{
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        for (handle, aabb) in self.proxies_to_update.drain(..) {
+            if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
+                proxy.aabb = aabb;
+
+                if !proxy.updated {
+                    proxy.updated = true;
+                    self.updated.push(handle);
+                }
+
+                if !proxy.inserted {
+                    proxy.inserted = true;
+                    self.inserted.push(handle);
+                }
+            }
+        }
+
+        if !self.updated.is_empty() {
+            /*
+             * Re-sort the bounds of the proxies already inserted, and check the pairs of
+             * proxies with swapped bounds.
+             */
+            for (axis, endpoints) in self.endpoints.iter_mut().enumerate() {
+                for endpoint in endpoints.iter_mut() {
+                    let aabb = &self.proxies[endpoint.proxy.uid()].aabb;
+                    endpoint.value = if endpoint.is_min {
+                        aabb.mins[axis]
+                    } else {
+                        aabb.maxs[axis]
+                    };
+                }
+
+                insertion_sort(endpoints, &mut self.pairs_to_check);
+            }
+
+            self.pairs_to_check.sort_unstable();
+            self.pairs_to_check.dedup();
+
+            for pair in self.pairs_to_check.drain(..) {
+                check_pair(&self.proxies, &mut self.pairs, pair, handler);
+            }
+
+            /*
+             * Insert the bounds of the new proxies, and find their interferences.
+             */
+            if !self.inserted.is_empty() {
+                let mut overlaps = HashSet::with_hasher(DeterministicState::new());
+
+                for (axis, endpoints) in self.endpoints.iter_mut().enumerate() {
+                    // The overlaps along the first axis are enough to find all the candidate pairs.
+                    let mut overlaps = if axis == 0 { Some(&mut overlaps) } else { None };
+
+                    for handle in &self.inserted {
+                        let aabb = &self.proxies[handle.uid()].aabb;
+                        let min = Endpoint {
+                            value: aabb.mins[axis],
+                            is_min: true,
+                            proxy: *handle,
+                        };
+                        let max = Endpoint {
+                            value: aabb.maxs[axis],
+                            is_min: false,
+                            proxy: *handle,
+                        };
+
+                        insert_sorted(endpoints, min, overlaps.as_deref_mut());
+                        insert_sorted(endpoints, max, overlaps.as_deref_mut());
+                    }
+                }
+
+                self.inserted.clear();
+                let mut new_pairs: Vec<_> = overlaps.into_iter().collect();
+                new_pairs.sort_unstable();
+
+                for pair in new_pairs {
+                    check_pair(&self.proxies, &mut self.pairs, pair, handler);
+                }
+            }
+
+            /*
+             * Remove the pairs of modified proxies which are no longer allowed.
+             */
+            let proxies = &self.proxies;
+            self.pairs_to_check.extend(
+                self.pairs
+                    .iter()
+                    .filter(|pair| proxies[pair.0.uid()].updated || proxies[pair.1.uid()].updated)
+                    .cloned(),
+            );
+
+            for pair in self.pairs_to_check.drain(..) {
+                check_pair(&self.proxies, &mut self.pairs, pair, handler);
+            }
+
+            for handle in self.updated.drain(..) {
+                self.proxies[handle.uid()].updated = false;
+            }
+        }
+
+        /*
+         * Re-check all the pairs involving the proxies for which it was requested.
+         */
+        if self.purge_all {
+            self.purge_all = false;
+            self.proxies_to_recompute.clear();
+
+            let mut all_pairs = Vec::new();
+            self.sweep(|h1, h2| all_pairs.push(SortedPair::new(h1, h2)));
+            all_pairs.extend(self.pairs.iter().cloned());
+            all_pairs.sort_unstable();
+            all_pairs.dedup();
+
+            for pair in all_pairs {
+                check_pair(&self.proxies, &mut self.pairs, pair, handler);
+            }
+        }
+
+        let mut pairs_to_check = Vec::new();
+
+        for handle in self.proxies_to_recompute.iter().cloned() {
+            let aabb = match self.proxies.get(handle.uid()) {
+                Some(proxy) if proxy.inserted => &proxy.aabb,
+                _ => continue,
+            };
+
+            for other in self.proxies_starting_before(aabb.maxs[0]) {
+                if other != handle && self.proxies[other.uid()].aabb.intersects(aabb) {
+                    pairs_to_check.push(SortedPair::new(handle, other));
+                }
+            }
+
+            pairs_to_check.extend(
+                self.pairs
+                    .iter()
+                    .filter(|pair| pair.0 == handle || pair.1 == handle)
+                    .cloned(),
+            );
+        }
+
+        self.proxies_to_recompute.clear();
+        pairs_to_check.sort_unstable();
+        pairs_to_check.dedup();
+
+        for pair in pairs_to_check {
+            check_pair(&self.proxies, &mut self.pairs, pair, handler);
+        }
+    }
+
+    fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&AABB<N>, &T)> {
+        let proxy = self.proxies.get(handle.uid())?;
+
+        if proxy.inserted {
+            Some((&proxy.aabb, &proxy.data))
+        } else {
+            None
+        }
+    }
+
+    fn create_proxy(&mut self, bv: AABB<N>, data: T) -> BroadPhaseProxyHandle {
+        let proxy = SAPBroadPhaseProxy {
+            data,
+            aabb: bv,
+            inserted: false,
+            updated: false,
+        };
+        let handle = BroadPhaseProxyHandle(self.proxies.insert(proxy));
+        self.proxies_to_update.push((handle, bv));
+        handle
+    }
+
+    fn remove(&mut self, handles: &[BroadPhaseProxyHandle], handler: &mut dyn FnMut(&T, &T)) {
+        for handle in handles {
+            if !self.proxies.contains(handle.uid()) {
+                panic!("Attempting to remove an object that does not exist.");
+            }
+        }
+
+        for endpoints in &mut self.endpoints {
+            endpoints.retain(|endpoint| !handles.contains(&endpoint.proxy));
+        }
+
+        let proxies = &self.proxies;
+        self.pairs.retain(|pair| {
+            if handles.contains(&pair.0) || handles.contains(&pair.1) {
+                handler(&proxies[pair.0.uid()].data, &proxies[pair.1.uid()].data);
+                false
+            } else {
+                true
+            }
+        });
+
+        for handle in handles {
+            let _ = self.proxies.remove(handle.uid());
+        }
+    }
+
+    fn deferred_set_bounding_volume(&mut self, handle: BroadPhaseProxyHandle, bv: AABB<N>) {
+        if let Some(proxy) = self.proxies.get(handle.uid()) {
+            if !proxy.inserted || !proxy.aabb.contains(&bv) {
+                let new_bv = bv.loosened(self.margin);
+                self.proxies_to_update.push((handle, new_bv));
+            }
+        } else {
+            panic!("Attempting to set the bounding volume of an object that does not exist.");
+        }
+    }
+
+    fn deferred_recompute_all_proximities_with(&mut self, handle: BroadPhaseProxyHandle) {
+        if self.proxies.contains(handle.uid()) {
+            self.proxies_to_recompute.push(handle);
+        }
+    }
+
+    fn deferred_recompute_all_proximities(&mut self) {
+        self.purge_all = true;
+    }
+
+    fn interferences_with_bounding_volume<'a>(&'a self, bv: &AABB<N>, out: &mut Vec<&'a T>) {
+        let collector = self
+            .proxies_starting_before(bv.maxs[0])
+            .filter(|handle| self.proxies[handle.uid()].aabb.intersects(bv))
+            .collect();
+
+        self.push_sorted(collector, out)
+    }
+
+    fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>) {
+        let m = Isometry::identity();
+
+        for (_, proxy) in self.proxies.iter() {
+            if proxy.inserted && proxy.aabb.intersects_ray(&m, ray, max_toi) {
+                out.push(&proxy.data)
+            }
+        }
+    }
+
+    fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>) {
+        let collector = self
+            .proxies_starting_before(point[0])
+            .filter(|handle| self.proxies[handle.uid()].aabb.contains_local_point(point))
+            .collect();
+
+        self.push_sorted(collector, out)
+    }
+
+    fn first_interference_with_ray<'a, 'b>(
+        &'a self,
+        ray: &'b Ray<N>,
+        max_toi: N,
+        cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+    ) -> Option<(T, RayIntersection<N>)> {
+        let m = Isometry::identity();
+        let mut candidates: Vec<_> = self
+            .proxies
+            .iter()
+            .filter(|(_, proxy)| proxy.inserted)
+            .filter_map(|(_, proxy)| {
+                let toi = proxy.aabb.toi_with_ray(&m, ray, max_toi, true)?;
+                Some((toi, proxy))
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut best_toi = N::max_value();
+        let mut best = None;
+
+        for (rough_toi, proxy) in candidates {
+            if rough_toi >= best_toi {
+                break;
+            }
+
+            if let Some(result) = cost_fn(proxy.data.clone(), ray, max_toi) {
+                if result.1.toi < best_toi {
+                    best_toi = result.1.toi;
+                    best = Some(result);
+                }
+            }
+        }
+
+        best
+    }
+
+    fn nearest_interference_with_point<'a, 'b>(
+        &'a self,
+        point: &'b Point<N>,
+        max_dist: N,
+        cost_fn: &'a dyn Fn(T, &'b Point<N>, N) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)>,
+    ) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)> {
+        let m = Isometry::identity();
+        let mut candidates: Vec<_> = self
+            .proxies
+            .iter()
+            .filter(|(_, proxy)| proxy.inserted)
+            .map(|(_, proxy)| (proxy.aabb.distance_to_point(&m, point, true), proxy))
+            .filter(|(dist, _)| *dist <= max_dist)
+            .collect();
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut best_dist = N::max_value();
+        let mut best = None;
+
+        for (rough_dist, proxy) in candidates {
+            if rough_dist >= best_dist {
+                break;
+            }
+
+            if let Some(result) = cost_fn(proxy.data.clone(), point, max_dist) {
+                let dist = na::distance(point, &result.1.point);
+
+                if dist <= max_dist && dist < best_dist {
+                    best_dist = dist;
+                    best = Some(result);
+                }
+            }
+        }
+
+        best
+    }
+}
@@ -1,11 +1,12 @@
 use na::RealField;
 use std::vec::IntoIter;
 
-use crate::bounding_volume::AABB;
-use crate::math::Point;
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Point, Rotation, Translation};
 use crate::pipeline::broad_phase::BroadPhase;
 use crate::pipeline::object::{CollisionGroups, CollisionObjectRef, CollisionObjectSet};
-use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::query::{self, PointQuery, Proximity, Ray, RayCast, RayIntersection};
+use crate::shape::{Cuboid, Segment};
 
 /// Returns an iterator yielding all the collision objects intersecting with the given ray.
 ///
@@ -76,6 +77,54 @@ where
     }
 }
 
+/// Returns all the collision objects intersecting with the given ray, sorted by increasing time
+/// of impact.
+///
+/// The result will only include collision objects in a group that can interact with the given
+/// `groups`. Objects with a proximity query type are ignored unless `include_proximity` is `true`.
+/// If `solid` is `true`, the ray is considered to hit the objects it starts inside of at a time
+/// of impact equal to zero.
+pub fn interferences_with_ray_sorted<N, Objects>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    ray: &Ray<N>,
+    max_toi: N,
+    groups: &CollisionGroups,
+    solid: bool,
+    include_proximity: bool,
+) -> Vec<(Objects::CollisionObjectHandle, RayIntersection<N>)>
+where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    let mut handles = Vec::new();
+    broad_phase.interferences_with_ray(ray, max_toi, &mut handles);
+
+    let mut hits: Vec<_> = handles
+        .into_iter()
+        .filter_map(|handle| {
+            let co = objects.collision_object(*handle)?;
+
+            if !co.collision_groups().can_interact_with_groups(groups)
+                || (!include_proximity && co.query_type().is_proximity_query())
+            {
+                return None;
+            }
+
+            co.shape()
+                .toi_and_normal_with_ray(&co.position(), ray, max_toi, solid)
+                .map(|inter| (*handle, inter))
+        })
+        .collect();
+
+    hits.sort_by(|a, b| {
+        a.1.toi
+            .partial_cmp(&b.1.toi)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    hits
+}
+
 /// Returns an iterator yielding all the collision objects containing the given point.
 ///
 /// The result will only include collision objects in a group that can interact with the given `groups`.
@@ -180,6 +229,70 @@ impl<'a, 'b, N: RealField, Objects: CollisionObjectSet<N>> Iterator
     }
 }
 
+/// Collects all the collision objects with an AABB touched by a capsule swept along the given path.
+///
+/// The path is the polyline joining consecutive `points`, and each of its segments is inflated by
+/// `radius`. A single broad-phase query is performed for the whole path, and each collision object
+/// is pushed at most once into `out`. The result will only include collision objects in a group
+/// that can interact with the given `groups`.
+pub fn interferences_along_path<N, Objects>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    points: &[Point<N>],
+    radius: N,
+    groups: &CollisionGroups,
+    out: &mut Vec<Objects::CollisionObjectHandle>,
+) where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    if points.is_empty() {
+        return;
+    }
+
+    let mut path_aabb = AABB::from_points(points.iter());
+    path_aabb.loosen(radius);
+
+    let mut handles = Vec::new();
+    broad_phase.interferences_with_bounding_volume(&path_aabb, &mut handles);
+
+    let segments: Vec<_> = if points.len() == 1 {
+        vec![Segment::new(points[0], points[0])]
+    } else {
+        points
+            .windows(2)
+            .map(|w| Segment::new(w[0], w[1]))
+            .collect()
+    };
+    let identity = Isometry::identity();
+
+    for handle in handles {
+        let aabb = objects
+            .collision_object(*handle)
+            .filter(|co| co.collision_groups().can_interact_with_groups(groups))
+            .and_then(|co| co.proxy_handle())
+            .and_then(|proxy_handle| broad_phase.proxy(proxy_handle))
+            .map(|proxy| proxy.0);
+
+        if let Some(aabb) = aabb {
+            let cuboid = Cuboid::new(aabb.half_extents());
+            let aabb_pos = Isometry::from_parts(
+                Translation::from(aabb.center().coords),
+                Rotation::identity(),
+            );
+
+            let touched = segments.iter().any(|seg| {
+                query::proximity_support_map_support_map(&aabb_pos, &cuboid, &identity, seg, radius)
+                    != Proximity::Disjoint
+            });
+
+            if touched {
+                out.push(*handle);
+            }
+        }
+    }
+}
+
 /// Return structure for `first_interference_with_ray`
 ///
 /// Contains the handle of the closest object along the ray along with its
@@ -8,6 +8,7 @@ use crate::pipeline::narrow_phase::{
     InteractionGraph, NarrowPhase,
 };
 use crate::pipeline::object::{CollisionObjectHandle, GeometricQueryType};
+use crate::query::ContactData;
 use crate::shape::Shape;
 
 /// Registers a collision object handle so it can be taken into acconut by the broad-phase and the narrow-phase.
@@ -16,10 +17,10 @@ use crate::shape::Shape;
 /// for a new collision object with the given `handle`, and known to currently have the given `position`, `shape` and `query_type`.
 /// The result of this registration is a pair of handles that must be stored by the user as the will be needed for various
 /// queries (on the broad-phase and interaction graph), as well as for freeing (using `remove_proxies`) the resources allocated here.
-pub fn create_proxies<'a, N: RealField, Handle: CollisionObjectHandle>(
+pub fn create_proxies<'a, N: RealField, Handle: CollisionObjectHandle, C: ContactData>(
     handle: Handle,
     broad_phase: &mut (impl BroadPhase<N, AABB<N>, Handle> + ?Sized),
-    interactions: &mut InteractionGraph<N, Handle>,
+    interactions: &mut InteractionGraph<N, Handle, C>,
     position: &Isometry<N>,
     shape: &(impl Shape<N> + ?Sized),
     query_type: GeometricQueryType<N>,
@@ -47,9 +48,9 @@ pub fn create_proxies<'a, N: RealField, Handle: CollisionObjectHandle>(
 ///  collision objects are not stable wrt. the deletion of collision objects. Therefore, removing one collision object can
 /// result in the collision object graph index of another collision object to be changed.
 #[must_use = "The graph index of the collision object returned by this method has been changed to the returned graph index."]
-pub fn remove_proxies<'a, N: RealField, Handle: CollisionObjectHandle>(
+pub fn remove_proxies<'a, N: RealField, Handle: CollisionObjectHandle, C: ContactData>(
     broad_phase: &mut (impl BroadPhase<N, AABB<N>, Handle> + ?Sized),
-    interactions: &mut InteractionGraph<N, Handle>,
+    interactions: &mut InteractionGraph<N, Handle, C>,
     proxy_handle: BroadPhaseProxyHandle,
     graph_index: CollisionObjectGraphIndex,
 ) -> Option<(Handle, CollisionObjectGraphIndex)> {
@@ -6,22 +6,24 @@ use crate::pipeline::broad_phase::{
 };
 use crate::pipeline::narrow_phase::{InteractionGraph, NarrowPhase};
 use crate::pipeline::object::{CollisionGroupsPairFilter, CollisionObjectRef, CollisionObjectSet};
+use crate::query::ContactData;
 
-struct CollisionWorldInterferenceHandler<'a, 'b, N, Objects, Filter>
+struct CollisionWorldInterferenceHandler<'a, 'b, N, Objects, Filter, C>
 where
     N: RealField,
+    C: ContactData,
     Objects: CollisionObjectSet<N>,
     Filter: BroadPhasePairFilter<N, Objects> + ?Sized,
 {
-    narrow_phase: &'b mut NarrowPhase<N, Objects::CollisionObjectHandle>,
-    interactions: &'b mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+    narrow_phase: &'b mut NarrowPhase<N, Objects::CollisionObjectHandle, C>,
+    interactions: &'b mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
     objects: &'a Objects,
     pair_filters: Option<&'a Filter>,
 }
 
-impl<'a, 'b, N: RealField, Objects, Filter>
+impl<'a, 'b, N: RealField, Objects, Filter, C: ContactData>
     BroadPhaseInterferenceHandler<Objects::CollisionObjectHandle>
-    for CollisionWorldInterferenceHandler<'a, 'b, N, Objects, Filter>
+    for CollisionWorldInterferenceHandler<'a, 'b, N, Objects, Filter, C>
 where
     Objects: CollisionObjectSet<N>,
     Filter: BroadPhasePairFilter<N, Objects> + ?Sized,
@@ -63,11 +65,11 @@ where
 ///
 /// This will update the broad-phase internal structure, and create potential interaction pairs in the interaction graph.
 /// A `pair_filters` can be provided to filter out pairs of object that should not be considered.
-pub fn perform_broad_phase<N: RealField, Objects>(
+pub fn perform_broad_phase<N: RealField, Objects, C: ContactData>(
     objects: &Objects,
     broad_phase: &mut (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
-    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle>,
-    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle, C>,
+    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
     pair_filters: Option<&(impl BroadPhasePairFilter<N, Objects> + ?Sized)>,
 ) where
     Objects: CollisionObjectSet<N>,
@@ -99,13 +101,14 @@ pub fn perform_broad_phase<N: RealField, Objects>(
 ///
 /// This will update all interactions in the interaction graph by computing new contacts,
 /// and proximities.
-pub fn perform_narrow_phase<N, Objects>(
+pub fn perform_narrow_phase<N, Objects, C>(
     objects: &Objects,
-    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle>,
-    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle, C>,
+    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
 ) where
     N: RealField,
     Objects: CollisionObjectSet<N>,
+    C: ContactData,
 {
     narrow_phase.update(interactions, objects);
 }
@@ -114,15 +117,16 @@ pub fn perform_narrow_phase<N, Objects>(
 ///
 /// This execute a complete collision detection pipeline by performing the broad-phase first and then
 /// the narrow-phase.
-pub fn perform_all_pipeline<'a, N, Objects>(
+pub fn perform_all_pipeline<'a, N, Objects, C>(
     objects: &Objects,
     broad_phase: &mut (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
-    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle>,
-    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle, C>,
+    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
     pair_filters: Option<&'a (impl BroadPhasePairFilter<N, Objects> + ?Sized)>,
 ) where
     N: RealField,
     Objects: CollisionObjectSet<N>,
+    C: ContactData,
 {
     perform_broad_phase(
         objects,
@@ -0,0 +1,96 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    self, ContactData, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    NeighborhoodGeometry,
+};
+use crate::shape::{Arc, FeatureId, Shape};
+use na::RealField;
+use std::marker::PhantomData;
+
+/// Collision detector between an arc and a shape implementing the `SupportMap` trait.
+#[derive(Clone)]
+pub struct ArcSupportMapManifoldGenerator<N: RealField> {
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> ArcSupportMapManifoldGenerator<N> {
+    /// Creates a new collision detector between an arc and a support-mapped shape.
+    ///
+    /// If `flip` is `true`, the arc is expected to be the second shape.
+    #[inline]
+    pub fn new(flip: bool) -> ArcSupportMapManifoldGenerator<N> {
+        ArcSupportMapManifoldGenerator {
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn do_update_to<C: ContactData>(
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+        flip: bool,
+    ) -> bool {
+        if let (Some(arc), Some(other)) = (g1.as_shape::<Arc<N>>(), g2.as_support_map()) {
+            if let Some(contact) =
+                query::contact_arc_support_map(m1, arc, m2, other, prediction.linear())
+            {
+                let local1 = m1.inverse_transform_point(&contact.world1);
+                let local2 = m2.inverse_transform_point(&contact.world2);
+                let mut kinematic = ContactKinematic::new();
+
+                let approx_arc =
+                    NeighborhoodGeometry::Plane(m1.inverse_transform_unit_vector(&contact.normal));
+                let approx_other = NeighborhoodGeometry::Point;
+
+                if !flip {
+                    kinematic.set_approx1(FeatureId::Face(0), local1, approx_arc);
+                    kinematic.set_approx2(FeatureId::Unknown, local2, approx_other);
+                    let _ = manifold.push(contact, kinematic, local1, proc1, proc2);
+                } else {
+                    let mut contact = contact;
+                    contact.flip();
+                    kinematic.set_approx1(FeatureId::Unknown, local2, approx_other);
+                    kinematic.set_approx2(FeatureId::Face(0), local1, approx_arc);
+                    let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+                }
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for ArcSupportMapManifoldGenerator<N>
+{
+    #[inline]
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if !self.flip {
+            Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
+        } else {
+            Self::do_update_to(m2, g2, proc2, m1, g1, proc1, prediction, manifold, true)
+        }
+    }
+}
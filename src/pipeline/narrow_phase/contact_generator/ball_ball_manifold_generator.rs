@@ -1,7 +1,7 @@
 use crate::math::{Isometry, Point};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
-    self, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    self, ContactData, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
     NeighborhoodGeometry,
 };
 use crate::shape::{Ball, FeatureId, Shape};
@@ -24,10 +24,10 @@ impl<N: RealField> BallBallManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for BallBallManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C> for BallBallManifoldGenerator<N> {
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -35,7 +35,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for BallBallManifoldGenerator<N>
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if let (Some(a), Some(b)) = (a.as_shape::<Ball<N>>(), b.as_shape::<Ball<N>>()) {
             let center_a = Point::from(ma.translation.vector);
@@ -1,8 +1,8 @@
 use crate::math::{Isometry, Point};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
-    Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
-    NeighborhoodGeometry,
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
 };
 use crate::shape::{Ball, FeatureId, Shape};
 use na::{RealField, Unit};
@@ -25,7 +25,7 @@ impl<N: RealField> BallConvexPolyhedronManifoldGenerator<N> {
         }
     }
 
-    fn do_generate(
+    fn do_generate<C: ContactData>(
         &mut self,
         m1: &Isometry<N>,
         a: &dyn Shape<N>,
@@ -34,7 +34,7 @@ impl<N: RealField> BallConvexPolyhedronManifoldGenerator<N> {
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         // NOTE: we use an underscore to silence a warning
         // for _cp2 because it is used in 3D but not in 2D.
@@ -121,10 +121,12 @@ impl<N: RealField> BallConvexPolyhedronManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for BallConvexPolyhedronManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for BallConvexPolyhedronManifoldGenerator<N>
+{
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -132,7 +134,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for BallConvexPolyhedronManifoldG
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             self.do_generate(m1, a, proc1, m2, b, proc2, prediction, manifold)
@@ -0,0 +1,194 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::BVH;
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    visitors::BoundingVolumeInterferencesCollector, Contact, ContactData, ContactKinematic,
+    ContactManifold, ContactPrediction, ContactPreprocessor, ContactTrackingMode,
+    NeighborhoodGeometry, PointQueryWithLocation,
+};
+use crate::shape::{Ball, FeatureId, Shape, TriMesh, TrianglePointLocation};
+use na::{RealField, Unit};
+use std::marker::PhantomData;
+
+/// Collision detector between a ball and a triangle mesh.
+///
+/// Each triangle close to the ball yields a contact classified as a face, edge, or vertex
+/// contact depending on where the center of the ball projects on the triangle. Edge and vertex
+/// contacts are discarded if their normal is not part of the normal cone of the corresponding
+/// mesh feature, i.e., if it points into an adjacent triangle. This prevents a ball rolling on a
+/// flat triangulated surface from hitting the internal edges of that surface.
+pub struct BallTriMeshManifoldGenerator<N: RealField> {
+    interferences: Vec<usize>,
+    features: Vec<FeatureId>,
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> BallTriMeshManifoldGenerator<N> {
+    /// Creates a new collision detector between a ball and a triangle mesh.
+    ///
+    /// If `flip` is `true`, the triangle mesh is expected to be the first shape.
+    pub fn new(flip: bool) -> BallTriMeshManifoldGenerator<N> {
+        BallTriMeshManifoldGenerator {
+            interferences: Vec::new(),
+            features: Vec::new(),
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    fn do_generate<C: ContactData>(
+        &mut self,
+        m1: &Isometry<N>,
+        ball: &Ball<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        mesh: &TriMesh<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) {
+        let ws_center = Point::from(m1.translation.vector);
+        let center = m2.inverse_transform_point(&ws_center);
+        let max_dist = ball.radius + prediction.linear();
+        let ls_aabb = AABB::from_half_extents(center, Vector::repeat(max_dist));
+        let sin_ang_tol = N::default_epsilon().sqrt();
+
+        {
+            let mut visitor =
+                BoundingVolumeInterferencesCollector::new(&ls_aabb, &mut self.interferences);
+            mesh.bvt().visit(&mut visitor);
+        }
+
+        self.features.clear();
+
+        for i in self.interferences.drain(..) {
+            let face = &mesh.faces()[i];
+            let (proj, loc) = mesh.triangle_at(i).project_point_with_location(
+                &Isometry::identity(),
+                &center,
+                false,
+            );
+            let dpt = center - proj.point;
+
+            let (dir, dist, feature) = match Unit::try_new_and_get(dpt, N::default_epsilon()) {
+                Some((dir, dist)) => {
+                    let feature = match loc {
+                        TrianglePointLocation::OnVertex(k) => FeatureId::Vertex(face.indices[k]),
+                        TrianglePointLocation::OnEdge(k, _) => FeatureId::Edge(face.edges[k]),
+                        _ => FeatureId::Face(i),
+                    };
+
+                    (dir, dist, feature)
+                }
+                None => {
+                    // The center of the ball lies on the triangle.
+                    if let Some(normal) = face.normal {
+                        (normal, N::zero(), FeatureId::Face(i))
+                    } else {
+                        continue;
+                    }
+                }
+            };
+
+            if dist > max_dist {
+                continue;
+            }
+
+            let (feature, geom) = match feature {
+                FeatureId::Face(_) => {
+                    let backface = face.normal.map(|n| n.dot(&dir) < N::zero()) == Some(true);
+                    let feature = if backface {
+                        FeatureId::Face(i + mesh.faces().len())
+                    } else {
+                        feature
+                    };
+
+                    (feature, NeighborhoodGeometry::Plane(dir))
+                }
+                FeatureId::Edge(e) => {
+                    if !mesh.edge_tangent_cone_polar_contains_orthogonal_dir(e, &dir, sin_ang_tol) {
+                        continue;
+                    }
+
+                    let segment = mesh.edge_segment(e);
+                    let edge_dir = Unit::new_normalize(segment.b - segment.a);
+                    (feature, NeighborhoodGeometry::Line(edge_dir))
+                }
+                FeatureId::Vertex(v) => {
+                    if !mesh.vertex_tangent_cone_polar_contains_dir(v, &dir, sin_ang_tol) {
+                        continue;
+                    }
+
+                    (feature, NeighborhoodGeometry::Point)
+                }
+                FeatureId::Unknown => continue,
+            };
+
+            // Several triangles may share the same edge or vertex.
+            if self.features.contains(&feature) {
+                continue;
+            }
+            self.features.push(feature);
+
+            let normal = m2 * -dir;
+            let depth = ball.radius - dist;
+            let world1 = ws_center + normal.into_inner() * ball.radius;
+            let world2 = m2 * proj.point;
+            let f1 = FeatureId::Face(0);
+
+            let mut kinematic = ContactKinematic::new();
+
+            if !self.flip {
+                let contact = Contact::new(world1, world2, normal, depth);
+                kinematic.set_approx1(f1, Point::origin(), NeighborhoodGeometry::Point);
+                kinematic.set_dilation1(ball.radius);
+                kinematic.set_approx2(feature, proj.point, geom);
+                let _ = manifold.push(contact, kinematic, proj.point, proc1, proc2);
+            } else {
+                let contact = Contact::new(world2, world1, -normal, depth);
+                kinematic.set_approx1(feature, proj.point, geom);
+                kinematic.set_approx2(f1, Point::origin(), NeighborhoodGeometry::Point);
+                kinematic.set_dilation2(ball.radius);
+                let _ = manifold.push(contact, kinematic, proj.point, proc2, proc1);
+            }
+        }
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for BallTriMeshManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        let (m1, g1, proc1, m2, g2, proc2) = if !self.flip {
+            (m1, g1, proc1, m2, g2, proc2)
+        } else {
+            (m2, g2, proc2, m1, g1, proc1)
+        };
+
+        if let (Some(ball), Some(mesh)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<TriMesh<N>>()) {
+            self.do_generate(m1, ball, proc1, m2, mesh, proc2, prediction, manifold);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn init_manifold(&self) -> ContactManifold<N, C> {
+        let mut res = ContactManifold::new();
+        res.set_tracking_mode(ContactTrackingMode::FeatureBased);
+        res
+    }
+}
@@ -2,9 +2,13 @@ use crate::math::Isometry;
 use crate::pipeline::narrow_phase::{
     ContactDispatcher, ContactManifoldGenerator, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
 };
-use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
-use crate::shape::{Capsule, Shape};
-use na::{self, RealField};
+use crate::query::{
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
+};
+use crate::shape::{Capsule, ConvexPolyhedron, FeatureId, Segment, Shape};
+use na::{self, RealField, Unit};
+use std::f64;
 
 /// Collision detector between a concave shape and another shape.
 pub struct CapsuleCapsuleManifoldGenerator<N: RealField> {
@@ -20,9 +24,9 @@ impl<N: RealField> CapsuleCapsuleManifoldGenerator<N> {
         }
     }
 
-    fn do_update(
+    fn do_update<C: ContactData>(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &Capsule<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -30,7 +34,7 @@ impl<N: RealField> CapsuleCapsuleManifoldGenerator<N> {
         g2: &Capsule<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         let segment1 = g1.segment();
         let segment2 = g2.segment();
@@ -39,25 +43,137 @@ impl<N: RealField> CapsuleCapsuleManifoldGenerator<N> {
         let new_linear_prediction = prediction.linear() + g1.radius + g2.radius;
         prediction.set_linear(new_linear_prediction);
 
+        let proc1 = (proc1, &g1.contact_preprocessor());
+        let proc2 = (proc2, &g2.contact_preprocessor());
+
+        if generate_parallel_contacts(
+            m1,
+            &segment1,
+            &proc1,
+            m2,
+            &segment2,
+            &proc2,
+            &prediction,
+            manifold,
+        ) {
+            return true;
+        }
+
         // Update all collisions
         self.sub_detector.generate_contacts(
             dispatcher,
             m1,
             &segment1,
-            Some(&(proc1, &g1.contact_preprocessor())),
+            Some(&proc1),
             m2,
             &segment2,
-            Some(&(proc2, &g2.contact_preprocessor())),
+            Some(&proc2),
             &prediction,
             manifold,
         )
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for CapsuleCapsuleManifoldGenerator<N> {
+/// Generates one contact at each end of the overlapping part of two parallel segments.
+///
+/// Returns `false` if the segments are not parallel, do not overlap along their direction, or
+/// if their axes intersect. The closest points must then be computed instead.
+fn generate_parallel_contacts<N: RealField, C: ContactData>(
+    m1: &Isometry<N>,
+    segment1: &Segment<N>,
+    proc1: &dyn ContactPreprocessor<N>,
+    m2: &Isometry<N>,
+    segment2: &Segment<N>,
+    proc2: &dyn ContactPreprocessor<N>,
+    prediction: &ContactPrediction<N>,
+    manifold: &mut ContactManifold<N, C>,
+) -> bool {
+    let eps = N::default_epsilon();
+    let a1 = m1 * segment1.a;
+    let a2 = m2 * segment2.a;
+
+    let (dir1, length1) = match Unit::try_new_and_get(m1 * segment1.b - a1, eps) {
+        Some(res) => res,
+        None => return false,
+    };
+    let (dir2, length2) = match Unit::try_new_and_get(m2 * segment2.b - a2, eps) {
+        Some(res) => res,
+        None => return false,
+    };
+
+    // Same tolerance as for the selection of the features of a segment.
+    let cos_tolerance: N = na::convert((f64::consts::PI / 180.0).cos());
+
+    if dir1.dot(&dir2).abs() < cos_tolerance {
+        return false;
+    }
+
+    // The part of the first segment facing the second one.
+    let t_a2 = (a2 - a1).dot(&dir1);
+    let t_b2 = (m2 * segment2.b - a1).dot(&dir1);
+    let t_min = t_a2.min(t_b2).max(N::zero());
+    let t_max = t_a2.max(t_b2).min(length1);
+
+    if t_max - t_min <= eps {
+        return false;
+    }
+
+    let mut contacts = [None, None];
+
+    for (t, contact) in [t_min, t_max].iter().zip(contacts.iter_mut()) {
+        let world1 = a1 + *dir1 * *t;
+        let s = (world1 - a2).dot(&dir2).max(N::zero()).min(length2);
+        let world2 = a2 + *dir2 * s;
+
+        match Unit::try_new_and_get(world2 - world1, eps) {
+            Some((normal, dist)) => *contact = Some(Contact::new(world1, world2, normal, -dist)),
+            None => return false,
+        }
+    }
+
+    for contact in contacts.iter().flatten() {
+        if -contact.depth > prediction.linear() {
+            continue;
+        }
+
+        let local1 = m1.inverse_transform_point(&contact.world1);
+        let local2 = m2.inverse_transform_point(&contact.world2);
+        let f1 =
+            segment1.support_feature_id_toward(&m1.inverse_transform_unit_vector(&contact.normal));
+        let f2 =
+            segment2.support_feature_id_toward(&m2.inverse_transform_unit_vector(&-contact.normal));
+
+        let mut kinematic = ContactKinematic::new();
+        kinematic.set_approx1(f1, local1, segment_neighborhood(segment1, f1));
+        kinematic.set_approx2(f2, local2, segment_neighborhood(segment2, f2));
+
+        let _ = manifold.push(contact.clone(), kinematic, local1, Some(proc1), Some(proc2));
+    }
+
+    true
+}
+
+/// The local approximation of a capsule segment around its feature `feature`.
+fn segment_neighborhood<N: RealField>(
+    segment: &Segment<N>,
+    feature: FeatureId,
+) -> NeighborhoodGeometry<N> {
+    match feature {
+        FeatureId::Face(_) => NeighborhoodGeometry::Plane(segment.feature_normal(feature)),
+        #[cfg(feature = "dim3")]
+        FeatureId::Edge(_) => segment
+            .direction()
+            .map_or(NeighborhoodGeometry::Point, NeighborhoodGeometry::Line),
+        _ => NeighborhoodGeometry::Point,
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for CapsuleCapsuleManifoldGenerator<N>
+{
     fn generate_contacts(
         &mut self,
-        d: &dyn ContactDispatcher<N>,
+        d: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -65,7 +181,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for CapsuleCapsuleManifoldGenerat
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if let (Some(cs1), Some(cs2)) = (a.as_shape::<Capsule<N>>(), b.as_shape::<Capsule<N>>()) {
             self.do_update(d, ma, cs1, proc1, mb, cs2, proc2, prediction, manifold)
@@ -1,18 +1,18 @@
 use crate::math::Isometry;
 use crate::pipeline::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
-use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::query::{ContactData, ContactManifold, ContactPrediction, ContactPreprocessor};
 use crate::shape::{Capsule, Shape};
 use na::{self, RealField};
 
 /// Collision detector between a concave shape and another shape.
-pub struct CapsuleShapeManifoldGenerator<N: RealField> {
-    sub_detector: Option<ContactAlgorithm<N>>,
+pub struct CapsuleShapeManifoldGenerator<N: RealField, C: ContactData = ()> {
+    sub_detector: Option<ContactAlgorithm<N, C>>,
     flip: bool,
 }
 
-impl<N: RealField> CapsuleShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> CapsuleShapeManifoldGenerator<N, C> {
     /// Creates a new collision detector between a concave shape and another shape.
-    pub fn new(flip: bool) -> CapsuleShapeManifoldGenerator<N> {
+    pub fn new(flip: bool) -> CapsuleShapeManifoldGenerator<N, C> {
         CapsuleShapeManifoldGenerator {
             sub_detector: None,
             flip,
@@ -21,7 +21,7 @@ impl<N: RealField> CapsuleShapeManifoldGenerator<N> {
 
     fn do_update(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &Capsule<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -29,7 +29,7 @@ impl<N: RealField> CapsuleShapeManifoldGenerator<N> {
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
         flip: bool,
     ) -> bool {
         let segment = g1.segment();
@@ -74,10 +74,12 @@ impl<N: RealField> CapsuleShapeManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for CapsuleShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for CapsuleShapeManifoldGenerator<N, C>
+{
     fn generate_contacts(
         &mut self,
-        d: &dyn ContactDispatcher<N>,
+        d: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -85,7 +87,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for CapsuleShapeManifoldGenerator
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             if let Some(cs) = a.as_shape::<Capsule<N>>() {
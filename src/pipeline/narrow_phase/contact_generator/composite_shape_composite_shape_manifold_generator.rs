@@ -3,7 +3,7 @@ use crate::pipeline::narrow_phase::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
 };
 use crate::query::{
-    visitors::AABBSetsInterferencesCollector, ContactManifold, ContactPrediction,
+    visitors::AABBSetsInterferencesCollector, ContactData, ContactManifold, ContactPrediction,
     ContactPreprocessor,
 };
 use crate::shape::{CompositeShape, Shape};
@@ -12,15 +12,15 @@ use na::RealField;
 use std::collections::{hash_map::Entry, HashMap};
 
 /// Collision detector between a concave shape and another shape.
-pub struct CompositeShapeCompositeShapeManifoldGenerator<N> {
-    sub_detectors: HashMap<(usize, usize), (ContactAlgorithm<N>, usize), DeterministicState>,
+pub struct CompositeShapeCompositeShapeManifoldGenerator<N, C: ContactData = ()> {
+    sub_detectors: HashMap<(usize, usize), (ContactAlgorithm<N, C>, usize), DeterministicState>,
     interferences: Vec<(usize, usize)>,
     timestamp: usize,
 }
 
-impl<N> CompositeShapeCompositeShapeManifoldGenerator<N> {
+impl<N, C: ContactData> CompositeShapeCompositeShapeManifoldGenerator<N, C> {
     /// Creates a new collision detector between a concave shape and another shape.
-    pub fn new() -> CompositeShapeCompositeShapeManifoldGenerator<N> {
+    pub fn new() -> CompositeShapeCompositeShapeManifoldGenerator<N, C> {
         CompositeShapeCompositeShapeManifoldGenerator {
             sub_detectors: HashMap::with_hasher(DeterministicState),
             interferences: Vec::new(),
@@ -29,10 +29,10 @@ impl<N> CompositeShapeCompositeShapeManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> CompositeShapeCompositeShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> CompositeShapeCompositeShapeManifoldGenerator<N, C> {
     fn do_update(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &dyn CompositeShape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -40,7 +40,7 @@ impl<N: RealField> CompositeShapeCompositeShapeManifoldGenerator<N> {
         g2: &dyn CompositeShape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) {
         self.timestamp += 1;
 
@@ -120,12 +120,12 @@ impl<N: RealField> CompositeShapeCompositeShapeManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N>
-    for CompositeShapeCompositeShapeManifoldGenerator<N>
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for CompositeShapeCompositeShapeManifoldGenerator<N, C>
 {
     fn generate_contacts(
         &mut self,
-        d: &dyn ContactDispatcher<N>,
+        d: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -133,7 +133,7 @@ impl<N: RealField> ContactManifoldGenerator<N>
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if let (Some(csa), Some(csb)) = (a.as_composite_shape(), b.as_composite_shape()) {
             self.do_update(d, ma, csa, proc1, mb, csb, proc2, prediction, manifold);
@@ -4,8 +4,8 @@ use crate::pipeline::narrow_phase::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
 };
 use crate::query::{
-    visitors::BoundingVolumeInterferencesCollector, ContactManifold, ContactPrediction,
-    ContactPreprocessor, ContactTrackingMode,
+    visitors::BoundingVolumeInterferencesCollector, ContactData, ContactManifold,
+    ContactPrediction, ContactPreprocessor, ContactTrackingMode,
 };
 use crate::shape::{CompositeShape, Shape};
 use crate::utils::DeterministicState;
@@ -13,16 +13,16 @@ use na::{self, RealField};
 use std::collections::{hash_map::Entry, HashMap};
 
 /// Collision detector between a concave shape and another shape.
-pub struct CompositeShapeShapeManifoldGenerator<N: RealField> {
-    sub_detectors: HashMap<usize, (ContactAlgorithm<N>, usize), DeterministicState>,
+pub struct CompositeShapeShapeManifoldGenerator<N: RealField, C: ContactData = ()> {
+    sub_detectors: HashMap<usize, (ContactAlgorithm<N, C>, usize), DeterministicState>,
     interferences: Vec<usize>,
     flip: bool,
     timestamp: usize,
 }
 
-impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> CompositeShapeShapeManifoldGenerator<N, C> {
     /// Creates a new collision detector between a concave shape and another shape.
-    pub fn new(flip: bool) -> CompositeShapeShapeManifoldGenerator<N> {
+    pub fn new(flip: bool) -> CompositeShapeShapeManifoldGenerator<N, C> {
         CompositeShapeShapeManifoldGenerator {
             sub_detectors: HashMap::with_hasher(DeterministicState),
             interferences: Vec::new(),
@@ -33,7 +33,7 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
 
     fn do_update(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &dyn CompositeShape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -41,7 +41,7 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
         flip: bool,
     ) {
         self.timestamp += 1;
@@ -120,10 +120,12 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for CompositeShapeShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for CompositeShapeShapeManifoldGenerator<N, C>
+{
     fn generate_contacts(
         &mut self,
-        d: &dyn ContactDispatcher<N>,
+        d: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -131,7 +133,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for CompositeShapeShapeManifoldGe
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             if let Some(cs) = a.as_composite_shape() {
@@ -148,7 +150,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for CompositeShapeShapeManifoldGe
         return false;
     }
 
-    fn init_manifold(&self) -> ContactManifold<N> {
+    fn init_manifold(&self) -> ContactManifold<N, C> {
         let mut res = ContactManifold::new();
         res.set_tracking_mode(ContactTrackingMode::FeatureBased);
         res
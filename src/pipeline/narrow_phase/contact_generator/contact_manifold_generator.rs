@@ -1,13 +1,15 @@
 use crate::math::Isometry;
 use crate::query::ContactPreprocessor;
-use crate::query::{ContactManifold, ContactPrediction};
+use crate::query::{ContactData, ContactManifold, ContactPrediction};
 use crate::shape::Shape;
 use na::RealField;
 use std::any::Any;
 
 /// An algorithm to compute contact points, normals and penetration depths between two specific
 /// objects.
-pub trait ContactManifoldGenerator<N: RealField>: Any + Send + Sync {
+///
+/// The contact manifolds generated carry one user-defined value of type `C` per contact.
+pub trait ContactManifoldGenerator<N: RealField, C: ContactData = ()>: Any + Send + Sync {
     /// Runs the collision detection on two objects. It is assumed that the same
     /// collision detector (the same structure) is always used with the same
     /// pair of objects.
@@ -18,7 +20,7 @@ pub trait ContactManifoldGenerator<N: RealField>: Any + Send + Sync {
     /// regardless.
     fn generate_contacts(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -26,22 +28,29 @@ pub trait ContactManifoldGenerator<N: RealField>: Any + Send + Sync {
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool;
 
     /// Generate an empty contact manifold configured as required by this contact manifold generator.
-    fn init_manifold(&self) -> ContactManifold<N> {
+    fn init_manifold(&self) -> ContactManifold<N, C> {
         ContactManifold::new()
     }
+
+    /// A name identifying this contact manifold generator, for debugging purposes.
+    ///
+    /// Defaults to the name of the implementing type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
-pub type ContactAlgorithm<N> = Box<dyn ContactManifoldGenerator<N>>;
+pub type ContactAlgorithm<N, C = ()> = Box<dyn ContactManifoldGenerator<N, C>>;
 
-pub trait ContactDispatcher<N>: Any + Send + Sync {
+pub trait ContactDispatcher<N, C = ()>: Any + Send + Sync {
     /// Allocate a collision algorithm corresponding to a pair of objects with the given shapes.
     fn get_contact_algorithm(
         &self,
         a: &dyn Shape<N>,
         b: &dyn Shape<N>,
-    ) -> Option<ContactAlgorithm<N>>;
+    ) -> Option<ContactAlgorithm<N, C>>;
 }
@@ -2,7 +2,9 @@ use crate::math::{Isometry, Vector};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::algorithms::gjk::GJKResult;
 use crate::query::algorithms::VoronoiSimplex;
-use crate::query::{self, Contact, ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::query::{
+    self, Contact, ContactData, ContactManifold, ContactPrediction, ContactPreprocessor,
+};
 #[cfg(feature = "dim3")]
 use crate::shape::ClippingCache;
 use crate::shape::ConvexPolygonalFeature;
@@ -77,12 +79,12 @@ impl<N: RealField> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N>
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
     for ConvexPolyhedronConvexPolyhedronManifoldGenerator<N>
 {
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -90,7 +92,7 @@ impl<N: RealField> ContactManifoldGenerator<N>
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if let (Some(cpa), Some(cpb)) = (a.as_convex_polyhedron(), b.as_convex_polyhedron()) {
             let contact = query::contact_support_map_support_map_with_params(
@@ -0,0 +1,205 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::algorithms::gjk::GJKResult;
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{
+    self, Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry, Ray,
+};
+use crate::shape::{Cone, Cylinder, FeatureId, Shape};
+use na::{self, RealField, Unit};
+
+/// Number of contacts sampled along the rim of a cylinder cap or of a cone base.
+const NUM_RIM_CONTACTS: usize = 8;
+
+/// Collision detector between a cylinder or a cone, and a shape implementing the `SupportMap`
+/// trait.
+///
+/// The deepest contact is computed by the GJK and EPA algorithms, and is always generated. If the
+/// contact normal deviates by less than the angular prediction from the normal of a cylinder cap
+/// or a cone base, this flat face rests on the other shape: its rim is sampled at regular angles,
+/// and each sample yields an additional contact with the point of the other shape it faces along
+/// the contact normal. Samples not facing the other shape are discarded. If the rim of the first
+/// shape yields no contact, e.g., because it is larger than the second shape, the rim of the
+/// second shape is tried too.
+///
+/// The rims of the bottom and top caps of a cylinder are identified by `FeatureId::Edge(0)` and
+/// `FeatureId::Edge(1)`, and the rim of the base of a cone by `FeatureId::Edge(0)`.
+#[derive(Clone)]
+pub struct CylinderConeSupportMapManifoldGenerator<N: RealField> {
+    simplex: VoronoiSimplex<N>,
+    last_gjk_dir: Option<Unit<Vector<N>>>,
+}
+
+impl<N: RealField> CylinderConeSupportMapManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a cylinder or a cone, and a
+    /// support-mapped shape.
+    pub fn new() -> Self {
+        CylinderConeSupportMapManifoldGenerator {
+            simplex: VoronoiSimplex::new(),
+            last_gjk_dir: None,
+        }
+    }
+}
+
+impl<N: RealField> Default for CylinderConeSupportMapManifoldGenerator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The rim of the flat face of `shape` with a normal close to `dir`, given in its local-space.
+//
+// Returns the height of the rim along the `y` axis, its radius, and its feature identifier.
+fn resting_rim<N: RealField>(
+    shape: &dyn Shape<N>,
+    dir: &Unit<Vector<N>>,
+    cos_angular: N,
+) -> Option<(N, N, FeatureId)> {
+    if let Some(cylinder) = shape.as_shape::<Cylinder<N>>() {
+        if dir.y >= cos_angular {
+            Some((cylinder.half_height, cylinder.radius, FeatureId::Edge(1)))
+        } else if -dir.y >= cos_angular {
+            Some((-cylinder.half_height, cylinder.radius, FeatureId::Edge(0)))
+        } else {
+            None
+        }
+    } else if let Some(cone) = shape.as_shape::<Cone<N>>() {
+        if -dir.y >= cos_angular {
+            Some((-cone.half_height, cone.radius, FeatureId::Edge(0)))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+// Pushes the contacts between the resting rim of `g1` and `g2` to `manifold`, with `normal`
+// pointing from `g1` toward `g2`. Returns the number of contacts pushed.
+fn push_rim_contacts<N: RealField, C: ContactData>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    proc1: Option<&dyn ContactPreprocessor<N>>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    proc2: Option<&dyn ContactPreprocessor<N>>,
+    normal: &Unit<Vector<N>>,
+    prediction: &ContactPrediction<N>,
+    manifold: &mut ContactManifold<N, C>,
+    flip: bool,
+) -> usize {
+    let cos_angular = if flip {
+        prediction.cos_angular2()
+    } else {
+        prediction.cos_angular1()
+    };
+    let local_dir = m1.inverse_transform_unit_vector(normal);
+    let (height, radius, f1) = match resting_rim(g1, &local_dir, cos_angular) {
+        Some(rim) => rim,
+        None => return 0,
+    };
+    let (sm2, rc2) = match (g2.as_support_map(), g2.as_ray_cast()) {
+        (Some(sm2), Some(rc2)) => (sm2, rc2),
+        _ => return 0,
+    };
+
+    // No point of `g2` lies behind this support point along the contact normal.
+    let support2 = sm2.support_point(m2, &-*normal);
+    let approx1 = NeighborhoodGeometry::Point;
+    let approx2 = NeighborhoodGeometry::Plane(m2.inverse_transform_unit_vector(&-*normal));
+    let mut num_contacts = 0;
+
+    for i in 0..NUM_RIM_CONTACTS {
+        let angle = N::two_pi() * na::convert(i as f64 / NUM_RIM_CONTACTS as f64);
+        let local1 = Point::new(angle.cos() * radius, height, angle.sin() * radius);
+        let world1 = m1 * local1;
+
+        // Cast a ray along the contact normal, from a point outside of `g2`.
+        let back = (world1 - support2).dot(normal).max(N::zero()) + prediction.linear();
+        let ray = Ray::new(world1 + (-**normal * back), **normal);
+
+        if let Some(toi) = rc2.toi_with_ray(m2, &ray, back + prediction.linear(), true) {
+            let world2 = ray.point_at(toi);
+            let local2 = m2.inverse_transform_point(&world2);
+            let f2 = FeatureId::Unknown;
+            let depth = back - toi;
+            let mut kinematic = ContactKinematic::new();
+            let contact;
+
+            if !flip {
+                contact = Contact::new(world1, world2, *normal, depth);
+                kinematic.set_approx1(f1, local1, approx1);
+                kinematic.set_approx2(f2, local2, approx2);
+                let _ = manifold.push(contact, kinematic, local1, proc1, proc2);
+            } else {
+                contact = Contact::new(world2, world1, -*normal, depth);
+                kinematic.set_approx1(f2, local2, approx2);
+                kinematic.set_approx2(f1, local1, approx1);
+                let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+            }
+
+            num_contacts += 1;
+        }
+    }
+
+    num_contacts
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for CylinderConeSupportMapManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if let (Some(sm1), Some(sm2)) = (g1.as_support_map(), g2.as_support_map()) {
+            let contact = query::contact_support_map_support_map_with_params(
+                m1,
+                sm1,
+                m2,
+                sm2,
+                prediction.linear(),
+                &mut self.simplex,
+                self.last_gjk_dir,
+            );
+
+            match contact {
+                GJKResult::ClosestPoints(world1, world2, dir) => {
+                    self.last_gjk_dir = Some(dir);
+                    let contact = Contact::new_wo_depth(world1, world2, dir);
+                    let normal = contact.normal;
+                    let local1 = m1.inverse_transform_point(&world1);
+                    let local2 = m2.inverse_transform_point(&world2);
+                    let mut kinematic = ContactKinematic::new();
+                    kinematic.set_approx1(FeatureId::Unknown, local1, NeighborhoodGeometry::Point);
+                    kinematic.set_approx2(FeatureId::Unknown, local2, NeighborhoodGeometry::Point);
+                    let _ = manifold.push(contact, kinematic, local1, proc1, proc2);
+
+                    if push_rim_contacts(
+                        m1, g1, proc1, m2, g2, proc2, &normal, prediction, manifold, false,
+                    ) == 0
+                    {
+                        let _ = push_rim_contacts(
+                            m2, g2, proc2, m1, g1, proc1, &-normal, prediction, manifold, true,
+                        );
+                    }
+                }
+                GJKResult::NoIntersection(dir) => self.last_gjk_dir = Some(dir),
+                _ => {}
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
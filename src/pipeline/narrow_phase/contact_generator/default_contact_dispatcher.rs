@@ -1,34 +1,60 @@
+#[cfg(feature = "dim2")]
+use crate::pipeline::narrow_phase::{
+    ArcSupportMapManifoldGenerator, PolylinePolylineManifoldGenerator,
+};
 #[cfg(feature = "dim3")]
-use crate::pipeline::narrow_phase::TriMeshTriMeshManifoldGenerator;
+use crate::pipeline::narrow_phase::{
+    BallTriMeshManifoldGenerator, CylinderConeSupportMapManifoldGenerator,
+    PlaneConeManifoldGenerator, TriMeshTriMeshManifoldGenerator,
+};
 use crate::pipeline::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
     CapsuleCapsuleManifoldGenerator, CapsuleShapeManifoldGenerator,
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
-    HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    HeightFieldHeightFieldManifoldGenerator, HeightFieldShapeManifoldGenerator,
+    PlaneBallManifoldGenerator, PlaneConvexPolyhedronManifoldGenerator,
+    PlaneSupportMapManifoldGenerator, SlabSupportMapManifoldGenerator,
+    SupportMapSupportMapManifoldGenerator,
 };
+use crate::query::ContactData;
+#[cfg(feature = "dim2")]
+use crate::shape::{Arc, Polyline};
+use crate::shape::{Ball, Capsule, HeightField, Plane, Shape, Slab};
 #[cfg(feature = "dim3")]
-use crate::shape::TriMesh;
-use crate::shape::{Ball, Capsule, HeightField, Plane, Shape};
+use crate::shape::{Cone, Cylinder, TriMesh};
 use na::RealField;
+use std::marker::PhantomData;
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
-pub struct DefaultContactDispatcher {}
+///
+/// The contact manifold generators it allocates carry one user-defined value of type `C` per
+/// contact.
+pub struct DefaultContactDispatcher<C = ()> {
+    phantom: PhantomData<C>,
+}
 
 impl DefaultContactDispatcher {
     /// Creates a new basic collision dispatcher.
     pub fn new() -> DefaultContactDispatcher {
-        DefaultContactDispatcher {}
+        DefaultContactDispatcher::default()
+    }
+}
+
+impl<C> Default for DefaultContactDispatcher<C> {
+    fn default() -> Self {
+        DefaultContactDispatcher {
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
+impl<N: RealField, C: ContactData> ContactDispatcher<N, C> for DefaultContactDispatcher<C> {
     fn get_contact_algorithm(
         &self,
         a: &dyn Shape<N>,
         b: &dyn Shape<N>,
-    ) -> Option<ContactAlgorithm<N>> {
+    ) -> Option<ContactAlgorithm<N, C>> {
         let a_is_ball = a.is_shape::<Ball<N>>();
         let b_is_ball = b.is_shape::<Ball<N>>();
         let a_is_plane = a.is_shape::<Plane<N>>();
@@ -38,6 +64,22 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         let a_is_heightfield = a.is_shape::<HeightField<N>>();
         let b_is_heightfield = b.is_shape::<HeightField<N>>();
 
+        #[cfg(feature = "dim2")]
+        {
+            let a_is_polyline = a.is_shape::<Polyline<N>>();
+            let b_is_polyline = b.is_shape::<Polyline<N>>();
+
+            if a_is_polyline && b_is_polyline {
+                return Some(Box::new(PolylinePolylineManifoldGenerator::<N>::new()));
+            }
+
+            if a.is_shape::<Arc<N>>() && b.is_support_map() {
+                return Some(Box::new(ArcSupportMapManifoldGenerator::<N>::new(false)));
+            } else if b.is_shape::<Arc<N>>() && a.is_support_map() {
+                return Some(Box::new(ArcSupportMapManifoldGenerator::<N>::new(true)));
+            }
+        }
+
         #[cfg(feature = "dim3")]
         {
             let a_is_trimesh = a.is_shape::<TriMesh<N>>();
@@ -46,30 +88,67 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
             if a_is_trimesh && b_is_trimesh {
                 return Some(Box::new(TriMeshTriMeshManifoldGenerator::<N>::new()));
             }
+
+            if a_is_ball && b_is_trimesh {
+                return Some(Box::new(BallTriMeshManifoldGenerator::<N>::new(false)));
+            } else if b_is_ball && a_is_trimesh {
+                return Some(Box::new(BallTriMeshManifoldGenerator::<N>::new(true)));
+            }
+
+            if a_is_plane && b.is_shape::<Cone<N>>() {
+                return Some(Box::new(PlaneConeManifoldGenerator::<N>::new(false)));
+            } else if b_is_plane && a.is_shape::<Cone<N>>() {
+                return Some(Box::new(PlaneConeManifoldGenerator::<N>::new(true)));
+            }
+
+            let a_is_round = a.is_shape::<Cylinder<N>>() || a.is_shape::<Cone<N>>();
+            let b_is_round = b.is_shape::<Cylinder<N>>() || b.is_shape::<Cone<N>>();
+
+            if (a_is_round && b.is_support_map() && !b_is_capsule)
+                || (b_is_round && a.is_support_map() && !a_is_capsule)
+            {
+                return Some(Box::new(CylinderConeSupportMapManifoldGenerator::<N>::new()));
+            }
         }
 
-        if a_is_heightfield || b_is_heightfield {
-            return Some(Box::new(HeightFieldShapeManifoldGenerator::<N>::new(
+        if a_is_heightfield && b_is_heightfield {
+            return Some(Box::new(
+                HeightFieldHeightFieldManifoldGenerator::<N, C>::new(),
+            ));
+        } else if a_is_heightfield || b_is_heightfield {
+            return Some(Box::new(HeightFieldShapeManifoldGenerator::<N, C>::new(
                 b_is_heightfield,
             )));
         } else if a_is_capsule && b_is_capsule {
             Some(Box::new(CapsuleCapsuleManifoldGenerator::<N>::new()))
         } else if a_is_capsule || b_is_capsule {
-            Some(Box::new(CapsuleShapeManifoldGenerator::<N>::new(
+            Some(Box::new(CapsuleShapeManifoldGenerator::<N, C>::new(
                 b_is_capsule,
             )))
+        } else if a.is_shape::<Slab<N>>() && b.is_support_map() {
+            Some(Box::new(SlabSupportMapManifoldGenerator::<N, C>::new(
+                false,
+            )))
+        } else if b.is_shape::<Slab<N>>() && a.is_support_map() {
+            Some(Box::new(SlabSupportMapManifoldGenerator::<N, C>::new(true)))
         } else if a_is_ball && b_is_ball {
             Some(Box::new(BallBallManifoldGenerator::<N>::new()))
         } else if a_is_plane && b_is_ball {
             Some(Box::new(PlaneBallManifoldGenerator::<N>::new(false)))
         } else if a_is_ball && b_is_plane {
             Some(Box::new(PlaneBallManifoldGenerator::<N>::new(true)))
-        } else if a_is_plane && b.is_support_map() {
+        } else if a_is_plane && b.is_convex_polyhedron() {
             let gen = PlaneConvexPolyhedronManifoldGenerator::<N>::new(false);
             Some(Box::new(gen))
-        } else if b_is_plane && a.is_support_map() {
+        } else if b_is_plane && a.is_convex_polyhedron() {
             let gen = PlaneConvexPolyhedronManifoldGenerator::<N>::new(true);
             Some(Box::new(gen))
+        } else if a_is_plane && b.is_support_map() {
+            let gen = PlaneSupportMapManifoldGenerator::<N>::new(false);
+            Some(Box::new(gen))
+        } else if b_is_plane && a.is_support_map() {
+            let gen = PlaneSupportMapManifoldGenerator::<N>::new(true);
+            Some(Box::new(gen))
         } else if a_is_ball && b.is_convex_polyhedron() {
             let gen = BallConvexPolyhedronManifoldGenerator::<N>::new(false);
             Some(Box::new(gen))
@@ -79,16 +158,20 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         } else if a.is_convex_polyhedron() && b.is_convex_polyhedron() {
             let gen = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
             Some(Box::new(gen))
+        } else if a.is_support_map() && b.is_support_map() {
+            let gen = SupportMapSupportMapManifoldGenerator::new();
+            Some(Box::new(gen))
         } else if a.is_composite_shape() && b.is_composite_shape() {
-            Some(Box::new(
-                CompositeShapeCompositeShapeManifoldGenerator::<N>::new(),
-            ))
+            Some(Box::new(CompositeShapeCompositeShapeManifoldGenerator::<
+                N,
+                C,
+            >::new()))
         } else if a.is_composite_shape() {
-            Some(Box::new(CompositeShapeShapeManifoldGenerator::<N>::new(
+            Some(Box::new(CompositeShapeShapeManifoldGenerator::<N, C>::new(
                 false,
             )))
         } else if b.is_composite_shape() {
-            Some(Box::new(CompositeShapeShapeManifoldGenerator::<N>::new(
+            Some(Box::new(CompositeShapeShapeManifoldGenerator::<N, C>::new(
                 true,
             )))
         } else {
@@ -0,0 +1,148 @@
+use crate::bounding_volume::{self, BoundingVolume};
+use crate::math::Isometry;
+use crate::pipeline::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{ContactData, ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::shape::{HeightField, Shape};
+use crate::utils::DeterministicState;
+use na::RealField;
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Collision detector between two heightfields.
+///
+/// Only the cells of each heightfield lying inside of the intersection of the bounding boxes
+/// of both heightfields are visited. Then, each element of the first heightfield is only
+/// tested against the elements of the second heightfield intersecting its bounding box. This
+/// makes the detection cheap when the heightfields only overlap on a thin seam, e.g., between
+/// two adjacent terrain tiles.
+pub struct HeightFieldHeightFieldManifoldGenerator<N: RealField, C: ContactData = ()> {
+    sub_detectors: HashMap<(usize, usize), (ContactAlgorithm<N, C>, usize), DeterministicState>,
+    timestamp: usize,
+}
+
+impl<N: RealField, C: ContactData> HeightFieldHeightFieldManifoldGenerator<N, C> {
+    /// Creates a new collision detector between two heightfields.
+    pub fn new() -> HeightFieldHeightFieldManifoldGenerator<N, C> {
+        HeightFieldHeightFieldManifoldGenerator {
+            sub_detectors: HashMap::with_hasher(DeterministicState),
+            timestamp: 0,
+        }
+    }
+
+    fn do_update(
+        &mut self,
+        dispatcher: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &HeightField<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &HeightField<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) {
+        self.timestamp += 1;
+
+        let ls_m2 = m1.inverse() * m2;
+        let ls_aabb2 = g2.aabb().transform_by(&ls_m2).loosened(prediction.linear());
+
+        let overlap1 = match g1.aabb().intersection(&ls_aabb2) {
+            Some(overlap) => overlap,
+            None => {
+                self.sub_detectors.clear();
+                return;
+            }
+        };
+
+        let ls_m1 = ls_m2.inverse();
+        let timestamp = self.timestamp;
+        let sub_detectors = &mut self.sub_detectors;
+
+        g1.map_elements_in_local_aabb(&overlap1, &mut |i1, elt1, part_proc1| {
+            let ls_aabb1 = bounding_volume::aabb(elt1, &ls_m1).loosened(prediction.linear());
+
+            let overlap2 = match g2.aabb().intersection(&ls_aabb1) {
+                Some(overlap) => overlap,
+                None => return,
+            };
+
+            g2.map_elements_in_local_aabb(&overlap2, &mut |i2, elt2, part_proc2| {
+                let proc1 = (proc1, part_proc1);
+                let proc2 = (proc2, part_proc2);
+
+                match sub_detectors.entry((i1, i2)) {
+                    Entry::Occupied(mut entry) => {
+                        let ok = entry.get_mut().0.generate_contacts(
+                            dispatcher,
+                            m1,
+                            elt1,
+                            Some(&proc1),
+                            m2,
+                            elt2,
+                            Some(&proc2),
+                            prediction,
+                            manifold,
+                        );
+
+                        if ok {
+                            entry.get_mut().1 = timestamp;
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        if let Some(mut new_detector) = dispatcher.get_contact_algorithm(elt1, elt2)
+                        {
+                            let _ = new_detector.generate_contacts(
+                                dispatcher,
+                                m1,
+                                elt1,
+                                Some(&proc1),
+                                m2,
+                                elt2,
+                                Some(&proc2),
+                                prediction,
+                                manifold,
+                            );
+                            let _ = entry.insert((new_detector, timestamp));
+                        }
+                    }
+                }
+            });
+        });
+
+        // Remove outdated entries.
+        self.sub_detectors
+            .retain(|_, detector| detector.1 == timestamp);
+    }
+}
+
+impl<N: RealField, C: ContactData> Default for HeightFieldHeightFieldManifoldGenerator<N, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for HeightFieldHeightFieldManifoldGenerator<N, C>
+{
+    fn generate_contacts(
+        &mut self,
+        d: &dyn ContactDispatcher<N, C>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if let (Some(hf1), Some(hf2)) = (
+            a.as_shape::<HeightField<N>>(),
+            b.as_shape::<HeightField<N>>(),
+        ) {
+            self.do_update(d, ma, hf1, proc1, mb, hf2, proc2, prediction, manifold);
+            true
+        } else {
+            false
+        }
+    }
+}
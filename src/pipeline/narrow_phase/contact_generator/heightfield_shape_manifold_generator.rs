@@ -1,22 +1,22 @@
 use crate::bounding_volume::{self, BoundingVolume};
 use crate::math::Isometry;
 use crate::pipeline::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
-use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::query::{ContactData, ContactManifold, ContactPrediction, ContactPreprocessor};
 use crate::shape::{HeightField, Shape};
 use crate::utils::DeterministicState;
 use na::{self, RealField};
 use std::collections::{hash_map::Entry, HashMap};
 
 /// Collision detector between an heightfield and another shape.
-pub struct HeightFieldShapeManifoldGenerator<N: RealField> {
-    sub_detectors: HashMap<usize, (ContactAlgorithm<N>, usize), DeterministicState>,
+pub struct HeightFieldShapeManifoldGenerator<N: RealField, C: ContactData = ()> {
+    sub_detectors: HashMap<usize, (ContactAlgorithm<N, C>, usize), DeterministicState>,
     flip: bool,
     timestamp: usize,
 }
 
-impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> HeightFieldShapeManifoldGenerator<N, C> {
     /// Creates a new collision detector between an heightfield and another shape.
-    pub fn new(flip: bool) -> HeightFieldShapeManifoldGenerator<N> {
+    pub fn new(flip: bool) -> HeightFieldShapeManifoldGenerator<N, C> {
         HeightFieldShapeManifoldGenerator {
             sub_detectors: HashMap::with_hasher(DeterministicState),
             flip,
@@ -26,7 +26,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
 
     fn do_update(
         &mut self,
-        dispatcher: &dyn ContactDispatcher<N>,
+        dispatcher: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &HeightField<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -34,7 +34,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
         flip: bool,
     ) {
         self.timestamp += 1;
@@ -123,10 +123,12 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for HeightFieldShapeManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for HeightFieldShapeManifoldGenerator<N, C>
+{
     fn generate_contacts(
         &mut self,
-        d: &dyn ContactDispatcher<N>,
+        d: &dyn ContactDispatcher<N, C>,
         ma: &Isometry<N>,
         a: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -134,7 +136,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for HeightFieldShapeManifoldGener
         b: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             if let Some(hf) = a.as_shape::<HeightField<N>>() {
@@ -151,7 +153,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for HeightFieldShapeManifoldGener
         return false;
     }
 
-    //    fn init_manifold(&self) -> ContactManifold<N> {
+    //    fn init_manifold(&self) -> ContactManifold<N, C> {
     //        let mut res = ContactManifold::new();
     //        res.set_tracking_mode(ContactTrackingMode::FeatureBased);
     //        res
@@ -1,6 +1,10 @@
 //! Persistant collision detection algorithms to compute contact points.
+#[cfg(feature = "dim2")]
+pub use self::arc_support_map_manifold_generator::ArcSupportMapManifoldGenerator;
 pub use self::ball_ball_manifold_generator::BallBallManifoldGenerator;
 pub use self::ball_convex_polyhedron_manifold_generator::BallConvexPolyhedronManifoldGenerator;
+#[cfg(feature = "dim3")]
+pub use self::ball_trimesh_manifold_generator::BallTriMeshManifoldGenerator;
 pub use self::capsule_capsule_manifold_generator::CapsuleCapsuleManifoldGenerator;
 pub use self::capsule_shape_manifold_generator::CapsuleShapeManifoldGenerator;
 pub use self::composite_shape_composite_shape_manifold_generator::CompositeShapeCompositeShapeManifoldGenerator;
@@ -10,16 +14,30 @@ pub use self::contact_manifold_generator::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
 };
 pub use self::convex_polyhedron_convex_polyhedron_manifold_generator::ConvexPolyhedronConvexPolyhedronManifoldGenerator;
+#[cfg(feature = "dim3")]
+pub use self::cylinder_cone_support_map_manifold_generator::CylinderConeSupportMapManifoldGenerator;
 pub use self::default_contact_dispatcher::DefaultContactDispatcher;
+pub use self::heightfield_heightfield_manifold_generator::HeightFieldHeightFieldManifoldGenerator;
 pub use self::heightfield_shape_manifold_generator::HeightFieldShapeManifoldGenerator;
 pub use self::plane_ball_manifold_generator::PlaneBallManifoldGenerator;
+#[cfg(feature = "dim3")]
+pub use self::plane_cone_manifold_generator::PlaneConeManifoldGenerator;
 pub use self::plane_convex_polyhedron_manifold_generator::PlaneConvexPolyhedronManifoldGenerator;
+pub use self::plane_support_map_manifold_generator::PlaneSupportMapManifoldGenerator;
+#[cfg(feature = "dim2")]
+pub use self::polyline_polyline_manifold_generator::PolylinePolylineManifoldGenerator;
+pub use self::slab_support_map_manifold_generator::SlabSupportMapManifoldGenerator;
+pub use self::support_map_support_map_manifold_generator::SupportMapSupportMapManifoldGenerator;
 #[cfg(feature = "dim3")]
 pub use self::trimesh_trimesh_manifold_generator::TriMeshTriMeshManifoldGenerator;
 
 // // FIXME: un-hide this and move everything to a folder.
+#[cfg(feature = "dim2")]
+mod arc_support_map_manifold_generator;
 mod ball_ball_manifold_generator;
 mod ball_convex_polyhedron_manifold_generator;
+#[cfg(feature = "dim3")]
+mod ball_trimesh_manifold_generator;
 mod capsule_capsule_manifold_generator;
 mod capsule_shape_manifold_generator;
 mod composite_shape_composite_shape_manifold_generator;
@@ -27,9 +45,19 @@ mod composite_shape_shape_manifold_generator;
 #[doc(hidden)]
 pub mod contact_manifold_generator;
 mod convex_polyhedron_convex_polyhedron_manifold_generator;
+#[cfg(feature = "dim3")]
+mod cylinder_cone_support_map_manifold_generator;
 mod default_contact_dispatcher;
+mod heightfield_heightfield_manifold_generator;
 mod heightfield_shape_manifold_generator;
 mod plane_ball_manifold_generator;
+#[cfg(feature = "dim3")]
+mod plane_cone_manifold_generator;
 mod plane_convex_polyhedron_manifold_generator;
+mod plane_support_map_manifold_generator;
+#[cfg(feature = "dim2")]
+mod polyline_polyline_manifold_generator;
+mod slab_support_map_manifold_generator;
+mod support_map_support_map_manifold_generator;
 #[cfg(feature = "dim3")]
 mod trimesh_trimesh_manifold_generator;
@@ -1,8 +1,8 @@
 use crate::math::{Isometry, Point};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
-    Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
-    NeighborhoodGeometry,
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
 };
 use crate::shape::{Ball, FeatureId, Plane, Shape};
 use na::{self, RealField};
@@ -27,7 +27,7 @@ impl<N: RealField> PlaneBallManifoldGenerator<N> {
     }
 
     #[inline]
-    fn do_update_to(
+    fn do_update_to<C: ContactData>(
         m1: &Isometry<N>,
         g1: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -35,7 +35,7 @@ impl<N: RealField> PlaneBallManifoldGenerator<N> {
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
         flip: bool,
     ) -> bool {
         if let (Some(plane), Some(ball)) = (g1.as_shape::<Plane<N>>(), g2.as_shape::<Ball<N>>()) {
@@ -83,11 +83,13 @@ impl<N: RealField> PlaneBallManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for PlaneBallManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for PlaneBallManifoldGenerator<N>
+{
     #[inline]
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -95,7 +97,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for PlaneBallManifoldGenerator<N>
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
@@ -0,0 +1,158 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
+};
+use crate::shape::{Cone, FeatureId, Plane, Shape, SupportMap};
+use na::{self, RealField, Unit};
+use std::marker::PhantomData;
+
+/// Number of contacts generated along the rim of a cone resting on its base.
+const NUM_RIM_CONTACTS: usize = 8;
+
+/// Collision detector between a plane and a cone.
+///
+/// The resting mode of the cone is deduced from the angle between its axis and the plane
+/// normal:
+///
+/// * if the cone base is parallel to the plane, several contacts are generated around its rim,
+/// * if one of the cone generatrices is parallel to the plane, two contacts are generated at the
+///   end of this generatrix (at the apex and on the rim),
+/// * otherwise, a single contact is generated at the deepest point of the cone.
+///
+/// The apex of the cone is identified by `FeatureId::Vertex(0)` and its rim by
+/// `FeatureId::Edge(0)`.
+#[derive(Clone)]
+pub struct PlaneConeManifoldGenerator<N: RealField> {
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> PlaneConeManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a plane and a cone.
+    ///
+    /// If `flip` is `true`, the cone is expected to be the first shape.
+    #[inline]
+    pub fn new(flip: bool) -> PlaneConeManifoldGenerator<N> {
+        PlaneConeManifoldGenerator {
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn do_update_to<C: ContactData>(
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+        flip: bool,
+    ) -> bool {
+        if let (Some(plane), Some(cone)) = (g1.as_shape::<Plane<N>>(), g2.as_shape::<Cone<N>>()) {
+            let plane_normal = m1 * plane.normal;
+            let plane_center = Point::from(m1.translation.vector);
+            let cos_angular = if flip {
+                prediction.cos_angular1()
+            } else {
+                prediction.cos_angular2()
+            };
+
+            let mut push = |local2: Point<N>, f2: FeatureId| {
+                let world2 = m2 * local2;
+                let dist = (world2 - plane_center).dot(plane_normal.as_ref());
+
+                if dist <= prediction.linear() {
+                    let world1 = world2 + (-*plane_normal * dist);
+                    let local1 = m1.inverse_transform_point(&world1);
+                    let f1 = FeatureId::Face(0);
+                    let mut kinematic = ContactKinematic::new();
+                    let contact;
+
+                    let approx_plane = NeighborhoodGeometry::Plane(plane.normal);
+                    let approx2 = NeighborhoodGeometry::Point;
+
+                    if !flip {
+                        contact = Contact::new(world1, world2, plane_normal, -dist);
+                        kinematic.set_approx1(f1, local1, approx_plane);
+                        kinematic.set_approx2(f2, local2, approx2);
+                        let _ = manifold.push(contact, kinematic, local2, proc1, proc2);
+                    } else {
+                        contact = Contact::new(world2, world1, -plane_normal, -dist);
+                        kinematic.set_approx1(f2, local2, approx2);
+                        kinematic.set_approx2(f1, local1, approx_plane);
+                        let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+                    }
+                }
+            };
+
+            // The direction toward the plane, in the local-space of the cone.
+            let dir = m2.inverse_transform_unit_vector(&-plane_normal);
+            let apex = Point::new(N::zero(), cone.half_height, N::zero());
+            let horizontal = Vector::new(dir.x, N::zero(), dir.z);
+
+            if -dir.y >= cos_angular {
+                // Resting on the base.
+                for i in 0..NUM_RIM_CONTACTS {
+                    let angle = N::two_pi() * na::convert(i as f64 / NUM_RIM_CONTACTS as f64);
+                    let rim = Point::new(
+                        angle.cos() * cone.radius,
+                        -cone.half_height,
+                        angle.sin() * cone.radius,
+                    );
+                    push(rim, FeatureId::Edge(0));
+                }
+            } else if let Some(horizontal) = Unit::try_new(horizontal, N::default_epsilon()) {
+                let rim = Point::from(*horizontal * cone.radius - Vector::y() * cone.half_height);
+                let side_normal = Unit::new_normalize(
+                    *horizontal * (cone.half_height * na::convert(2.0f64))
+                        + Vector::y() * cone.radius,
+                );
+
+                if dir.dot(&side_normal) >= cos_angular {
+                    // Resting on the side.
+                    push(apex, FeatureId::Vertex(0));
+                    push(rim, FeatureId::Edge(0));
+                } else if cone.local_support_point_toward(&dir) == apex {
+                    push(apex, FeatureId::Vertex(0));
+                } else {
+                    push(rim, FeatureId::Edge(0));
+                }
+            } else {
+                // Resting on the apex.
+                push(apex, FeatureId::Vertex(0));
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for PlaneConeManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if !self.flip {
+            Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
+        } else {
+            Self::do_update_to(m2, g2, proc2, m1, g1, proc1, prediction, manifold, true)
+        }
+    }
+}
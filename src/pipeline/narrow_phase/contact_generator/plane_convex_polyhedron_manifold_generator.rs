@@ -1,13 +1,21 @@
 use crate::math::{Isometry, Point};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
-    Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
-    NeighborhoodGeometry,
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
 };
 use crate::shape::{ConvexPolygonalFeature, FeatureId, Plane, Shape};
-use na::{self, RealField};
+use na::{self, RealField, Unit};
+use std::f64;
 
-/// Collision detector between g1 plane and g1 shape implementing the `SupportMap` trait.
+/// Collision detector between a plane and a shape implementing the `ConvexPolyhedron` trait.
+///
+/// The contacts are generated from the feature of the polyhedron facing the plane: a vertex, an
+/// edge, or a face deviating from the plane by less than the angular prediction (or one degree,
+/// whichever is the largest). This feature is clipped by the half-space at a distance smaller
+/// than the linear prediction from the plane, so a tilted face resting on the plane generates a
+/// contact at each of its vertices close enough to the plane, and at each point where its edges
+/// leave this half-space. No contact is generated if the polyhedron is entirely above the plane.
 #[derive(Clone)]
 pub struct PlaneConvexPolyhedronManifoldGenerator<N: RealField> {
     flip: bool,
@@ -15,8 +23,9 @@ pub struct PlaneConvexPolyhedronManifoldGenerator<N: RealField> {
 }
 
 impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
-    /// Creates g1 new persistent collision detector between g1 plane and g1 shape with g1 support
-    /// mapping function.
+    /// Creates a new persistent collision detector between a plane and a convex polyhedron.
+    ///
+    /// If `flip` is `true`, the convex polyhedron is expected to be the first shape.
     #[inline]
     pub fn new(flip: bool) -> PlaneConvexPolyhedronManifoldGenerator<N> {
         PlaneConvexPolyhedronManifoldGenerator {
@@ -26,7 +35,7 @@ impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
     }
 
     #[inline]
-    fn do_update_to(
+    fn do_update_to<C: ContactData>(
         m1: &Isometry<N>,
         g1: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -35,41 +44,74 @@ impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
         poly_feature: &mut ConvexPolygonalFeature<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
         flip: bool,
     ) -> bool {
         if let (Some(plane), Some(cp)) = (g1.as_shape::<Plane<N>>(), g2.as_convex_polyhedron()) {
             let plane_normal = m1 * plane.normal;
             let plane_center = Point::from(m1.translation.vector);
+            let dist_to_plane = |pt: &Point<N>| (pt - plane_center).dot(plane_normal.as_ref());
+            let deepest = cp.support_point_toward(m2, &-plane_normal);
 
-            cp.support_face_toward(m2, &-plane_normal, poly_feature);
+            if dist_to_plane(&deepest) > prediction.linear() {
+                // The polyhedron is entirely above the plane.
+                return true;
+            }
+
+            let min_angle: N = na::convert(f64::consts::PI / 180.0);
+            let angle = if flip {
+                prediction.angular1()
+            } else {
+                prediction.angular2()
+            };
+
+            cp.support_feature_toward(m2, &-plane_normal, angle.max(min_angle), poly_feature);
+
+            let vertices = poly_feature.vertices();
+            let nedges = match vertices.len() {
+                1 => 0,
+                2 => 1,
+                n => n,
+            };
 
-            for (i, world2) in poly_feature.vertices.iter().enumerate() {
-                let dpt = *world2 - plane_center;
-                let dist = dpt.dot(plane_normal.as_ref());
+            for (i, world2) in vertices.iter().enumerate() {
+                let dist = dist_to_plane(world2);
 
                 if dist <= prediction.linear() {
-                    let world1 = *world2 + (-*plane_normal * dist);
-                    let local1 = m1.inverse_transform_point(&world1);
-                    let local2 = m2.inverse_transform_point(&world2);
-                    let f1 = FeatureId::Face(0);
                     let f2 = poly_feature.vertices_id[i];
-                    let mut kinematic = ContactKinematic::new();
-                    let contact;
-
-                    let approx_plane = NeighborhoodGeometry::Plane(plane.normal);
                     let approx2 = NeighborhoodGeometry::Point;
+                    push_contact(
+                        m1, plane, proc1, m2, proc2, world2, dist, f2, approx2, manifold, flip,
+                    );
+                }
 
-                    if !flip {
-                        contact = Contact::new(world1, *world2, plane_normal, -dist);
-                        kinematic.set_approx1(f1, local1, approx_plane);
-                        kinematic.set_approx2(f2, local2, approx2);
-                        let _ = manifold.push(contact, kinematic, local2, proc1, proc2);
-                    } else {
-                        contact = Contact::new(*world2, world1, -plane_normal, -dist);
-                        kinematic.set_approx1(f2, local2, approx2);
-                        kinematic.set_approx2(f1, local1, approx_plane);
-                        let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+                if i < nedges {
+                    let next2 = &vertices[(i + 1) % vertices.len()];
+                    let next_dist = dist_to_plane(next2);
+
+                    // Clip the edge where it leaves the half-space of the contact region.
+                    if (dist <= prediction.linear()) != (next_dist <= prediction.linear()) {
+                        let t = (prediction.linear() - dist) / (next_dist - dist);
+                        let clipped2 = world2 + (next2 - world2) * t;
+                        let local_dir = m2.inverse_transform_vector(&(next2 - world2));
+                        let approx2 = match Unit::try_new(local_dir, N::default_epsilon()) {
+                            Some(dir) => NeighborhoodGeometry::Line(dir),
+                            None => NeighborhoodGeometry::Point,
+                        };
+                        let f2 = edge_id(poly_feature, i);
+                        push_contact(
+                            m1,
+                            plane,
+                            proc1,
+                            m2,
+                            proc2,
+                            &clipped2,
+                            prediction.linear(),
+                            f2,
+                            approx2,
+                            manifold,
+                            flip,
+                        );
                     }
                 }
             }
@@ -81,10 +123,65 @@ impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for PlaneConvexPolyhedronManifoldGenerator<N> {
+// The identifier of the edge from the `i`-th vertex of `feature` to the next one.
+#[cfg(feature = "dim2")]
+fn edge_id<N: RealField>(feature: &ConvexPolygonalFeature<N>, _: usize) -> FeatureId {
+    // A 2D polygonal feature with an edge is a face.
+    feature.feature_id
+}
+
+// The identifier of the edge from the `i`-th vertex of `feature` to the next one.
+#[cfg(feature = "dim3")]
+fn edge_id<N: RealField>(feature: &ConvexPolygonalFeature<N>, i: usize) -> FeatureId {
+    feature
+        .edges_id
+        .get(i)
+        .cloned()
+        .unwrap_or(feature.feature_id)
+}
+
+// Adds to `manifold` the contact between the plane and the point `world2` of the polyhedron
+// lying at the distance `dist` from the plane.
+fn push_contact<N: RealField, C: ContactData>(
+    m1: &Isometry<N>,
+    plane: &Plane<N>,
+    proc1: Option<&dyn ContactPreprocessor<N>>,
+    m2: &Isometry<N>,
+    proc2: Option<&dyn ContactPreprocessor<N>>,
+    world2: &Point<N>,
+    dist: N,
+    f2: FeatureId,
+    approx2: NeighborhoodGeometry<N>,
+    manifold: &mut ContactManifold<N, C>,
+    flip: bool,
+) {
+    let plane_normal = m1 * plane.normal;
+    let world1 = world2 + (-*plane_normal * dist);
+    let local1 = m1.inverse_transform_point(&world1);
+    let local2 = m2.inverse_transform_point(world2);
+    let f1 = FeatureId::Face(0);
+    let approx_plane = NeighborhoodGeometry::Plane(plane.normal);
+    let mut kinematic = ContactKinematic::new();
+
+    if !flip {
+        let contact = Contact::new(world1, *world2, plane_normal, -dist);
+        kinematic.set_approx1(f1, local1, approx_plane);
+        kinematic.set_approx2(f2, local2, approx2);
+        let _ = manifold.push(contact, kinematic, local2, proc1, proc2);
+    } else {
+        let contact = Contact::new(*world2, world1, -plane_normal, -dist);
+        kinematic.set_approx1(f2, local2, approx2);
+        kinematic.set_approx2(f1, local1, approx_plane);
+        let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for PlaneConvexPolyhedronManifoldGenerator<N>
+{
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -92,7 +189,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for PlaneConvexPolyhedronManifold
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if !self.flip {
             Self::do_update_to(
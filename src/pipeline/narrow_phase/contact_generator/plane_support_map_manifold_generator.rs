@@ -0,0 +1,232 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
+};
+use crate::shape::{FeatureId, Plane, Shape, SupportMap};
+use na::{self, RealField, Unit};
+use std::f64;
+use std::marker::PhantomData;
+
+/// Number of directions along which the extent of the contact region is estimated.
+#[cfg(feature = "dim3")]
+const NUM_TANGENT_DIRECTIONS: usize = 8;
+
+/// Maximum number of contacts sampled between the deepest point and each end of the contact
+/// region.
+const MAX_SAMPLES_PER_DIRECTION: usize = 8;
+
+/// Collision detector between a plane and a curved shape implementing the `SupportMap` trait.
+///
+/// The contact region is the part of the shape's surface with a normal deviating from the plane
+/// normal by less than the angular prediction (or one degree, whichever is the largest). Its end
+/// along a direction tangent to the plane is given by the support point toward the plane tilted
+/// by this angle along that tangent. The rise of this end above the deepest point, compared to
+/// its tangential offset, tells if the surface is curved or flat along this tangent:
+///
+/// * along curved directions, the contact region shrinks to the deepest point, so the shape can
+///   roll freely,
+/// * along flat directions, e.g., along the generatrix of a cylinder lying on its side, contacts
+///   are sampled from the deepest point to the end of the contact region. The samples are spaced
+///   by the largest radius of curvature estimated along the curved directions, so longer contacts
+///   get more points.
+///
+/// Shapes implementing the `ConvexPolyhedron` trait should use a
+/// `PlaneConvexPolyhedronManifoldGenerator` instead.
+#[derive(Clone)]
+pub struct PlaneSupportMapManifoldGenerator<N: RealField> {
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> PlaneSupportMapManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a plane and a support-mapped shape.
+    ///
+    /// If `flip` is `true`, the support-mapped shape is expected to be the first shape.
+    #[inline]
+    pub fn new(flip: bool) -> PlaneSupportMapManifoldGenerator<N> {
+        PlaneSupportMapManifoldGenerator {
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn do_update_to<C: ContactData>(
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+        flip: bool,
+    ) -> bool {
+        if let (Some(plane), Some(sm)) = (g1.as_shape::<Plane<N>>(), g2.as_support_map()) {
+            let plane_normal = m1 * plane.normal;
+            let plane_center = Point::from(m1.translation.vector);
+            let min_angle: N = na::convert(f64::consts::PI / 180.0);
+            let angle = if flip {
+                prediction.angular1()
+            } else {
+                prediction.angular2()
+            };
+
+            // The direction toward the plane, in the local-space of the shape.
+            let dir = m2.inverse_transform_unit_vector(&-plane_normal);
+
+            for local2 in contact_samples(sm, &dir, angle.max(min_angle)) {
+                let world2 = m2 * local2;
+                let dist = (world2 - plane_center).dot(plane_normal.as_ref());
+
+                if dist <= prediction.linear() {
+                    let world1 = world2 + (-*plane_normal * dist);
+                    let local1 = m1.inverse_transform_point(&world1);
+                    let f1 = FeatureId::Face(0);
+                    let f2 = FeatureId::Unknown;
+                    let mut kinematic = ContactKinematic::new();
+                    let contact;
+
+                    let approx_plane = NeighborhoodGeometry::Plane(plane.normal);
+                    let approx2 = NeighborhoodGeometry::Point;
+
+                    if !flip {
+                        contact = Contact::new(world1, world2, plane_normal, -dist);
+                        kinematic.set_approx1(f1, local1, approx_plane);
+                        kinematic.set_approx2(f2, local2, approx2);
+                        let _ = manifold.push(contact, kinematic, local2, proc1, proc2);
+                    } else {
+                        contact = Contact::new(world2, world1, -plane_normal, -dist);
+                        kinematic.set_approx1(f2, local2, approx2);
+                        kinematic.set_approx2(f1, local1, approx_plane);
+                        let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
+                    }
+                }
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// The directions tangent to the plane with the normal `dir`.
+fn tangent_directions<N: RealField>(dir: &Unit<Vector<N>>) -> Vec<Vector<N>> {
+    let mut basis = Vec::new();
+    Vector::orthonormal_subspace_basis(&[dir.into_inner()], |v| {
+        basis.push(*v);
+        true
+    });
+
+    #[cfg(feature = "dim2")]
+    {
+        vec![basis[0], -basis[0]]
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        (0..NUM_TANGENT_DIRECTIONS)
+            .map(|i| {
+                let angle: N = N::two_pi() * na::convert(i as f64 / NUM_TANGENT_DIRECTIONS as f64);
+                basis[0] * angle.cos() + basis[1] * angle.sin()
+            })
+            .collect()
+    }
+}
+
+// Local points of `shape` sampling its contact region with a plane in the direction `dir`.
+fn contact_samples<N: RealField>(
+    shape: &dyn SupportMap<N>,
+    dir: &Unit<Vector<N>>,
+    angle: N,
+) -> Vec<Point<N>> {
+    let (sin, cos) = angle.sin_cos();
+    let deepest = shape.local_support_point_toward(dir);
+    let eps = N::default_epsilon().sqrt();
+    // A circular arc tilted by `angle` rises by `tan(angle / 2)` times its tangential offset.
+    let arc_slope = (angle * na::convert(0.5)).tan();
+    // Flat ends seen from the deepest point in directions closer than this are merged.
+    let merge_cos = (N::pi() / na::convert(8.0)).cos();
+    // The flat ends, with their rise above the deepest point.
+    let mut flat_ends: Vec<(Point<N>, N)> = Vec::new();
+    let mut max_radius = N::zero();
+
+    for tangent in tangent_directions(dir) {
+        let end = shape.local_support_point(&(dir.into_inner() * cos + tangent * sin));
+        let offset = (end - deepest).dot(&tangent);
+        let rise = (deepest - end).dot(dir);
+
+        if offset <= eps {
+            // Sharp edge: no information about the curvature.
+            continue;
+        }
+
+        if rise > offset * arc_slope * na::convert(0.5) {
+            // The radius of the circle with a point at this offset and tilted by `angle`.
+            max_radius = max_radius.max(offset / sin);
+            continue;
+        }
+
+        // Tangents mixing flat and curved directions give nearly the same end as the flat
+        // direction, but raised by the curvature. Keep the lowest one.
+        let end_dir = (end - deepest).normalize();
+        let similar = flat_ends
+            .iter()
+            .position(|(other, _)| (other - deepest).normalize().dot(&end_dir) >= merge_cos);
+
+        match similar {
+            Some(i) => {
+                if rise < flat_ends[i].1 {
+                    flat_ends[i] = (end, rise)
+                }
+            }
+            None => flat_ends.push((end, rise)),
+        }
+    }
+
+    let mut samples = vec![deepest];
+    let max_samples: N = na::convert(MAX_SAMPLES_PER_DIRECTION as f64);
+
+    for (end, _) in flat_ends {
+        let length = na::distance(&deepest, &end);
+        let nsamples = if max_radius > N::zero() {
+            (length / max_radius).ceil().min(max_samples)
+        } else {
+            N::one()
+        };
+        let nsamples = na::try_convert::<N, f64>(nsamples).unwrap_or(1.0).max(1.0) as usize;
+
+        for i in 1..=nsamples {
+            let t: N = na::convert(i as f64 / nsamples as f64);
+            samples.push(deepest + (end - deepest) * t);
+        }
+    }
+
+    samples
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for PlaneSupportMapManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if !self.flip {
+            Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
+        } else {
+            Self::do_update_to(m2, g2, proc2, m1, g1, proc1, prediction, manifold, true)
+        }
+    }
+}
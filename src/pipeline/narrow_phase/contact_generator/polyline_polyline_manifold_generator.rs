@@ -0,0 +1,236 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::BVH;
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    self, visitors::AABBSetsInterferencesCollector, Contact, ContactData, ContactKinematic,
+    ContactManifold, ContactPrediction, ContactPreprocessor, ContactTrackingMode,
+    NeighborhoodGeometry, PointQueryWithLocation,
+};
+use crate::shape::{FeatureId, Polyline, Segment, SegmentPointLocation, Shape};
+use na::{RealField, Unit};
+use std::marker::PhantomData;
+
+/// Collision detector between two polylines.
+///
+/// Contacts are computed between each pair of nearby segments. The feature identifiers stored
+/// on the contact kinematic are polyline features: `FeatureId::Vertex(i)` for the `i`-th point
+/// of the polyline, and `FeatureId::Face(i)` (or `FeatureId::Face(i + nedges)` for its backface)
+/// for the `i`-th segment. Use `Polyline::edge_containing_feature` to retrieve the index of the
+/// segment a contact lies on.
+pub struct PolylinePolylineManifoldGenerator<N: RealField> {
+    interferences: Vec<(usize, usize)>,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> PolylinePolylineManifoldGenerator<N> {
+    /// Creates a new collision detector between two polylines.
+    pub fn new() -> PolylinePolylineManifoldGenerator<N> {
+        PolylinePolylineManifoldGenerator {
+            interferences: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Computes the contacts between the non-adjacent segments of a single polyline.
+    ///
+    /// This is the self-collision variant of this contact generator. Two segments sharing
+    /// a vertex are never tested against each other. Both sides of each contact refer to
+    /// features of `polyline`.
+    pub fn generate_self_contacts<C: ContactData>(
+        &mut self,
+        m: &Isometry<N>,
+        polyline: &Polyline<N>,
+        proc: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) {
+        let identity = Isometry::identity();
+        let abs_rot = identity.rotation.to_rotation_matrix().matrix().abs();
+
+        {
+            let mut visitor = AABBSetsInterferencesCollector::new(
+                prediction.linear(),
+                &identity,
+                &abs_rot,
+                &mut self.interferences,
+            );
+            polyline.bvt().visit_bvtt(polyline.bvt(), &mut visitor);
+        }
+
+        let edges = polyline.edges();
+
+        for (i1, i2) in self.interferences.drain(..) {
+            if i1 >= i2 {
+                // Each pair is visited twice, and a segment always touches itself.
+                continue;
+            }
+
+            let idx1 = edges[i1].indices;
+            let idx2 = edges[i2].indices;
+
+            if idx1.x == idx2.x || idx1.x == idx2.y || idx1.y == idx2.x || idx1.y == idx2.y {
+                // Adjacent segments.
+                continue;
+            }
+
+            compute_segments_contact(
+                &identity, m, polyline, i1, proc, m, polyline, i2, proc, prediction, manifold,
+            );
+        }
+    }
+}
+
+impl<N: RealField> Default for PolylinePolylineManifoldGenerator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn polyline_feature<N: RealField>(
+    polyline: &Polyline<N>,
+    edge_id: usize,
+    seg: &Segment<N>,
+    location: &SegmentPointLocation<N>,
+    local_dir: &Unit<Vector<N>>,
+) -> (FeatureId, Point<N>, NeighborhoodGeometry<N>) {
+    match location {
+        SegmentPointLocation::OnVertex(i) => {
+            let ip = polyline.edges()[edge_id].indices[*i];
+            (
+                FeatureId::Vertex(ip),
+                polyline.points()[ip],
+                NeighborhoodGeometry::Point,
+            )
+        }
+        SegmentPointLocation::OnEdge(_) => {
+            if let Some(normal) = seg.normal() {
+                if normal.dot(local_dir) >= N::zero() {
+                    (
+                        FeatureId::Face(edge_id),
+                        seg.a,
+                        NeighborhoodGeometry::Plane(normal),
+                    )
+                } else {
+                    (
+                        FeatureId::Face(edge_id + polyline.edges().len()),
+                        seg.a,
+                        NeighborhoodGeometry::Plane(-normal),
+                    )
+                }
+            } else {
+                (FeatureId::Face(edge_id), seg.a, NeighborhoodGeometry::Point)
+            }
+        }
+    }
+}
+
+fn compute_segments_contact<N: RealField, C: ContactData>(
+    m12: &Isometry<N>,
+    m1: &Isometry<N>,
+    polyline1: &Polyline<N>,
+    i1: usize,
+    proc1: Option<&dyn ContactPreprocessor<N>>,
+    m2: &Isometry<N>,
+    polyline2: &Polyline<N>,
+    i2: usize,
+    proc2: Option<&dyn ContactPreprocessor<N>>,
+    prediction: &ContactPrediction<N>,
+    manifold: &mut ContactManifold<N, C>,
+) {
+    let seg1 = polyline1.segment_at(i1);
+    let seg2 = polyline2.segment_at(i2);
+    // The second segment expressed in the local-space of the first polyline.
+    let ls_seg2 = seg2.transformed(m12);
+
+    let locs = query::closest_points_segment_segment_with_locations_nD(
+        (&seg1.a, &seg1.b),
+        (&ls_seg2.a, &ls_seg2.b),
+    );
+    let p1 = seg1.point_at(&locs.0);
+    let p2 = ls_seg2.point_at(&locs.1);
+
+    let contact;
+    let (loc1, loc2);
+
+    if let Some((dir, dist)) = Unit::try_new_and_get(p2 - p1, N::default_epsilon()) {
+        if dist > prediction.linear() {
+            return;
+        }
+
+        contact = Contact::new(m1 * p1, m1 * p2, m1 * dir, -dist);
+        loc1 = locs.0;
+        loc2 = locs.1;
+    } else {
+        // The segments intersect: fall back to the penetration depth computed by the EPA.
+        if let Some(c) = query::contact_support_map_support_map(m1, &seg1, m2, &seg2, N::zero()) {
+            loc1 = seg1.project_point_with_location(m1, &c.world1, true).1;
+            loc2 = seg2.project_point_with_location(m2, &c.world2, true).1;
+            contact = c;
+        } else {
+            return;
+        }
+    }
+
+    let local_dir1 = m1.inverse_transform_unit_vector(&contact.normal);
+    let local_dir2 = m2.inverse_transform_unit_vector(&-contact.normal);
+    let (f1, pt1, geom1) = polyline_feature(polyline1, i1, &seg1, &loc1, &local_dir1);
+    let (f2, pt2, geom2) = polyline_feature(polyline2, i2, &seg2, &loc2, &local_dir2);
+
+    let mut kinematic = ContactKinematic::new();
+    kinematic.set_approx1(f1, pt1, geom1);
+    kinematic.set_approx2(f2, pt2, geom2);
+
+    let tracking_pt = m1.inverse_transform_point(&contact.world1);
+    let _ = manifold.push(contact, kinematic, tracking_pt, proc1, proc2);
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for PolylinePolylineManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if let (Some(polyline1), Some(polyline2)) =
+            (g1.as_shape::<Polyline<N>>(), g2.as_shape::<Polyline<N>>())
+        {
+            let m12 = m1.inverse() * m2;
+            // For transforming AABBs from polyline2 in the local space of polyline1.
+            let m12_abs_rot = m12.rotation.to_rotation_matrix().matrix().abs();
+
+            {
+                let mut visitor = AABBSetsInterferencesCollector::new(
+                    prediction.linear(),
+                    &m12,
+                    &m12_abs_rot,
+                    &mut self.interferences,
+                );
+                polyline1.bvt().visit_bvtt(polyline2.bvt(), &mut visitor);
+            }
+
+            for (i1, i2) in self.interferences.drain(..) {
+                compute_segments_contact(
+                    &m12, m1, polyline1, i1, proc1, m2, polyline2, i2, proc2, prediction, manifold,
+                );
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn init_manifold(&self) -> ContactManifold<N, C> {
+        let mut res = ContactManifold::new();
+        res.set_tracking_mode(ContactTrackingMode::FeatureBased);
+        res
+    }
+}
@@ -0,0 +1,100 @@
+use crate::math::{Isometry, Point};
+use crate::pipeline::narrow_phase::{
+    ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
+};
+use crate::query::{ContactData, ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::shape::{Plane, Shape, Slab, SupportMap};
+use na::RealField;
+
+/// Collision detector between a slab and a shape implementing the `SupportMap` trait.
+///
+/// The contacts are generated against the bounding plane of the slab the shape penetrates the
+/// least, using the contact generator returned by the dispatcher for that plane and the shape.
+pub struct SlabSupportMapManifoldGenerator<N: RealField, C: ContactData = ()> {
+    sub_detectors: [Option<ContactAlgorithm<N, C>>; 2],
+    flip: bool,
+}
+
+impl<N: RealField, C: ContactData> SlabSupportMapManifoldGenerator<N, C> {
+    /// Creates a new collision detector between a slab and a support-mapped shape.
+    ///
+    /// If `flip` is `true`, the support-mapped shape is expected to be the first shape.
+    pub fn new(flip: bool) -> SlabSupportMapManifoldGenerator<N, C> {
+        SlabSupportMapManifoldGenerator {
+            sub_detectors: [None, None],
+            flip,
+        }
+    }
+}
+
+// The penetration depth of a support-mapped shape into the half-space bounded by a plane.
+fn plane_depth<N: RealField>(
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+    m: &Isometry<N>,
+    shape: &dyn SupportMap<N>,
+) -> N {
+    let normal = mplane * plane.normal;
+    let center = Point::from(mplane.translation.vector);
+    let deepest = shape.support_point_toward(m, &-normal);
+
+    normal.dot(&(center - deepest))
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for SlabSupportMapManifoldGenerator<N, C>
+{
+    fn generate_contacts(
+        &mut self,
+        dispatcher: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        let (m1, g1, proc1, m2, g2, proc2) = if !self.flip {
+            (m1, g1, proc1, m2, g2, proc2)
+        } else {
+            (m2, g2, proc2, m1, g1, proc1)
+        };
+
+        let (slab, other) = match (g1.as_shape::<Slab<N>>(), g2.as_support_map()) {
+            (Some(slab), Some(other)) => (slab, other),
+            _ => return false,
+        };
+
+        let [(lm_a, plane_a), (lm_b, plane_b)] = slab.planes();
+        let (m_a, m_b) = (m1 * lm_a, m1 * lm_b);
+        let depth_a = plane_depth(&m_a, &plane_a, m2, other);
+        let depth_b = plane_depth(&m_b, &plane_b, m2, other);
+
+        // Move the shape out of the slab through the closest bounding plane.
+        let (i, mplane, plane) = if depth_a <= depth_b {
+            (0, m_a, plane_a)
+        } else {
+            (1, m_b, plane_b)
+        };
+
+        if self.sub_detectors[i].is_none() {
+            self.sub_detectors[i] = if !self.flip {
+                dispatcher.get_contact_algorithm(&plane, g2)
+            } else {
+                dispatcher.get_contact_algorithm(g2, &plane)
+            };
+        }
+
+        match &mut self.sub_detectors[i] {
+            Some(detector) if !self.flip => detector.generate_contacts(
+                dispatcher, &mplane, &plane, proc1, m2, g2, proc2, prediction, manifold,
+            ),
+            Some(detector) => detector.generate_contacts(
+                dispatcher, m2, g2, proc2, &mplane, &plane, proc1, prediction, manifold,
+            ),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::math::{Isometry, Vector};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::algorithms::gjk::GJKResult;
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{
+    self, Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
+};
+use crate::shape::{FeatureId, RoundShape, Shape, SupportMap};
+use na::{RealField, Unit};
+
+/// Collision detector between two shapes implementing the `SupportMap` trait.
+///
+/// Only the deepest contact is generated. The contact between a `RoundShape` and another shape is
+/// computed from the shape it rounds, and then moved outward by its radius.
+#[derive(Clone)]
+pub struct SupportMapSupportMapManifoldGenerator<N: RealField> {
+    simplex: VoronoiSimplex<N>,
+    last_gjk_dir: Option<Unit<Vector<N>>>,
+}
+
+impl<N: RealField> SupportMapSupportMapManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between two support-mapped shapes.
+    pub fn new() -> Self {
+        SupportMapSupportMapManifoldGenerator {
+            simplex: VoronoiSimplex::new(),
+            last_gjk_dir: None,
+        }
+    }
+}
+
+impl<N: RealField> Default for SupportMapSupportMapManifoldGenerator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The support map of `shape` without its rounded borders, and the radius of those borders.
+fn unrounded<N: RealField>(shape: &dyn Shape<N>) -> Option<(&dyn SupportMap<N>, N)> {
+    match shape.as_shape::<RoundShape<N>>() {
+        Some(round) => Some((round.shape().as_support_map()?, round.radius())),
+        None => Some((shape.as_support_map()?, N::zero())),
+    }
+}
+
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for SupportMapSupportMapManifoldGenerator<N>
+{
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N, C>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N, C>,
+    ) -> bool {
+        if let (Some((sm1, radius1)), Some((sm2, radius2))) = (unrounded(g1), unrounded(g2)) {
+            let contact = query::contact_support_map_support_map_with_params(
+                m1,
+                sm1,
+                m2,
+                sm2,
+                prediction.linear() + radius1 + radius2,
+                &mut self.simplex,
+                self.last_gjk_dir,
+            );
+
+            match contact {
+                GJKResult::ClosestPoints(world1, world2, dir) => {
+                    self.last_gjk_dir = Some(dir);
+                    let local1 = m1.inverse_transform_point(&world1);
+                    let local2 = m2.inverse_transform_point(&world2);
+                    let contact = Contact::new_wo_depth(
+                        world1 + *dir * radius1,
+                        world2 - *dir * radius2,
+                        dir,
+                    );
+                    let mut kinematic = ContactKinematic::new();
+                    kinematic.set_approx1(FeatureId::Unknown, local1, NeighborhoodGeometry::Point);
+                    kinematic.set_approx2(FeatureId::Unknown, local2, NeighborhoodGeometry::Point);
+                    kinematic.set_dilation1(radius1);
+                    kinematic.set_dilation2(radius2);
+                    let _ = manifold.push(contact, kinematic, local1, proc1, proc2);
+                }
+                GJKResult::NoIntersection(dir) => self.last_gjk_dir = Some(dir),
+                _ => {}
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
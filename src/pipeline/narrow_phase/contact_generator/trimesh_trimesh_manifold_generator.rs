@@ -1,8 +1,9 @@
 use crate::math::{Isometry, Vector};
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
-    self, visitors::AABBSetsInterferencesCollector, Contact, ContactKinematic, ContactManifold,
-    ContactPrediction, ContactPreprocessor, ContactTrackingMode, NeighborhoodGeometry,
+    self, visitors::AABBSetsInterferencesCollector, Contact, ContactData, ContactKinematic,
+    ContactManifold, ContactPrediction, ContactPreprocessor, ContactTrackingMode,
+    NeighborhoodGeometry,
 };
 use crate::shape::{
     ClippingCache, CompositeShape, ConvexPolygonalFeature, FeatureId, Segment,
@@ -11,7 +12,16 @@ use crate::shape::{
 use na::{self, RealField, Unit};
 use std::mem;
 
-/// Collision detector between a concave shape and another shape.
+/// The maximum number of contacts generated between two triangle meshes.
+const MAX_CONTACTS: usize = 32;
+
+/// Collision detector between two triangle meshes.
+///
+/// The BVTs of both meshes are traversed simultaneously to find the pairs of triangles close to
+/// each other, and each pair generates its contacts into the same manifold. Contacts generated
+/// twice at the shared edges and vertices of adjacent triangles are merged. If more than 32
+/// contacts remain, only the deepest one and a subset of the others spread as far as possible
+/// from each other are kept.
 pub struct TriMeshTriMeshManifoldGenerator<N: RealField> {
     clip_cache: ClippingCache<N>,
     new_contacts: Vec<(Contact<N>, FeatureId, FeatureId)>,
@@ -34,7 +44,7 @@ impl<N: RealField> TriMeshTriMeshManifoldGenerator<N> {
 }
 
 impl<N: RealField> TriMeshTriMeshManifoldGenerator<N> {
-    fn compute_faces_closest_points(
+    fn compute_faces_closest_points<C: ContactData>(
         &mut self,
         m12: &Isometry<N>,
         m21: &Isometry<N>,
@@ -47,7 +57,7 @@ impl<N: RealField> TriMeshTriMeshManifoldGenerator<N> {
         i2: usize,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) {
         let face1 = &mesh1.faces()[i1];
         let face2 = &mesh2.faces()[i2];
@@ -515,10 +525,12 @@ impl<N: RealField> TriMeshTriMeshManifoldGenerator<N> {
     }
 }
 
-impl<N: RealField> ContactManifoldGenerator<N> for TriMeshTriMeshManifoldGenerator<N> {
+impl<N: RealField, C: ContactData> ContactManifoldGenerator<N, C>
+    for TriMeshTriMeshManifoldGenerator<N>
+{
     fn generate_contacts(
         &mut self,
-        _: &dyn ContactDispatcher<N>,
+        _: &dyn ContactDispatcher<N, C>,
         m1: &Isometry<N>,
         g1: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
@@ -526,7 +538,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for TriMeshTriMeshManifoldGenerat
         g2: &dyn Shape<N>,
         proc2: Option<&dyn ContactPreprocessor<N>>,
         prediction: &ContactPrediction<N>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) -> bool {
         if let (Some(mesh1), Some(mesh2)) =
             (g1.as_shape::<TriMesh<N>>(), g2.as_shape::<TriMesh<N>>())
@@ -557,15 +569,75 @@ impl<N: RealField> ContactManifoldGenerator<N> for TriMeshTriMeshManifoldGenerat
             }
             self.interferences = interferences;
 
+            // Adjacent triangles generate the same contacts at their shared edges and vertices.
+            let _ = manifold.sanitize(N::default_epsilon().sqrt());
+            reduce_manifold(manifold, MAX_CONTACTS);
+
             true
         } else {
             false
         }
     }
 
-    fn init_manifold(&self) -> ContactManifold<N> {
+    fn init_manifold(&self) -> ContactManifold<N, C> {
         let mut res = ContactManifold::new();
         res.set_tracking_mode(ContactTrackingMode::FeatureBased);
         res
     }
 }
+
+// Removes contacts from `manifold` until at most `max_contacts` remain, keeping the deepest one
+// first, then iteratively the contact farthest from all the contacts kept so far.
+fn reduce_manifold<N: RealField, C: ContactData>(
+    manifold: &mut ContactManifold<N, C>,
+    max_contacts: usize,
+) {
+    if manifold.len() <= max_contacts {
+        return;
+    }
+
+    let contacts: Vec<_> = manifold
+        .contacts()
+        .map(|c| (c.contact.world1, c.contact.depth))
+        .collect();
+    let mut deepest = 0;
+
+    for (i, c) in contacts.iter().enumerate() {
+        if c.1 > contacts[deepest].1 {
+            deepest = i;
+        }
+    }
+
+    let mut dists: Vec<N> = contacts
+        .iter()
+        .map(|c| na::distance_squared(&c.0, &contacts[deepest].0))
+        .collect();
+    let mut keep = vec![false; contacts.len()];
+    keep[deepest] = true;
+    let mut nkept = 1;
+
+    while nkept < max_contacts {
+        let mut farthest = 0;
+
+        for (i, dist) in dists.iter().enumerate() {
+            if *dist > dists[farthest] {
+                farthest = i;
+            }
+        }
+
+        let pt = contacts[farthest].0;
+        keep[farthest] = true;
+        nkept += 1;
+
+        for (dist, other) in dists.iter_mut().zip(contacts.iter()) {
+            *dist = dist.min(na::distance_squared(&other.0, &pt));
+        }
+    }
+
+    // `retain` visits the contacts in the same order as `contacts`.
+    let mut i = 0;
+    let _ = manifold.retain(|_| {
+        i += 1;
+        keep[i - 1]
+    });
+}
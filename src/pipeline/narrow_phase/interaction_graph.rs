@@ -4,9 +4,10 @@ use petgraph::visit::EdgeRef;
 
 use crate::pipeline::narrow_phase::{ContactAlgorithm, ProximityAlgorithm, ProximityDetector};
 use crate::pipeline::object::CollisionObjectHandle;
-use crate::query::{ContactManifold, Proximity};
+use crate::query::{ContactData, ContactManifold, Proximity};
 use petgraph::prelude::EdgeIndex;
 use petgraph::Direction;
+use std::fmt::Write;
 
 /// Index of a node of the interaction graph.
 pub type CollisionObjectGraphIndex = NodeIndex<usize>;
@@ -14,12 +15,12 @@ pub type CollisionObjectGraphIndex = NodeIndex<usize>;
 pub type TemporaryInteractionIndex = EdgeIndex<usize>;
 
 /// An interaction between two collision objects.
-pub enum Interaction<N: RealField> {
+pub enum Interaction<N: RealField, C: ContactData = ()> {
     /// A potential contact between two collision objects.
     ///
     /// Generated only for pairs of collision objects both configured
     /// with a `GeometricQueryType::Contact(..)`.
-    Contact(ContactAlgorithm<N>, ContactManifold<N>),
+    Contact(ContactAlgorithm<N, C>, ContactManifold<N, C>),
     /// A proximity between two collision objects.
     ///
     /// Generated only for pairs of collision objects with at least one configured
@@ -27,7 +28,7 @@ pub enum Interaction<N: RealField> {
     Proximity(ProximityAlgorithm<N>, Proximity),
 }
 
-impl<N: RealField> Interaction<N> {
+impl<N: RealField, C: ContactData> Interaction<N, C> {
     /// Checks if this interaction is a potential contact interaction.
     pub fn is_contact(&self) -> bool {
         match self {
@@ -46,11 +47,11 @@ impl<N: RealField> Interaction<N> {
 }
 
 /// A graph where nodes are collision objects and edges are contact or proximity algorithms.
-pub struct InteractionGraph<N: RealField, Handle: CollisionObjectHandle>(
-    pub(crate) UnGraph<Handle, Interaction<N>, usize>,
+pub struct InteractionGraph<N: RealField, Handle: CollisionObjectHandle, C: ContactData = ()>(
+    pub(crate) UnGraph<Handle, Interaction<N, C>, usize>,
 );
 
-impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
+impl<N: RealField, Handle: CollisionObjectHandle, C: ContactData> InteractionGraph<N, Handle, C> {
     /// Creates a new empty collection of collision objects.
     pub fn new() -> Self {
         InteractionGraph(UnGraph::with_capacity(10, 10))
@@ -88,7 +89,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
     pub fn interaction_pairs(
         &self,
         effective_only: bool,
-    ) -> impl Iterator<Item = (Handle, Handle, &Interaction<N>)> {
+    ) -> impl Iterator<Item = (Handle, Handle, &Interaction<N, C>)> {
         self.0.edge_references().filter_map(move |e| {
             let interaction = e.weight();
 
@@ -107,7 +108,14 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
     pub fn contact_pairs(
         &self,
         effective_only: bool,
-    ) -> impl Iterator<Item = (Handle, Handle, &ContactAlgorithm<N>, &ContactManifold<N>)> {
+    ) -> impl Iterator<
+        Item = (
+            Handle,
+            Handle,
+            &ContactAlgorithm<N, C>,
+            &ContactManifold<N, C>,
+        ),
+    > {
         self.interaction_pairs(effective_only)
             .filter_map(|(h1, h2, inter)| match inter {
                 Interaction::Contact(algo, manifold) => Some((h1, h2, algo, manifold)),
@@ -139,7 +147,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         id1: CollisionObjectGraphIndex,
         id2: CollisionObjectGraphIndex,
         effective_only: bool,
-    ) -> Option<(Handle, Handle, &Interaction<N>)> {
+    ) -> Option<(Handle, Handle, &Interaction<N, C>)> {
         let inter = self.0.find_edge(id1, id2).and_then(|edge| {
             let endpoints = self.0.edge_endpoints(edge)?;
             let h1 = self.0.node_weight(endpoints.0)?;
@@ -162,7 +170,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         &mut self,
         id1: CollisionObjectGraphIndex,
         id2: CollisionObjectGraphIndex,
-    ) -> Option<(Handle, Handle, &mut Interaction<N>)> {
+    ) -> Option<(Handle, Handle, &mut Interaction<N, C>)> {
         let edge = self.0.find_edge(id1, id2)?;
         let endpoints = self.0.edge_endpoints(edge)?;
         let h1 = self.0.node_weight(endpoints.0)?;
@@ -179,7 +187,12 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         id1: CollisionObjectGraphIndex,
         id2: CollisionObjectGraphIndex,
         effective_only: bool,
-    ) -> Option<(Handle, Handle, &ContactAlgorithm<N>, &ContactManifold<N>)> {
+    ) -> Option<(
+        Handle,
+        Handle,
+        &ContactAlgorithm<N, C>,
+        &ContactManifold<N, C>,
+    )> {
         self.interaction_pair(id1, id2, effective_only)
             .and_then(|inter| match inter.2 {
                 Interaction::Contact(algo, manifold) => Some((inter.0, inter.1, algo, manifold)),
@@ -187,6 +200,27 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
             })
     }
 
+    /// The contact pair between the two collision objects identified by their graph index.
+    ///
+    /// Refer to the official [user guide](https://ncollide.org/interaction_handling_and_sensors/#interaction-iterators)
+    /// for details.
+    pub fn contact_pair_mut(
+        &mut self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+    ) -> Option<(
+        Handle,
+        Handle,
+        &mut ContactAlgorithm<N, C>,
+        &mut ContactManifold<N, C>,
+    )> {
+        let inter = self.interaction_pair_mut(id1, id2)?;
+        match inter.2 {
+            Interaction::Contact(algo, manifold) => Some((inter.0, inter.1, algo, manifold)),
+            _ => None,
+        }
+    }
+
     /// The proximity pair between the two collision objects identified by their graph index.
     ///
     /// Refer to the official [user guide](https://ncollide.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -233,7 +267,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         &self,
         id: CollisionObjectGraphIndex,
         effective_only: bool,
-    ) -> impl Iterator<Item = (Handle, Handle, &Interaction<N>)> {
+    ) -> impl Iterator<Item = (Handle, Handle, &Interaction<N, C>)> {
         self.0.edges(id).filter_map(move |e| {
             let inter = e.weight();
 
@@ -250,7 +284,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
     pub fn index_interaction(
         &self,
         id: TemporaryInteractionIndex,
-    ) -> Option<(Handle, Handle, &Interaction<N>)> {
+    ) -> Option<(Handle, Handle, &Interaction<N, C>)> {
         if let (Some(e), Some(endpoints)) = (self.0.edge_weight(id), self.0.edge_endpoints(id)) {
             Some((self.0[endpoints.0], self.0[endpoints.1], e))
         } else {
@@ -270,7 +304,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
             Handle,
             Handle,
             TemporaryInteractionIndex,
-            &mut Interaction<N>,
+            &mut Interaction<N, C>,
         ),
     > {
         let incoming_edge = self.0.first_edge(id, Direction::Incoming);
@@ -307,7 +341,14 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         &self,
         handle: CollisionObjectGraphIndex,
         effective_only: bool,
-    ) -> impl Iterator<Item = (Handle, Handle, &ContactAlgorithm<N>, &ContactManifold<N>)> {
+    ) -> impl Iterator<
+        Item = (
+            Handle,
+            Handle,
+            &ContactAlgorithm<N, C>,
+            &ContactManifold<N, C>,
+        ),
+    > {
         self.interactions_with(handle, effective_only)
             .filter_map(|(h1, h2, inter)| match inter {
                 Interaction::Contact(algo, manifold) => Some((h1, h2, algo, manifold)),
@@ -384,10 +425,52 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
         })
     }
 
+    /// Exports this graph to the Graphviz DOT format.
+    ///
+    /// Each node is labeled by `node_label`. Contact interactions are drawn as solid edges labeled
+    /// with their number of contacts, and proximity interactions as dashed edges labeled with
+    /// their proximity status. Effective interactions are red while the others are gray.
+    pub fn to_dot(&self, node_label: impl Fn(Handle) -> String) -> String {
+        let mut dot = String::from("graph interactions {\n");
+
+        for id in self.0.node_indices() {
+            let label = node_label(self.0[id])
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            let _ = writeln!(dot, "    {} [label=\"{}\"];", id.index(), label);
+        }
+
+        for e in self.0.edge_references() {
+            let interaction = e.weight();
+            let color = if Self::is_interaction_effective(interaction) {
+                "red"
+            } else {
+                "gray"
+            };
+            let (style, label) = match interaction {
+                Interaction::Contact(_, manifold) => ("solid", format!("{}", manifold.len())),
+                Interaction::Proximity(_, prox) => ("dashed", format!("{:?}", prox)),
+            };
+
+            let _ = writeln!(
+                dot,
+                "    {} -- {} [style={}, color={}, label=\"{}\"];",
+                e.source().index(),
+                e.target().index(),
+                style,
+                color,
+                label
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
     // NOTE: we don't make this method public because different
     // applications will have a different interpretation of when a
     // contact is considered effective (for example in nphysics).
-    fn is_interaction_effective(interaction: &Interaction<N>) -> bool {
+    fn is_interaction_effective(interaction: &Interaction<N, C>) -> bool {
         match interaction {
             Interaction::Contact(_, manifold) => {
                 if let Some(ctct) = manifold.deepest_contact() {
@@ -401,20 +484,21 @@ impl<N: RealField, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
     }
 }
 
-pub struct InteractionsWithMut<'a, N: RealField, Handle: CollisionObjectHandle> {
-    graph: &'a mut InteractionGraph<N, Handle>,
+pub struct InteractionsWithMut<'a, N: RealField, Handle: CollisionObjectHandle, C: ContactData = ()>
+{
+    graph: &'a mut InteractionGraph<N, Handle, C>,
     incoming_edge: Option<EdgeIndex<usize>>,
     outgoing_edge: Option<EdgeIndex<usize>>,
 }
 
-impl<'a, N: RealField, Handle: CollisionObjectHandle> Iterator
-    for InteractionsWithMut<'a, N, Handle>
+impl<'a, N: RealField, Handle: CollisionObjectHandle, C: ContactData> Iterator
+    for InteractionsWithMut<'a, N, Handle, C>
 {
     type Item = (
         Handle,
         Handle,
         TemporaryInteractionIndex,
-        &'a mut Interaction<N>,
+        &'a mut Interaction<N, C>,
     );
 
     #[inline]
@@ -424,7 +508,7 @@ impl<'a, N: RealField, Handle: CollisionObjectHandle> Iterator
         Handle,
         Handle,
         TemporaryInteractionIndex,
-        &'a mut Interaction<N>,
+        &'a mut Interaction<N, C>,
     )> {
         if let Some(edge) = self.incoming_edge {
             self.incoming_edge = self.graph.0.next_edge(edge, Direction::Incoming);
@@ -1,7 +1,9 @@
 //! Persistent collision detection algorithms to compute contact points.
 
-#[cfg(feature = "dim3")]
-pub use self::contact_generator::TriMeshTriMeshManifoldGenerator;
+#[cfg(feature = "dim2")]
+pub use self::contact_generator::{
+    ArcSupportMapManifoldGenerator, PolylinePolylineManifoldGenerator,
+};
 #[doc(inline)]
 pub use self::contact_generator::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
@@ -9,8 +11,15 @@ pub use self::contact_generator::{
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
     ConvexPolyhedronConvexPolyhedronManifoldGenerator, DefaultContactDispatcher,
-    HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    HeightFieldHeightFieldManifoldGenerator, HeightFieldShapeManifoldGenerator,
+    PlaneBallManifoldGenerator, PlaneConvexPolyhedronManifoldGenerator,
+    PlaneSupportMapManifoldGenerator, SlabSupportMapManifoldGenerator,
+    SupportMapSupportMapManifoldGenerator,
+};
+#[cfg(feature = "dim3")]
+pub use self::contact_generator::{
+    BallTriMeshManifoldGenerator, CylinderConeSupportMapManifoldGenerator,
+    PlaneConeManifoldGenerator, TriMeshTriMeshManifoldGenerator,
 };
 pub use self::events::{ContactEvent, ContactEvents, EventPool, ProximityEvent, ProximityEvents};
 pub use self::interaction_graph::{
@@ -8,23 +8,23 @@ use crate::pipeline::narrow_phase::{
 use crate::pipeline::object::{
     CollisionObjectHandle, CollisionObjectRef, CollisionObjectSet, GeometricQueryType,
 };
-use crate::query::{ContactId, ContactManifold, Proximity};
+use crate::query::{ContactData, ContactId, ContactManifold, Proximity};
 
 /// Collision detector dispatcher for collision objects.
-pub struct NarrowPhase<N: RealField, Handle: CollisionObjectHandle> {
-    contact_dispatcher: Box<dyn ContactDispatcher<N>>,
+pub struct NarrowPhase<N: RealField, Handle: CollisionObjectHandle, C: ContactData = ()> {
+    contact_dispatcher: Box<dyn ContactDispatcher<N, C>>,
     proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
     contact_events: ContactEvents<Handle>,
     proximity_events: ProximityEvents<Handle>,
     id_allocator: SlotMap<ContactId, bool>,
 }
 
-impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
+impl<N: RealField, Handle: CollisionObjectHandle, C: ContactData> NarrowPhase<N, Handle, C> {
     /// Creates a new `NarrowPhase`.
     pub fn new(
-        contact_dispatcher: Box<dyn ContactDispatcher<N>>,
+        contact_dispatcher: Box<dyn ContactDispatcher<N, C>>,
         proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
-    ) -> NarrowPhase<N, Handle> {
+    ) -> NarrowPhase<N, Handle, C> {
         NarrowPhase {
             contact_dispatcher,
             proximity_dispatcher,
@@ -34,13 +34,15 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         }
     }
 
-    fn garbage_collect_ids(&mut self, interactions: &mut InteractionGraph<N, Handle>) {
+    fn garbage_collect_ids(&mut self, interactions: &mut InteractionGraph<N, Handle, C>) {
         for interaction in interactions.0.edge_weights_mut() {
             match interaction {
                 Interaction::Contact(_, manifold) => {
                     for contact in manifold.contacts() {
-                        if !contact.id.is_null() {
-                            self.id_allocator[contact.id] = true;
+                        // The identifiers of contacts restored from a `WorldSnapshot` may have
+                        // been freed since. They cannot be allocated again, so they stay unique.
+                        if let Some(is_valid) = self.id_allocator.get_mut(contact.id) {
+                            *is_valid = true;
                         }
                     }
                 }
@@ -59,8 +61,8 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         co2: &impl CollisionObjectRef<N>,
         handle1: Handle,
         handle2: Handle,
-        detector: &mut dyn ContactManifoldGenerator<N>,
-        manifold: &mut ContactManifold<N>,
+        detector: &mut dyn ContactManifoldGenerator<N, C>,
+        manifold: &mut ContactManifold<N, C>,
     ) {
         let had_contacts = manifold.len() != 0;
 
@@ -68,6 +70,12 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
             .query_type()
             .contact_queries_to_prediction(co2.query_type())
         {
+            // Generate the contacts up to the breaking distance, and then remove the new ones
+            // further than the linear prediction.
+            let linear_prediction = prediction.linear();
+            let mut generation_prediction = prediction.clone();
+            generation_prediction.set_linear(linear_prediction.max(prediction.breaking_distance()));
+
             manifold.save_cache_and_clear();
             let _ = detector.generate_contacts(
                 &*self.contact_dispatcher,
@@ -77,17 +85,28 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                 &co2.position(),
                 co2.shape(),
                 None,
-                &prediction,
+                &generation_prediction,
                 manifold,
             );
 
+            if prediction.breaking_distance() > linear_prediction {
+                let _ =
+                    manifold.retain(|c| !c.id.is_null() || -c.contact.depth <= linear_prediction);
+            }
+
             for contact in manifold.contacts_mut() {
                 if contact.id.is_null() {
                     contact.id = self.id_allocator.insert(false)
                 }
             }
+
+            if manifold.canonical_ordering() {
+                manifold.canonicalize();
+            }
+
+            manifold.update_weights();
         } else {
-            panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)`.")
+            panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)` or `GeometricQueryType::ContactsWithBreakingDistance(..)`.")
         }
 
         if manifold.len() == 0 {
@@ -129,13 +148,21 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         detector: &mut dyn ProximityDetector<N>,
         curr_proximity: &mut Proximity,
     ) {
+        let (qt1, qt2) = (co1.query_type(), co2.query_type());
+        // Use the exit margins to keep the pair close if it was so, to obtain some hysteresis.
+        let margin = if *curr_proximity == Proximity::Disjoint {
+            qt1.enter_query_limit() + qt2.enter_query_limit()
+        } else {
+            qt1.exit_query_limit() + qt2.exit_query_limit()
+        };
+
         if let Some(new_proximity) = detector.update(
             &*self.proximity_dispatcher,
             &co1.position(),
             co1.shape(),
             &co2.position(),
             co2.shape(),
-            co1.query_type().query_limit() + co2.query_type().query_limit(),
+            margin,
         ) {
             self.emit_proximity_event(handle1, handle2, *curr_proximity, new_proximity);
             *curr_proximity = new_proximity;
@@ -149,7 +176,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         co2: &impl CollisionObjectRef<N>,
         handle1: Handle,
         handle2: Handle,
-        interaction: &mut Interaction<N>,
+        interaction: &mut Interaction<N, C>,
     ) {
         match interaction {
             Interaction::Contact(detector, manifold) => {
@@ -167,7 +194,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
     /// This will push relevant events to `contact_events` and `proximity_events`.
     pub fn update<Objects>(
         &mut self,
-        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
         objects: &Objects,
     ) where
         Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
@@ -199,7 +226,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
     /// Handles a pair of collision objects detected as either started or stopped interacting.
     pub fn handle_interaction<Objects>(
         &mut self,
-        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle, C>,
         objects: &Objects,
         handle1: Objects::CollisionObjectHandle,
         handle2: Objects::CollisionObjectHandle,
@@ -215,7 +242,19 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         if started {
             if !interactions.0.contains_edge(id1, id2) {
                 match (co1.query_type(), co2.query_type()) {
-                    (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..)) => {
+                    (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..))
+                    | (
+                        GeometricQueryType::Contacts(..),
+                        GeometricQueryType::ContactsWithBreakingDistance(..),
+                    )
+                    | (
+                        GeometricQueryType::ContactsWithBreakingDistance(..),
+                        GeometricQueryType::Contacts(..),
+                    )
+                    | (
+                        GeometricQueryType::ContactsWithBreakingDistance(..),
+                        GeometricQueryType::ContactsWithBreakingDistance(..),
+                    ) => {
                         let dispatcher = &self.contact_dispatcher;
 
                         if let Some(detector) =
@@ -230,7 +269,9 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                         }
                     }
                     (_, GeometricQueryType::Proximity(_))
-                    | (GeometricQueryType::Proximity(_), _) => {
+                    | (_, GeometricQueryType::ProximityWithHysteresis(..))
+                    | (GeometricQueryType::Proximity(_), _)
+                    | (GeometricQueryType::ProximityWithHysteresis(..), _) => {
                         let dispatcher = &self.proximity_dispatcher;
 
                         if let Some(detector) =
@@ -17,6 +17,13 @@ pub trait ProximityDetector<N: RealField>: Any + Send + Sync {
         b: &dyn Shape<N>,
         margin: N,
     ) -> Option<Proximity>;
+
+    /// A name identifying this proximity detector, for debugging purposes.
+    ///
+    /// Defaults to the name of the implementing type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 pub type ProximityAlgorithm<N> = Box<dyn ProximityDetector<N>>;
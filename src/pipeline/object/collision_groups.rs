@@ -40,7 +40,19 @@ const NO_GROUP: u32 = 0;
 ///    * A and B will **not** interact because B is part of the group 1 which is blacklisted by A.
 ///    * Finally, B and C will **not** interact either because, even if C whitelists the group 3
 ///    (which B is part of), B does not whitelists the groups 6 nor 9 (which B is part of).
-#[derive(Clone, Debug, Copy)]
+///
+/// A serialized `CollisionGroups` stores the indices of the groups of its membership, whitelist
+/// and blacklist, as well as whether self-interaction is enabled. Deserialization fails if one
+/// of these indices is not a valid group index.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "SerializedCollisionGroups",
+        try_from = "SerializedCollisionGroups"
+    )
+)]
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub struct CollisionGroups {
     membership: u32,
     whitelist: u32,
@@ -363,6 +375,121 @@ impl CollisionGroups {
     pub fn can_interact_with_self(&self) -> bool {
         self.whitelist & SELF_COLLISION != 0
     }
+
+    /// The first rule preventing `self` from interacting with `other`, if any.
+    ///
+    /// Returns `None` if `self.can_interact_with_groups(other)` is `true`. Blacklists are checked
+    /// before whitelists since they always have priority.
+    pub fn interaction_blocker(&self, other: &CollisionGroups) -> Option<CollisionGroupsRule> {
+        if self.membership & other.blacklist != 0 {
+            Some(CollisionGroupsRule::BlacklistedBySecond)
+        } else if other.membership & self.blacklist != 0 {
+            Some(CollisionGroupsRule::BlacklistedByFirst)
+        } else if self.membership & other.whitelist == 0 {
+            Some(CollisionGroupsRule::NotWhitelistedBySecond)
+        } else if other.membership & self.whitelist == 0 {
+            Some(CollisionGroupsRule::NotWhitelistedByFirst)
+        } else {
+            None
+        }
+    }
+}
+
+/// The serialized representation of a `CollisionGroups`.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerializedCollisionGroups {
+    membership: Vec<usize>,
+    whitelist: Vec<usize>,
+    blacklist: Vec<usize>,
+    self_interaction: bool,
+}
+
+#[cfg(feature = "serde")]
+fn mask_groups(mask: u32) -> Vec<usize> {
+    (0..=CollisionGroups::max_group_id())
+        .filter(|i| mask & (1 << i) != 0)
+        .collect()
+}
+
+#[cfg(feature = "serde")]
+fn groups_mask(groups: &[usize]) -> Result<u32, InvalidCollisionGroup> {
+    groups.iter().try_fold(NO_GROUP, |mask, i| {
+        if *i <= CollisionGroups::max_group_id() {
+            Ok(mask | (1 << *i))
+        } else {
+            Err(InvalidCollisionGroup(*i))
+        }
+    })
+}
+
+#[cfg(feature = "serde")]
+impl From<CollisionGroups> for SerializedCollisionGroups {
+    fn from(groups: CollisionGroups) -> Self {
+        SerializedCollisionGroups {
+            membership: mask_groups(groups.membership),
+            whitelist: mask_groups(groups.whitelist),
+            blacklist: mask_groups(groups.blacklist),
+            self_interaction: groups.can_interact_with_self(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<SerializedCollisionGroups> for CollisionGroups {
+    type Error = InvalidCollisionGroup;
+
+    fn try_from(data: SerializedCollisionGroups) -> Result<Self, InvalidCollisionGroup> {
+        let mut groups = CollisionGroups {
+            membership: groups_mask(&data.membership)?,
+            whitelist: groups_mask(&data.whitelist)?,
+            blacklist: groups_mask(&data.blacklist)?,
+        };
+
+        if data.self_interaction {
+            groups.enable_self_interaction();
+        }
+
+        Ok(groups)
+    }
+}
+
+/// Error raised when deserializing a `CollisionGroups` referencing a group index greater than
+/// `CollisionGroups::max_group_id()`.
+#[cfg(feature = "serde")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InvalidCollisionGroup(pub usize);
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for InvalidCollisionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "collision group {} is out of range, there are at most 30 groups",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for InvalidCollisionGroup {}
+
+/// A collision groups rule that prevented two collision objects from interacting.
+///
+/// Here, "first" and "second" refer to the order in which the two collision groups
+/// were given to `CollisionGroups::interaction_blocker`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CollisionGroupsRule {
+    /// The object is tested against itself but self-interaction is disabled.
+    SelfInteractionDisabled,
+    /// The second object blacklists a group the first object is member of.
+    BlacklistedBySecond,
+    /// The first object blacklists a group the second object is member of.
+    BlacklistedByFirst,
+    /// The second object does not whitelist any group the first object is member of.
+    NotWhitelistedBySecond,
+    /// The first object does not whitelist any group the second object is member of.
+    NotWhitelistedByFirst,
 }
 
 impl Default for CollisionGroups {
@@ -403,3 +530,60 @@ impl<N: RealField, Set: CollisionObjectSet<N>> BroadPhasePairFilter<N, Set>
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::{CollisionGroups, InvalidCollisionGroup, SerializedCollisionGroups};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn serialized_collision_groups_round_trip() {
+        let mut groups = CollisionGroups::new()
+            .with_membership(&[0, 3, 29])
+            .with_whitelist(&[1, 3, 5, 7])
+            .with_blacklist(&[2, 5]);
+        groups.enable_self_interaction();
+
+        for groups in [groups, CollisionGroups::new()].iter() {
+            let data = SerializedCollisionGroups::from(*groups);
+            assert_eq!(CollisionGroups::try_from(data), Ok(*groups));
+        }
+    }
+
+    #[test]
+    fn serialized_collision_groups_with_invalid_group_are_rejected() {
+        let data = SerializedCollisionGroups {
+            membership: vec![0, 1],
+            whitelist: vec![2, 30],
+            blacklist: vec![],
+            self_interaction: true,
+        };
+
+        assert_eq!(
+            CollisionGroups::try_from(data),
+            Err(InvalidCollisionGroup(30))
+        );
+    }
+
+    #[test]
+    fn collision_groups_json_round_trip() {
+        let mut groups = CollisionGroups::new()
+            .with_membership(&[0, 3, 29])
+            .with_whitelist(&[1, 3, 5, 7])
+            .with_blacklist(&[2, 5]);
+        groups.disable_self_interaction();
+
+        let json = serde_json::to_string(&groups).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CollisionGroups>(&json).unwrap(),
+            groups
+        );
+    }
+
+    #[test]
+    fn collision_groups_json_with_invalid_group_are_rejected() {
+        let json = r#"{"membership":[0],"whitelist":[30],"blacklist":[],"self_interaction":true}"#;
+        let err = serde_json::from_str::<CollisionGroups>(json).unwrap_err();
+        assert_eq!(err.to_string(), InvalidCollisionGroup(30).to_string());
+    }
+}
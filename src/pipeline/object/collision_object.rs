@@ -4,7 +4,7 @@ use crate::pipeline::broad_phase::BroadPhaseProxyHandle;
 use crate::pipeline::narrow_phase::CollisionObjectGraphIndex;
 use crate::pipeline::object::CollisionGroups;
 use crate::pipeline::object::GeometricQueryType;
-use crate::shape::{Shape, ShapeHandle};
+use crate::shape::{MorphShape, Shape, ShapeHandle};
 use simba::scalar::RealField;
 
 bitflags! {
@@ -69,6 +69,8 @@ pub trait CollisionObjectRef<N: RealField> {
     /// Se the `glue::create_proxies` for more details.
     fn proxy_handle(&self) -> Option<BroadPhaseProxyHandle>;
     /// The position of this collision object.
+    ///
+    /// This is always an isometry. Use a `shape::ScaledShape` to scale a collision object.
     fn position(&self) -> &Isometry<N>;
     /// The expected position of this collision object in the next updates.
     ///
@@ -250,6 +252,19 @@ impl<N: RealField, T> CollisionObject<N, T> {
             .set_deformations(coords)
     }
 
+    /// Sets the interpolation parameter of the underlying morph shape.
+    ///
+    /// Panics if the shape is not a `MorphShape`.
+    #[inline]
+    pub fn set_morph_parameter(&mut self, t: N) {
+        self.update_flags |= CollisionObjectUpdateFlags::POSITION_CHANGED;
+        self.shape
+            .make_mut()
+            .downcast_mut::<MorphShape<N>>()
+            .expect("Attempting to morph a shape that is not a morph shape.")
+            .set_t(t)
+    }
+
     /// The collision object shape.
     #[inline]
     pub fn shape(&self) -> &ShapeHandle<N> {
@@ -1,6 +1,8 @@
 //! Definition of collision objects and some of their properties.
 
-pub use self::collision_groups::{CollisionGroups, CollisionGroupsPairFilter};
+#[cfg(feature = "serde")]
+pub use self::collision_groups::InvalidCollisionGroup;
+pub use self::collision_groups::{CollisionGroups, CollisionGroupsPairFilter, CollisionGroupsRule};
 pub use self::collision_object::{
     CollisionObject, CollisionObjectRef, CollisionObjectSlabHandle, CollisionObjectUpdateFlags,
 };
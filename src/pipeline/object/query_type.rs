@@ -9,12 +9,30 @@ use na::RealField;
 /// * Contacts + Contacts = exact contact point coputation.
 /// * Contacts + Proximity = proximity test only.
 /// * Proximity + Proximity = proximity test only.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum GeometricQueryType<N: RealField> {
     /// This objects can respond to both contact point computation and proximity queries.
     Contacts(N, N),
+    /// Same as `Contacts` but with a contact breaking distance distinct from the linear prediction.
+    ///
+    /// The values are the linear prediction, the angular prediction, and the contact breaking
+    /// distance. New contacts are generated only if they are closer than the linear prediction,
+    /// but contacts generated at a previous update are kept as long as they are closer than the
+    /// breaking distance. The breaking distance should be greater than the linear prediction so
+    /// the contacts persist a bit longer than they are predicted. `Contacts(linear, angular)` is
+    /// equivalent to `ContactsWithBreakingDistance(linear, angular, linear)`.
+    ContactsWithBreakingDistance(N, N, N),
     /// This object can respond to proximity tests only.
     Proximity(N),
+    /// This object can respond to proximity tests only, with separate enter and exit margins.
+    ///
+    /// The first value is the enter margin: a disjoint pair starts being reported as
+    /// `Proximity::WithinMargin` when closer than this distance. The second value is the exit
+    /// margin: a pair that is not disjoint is reported as `Proximity::Disjoint` only after moving
+    /// apart further than this distance. The exit margin should be greater than the enter margin
+    /// to prevent the proximity status from flickering when an object lies at the boundary.
+    ProximityWithHysteresis(N, N),
     // FIXME: not yet implemented: Distance
 }
 
@@ -25,27 +43,63 @@ impl<N: RealField> GeometricQueryType<N> {
     /// `query_limit`, the corresponding query will not by performed. For proximity queries,
     /// non-intersecting object closer than a distance equal to the sum of their `query_limit` will
     /// be reported as `Proximity::WithinMargin`.
+    ///
+    /// For `GeometricQueryType::ProximityWithHysteresis`, this is the largest of its two margins.
+    /// For `GeometricQueryType::ContactsWithBreakingDistance`, this is the largest of its linear
+    /// prediction and its breaking distance.
     #[inline]
     pub fn query_limit(&self) -> N {
         match *self {
             GeometricQueryType::Contacts(ref val, _) => *val,
+            GeometricQueryType::ContactsWithBreakingDistance(linear, _, breaking) => {
+                linear.max(breaking)
+            }
             GeometricQueryType::Proximity(ref val) => *val,
+            GeometricQueryType::ProximityWithHysteresis(enter, exit) => enter.max(exit),
+        }
+    }
+
+    /// The distance limit used by proximity queries between objects currently disjoint.
+    ///
+    /// This differs from `self.query_limit()` only for `GeometricQueryType::ProximityWithHysteresis`.
+    #[inline]
+    pub fn enter_query_limit(&self) -> N {
+        match *self {
+            GeometricQueryType::ProximityWithHysteresis(enter, _) => enter,
+            _ => self.query_limit(),
+        }
+    }
+
+    /// The distance limit used by proximity queries between objects currently not disjoint.
+    ///
+    /// This differs from `self.query_limit()` only for `GeometricQueryType::ProximityWithHysteresis`.
+    #[inline]
+    pub fn exit_query_limit(&self) -> N {
+        match *self {
+            GeometricQueryType::ProximityWithHysteresis(_, exit) => exit,
+            _ => self.query_limit(),
         }
     }
 
     /// Given two contact query types, returns the corresponding contact prediction parameters.
     ///
-    /// Returns `None` if any of `self` or `other` is not a `GeometricQueryType::Contacts`.
+    /// The linear predictions and the contact breaking distances of both query types are summed.
+    /// Returns `None` if any of `self` or `other` is not a contacts query type.
     pub fn contact_queries_to_prediction(self, other: Self) -> Option<ContactPrediction<N>> {
-        match (self, other) {
-            (
-                GeometricQueryType::Contacts(linear1, angular1),
-                GeometricQueryType::Contacts(linear2, angular2),
-            ) => Some(ContactPrediction::new(
-                linear1 + linear2,
-                angular1,
-                angular2,
-            )),
+        let (linear1, angular1, breaking1) = self.contacts_parameters()?;
+        let (linear2, angular2, breaking2) = other.contacts_parameters()?;
+        let mut prediction = ContactPrediction::new(linear1 + linear2, angular1, angular2);
+        prediction.set_breaking_distance(breaking1 + breaking2);
+        Some(prediction)
+    }
+
+    // The linear prediction, angular prediction, and breaking distance of a contacts query type.
+    fn contacts_parameters(&self) -> Option<(N, N, N)> {
+        match *self {
+            GeometricQueryType::Contacts(linear, angular) => Some((linear, angular, linear)),
+            GeometricQueryType::ContactsWithBreakingDistance(linear, angular, breaking) => {
+                Some((linear, angular, breaking))
+            }
             _ => None,
         }
     }
@@ -53,20 +107,45 @@ impl<N: RealField> GeometricQueryType<N> {
     /// Returns `true` if this is a contacts query type.
     #[inline]
     pub fn is_contacts_query(&self) -> bool {
-        if let GeometricQueryType::Contacts(..) = *self {
-            true
-        } else {
-            false
+        match *self {
+            GeometricQueryType::Contacts(..)
+            | GeometricQueryType::ContactsWithBreakingDistance(..) => true,
+            _ => false,
         }
     }
 
     /// Returns `true` if this is a proximity query type.
     #[inline]
     pub fn is_proximity_query(&self) -> bool {
-        if let GeometricQueryType::Proximity(_) = *self {
-            true
-        } else {
-            false
+        match *self {
+            GeometricQueryType::Proximity(_) | GeometricQueryType::ProximityWithHysteresis(..) => {
+                true
+            }
+            GeometricQueryType::Contacts(..)
+            | GeometricQueryType::ContactsWithBreakingDistance(..) => false,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::GeometricQueryType;
+
+    #[test]
+    fn geometric_query_type_json_round_trip() {
+        let query_types = [
+            GeometricQueryType::Contacts(0.1, 0.2),
+            GeometricQueryType::ContactsWithBreakingDistance(0.1, 0.2, 0.3),
+            GeometricQueryType::Proximity(0.4),
+            GeometricQueryType::ProximityWithHysteresis(0.4, 0.5),
+        ];
+
+        for query_type in query_types.iter() {
+            let json = serde_json::to_string(query_type).unwrap();
+            assert_eq!(
+                serde_json::from_str::<GeometricQueryType<f64>>(&json).unwrap(),
+                *query_type
+            );
         }
     }
 }
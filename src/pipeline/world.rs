@@ -1,6 +1,7 @@
 //! High level API to detect collisions in large, complex scenes.
 
-use na::{RealField, Unit};
+use na::{self, RealField, Unit};
+use std::collections::HashMap;
 
 use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Rotation, Translation, Vector};
@@ -15,37 +16,200 @@ use crate::pipeline::narrow_phase::{
     TemporaryInteractionIndex,
 };
 use crate::pipeline::object::{
-    CollisionGroups, CollisionObject, CollisionObjectSet, CollisionObjectSlab,
-    CollisionObjectSlabHandle, CollisionObjects, GeometricQueryType,
+    CollisionGroups, CollisionGroupsRule, CollisionObject, CollisionObjectRef, CollisionObjectSet,
+    CollisionObjectSlab, CollisionObjectSlabHandle, CollisionObjects, GeometricQueryType,
 };
-use crate::query::{ContactManifold, DefaultTOIDispatcher, Proximity, Ray, TOIDispatcher, TOI};
-use crate::shape::{Shape, ShapeHandle};
+use crate::query::{
+    self, ContactData, ContactManifold, DefaultTOIDispatcher, PointProjection, Proximity, Ray,
+    RayCast, RayIntersection, TOIDispatcher, TOIStatus, TrackedContact, TOI,
+};
+use crate::shape::{Capsule, Shape, ShapeHandle};
 
 /// Type of the broad phase trait-object used by the collision world.
 pub type BroadPhaseObject<N> = Box<dyn BroadPhase<N, AABB<N>, CollisionObjectSlabHandle>>;
 
+/// A report explaining the current collision detection status of a pair of collision objects.
+///
+/// This is computed by `CollisionWorld::explain_pair`.
+#[derive(Clone, Debug)]
+pub struct PairDiagnostics<N: RealField> {
+    /// Whether the broad-phase AABBs of both collision objects intersect.
+    pub aabbs_overlap: bool,
+    /// The collision groups rule preventing both collision objects from interacting, if any.
+    pub groups_blocker: Option<CollisionGroupsRule>,
+    /// Whether the user-defined broad-phase pair filter accepts this pair.
+    ///
+    /// This is `true` if no such filter has been set.
+    pub pair_filter_accepts: bool,
+    /// The interaction currently registered between both collision objects.
+    pub interaction: PairInteractionStatus<N>,
+}
+
+/// The interaction registered between two collision objects, as reported by `PairDiagnostics`.
+#[derive(Clone, Debug)]
+pub enum PairInteractionStatus<N: RealField> {
+    /// No contact or proximity algorithm has been dispatched for this pair.
+    None,
+    /// A contact manifold generator has been dispatched for this pair.
+    Contact {
+        /// The name of the contact manifold generator.
+        algorithm: &'static str,
+        /// The number of contacts currently on the contact manifold.
+        num_contacts: usize,
+        /// The penetration depth of the deepest contact, if the manifold is not empty.
+        deepest_depth: Option<N>,
+    },
+    /// A proximity detector has been dispatched for this pair.
+    Proximity {
+        /// The name of the proximity detector.
+        algorithm: &'static str,
+        /// The last proximity status computed by the proximity detector.
+        proximity: Proximity,
+    },
+}
+
+impl<N: RealField> PairDiagnostics<N> {
+    /// Whether an algorithm has been dispatched and reports an actual contact or intersection.
+    pub fn is_effective(&self) -> bool {
+        match &self.interaction {
+            PairInteractionStatus::None => false,
+            PairInteractionStatus::Contact { num_contacts, .. } => *num_contacts != 0,
+            PairInteractionStatus::Proximity { proximity, .. } => {
+                *proximity == Proximity::Intersecting
+            }
+        }
+    }
+}
+
+/// A copy of the interaction pairs of a collision world at a given point in time.
+///
+/// This is computed by `CollisionWorld::snapshot_pairs` and is not affected by subsequent updates
+/// of the collision world.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct PairSnapshot<N: RealField, C = ()> {
+    contacts: Vec<(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        ContactManifold<N, C>,
+    )>,
+    proximities: Vec<(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        Proximity,
+    )>,
+}
+
+impl<N: RealField, C> PairSnapshot<N, C> {
+    /// The number of pairs on this snapshot.
+    pub fn len(&self) -> usize {
+        self.contacts.len() + self.proximities.len()
+    }
+
+    /// Whether this snapshot contains no pair at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The contact pairs of this snapshot, along with a copy of their contact manifold.
+    pub fn contact_pairs(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            &ContactManifold<N, C>,
+        ),
+    > {
+        self.contacts.iter().map(|(h1, h2, m)| (*h1, *h2, m))
+    }
+
+    /// The proximity pairs of this snapshot, along with their proximity status.
+    pub fn proximity_pairs(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            Proximity,
+        ),
+    > + '_ {
+        self.proximities.iter().cloned()
+    }
+}
+
+// The state of a collision object saved by a `WorldSnapshot`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+struct ObjectSnapshot<N: RealField> {
+    handle: CollisionObjectSlabHandle,
+    position: Isometry<N>,
+    predicted_position: Option<Isometry<N>>,
+    collision_groups: CollisionGroups,
+    query_type: GeometricQueryType<N>,
+}
+
+/// A checkpoint of the state of a collision world, computed by `CollisionWorld::snapshot`.
+///
+/// This contains the position, collision groups, and query type of each collision object, as
+/// well as a copy of every interaction pair along with its contact manifold or proximity status.
+/// The shapes and user-defined data of the collision objects are not part of the snapshot.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WorldSnapshot<N: RealField, C = ()> {
+    objects: Vec<ObjectSnapshot<N>>,
+    pairs: PairSnapshot<N, C>,
+}
+
+impl<N: RealField, C> WorldSnapshot<N, C> {
+    /// The number of collision objects on this snapshot.
+    pub fn num_objects(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// The interaction pairs on this snapshot.
+    pub fn pairs(&self) -> &PairSnapshot<N, C> {
+        &self.pairs
+    }
+}
+
 /// A world that handles collision objects.
-pub struct CollisionWorld<N: RealField, T> {
+pub struct CollisionWorld<N: RealField, T, C: ContactData = ()> {
     /// The set of objects on this collision world.
     pub objects: CollisionObjectSlab<N, T>,
     /// The broad phase used by this collision world.
     pub broad_phase: BroadPhaseObject<N>,
     /// The narrow-phase used by this collision world.
-    pub narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle>,
+    pub narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle, C>,
     /// The Time of Impact dispatcher used.
     pub toi_dispatcher: Box<dyn TOIDispatcher<N>>,
     /// The graph of interactions detected so far.
-    pub interactions: InteractionGraph<N, CollisionObjectSlabHandle>,
+    pub interactions: InteractionGraph<N, CollisionObjectSlabHandle, C>,
     /// A user-defined broad-phase pair filter.
     pub pair_filters: Option<Box<dyn BroadPhasePairFilter<N, CollisionObjectSlab<N, T>>>>,
+    // Objects added since the last broad phase, the proxies of which are not inserted yet.
+    objects_to_announce: Vec<CollisionObjectSlabHandle>,
+    object_added_callback: Option<Box<dyn FnMut(CollisionObjectSlabHandle) + Send + Sync>>,
+    object_removed_callback: Option<Box<dyn FnMut(CollisionObjectSlabHandle) + Send + Sync>>,
+    // Objects with a bounding volume updated by the last call to `update`, with their new AABB.
+    changed_aabbs: Vec<(CollisionObjectSlabHandle, AABB<N>)>,
 }
 
 impl<N: RealField, T> CollisionWorld<N, T> {
     /// Creates a new collision world.
     // FIXME: use default values for `margin` and allow its modification by the user ?
     pub fn new(margin: N) -> CollisionWorld<N, T> {
+        Self::with_contact_data(margin)
+    }
+}
+
+impl<N: RealField, T, C: ContactData> CollisionWorld<N, T, C> {
+    /// Creates a new collision world where each contact carries a user-defined data of type `C`.
+    ///
+    /// See `TrackedContact::data`.
+    pub fn with_contact_data(margin: N) -> CollisionWorld<N, T, C> {
         let objects = CollisionObjectSlab::new();
-        let coll_dispatcher = Box::new(DefaultContactDispatcher::new());
+        let coll_dispatcher = Box::new(DefaultContactDispatcher::default());
         let prox_dispatcher = Box::new(DefaultProximityDispatcher::new());
         let toi_dispatcher = Box::new(DefaultTOIDispatcher);
         let broad_phase =
@@ -59,6 +223,41 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             narrow_phase,
             toi_dispatcher,
             pair_filters: None,
+            objects_to_announce: Vec::new(),
+            object_added_callback: None,
+            object_removed_callback: None,
+            changed_aabbs: Vec::new(),
+        }
+    }
+
+    /// Sets the callback called when the broad-phase proxy of a collision object is inserted.
+    ///
+    /// The proxy of an object is inserted into the broad phase by the first call to `update`, or
+    /// to `perform_broad_phase`, following the call to `add` that created this object. The
+    /// callback is then called with the handle of this object.
+    pub fn on_object_added(
+        &mut self,
+        callback: impl FnMut(CollisionObjectSlabHandle) + Send + Sync + 'static,
+    ) {
+        self.object_added_callback = Some(Box::new(callback))
+    }
+
+    /// Sets the callback called when the broad-phase proxy of a collision object is removed.
+    ///
+    /// The callback is called by `remove` with the handle of each removed object whose proxy has
+    /// been inserted into the broad phase, i.e., for which the `on_object_added` callback would
+    /// have been called.
+    pub fn on_object_removed(
+        &mut self,
+        callback: impl FnMut(CollisionObjectSlabHandle) + Send + Sync + 'static,
+    ) {
+        self.object_removed_callback = Some(Box::new(callback))
+    }
+
+    fn announce_added_objects(&mut self) {
+        match &mut self.object_added_callback {
+            Some(callback) => self.objects_to_announce.drain(..).for_each(callback),
+            None => self.objects_to_announce.clear(),
         }
     }
 
@@ -92,6 +291,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             data,
         );
 
+        self.objects_to_announce.push(handle);
         (handle, entry.insert(co))
     }
 
@@ -111,13 +311,32 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             &mut self.interactions,
             self.pair_filters.as_ref().map(|f| &**f),
         );
+        self.announce_added_objects();
 
         // Clear update flags.
-        for (_, co) in self.objects.iter_mut() {
+        self.changed_aabbs.clear();
+
+        for (handle, co) in self.objects.iter_mut() {
+            if co.update_flags().needs_bounding_volume_update() {
+                self.changed_aabbs.push((handle, co.compute_swept_aabb()));
+            }
+
             co.clear_update_flags();
         }
     }
 
+    /// The collision objects with a bounding volume updated by the last call to `update`.
+    ///
+    /// Yields the handle of each added, moved, or reshaped collision object, or of each object
+    /// with a modified query type, along with its new AABB, i.e., the AABB of its shape enlarged
+    /// by its query limit. Contrary to the AABBs stored by the broad phase, these are not enlarged
+    /// by the broad phase margin. This is replaced by each call to `update`, but not by calls to
+    /// `perform_broad_phase`. The handles of objects removed since the last `update` may still be
+    /// yielded.
+    pub fn changed_aabbs(&self) -> impl Iterator<Item = (CollisionObjectSlabHandle, &AABB<N>)> {
+        self.changed_aabbs.iter().map(|(h, aabb)| (*h, aabb))
+    }
+
     /// Empty the contact and proximity event pools.
     pub fn clear_events(&mut self) {
         self.narrow_phase.clear_events();
@@ -140,6 +359,13 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             ) {
                 self.objects[new_handle].set_graph_index(Some(new_index))
             }
+
+            if let Some(i) = self.objects_to_announce.iter().position(|h| h == handle) {
+                // The proxy of this object was never inserted.
+                let _ = self.objects_to_announce.swap_remove(i);
+            } else if let Some(callback) = &mut self.object_removed_callback {
+                callback(*handle)
+            }
         }
     }
 
@@ -224,7 +450,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             &mut self.narrow_phase,
             &mut self.interactions,
             self.pair_filters.as_ref().map(|f| &**f),
-        )
+        );
+        self.announce_added_objects();
     }
 
     /// Executes the narrow phase of the collision detection pipeline.
@@ -243,6 +470,66 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.broad_phase.proxy(proxy_handle).map(|p| p.0)
     }
 
+    /// Explains why the two specified collision objects interact or not.
+    ///
+    /// This reports the status of each stage of the collision detection pipeline for this pair,
+    /// as of the last call to `.update()`. Returns `None` if one of the handles is invalid.
+    pub fn explain_pair(
+        &self,
+        handle1: CollisionObjectSlabHandle,
+        handle2: CollisionObjectSlabHandle,
+    ) -> Option<PairDiagnostics<N>> {
+        let co1 = self.objects.collision_object(handle1)?;
+        let co2 = self.objects.collision_object(handle2)?;
+
+        let aabbs_overlap = match (
+            self.broad_phase_aabb(handle1),
+            self.broad_phase_aabb(handle2),
+        ) {
+            (Some(aabb1), Some(aabb2)) => aabb1.intersects(aabb2),
+            _ => false,
+        };
+
+        let groups_blocker = if handle1 == handle2 {
+            if co1.collision_groups().can_interact_with_self() {
+                None
+            } else {
+                Some(CollisionGroupsRule::SelfInteractionDisabled)
+            }
+        } else {
+            co1.collision_groups()
+                .interaction_blocker(co2.collision_groups())
+        };
+
+        let pair_filter_accepts = self
+            .pair_filters
+            .as_ref()
+            .map(|f| f.is_pair_valid(handle1, handle2, &self.objects))
+            .unwrap_or(true);
+
+        let interaction = match self.interaction_pair(handle1, handle2, false) {
+            Some((_, _, Interaction::Contact(alg, manifold))) => PairInteractionStatus::Contact {
+                algorithm: alg.name(),
+                num_contacts: manifold.len(),
+                deepest_depth: manifold.deepest_contact().map(|c| c.contact.depth),
+            },
+            Some((_, _, Interaction::Proximity(alg, proximity))) => {
+                PairInteractionStatus::Proximity {
+                    algorithm: alg.name(),
+                    proximity: *proximity,
+                }
+            }
+            None => PairInteractionStatus::None,
+        };
+
+        Some(PairDiagnostics {
+            aabbs_overlap,
+            groups_blocker,
+            pair_filter_accepts,
+            interaction,
+        })
+    }
+
     /// Iterates through all collision objects.
     #[inline]
     pub fn collision_objects(&self) -> CollisionObjects<N, T> {
@@ -337,6 +624,269 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         })
     }
 
+    /// Computes the first object hit by a capsule moving from `start` by the displacement `motion`.
+    ///
+    /// Objects rejected by `groups` or for which `filter` returns `false` are ignored. Use
+    /// `&|_, _| true` as `filter` to consider every object allowed by `groups`. Objects with a
+    /// shape for which the time of impact dispatcher does not support casting a capsule are
+    /// ignored as well. The returned TOI is a fraction of `motion` in `[0, 1]`, and its witness
+    /// points and normals are expressed in the local spaces of the capsule and of the object hit.
+    /// If the capsule already overlaps an object at `start`, the TOI is zero and its status is
+    /// `TOIStatus::Penetrating`: in that case, `normal1` is the penetration direction so moving
+    /// the capsule along `-normal1` by the distance between the witness points separates it from
+    /// the object.
+    pub fn capsule_cast(
+        &self,
+        capsule: &Capsule<N>,
+        start: &Isometry<N>,
+        motion: &Vector<N>,
+        groups: &CollisionGroups,
+        filter: &dyn Fn(CollisionObjectSlabHandle, &CollisionObject<N, T>) -> bool,
+    ) -> Option<(CollisionObjectSlabHandle, TOI<N>)> {
+        let end = Isometry::from_parts(
+            Translation::from(start.translation.vector + motion),
+            start.rotation,
+        );
+        let aabb = capsule.aabb(start).merged(&capsule.aabb(&end));
+        let dispatcher = &*self.toi_dispatcher;
+        let mut result: Option<(CollisionObjectSlabHandle, TOI<N>)> = None;
+
+        for (handle, co) in self.interferences_with_aabb(&aabb, groups) {
+            if !filter(handle, co) {
+                continue;
+            }
+
+            let toi = dispatcher
+                .time_of_impact(
+                    dispatcher,
+                    start,
+                    motion,
+                    capsule,
+                    co.position(),
+                    &Vector::zeros(),
+                    co.shape().as_ref(),
+                    N::one(),
+                    N::zero(),
+                )
+                .unwrap_or(None);
+
+            if let Some(mut toi) = toi {
+                let closer = match &result {
+                    Some(best) => toi.toi < best.1.toi,
+                    None => true,
+                };
+
+                if closer {
+                    if toi.status == TOIStatus::Penetrating {
+                        // The witness points are not meaningful here, so compute the
+                        // penetration direction instead.
+                        if let Some(c) = query::contact(
+                            start,
+                            capsule,
+                            co.position(),
+                            co.shape().as_ref(),
+                            N::zero(),
+                        ) {
+                            toi.witness1 = start.inverse_transform_point(&c.world1);
+                            toi.witness2 = co.position().inverse_transform_point(&c.world2);
+                            toi.normal1 = start.inverse_transform_unit_vector(&c.normal);
+                            toi.normal2 = co.position().inverse_transform_unit_vector(&-c.normal);
+                        }
+                    }
+
+                    result = Some((handle, toi));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Checks if `shape` placed at `pose` does not intersect any object of this world.
+    ///
+    /// Objects rejected by `groups` or for which `filter` returns `false` are ignored. Use
+    /// `&|_, _| true` as `filter` to consider every object allowed by `groups`. This returns
+    /// `false` as soon as an intersection is found. The shape is not added to the world.
+    pub fn is_pose_free(
+        &self,
+        shape: &dyn Shape<N>,
+        pose: &Isometry<N>,
+        groups: &CollisionGroups,
+        filter: &dyn Fn(CollisionObjectSlabHandle, &CollisionObject<N, T>) -> bool,
+    ) -> bool {
+        let aabb = shape.aabb(pose);
+
+        for (handle, co) in self.interferences_with_aabb(&aabb, groups) {
+            if filter(handle, co)
+                && query::intersection_test(pose, shape, co.position(), co.shape().as_ref())
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Finds the pairs of objects that collided between their previous and current positions,
+    /// but that are not interacting anymore.
+    ///
+    /// Each object with an entry in `prev_positions` is assumed to have moved linearly from that
+    /// previous position to its current position. Its rotation is ignored: the shape keeps the
+    /// orientation of the previous position during the whole motion. Objects without an entry in
+    /// `prev_positions` are assumed to be static. A pair is reported if the time of impact of the
+    /// two moving shapes is in `[0, 1]`, if they were not already penetrating at their previous
+    /// positions, and if there is no effective interaction between them in the interaction graph.
+    /// Thus, `self.update()` should be called after setting the current positions and before
+    /// calling this method. The first handle of each reported pair is smaller than the second one,
+    /// and the pairs are sorted so they do not depend on the iteration order of `prev_positions`.
+    pub fn detect_tunneling(
+        &self,
+        prev_positions: &HashMap<CollisionObjectSlabHandle, Isometry<N>>,
+    ) -> Vec<(CollisionObjectSlabHandle, CollisionObjectSlabHandle)> {
+        let mut moving = Vec::new();
+
+        for (handle, prev) in prev_positions {
+            if let Some(co) = self.objects.collision_object(*handle) {
+                let motion = co.position().translation.vector - prev.translation.vector;
+                let aabb = co
+                    .shape()
+                    .aabb(prev)
+                    .merged(&co.shape().aabb(co.position()));
+                moving.push((*handle, co, prev, motion, aabb));
+            }
+        }
+
+        let mut result = Vec::new();
+        let mut test_pair = |handle1: CollisionObjectSlabHandle,
+                             co1: &CollisionObject<N, T>,
+                             pos1: &Isometry<N>,
+                             motion1: &Vector<N>,
+                             handle2: CollisionObjectSlabHandle,
+                             co2: &CollisionObject<N, T>,
+                             pos2: &Isometry<N>,
+                             motion2: &Vector<N>| {
+            let dispatcher = &*self.toi_dispatcher;
+            let toi = dispatcher
+                .time_of_impact(
+                    dispatcher,
+                    pos1,
+                    motion1,
+                    co1.shape().as_ref(),
+                    pos2,
+                    motion2,
+                    co2.shape().as_ref(),
+                    N::one(),
+                    N::zero(),
+                )
+                .unwrap_or(None);
+
+            if let Some(toi) = toi {
+                if toi.status != TOIStatus::Penetrating
+                    && self.interaction_pair(handle1, handle2, true).is_none()
+                {
+                    result.push((handle1.min(handle2), handle1.max(handle2)));
+                }
+            }
+        };
+
+        for (i, (handle1, co1, prev1, motion1, aabb1)) in moving.iter().enumerate() {
+            // Moving objects against static objects.
+            for (handle2, co2) in self.interferences_with_aabb(aabb1, co1.collision_groups()) {
+                if !prev_positions.contains_key(&handle2) {
+                    test_pair(
+                        *handle1,
+                        co1,
+                        prev1,
+                        motion1,
+                        handle2,
+                        co2,
+                        co2.position(),
+                        &Vector::zeros(),
+                    );
+                }
+            }
+
+            // Moving objects against each other. The broad phase cannot be used here since it
+            // only knows the current positions of the objects.
+            for (handle2, co2, prev2, motion2, aabb2) in &moving[i + 1..] {
+                if aabb1.intersects(aabb2)
+                    && co1
+                        .collision_groups()
+                        .can_interact_with_groups(co2.collision_groups())
+                {
+                    test_pair(*handle1, co1, prev1, motion1, *handle2, co2, prev2, motion2);
+                }
+            }
+        }
+
+        result.sort();
+        result
+    }
+
+    /// Finds the pairs of objects with boundaries closer than `distance` from each other.
+    ///
+    /// The candidate pairs are found by the broad phase, with the AABB of each object enlarged by
+    /// `distance / 2`. Each candidate pair of objects allowed to interact by their collision
+    /// groups is then reported with the distance between their shapes, if it is smaller than
+    /// `distance`. Intersecting objects are reported with a distance of zero. Pairs of shapes the
+    /// distance cannot be computed between, e.g., two planes, are skipped. The first handle of
+    /// each reported pair is smaller than the second one. The broad phase is queried with the
+    /// positions of the objects at the last call to `self.update()`.
+    pub fn pairs_within_distance(
+        &self,
+        distance: N,
+    ) -> Vec<(CollisionObjectSlabHandle, CollisionObjectSlabHandle, N)> {
+        let mut result = Vec::new();
+
+        for (handle1, co1) in self.objects.iter() {
+            // Enlarging both AABBs by `distance / 2` is the same as enlarging one by `distance`.
+            let aabb = co1.shape().aabb(co1.position()).loosened(distance);
+
+            for (handle2, co2) in self.interferences_with_aabb(&aabb, co1.collision_groups()) {
+                if handle1 >= handle2 {
+                    continue;
+                }
+
+                let dist = query::try_distance(
+                    co1.position(),
+                    co1.shape().as_ref(),
+                    co2.position(),
+                    co2.shape().as_ref(),
+                );
+
+                if let Ok(dist) = dist {
+                    if dist < distance {
+                        result.push((handle1, handle2, dist));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Collects all objects whose broad-phase AABB is touched by a capsule swept along a path.
+    ///
+    /// The path is the polyline joining consecutive `points`, inflated by `radius`. Each object is
+    /// pushed at most once into `out`, even if it is touched by several segments of the path.
+    #[inline]
+    pub fn interferences_along_path(
+        &self,
+        points: &[Point<N>],
+        radius: N,
+        groups: &CollisionGroups,
+        out: &mut Vec<CollisionObjectSlabHandle>,
+    ) {
+        glue::interferences_along_path(
+            &self.objects,
+            &*self.broad_phase,
+            points,
+            radius,
+            groups,
+            out,
+        )
+    }
+
     /// Computes the interferences between every rigid bodies on this world and a ray.
     #[inline]
     pub fn interferences_with_ray<'a, 'b>(
@@ -348,6 +898,31 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         glue::interferences_with_ray(&self.objects, &*self.broad_phase, ray, max_toi, groups)
     }
 
+    /// Computes all the objects intersected by a ray, sorted by increasing time of impact.
+    ///
+    /// Objects rejected by `groups` are ignored, as well as objects with a proximity query type
+    /// unless `include_proximity` is `true`. If `solid` is `true`, objects containing the ray
+    /// origin are hit at a time of impact equal to zero.
+    pub fn interferences_with_ray_sorted(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        groups: &CollisionGroups,
+        solid: bool,
+        include_proximity: bool,
+    ) -> impl Iterator<Item = (CollisionObjectSlabHandle, RayIntersection<N>)> {
+        glue::interferences_with_ray_sorted(
+            &self.objects,
+            &*self.broad_phase,
+            ray,
+            max_toi,
+            groups,
+            solid,
+            include_proximity,
+        )
+        .into_iter()
+    }
+
     /// Computes the first interference with `ray` and
     #[inline]
     pub fn first_interference_with_ray<'a, 'b>(
@@ -359,6 +934,50 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         glue::first_interference_with_ray(&self.objects, &*self.broad_phase, ray, max_toi, groups)
     }
 
+    /// Computes the first object hit by each ray of `rays`.
+    ///
+    /// The i-th element of the result is the first hit of the i-th ray, if any. Objects rejected
+    /// by `groups` or for which `filter` returns `false` are ignored. With the `parallel` feature
+    /// enabled, the rays are cast on the rayon thread pool. Each ray is cast independently from
+    /// the others so the result is the same as with the serial version.
+    pub fn cast_rays(
+        &self,
+        rays: &[Ray<N>],
+        groups: &CollisionGroups,
+        filter: &(dyn Fn(CollisionObjectSlabHandle, &CollisionObject<N, T>) -> bool + Sync),
+    ) -> Vec<Option<(CollisionObjectSlabHandle, RayIntersection<N>)>>
+    where
+        T: Sync,
+    {
+        let cast_ray = |ray: &Ray<N>| {
+            let narrow_phase = |handle, ray: &Ray<N>, max_toi: N| {
+                let co = self.objects.collision_object(handle)?;
+
+                if co.collision_groups().can_interact_with_groups(groups) && filter(handle, co) {
+                    co.shape()
+                        .toi_and_normal_with_ray(co.position(), ray, max_toi, true)
+                        .map(|inter| (handle, inter))
+                } else {
+                    None
+                }
+            };
+
+            self.broad_phase
+                .first_interference_with_ray(ray, N::max_value(), &narrow_phase)
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            rays.par_iter().map(cast_ray).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            rays.iter().map(cast_ray).collect()
+        }
+    }
+
     /// Computes the interferences between every rigid bodies of a given broad phase, and a point.
     #[inline]
     pub fn interferences_with_point<'a, 'b>(
@@ -379,8 +998,75 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         glue::interferences_with_aabb(&self.objects, &*self.broad_phase, aabb, groups)
     }
 
+    /// Computes the surface normals of all the objects closer than `radius` to a point.
+    ///
+    /// The point is projected on the boundary of each object that can interact with `groups` and
+    /// whose AABB intersects the box of half-extents `radius` centered at `pt`. For each object
+    /// with a projection closer than `radius` to `pt`, its handle and the outward normal of its
+    /// boundary at the projection are pushed into `out`, which is not cleared beforehand. Objects
+    /// with shapes that do not support point projection are ignored.
+    pub fn surface_normals_near(
+        &self,
+        pt: &Point<N>,
+        radius: N,
+        groups: &CollisionGroups,
+        out: &mut Vec<(CollisionObjectSlabHandle, Unit<Vector<N>>)>,
+    ) {
+        let aabb = AABB::from_half_extents(*pt, Vector::repeat(radius));
+
+        for (handle, co) in self.interferences_with_aabb(&aabb, groups) {
+            let (proj, normal) = match co.shape().as_point_query() {
+                Some(point_query) => point_query.project_point_with_normal(co.position(), pt),
+                None => continue,
+            };
+
+            if na::distance_squared(pt, &proj.point) <= radius * radius {
+                out.push((handle, normal));
+            }
+        }
+    }
+
+    /// Finds the object with the boundary closest to `pt`, among those closer than `max_dist`.
+    ///
+    /// Returns the handle of this object, the projection of `pt` on its boundary, and the outward
+    /// normal of its boundary at the projected point. Objects rejected by `groups` or for which
+    /// `filter` returns `false`, and objects with shapes that do not support point projection,
+    /// are ignored. The objects are visited by increasing distance to their AABB, so the objects
+    /// with an AABB farther than the closest boundary found so far are not projected on.
+    ///
+    /// This always returns `None` if the broad phase does not override
+    /// `BroadPhase::nearest_interference_with_point`.
+    pub fn nearest_surface(
+        &self,
+        pt: &Point<N>,
+        max_dist: N,
+        groups: &CollisionGroups,
+        filter: &dyn Fn(CollisionObjectSlabHandle, &CollisionObject<N, T>) -> bool,
+    ) -> Option<(
+        CollisionObjectSlabHandle,
+        PointProjection<N>,
+        Unit<Vector<N>>,
+    )> {
+        let narrow_phase = |handle, pt: &Point<N>, _| {
+            let co = self.objects.collision_object(handle)?;
+
+            if co.collision_groups().can_interact_with_groups(groups) && filter(handle, co) {
+                let (proj, normal) = co
+                    .shape()
+                    .as_point_query()?
+                    .project_point_with_normal(co.position(), pt);
+                Some((handle, proj, normal))
+            } else {
+                None
+            }
+        };
+
+        self.broad_phase
+            .nearest_interference_with_point(pt, max_dist, &narrow_phase)
+    }
+
     /// Customize the selection of narrowphase collision detection algorithms
-    pub fn set_narrow_phase(&mut self, narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle>) {
+    pub fn set_narrow_phase(&mut self, narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle, C>) {
         self.narrow_phase = narrow_phase;
         self.broad_phase.deferred_recompute_all_proximities();
     }
@@ -402,12 +1088,193 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         Item = (
             CollisionObjectSlabHandle,
             CollisionObjectSlabHandle,
-            &Interaction<N>,
+            &Interaction<N, C>,
         ),
     > {
         self.interactions.interaction_pairs(effective_only)
     }
 
+    /// Same as `self.interaction_pairs(effective_only)`, sorted by the handles of the two
+    /// collision objects of each pair.
+    ///
+    /// Unlike `interaction_pairs`, the iteration order does not depend on the internal layout of
+    /// the interaction graph, so it is the same for two worlds on which the same operations were
+    /// performed.
+    pub fn interaction_pairs_sorted(
+        &self,
+        effective_only: bool,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            &Interaction<N, C>,
+        ),
+    > {
+        let mut pairs: Vec<_> = self.interaction_pairs(effective_only).collect();
+        pairs.sort_unstable_by_key(|(h1, h2, _)| (*h1, *h2));
+        pairs.into_iter()
+    }
+
+    /// Copies the current interaction pairs of this world, along with their contact manifolds.
+    ///
+    /// The snapshot contains every pair detected by the broad phase for which a contact or
+    /// proximity algorithm has been dispatched, effective or not. It is not modified by subsequent
+    /// updates of this world, so it can be iterated on repeatedly until it is replaced.
+    pub fn snapshot_pairs(&self) -> PairSnapshot<N, C> {
+        let mut contacts = Vec::new();
+        let mut proximities = Vec::new();
+
+        for (h1, h2, interaction) in self.interactions.interaction_pairs(false) {
+            match interaction {
+                Interaction::Contact(_, manifold) => contacts.push((h1, h2, manifold.clone())),
+                Interaction::Proximity(_, prox) => proximities.push((h1, h2, *prox)),
+            }
+        }
+
+        PairSnapshot {
+            contacts,
+            proximities,
+        }
+    }
+
+    /// Saves the state of this world so it can be restored later by `restore`.
+    ///
+    /// The snapshot should be taken right after a call to `update`, so the contact manifolds it
+    /// contains are consistent with the positions of the collision objects.
+    pub fn snapshot(&self) -> WorldSnapshot<N, C> {
+        let objects = self
+            .objects
+            .iter()
+            .map(|(handle, co)| ObjectSnapshot {
+                handle,
+                position: *co.position(),
+                predicted_position: co.predicted_position().cloned(),
+                collision_groups: *co.collision_groups(),
+                query_type: co.query_type(),
+            })
+            .collect();
+
+        WorldSnapshot {
+            objects,
+            pairs: self.snapshot_pairs(),
+        }
+    }
+
+    /// Restores the state of this world saved by `snapshot`.
+    ///
+    /// Only the collision objects with a state different from the snapshot are updated on the
+    /// broad phase, and the narrow phase is not executed: the contact manifolds and proximity
+    /// statuses of the interaction pairs are copied from the snapshot instead, along with their
+    /// contact identifiers and user-defined contact data. The event pools are cleared.
+    ///
+    /// The collision objects added or removed since the snapshot was taken are neither removed nor
+    /// re-created, and the internal state of the contact and proximity algorithms is not restored.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot<N, C>) {
+        for state in &snapshot.objects {
+            if let Some(co) = self.objects.get_mut(state.handle) {
+                if co.position() != &state.position
+                    || co.predicted_position() != state.predicted_position.as_ref()
+                {
+                    match state.predicted_position {
+                        Some(predicted) => {
+                            co.set_position_with_prediction(state.position, predicted)
+                        }
+                        None => co.set_position(state.position),
+                    }
+                }
+
+                if co.collision_groups() != &state.collision_groups {
+                    co.set_collision_groups(state.collision_groups);
+                }
+
+                if co.query_type() != state.query_type {
+                    co.set_query_type(state.query_type);
+                }
+            }
+        }
+
+        // Updates the proxies of the modified objects, and the set of interaction pairs.
+        self.perform_broad_phase();
+
+        let sorted = |h1, h2| if h1 < h2 { (h1, h2) } else { (h2, h1) };
+        let contacts: HashMap<_, _> = snapshot
+            .pairs
+            .contact_pairs()
+            .map(|(h1, h2, manifold)| (sorted(h1, h2), (h1, h2, manifold)))
+            .collect();
+        let proximities: HashMap<_, _> = snapshot
+            .pairs
+            .proximity_pairs()
+            .map(|(h1, h2, prox)| (sorted(h1, h2), prox))
+            .collect();
+        let mut flipped = Vec::new();
+
+        for eid in self.interactions.0.edge_indices() {
+            let (id1, id2) = self.interactions.0.edge_endpoints(eid).unwrap();
+            let h1 = self.interactions.0[id1];
+            let h2 = self.interactions.0[id2];
+            let key = sorted(h1, h2);
+
+            match self.interactions.0.edge_weight_mut(eid).unwrap() {
+                Interaction::Contact(_, manifold) => match contacts.get(&key) {
+                    Some((saved1, _, saved)) if *saved1 == h1 => *manifold = (*saved).clone(),
+                    Some(_) => flipped.push(key),
+                    None => manifold.clear(),
+                },
+                Interaction::Proximity(_, prox) => {
+                    *prox = proximities
+                        .get(&key)
+                        .copied()
+                        .unwrap_or(Proximity::Disjoint)
+                }
+            }
+        }
+
+        // The contact manifolds depend on the order of the objects of the pair, which can only be
+        // changed by dispatching a new contact algorithm.
+        for key in flipped {
+            let (h1, h2, saved) = contacts[&key];
+            let id1 = self.objects[h1]
+                .graph_index()
+                .expect(crate::NOT_REGISTERED_ERROR);
+            let id2 = self.objects[h2]
+                .graph_index()
+                .expect(crate::NOT_REGISTERED_ERROR);
+
+            if let Some(eid) = self.interactions.0.find_edge(id1, id2) {
+                let _ = self.interactions.0.remove_edge(eid);
+            }
+
+            self.narrow_phase.handle_interaction(
+                &mut self.interactions,
+                &self.objects,
+                h1,
+                h2,
+                true,
+            );
+
+            if let Some((_, _, Interaction::Contact(_, manifold))) =
+                self.interactions.interaction_pair_mut(id1, id2)
+            {
+                *manifold = saved.clone();
+            }
+        }
+
+        for (_, co) in self.objects.iter_mut() {
+            co.clear_update_flags();
+        }
+
+        self.narrow_phase.clear_events();
+    }
+
+    /// Exports the interaction graph of this world to the Graphviz DOT format.
+    ///
+    /// Nodes are labeled by the unique identifiers of their collision object handles. See
+    /// `InteractionGraph::to_dot` for details about the edge styles.
+    pub fn to_dot(&self) -> String {
+        self.interactions.to_dot(|handle| handle.uid().to_string())
+    }
+
     /// All the potential contact pairs.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -419,13 +1286,56 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         Item = (
             CollisionObjectSlabHandle,
             CollisionObjectSlabHandle,
-            &ContactAlgorithm<N>,
-            &ContactManifold<N>,
+            &ContactAlgorithm<N, C>,
+            &ContactManifold<N, C>,
         ),
     > {
         self.interactions.contact_pairs(effective_only)
     }
 
+    /// Same as `self.contact_pairs(effective_only)`, sorted by the handles of the two collision
+    /// objects of each pair.
+    ///
+    /// Unlike `contact_pairs`, the iteration order does not depend on the internal layout of the
+    /// interaction graph, so it is the same for two worlds on which the same operations were
+    /// performed.
+    pub fn contact_pairs_sorted(
+        &self,
+        effective_only: bool,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            &ContactAlgorithm<N, C>,
+            &ContactManifold<N, C>,
+        ),
+    > {
+        let mut pairs: Vec<_> = self.contact_pairs(effective_only).collect();
+        pairs.sort_unstable_by_key(|(h1, h2, _, _)| (*h1, *h2));
+        pairs.into_iter()
+    }
+
+    /// All the contacts with a penetration depth strictly greater than `min_depth`.
+    ///
+    /// Each contact is yielded along with the handles of the two collision objects of its contact
+    /// pair. This is a filter applied to the contacts after their generation, so it is independent
+    /// from the prediction distances of the collision objects. See `ContactManifold::deep_contacts`.
+    pub fn deep_contacts(
+        &self,
+        min_depth: N,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            &TrackedContact<N, C>,
+        ),
+    > {
+        self.contact_pairs(false)
+            .flat_map(move |(h1, h2, _, manifold)| {
+                manifold.deep_contacts(min_depth).map(move |c| (h1, h2, c))
+            })
+    }
+
     /// All the potential proximity pairs.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -456,7 +1366,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     ) -> Option<(
         CollisionObjectSlabHandle,
         CollisionObjectSlabHandle,
-        &Interaction<N>,
+        &Interaction<N, C>,
     )> {
         let co1 = self.objects.collision_object(handle1)?;
         let co2 = self.objects.collision_object(handle2)?;
@@ -477,8 +1387,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     ) -> Option<(
         CollisionObjectSlabHandle,
         CollisionObjectSlabHandle,
-        &ContactAlgorithm<N>,
-        &ContactManifold<N>,
+        &ContactAlgorithm<N, C>,
+        &ContactManifold<N, C>,
     )> {
         let co1 = self.objects.collision_object(handle1)?;
         let co2 = self.objects.collision_object(handle2)?;
@@ -487,6 +1397,27 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.interactions.contact_pair(id1, id2, effective_only)
     }
 
+    /// The potential contact pair between the two specified collision objects.
+    ///
+    /// This gives a mutable access to the contact manifold, e.g., to modify the user-defined data
+    /// of its contacts.
+    pub fn contact_pair_mut(
+        &mut self,
+        handle1: CollisionObjectSlabHandle,
+        handle2: CollisionObjectSlabHandle,
+    ) -> Option<(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        &mut ContactAlgorithm<N, C>,
+        &mut ContactManifold<N, C>,
+    )> {
+        let co1 = self.objects.collision_object(handle1)?;
+        let co2 = self.objects.collision_object(handle2)?;
+        let id1 = co1.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        let id2 = co2.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        self.interactions.contact_pair_mut(id1, id2)
+    }
+
     /// The potential proximity pair between the two specified collision objects.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -522,7 +1453,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             Item = (
                 CollisionObjectSlabHandle,
                 CollisionObjectSlabHandle,
-                &Interaction<N>,
+                &Interaction<N, C>,
             ),
         >,
     > {
@@ -539,13 +1470,13 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         &mut self,
         handle: CollisionObjectSlabHandle,
     ) -> Option<(
-        &mut NarrowPhase<N, CollisionObjectSlabHandle>,
+        &mut NarrowPhase<N, CollisionObjectSlabHandle, C>,
         impl Iterator<
             Item = (
                 CollisionObjectSlabHandle,
                 CollisionObjectSlabHandle,
                 TemporaryInteractionIndex,
-                &mut Interaction<N>,
+                &mut Interaction<N, C>,
             ),
         >,
     )> {
@@ -593,8 +1524,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             Item = (
                 CollisionObjectSlabHandle,
                 CollisionObjectSlabHandle,
-                &ContactAlgorithm<N>,
-                &ContactManifold<N>,
+                &ContactAlgorithm<N, C>,
+                &ContactManifold<N, C>,
             ),
         >,
     > {
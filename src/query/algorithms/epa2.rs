@@ -10,6 +10,7 @@ use crate::math::{Isometry, Point, Vector};
 use crate::query::algorithms::{
     gjk, special_support_maps::ConstantOrigin, CSOPoint, VoronoiSimplex,
 };
+use crate::query::diagnostics;
 use crate::shape::SupportMap;
 use crate::utils;
 
@@ -165,6 +166,27 @@ impl<N: RealField> EPA<N> {
         g2: &G2,
         simplex: &VoronoiSimplex<N>,
     ) -> Option<(Point<N>, Point<N>, Unit<Vector<N>>)>
+    where
+        G1: SupportMap<N>,
+        G2: SupportMap<N>,
+    {
+        let mut niter = 0;
+        let mut converged = false;
+        let res = self.do_closest_points(m1, g1, m2, g2, simplex, &mut niter, &mut converged);
+        diagnostics::record_epa(niter, self.vertices.len(), converged, res.is_none());
+        res
+    }
+
+    fn do_closest_points<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        m1: &Isometry<N>,
+        g1: &G1,
+        m2: &Isometry<N>,
+        g2: &G2,
+        simplex: &VoronoiSimplex<N>,
+        niter: &mut usize,
+        converged: &mut bool,
+    ) -> Option<(Point<N>, Point<N>, Unit<Vector<N>>)>
     where
         G1: SupportMap<N>,
         G2: SupportMap<N>,
@@ -219,6 +241,7 @@ impl<N: RealField> EPA<N> {
                 }
             }
 
+            *converged = true;
             return Some((Point::origin(), Point::origin(), n));
         } else if simplex.dimension() == 2 {
             let dp1 = self.vertices[1] - self.vertices[0];
@@ -278,7 +301,6 @@ impl<N: RealField> EPA<N> {
             self.heap.push(FaceId::new(1, dist2)?);
         }
 
-        let mut niter = 0;
         let mut max_dist = N::max_value();
         let mut best_face_id = *self.heap.peek().unwrap();
 
@@ -307,6 +329,7 @@ impl<N: RealField> EPA<N> {
             let curr_dist = -face_id.neg_dist;
 
             if max_dist - curr_dist < _eps_tol {
+                *converged = true;
                 let best_face = &self.faces[best_face_id.id];
                 let cpts = best_face.closest_points(&self.vertices);
                 return Some((cpts.0, cpts.1, best_face.normal));
@@ -338,12 +361,13 @@ impl<N: RealField> EPA<N> {
                 self.faces.push(f.0.clone());
             }
 
-            niter += 1;
-            if niter > 10000 {
+            *niter += 1;
+            if *niter > 10000 {
                 return None;
             }
         }
 
+        *converged = true;
         let best_face = &self.faces[best_face_id.id];
         let cpts = best_face.closest_points(&self.vertices);
         return Some((cpts.0, cpts.1, best_face.normal));
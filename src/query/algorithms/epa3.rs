@@ -3,7 +3,7 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::query::algorithms::special_support_maps::ConstantOrigin;
 use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
-use crate::query::PointQueryWithLocation;
+use crate::query::{diagnostics, PointQueryWithLocation};
 use crate::shape::{SupportMap, Triangle, TrianglePointLocation};
 use crate::utils;
 use na::{self, RealField, Unit};
@@ -223,6 +223,27 @@ impl<N: RealField> EPA<N> {
         g2: &G2,
         simplex: &VoronoiSimplex<N>,
     ) -> Option<(Point<N>, Point<N>, Unit<Vector<N>>)>
+    where
+        G1: SupportMap<N>,
+        G2: SupportMap<N>,
+    {
+        let mut niter = 0;
+        let mut converged = false;
+        let res = self.do_closest_points(m1, g1, m2, g2, simplex, &mut niter, &mut converged);
+        diagnostics::record_epa(niter, self.vertices.len(), converged, res.is_none());
+        res
+    }
+
+    fn do_closest_points<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        m1: &Isometry<N>,
+        g1: &G1,
+        m2: &Isometry<N>,
+        g2: &G2,
+        simplex: &VoronoiSimplex<N>,
+        niter: &mut usize,
+        converged: &mut bool,
+    ) -> Option<(Point<N>, Point<N>, Unit<Vector<N>>)>
     where
         G1: SupportMap<N>,
         G2: SupportMap<N>,
@@ -242,6 +263,7 @@ impl<N: RealField> EPA<N> {
         if simplex.dimension() == 0 {
             let mut n: Vector<N> = na::zero();
             n[1] = na::one();
+            *converged = true;
             return Some((Point::origin(), Point::origin(), Unit::new_unchecked(n)));
         } else if simplex.dimension() == 3 {
             let dp1 = self.vertices[1] - self.vertices[0];
@@ -319,7 +341,6 @@ impl<N: RealField> EPA<N> {
             self.heap.push(FaceId::new(1, na::zero())?);
         }
 
-        let mut niter = 0;
         let mut max_dist = N::max_value();
         let mut best_face_id = *self.heap.peek().unwrap();
 
@@ -348,6 +369,7 @@ impl<N: RealField> EPA<N> {
             let curr_dist = -face_id.neg_dist;
 
             if max_dist - curr_dist < _eps_tol {
+                *converged = true;
                 let best_face = &self.faces[best_face_id.id];
                 let points = best_face.closest_points(&self.vertices);
                 return Some((points.0, points.1, best_face.normal));
@@ -417,12 +439,13 @@ impl<N: RealField> EPA<N> {
             self.silhouette.clear();
             // self.check_topology(); // NOTE: for debugging only.
 
-            niter += 1;
-            if niter > 10000 {
+            *niter += 1;
+            if *niter > 10000 {
                 return None;
             }
         }
 
+        *converged = true;
         let best_face = &self.faces[best_face_id.id];
         let points = best_face.closest_points(&self.vertices);
         return Some((points.0, points.1, best_face.normal));
@@ -7,7 +7,7 @@ use crate::query::algorithms::{special_support_maps::ConstantOrigin, CSOPoint, V
 use crate::shape::SupportMap;
 // use query::Proximity;
 use crate::math::{Isometry, Point, Vector, DIM};
-use crate::query::{self, Ray};
+use crate::query::{self, diagnostics, Ray};
 
 /// Results of the GJK algorithm.
 #[derive(Clone, Debug, PartialEq)]
@@ -22,6 +22,128 @@ pub enum GJKResult<N: RealField> {
     NoIntersection(Unit<Vector<N>>),
 }
 
+/// The maximum change of the relative pose of two shapes, in radians, for which the simplex
+/// found by the last run of the GJK algorithm is still used to initialize the next one.
+const MAX_WARM_START_ANGLE: f64 = 0.5;
+
+/// Data kept between successive runs of the GJK algorithm on the same pair of shapes.
+///
+/// The support points of both shapes forming the simplex the last run stopped with are recorded
+/// in the local-space of their respective shape. The next run starts from the simplex formed by
+/// these points at the new poses of the shapes. If the shapes only moved a little bit relative to
+/// each other in-between, this simplex is already close to the final one and the GJK algorithm
+/// converges in very few iterations. If the relative orientation of the shapes, or the direction
+/// from the origin of the first shape to the origin of the second one, in the local-space of the
+/// first shape, changed by more than half a radian, this simplex is considered stale and the
+/// context is reset before the next run.
+///
+/// The result of a run initialized with this context is always correct since the recorded points
+/// still lie on their shapes, though it may take more iterations than a run initialized from
+/// scratch if the context is not stale but does not match the new poses very well.
+#[derive(Clone, Debug)]
+pub struct GJKContext<N: RealField> {
+    simplex: VoronoiSimplex<N>,
+    // The support points on the first and second shapes of the vertices of the last simplex, in
+    // the local-space of their respective shape.
+    local_vertices: Vec<(Point<N>, Point<N>)>,
+    // The pose of the second shape relative to the first one, at the last run.
+    pose12: Isometry<N>,
+}
+
+impl<N: RealField> GJKContext<N> {
+    /// Creates a new empty context.
+    pub fn new() -> Self {
+        GJKContext {
+            simplex: VoronoiSimplex::new(),
+            local_vertices: Vec::new(),
+            pose12: Isometry::identity(),
+        }
+    }
+
+    /// Forgets the result of the last run, so the next run starts from scratch.
+    pub fn reset(&mut self) {
+        self.local_vertices.clear()
+    }
+
+    /// Whether the next run will be initialized by the result of the last one.
+    ///
+    /// This does not check if the simplex of the last run is stale.
+    pub fn is_warm(&self) -> bool {
+        !self.local_vertices.is_empty()
+    }
+
+    /// The simplex used by the GJK algorithm.
+    pub fn simplex_mut(&mut self) -> &mut VoronoiSimplex<N> {
+        &mut self.simplex
+    }
+
+    /// Initializes the simplex with the support points recorded by the last run, for shapes with
+    /// the poses `m1` and `m2`.
+    ///
+    /// Resets this context and returns `false`, leaving the simplex unchanged, if the recorded
+    /// simplex is stale, or if there is none.
+    pub fn warm_start(&mut self, m1: &Isometry<N>, m2: &Isometry<N>) -> bool {
+        if self.local_vertices.is_empty() {
+            return false;
+        }
+
+        let pose12 = m1.inverse() * m2;
+        let cos_max_angle = na::convert::<_, N>(MAX_WARM_START_ANGLE).cos();
+        let rotation = (self.pose12.rotation.inverse() * pose12.rotation).angle();
+        let mut stale = rotation.abs() > na::convert(MAX_WARM_START_ANGLE);
+
+        if let (Some(old), Some(new)) = (
+            Unit::try_new(self.pose12.translation.vector, N::default_epsilon()),
+            Unit::try_new(pose12.translation.vector, N::default_epsilon()),
+        ) {
+            stale = stale || old.dot(&new) < cos_max_angle;
+        }
+
+        if stale {
+            self.reset();
+            return false;
+        }
+
+        let mut vertices = self
+            .local_vertices
+            .iter()
+            .map(|(pt1, pt2)| CSOPoint::new(m1 * pt1, m2 * pt2));
+
+        if let Some(first) = vertices.next() {
+            self.simplex.reset(first);
+        }
+
+        for vertex in vertices {
+            // Vertices that became affinely dependent are skipped.
+            let _ = self.simplex.add_point(vertex);
+        }
+
+        true
+    }
+
+    /// Records the vertices of the simplex a run for shapes with the poses `m1` and `m2` stopped
+    /// with.
+    pub fn record_simplex(&mut self, m1: &Isometry<N>, m2: &Isometry<N>) {
+        let simplex = &self.simplex;
+        self.local_vertices = (0..simplex.dimension() + 1)
+            .map(|i| {
+                let pt = simplex.point(i);
+                (
+                    m1.inverse_transform_point(&pt.orig1),
+                    m2.inverse_transform_point(&pt.orig2),
+                )
+            })
+            .collect();
+        self.pose12 = m1.inverse() * m2;
+    }
+}
+
+impl<N: RealField> Default for GJKContext<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// The absolute tolerence used by the GJK algorithm.
 pub fn eps_tol<N: RealField>() -> N {
     let _eps = N::default_epsilon();
@@ -82,6 +204,91 @@ pub fn closest_points<N, G1: ?Sized, G2: ?Sized>(
     exact_dist: bool,
     simplex: &mut VoronoiSimplex<N>,
 ) -> GJKResult<N>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let mut niter = 0;
+    let (res, _) = do_closest_points(m1, g1, m2, g2, max_dist, exact_dist, simplex, &mut niter);
+    diagnostics::record_gjk(niter, niter == 10000);
+    res
+}
+
+/// A vertex of the simplex computed by the GJK algorithm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GJKSimplexVertex<N: RealField> {
+    /// The support point on the first shape, in the local-space of the first shape.
+    pub local1: Point<N>,
+    /// The support point on the second shape, in the local-space of the second shape.
+    pub local2: Point<N>,
+    /// The vertex of the CSO, i.e., the difference of the world-space support points on both
+    /// shapes.
+    pub point: Point<N>,
+    /// The barycentric coordinate of this vertex for the projection of the origin on the simplex.
+    pub weight: N,
+}
+
+/// Computes the distance between two shapes, and the simplex the GJK algorithm stopped with.
+///
+/// The simplex has between one and four vertices (three in 2D), with barycentric weights summing
+/// to one. The witness points on both shapes, in their respective local-space, are the weighted
+/// sums of the `local1` and `local2` support points of these vertices. Returns `None` if the
+/// shapes intersect, or if they are farther than `max_dist` from each other.
+///
+/// # Arguments:
+/// * simplex - the simplex to be used by the GJK algorithm. It must be already initialized
+///             with at least one point on the shape boundary.
+pub fn closest_points_with_simplex<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    max_dist: N,
+    simplex: &mut VoronoiSimplex<N>,
+) -> Option<(N, Vec<GJKSimplexVertex<N>>)>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let mut niter = 0;
+    let (res, prev) = do_closest_points(m1, g1, m2, g2, max_dist, true, simplex, &mut niter);
+    diagnostics::record_gjk(niter, niter == 10000);
+
+    if let GJKResult::ClosestPoints(p1, p2, _) = res {
+        let vertex = |point: &CSOPoint<N>, weight| GJKSimplexVertex {
+            local1: m1.inverse_transform_point(&point.orig1),
+            local2: m2.inverse_transform_point(&point.orig2),
+            point: point.point,
+            weight,
+        };
+        let vertices = if prev {
+            (0..simplex.prev_dimension() + 1)
+                .map(|i| vertex(simplex.prev_point(i), simplex.prev_proj_coord(i)))
+                .collect()
+        } else {
+            (0..simplex.dimension() + 1)
+                .map(|i| vertex(simplex.point(i), simplex.proj_coord(i)))
+                .collect()
+        };
+
+        Some((na::distance(&p1, &p2), vertices))
+    } else {
+        None
+    }
+}
+
+fn do_closest_points<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    max_dist: N,
+    exact_dist: bool,
+    simplex: &mut VoronoiSimplex<N>,
+    niter: &mut usize,
+) -> (GJKResult<N>, bool)
 where
     N: RealField,
     G1: SupportMap<N>,
@@ -99,12 +306,11 @@ where
     if let Some(proj_dir) = Unit::try_new(proj.coords, N::zero()) {
         old_dir = -proj_dir;
     } else {
-        return GJKResult::Intersection;
+        return (GJKResult::Intersection, false);
     }
 
     let mut max_bound = N::max_value();
     let mut dir;
-    let mut niter = 0;
 
     loop {
         let old_max_bound = max_bound;
@@ -114,15 +320,15 @@ where
             max_bound = dist;
         } else {
             // The origin is on the simplex.
-            return GJKResult::Intersection;
+            return (GJKResult::Intersection, false);
         }
 
         if max_bound >= old_max_bound {
             if exact_dist {
                 let (p1, p2) = result(simplex, true);
-                return GJKResult::ClosestPoints(p1, p2, old_dir); // upper bounds inconsistencies
+                return (GJKResult::ClosestPoints(p1, p2, old_dir), true); // upper bounds inconsistencies
             } else {
-                return GJKResult::Proximity(old_dir);
+                return (GJKResult::Proximity(old_dir), false);
             }
         }
 
@@ -132,24 +338,24 @@ where
         assert!(min_bound == min_bound);
 
         if min_bound > max_dist {
-            return GJKResult::NoIntersection(dir);
+            return (GJKResult::NoIntersection(dir), false);
         } else if !exact_dist && min_bound > na::zero() && max_bound <= max_dist {
-            return GJKResult::Proximity(old_dir);
+            return (GJKResult::Proximity(old_dir), false);
         } else if max_bound - min_bound <= _eps_rel * max_bound {
             if exact_dist {
                 let (p1, p2) = result(simplex, false);
-                return GJKResult::ClosestPoints(p1, p2, dir); // the distance found has a good enough precision
+                return (GJKResult::ClosestPoints(p1, p2, dir), false); // the distance found has a good enough precision
             } else {
-                return GJKResult::Proximity(dir);
+                return (GJKResult::Proximity(dir), false);
             }
         }
 
         if !simplex.add_point(cso_point) {
             if exact_dist {
                 let (p1, p2) = result(simplex, false);
-                return GJKResult::ClosestPoints(p1, p2, dir);
+                return (GJKResult::ClosestPoints(p1, p2, dir), false);
             } else {
-                return GJKResult::Proximity(dir);
+                return (GJKResult::Proximity(dir), false);
             }
         }
 
@@ -160,18 +366,76 @@ where
             if min_bound >= _eps_tol {
                 if exact_dist {
                     let (p1, p2) = result(simplex, true);
-                    return GJKResult::ClosestPoints(p1, p2, old_dir);
+                    return (GJKResult::ClosestPoints(p1, p2, old_dir), true);
                 } else {
                     // NOTE: previous implementation used old_proj here.
-                    return GJKResult::Proximity(old_dir);
+                    return (GJKResult::Proximity(old_dir), false);
                 }
             } else {
-                return GJKResult::Intersection; // Point inside of the cso.
+                return (GJKResult::Intersection, false); // Point inside of the cso.
             }
         }
+        *niter += 1;
+        if *niter == 10000 {
+            return (GJKResult::NoIntersection(Vector::x_axis()), false);
+        }
+    }
+}
+
+/// Tests whether the CSO of two shapes contains the origin using the boolean variant of the
+/// GJK algorithm.
+///
+/// The algorithm stops as soon as a separating axis is found, or as soon as the simplex
+/// encloses the origin. No distance information is computed.
+///
+/// # Arguments:
+/// * simplex - the simplex to be used by the GJK algorithm. It must be already initialized
+///             with at least one point on the shape boundary.
+pub fn intersection_test<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+) -> bool
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let _eps_tol: N = eps_tol();
+    let mut proj = simplex.project_origin_and_reduce();
+    let mut niter = 0;
+
+    loop {
+        let dir = if let Some(dir) = Unit::try_new(-proj.coords, _eps_tol) {
+            dir
+        } else {
+            // The origin is on the simplex.
+            return true;
+        };
+
+        let cso_point = CSOPoint::from_shapes(m1, g1, m2, g2, &dir);
+
+        if dir.dot(&cso_point.point.coords) < na::zero() {
+            // `dir` is a separating axis.
+            return false;
+        }
+
+        if !simplex.add_point(cso_point) {
+            // No progress can be made: the origin is at a non-zero distance from the CSO.
+            return false;
+        }
+
+        proj = simplex.project_origin_and_reduce();
+
+        if simplex.dimension() == DIM {
+            return true; // Point inside of the cso.
+        }
+
         niter += 1;
         if niter == 10000 {
-            return GJKResult::NoIntersection(Vector::x_axis());
+            return false;
         }
     }
 }
@@ -57,3 +57,23 @@ impl<'a, N: RealField, S: ?Sized + SupportMap<N>> SupportMap<N> for DilatedShape
         self.shape.local_support_point_toward(dir) + **dir * self.radius
     }
 }
+
+/// A shape scaled uniformly about its local origin.
+pub struct UniformlyScaledShape<'a, N: RealField, S: ?Sized + SupportMap<N>> {
+    /// The shape being scaled.
+    pub shape: &'a S,
+    /// The positive scaling factor.
+    pub scale: N,
+}
+
+impl<'a, N: RealField, S: ?Sized + SupportMap<N>> SupportMap<N> for UniformlyScaledShape<'a, N, S> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.shape.local_support_point(dir) * self.scale
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.shape.local_support_point_toward(dir) * self.scale
+    }
+}
@@ -0,0 +1,40 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Point, Vector};
+
+/// The intersection of the boundaries of two balls, given by their centers and radii.
+///
+/// Returns the center of the intersection circle, its radius, and the normal of its plane which
+/// points from `c1` toward `c2`. In 2D, the intersection consists of the two points at a distance
+/// `radius` from the returned center along the orthogonal of the normal.
+///
+/// If the balls are tangent, the intersection is their contact point, which is returned as a
+/// circle with a zero radius. This includes the case where one ball touches the boundary of the
+/// other from the inside. Returns `None` if the balls are disjoint, if one strictly contains the
+/// other, or if they are concentric.
+pub fn ball_ball_intersection_circle<N: RealField>(
+    c1: &Point<N>,
+    r1: N,
+    c2: &Point<N>,
+    r2: N,
+) -> Option<(Point<N>, N, Unit<Vector<N>>)> {
+    let (normal, dist) = Unit::try_new_and_get(c2 - c1, N::zero())?;
+
+    if dist > r1 + r2 || dist < (r1 - r2).abs() {
+        return None;
+    }
+
+    // The signed distance from `c1` to the plane of the circle.
+    let offset = (dist * dist + r1 * r1 - r2 * r2) / (dist * na::convert(2.0));
+    let center = c1 + *normal * offset;
+
+    if dist == r1 + r2 || dist == (r1 - r2).abs() {
+        // Tangent balls.
+        return Some((center, N::zero(), normal));
+    }
+
+    // Rounding errors may bring the squared radius slightly below zero close to tangency.
+    let radius = (r1 * r1 - offset * offset).max(N::zero()).sqrt();
+
+    Some((center, radius, normal))
+}
@@ -0,0 +1,122 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point};
+use crate::partitioning::{WeightedValue, BVH};
+use crate::query::{self, ClosestPoints};
+use crate::shape::Polyline;
+use na::{self, RealField};
+use std::collections::BinaryHeap;
+
+/// Closest points between two polylines.
+///
+/// The BVTs of both polylines are traversed simultaneously, visiting first the pairs of nodes
+/// with the closest AABBs. Pairs of nodes with AABBs further apart than `margin`, or than the
+/// closest pair of segments found so far, are pruned. Thus, the returned points are the closest
+/// points among all pairs of segments, not only a local minimum. If the polylines cross or
+/// touch each other, both points are equal to the first crossing point found.
+pub fn closest_points_polyline_polyline<N: RealField>(
+    m1: &Isometry<N>,
+    polyline1: &Polyline<N>,
+    m2: &Isometry<N>,
+    polyline2: &Polyline<N>,
+    margin: N,
+) -> ClosestPoints<N> {
+    let bvt1 = polyline1.bvt();
+    let bvt2 = polyline2.bvt();
+    let ls_m2 = m1.inverse() * m2;
+    let aabbs_distance = |bv1: &AABB<N>, bv2: &AABB<N>| {
+        let ls_bv2 = bv2.transform_by(&ls_m2);
+        let mut dist = N::zero();
+
+        for i in 0..bv1.mins.len() {
+            let gap = (bv1.mins[i] - ls_bv2.maxs[i])
+                .max(ls_bv2.mins[i] - bv1.maxs[i])
+                .max(N::zero());
+            dist += gap * gap;
+        }
+
+        dist.sqrt()
+    };
+
+    let mut queue: BinaryHeap<WeightedValue<N, _>> = BinaryHeap::new();
+    let mut best: Option<(N, Point<N>, Point<N>)> = None;
+
+    if let (Some(root1), Some(root2)) = (bvt1.root(), bvt2.root()) {
+        let dist = aabbs_distance(bvt1.content(root1).0, bvt2.content(root2).0);
+
+        if dist <= margin {
+            queue.push(WeightedValue::new((root1, root2), -dist));
+        }
+    }
+
+    while let Some(entry) = queue.pop() {
+        let best_dist = best.as_ref().map(|b| b.0).unwrap_or(margin);
+
+        if best.is_some() && -entry.cost >= best_dist {
+            // No pair left closer than the best one.
+            break;
+        }
+
+        let (node1, node2) = entry.value;
+        let (bv1, data1) = bvt1.content(node1);
+        let (bv2, data2) = bvt2.content(node2);
+
+        match (data1, data2) {
+            (Some(i1), Some(i2)) => {
+                let seg1 = polyline1.segment_at(*i1).transformed(m1);
+                let seg2 = polyline2.segment_at(*i2).transformed(m2);
+                let (loc1, loc2) = query::closest_points_segment_segment_with_locations_nD(
+                    (&seg1.a, &seg1.b),
+                    (&seg2.a, &seg2.b),
+                );
+                let p1 = seg1.point_at(&loc1);
+                let p2 = seg2.point_at(&loc2);
+                let dist = na::distance(&p1, &p2);
+                // Segments closer than this, relative to the magnitude of their coordinates, cross.
+                let tolerance = N::default_epsilon()
+                    * na::convert(100.0)
+                    * (N::one() + p1.coords.amax().max(p2.coords.amax()));
+
+                if dist <= tolerance {
+                    // No pair of segments can be closer.
+                    let crossing = na::center(&p1, &p2);
+                    return ClosestPoints::WithinMargin(crossing, crossing);
+                }
+
+                if dist <= best_dist && best.as_ref().map(|b| dist < b.0) != Some(false) {
+                    best = Some((dist, p1, p2));
+                }
+            }
+            _ => {
+                // Descend into the largest node, so both trees are refined evenly.
+                let descend1 = data2.is_some()
+                    || (data1.is_none()
+                        && bv1.half_extents().norm_squared() >= bv2.half_extents().norm_squared());
+
+                if descend1 {
+                    for i in 0..bvt1.num_children(node1) {
+                        let child1 = bvt1.child(i, node1);
+                        let dist = aabbs_distance(bvt1.content(child1).0, bv2);
+
+                        if dist <= best_dist {
+                            queue.push(WeightedValue::new((child1, node2), -dist));
+                        }
+                    }
+                } else {
+                    for i in 0..bvt2.num_children(node2) {
+                        let child2 = bvt2.child(i, node2);
+                        let dist = aabbs_distance(bv1, bvt2.content(child2).0);
+
+                        if dist <= best_dist {
+                            queue.push(WeightedValue::new((node1, child2), -dist));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    match best {
+        Some((_, p1, p2)) => ClosestPoints::WithinMargin(p1, p2),
+        None => ClosestPoints::Disjoint,
+    }
+}
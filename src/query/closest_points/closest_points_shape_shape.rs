@@ -2,7 +2,7 @@ use na::RealField;
 
 use crate::math::{Isometry, Point};
 use crate::query::{self, ClosestPoints};
-use crate::shape::{Ball, Plane, Segment, Shape};
+use crate::shape::{Ball, Plane, Polyline, Segment, Shape};
 
 /// Computes the pair of closest points between two shapes.
 ///
@@ -28,6 +28,10 @@ pub fn closest_points<N: RealField>(
         query::closest_points_support_map_plane(m1, s1, m2, p2, max_dist)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::closest_points_support_map_support_map(m1, s1, m2, s2, max_dist)
+    } else if let (Some(p1), Some(p2)) =
+        (g1.as_shape::<Polyline<N>>(), g2.as_shape::<Polyline<N>>())
+    {
+        query::closest_points_polyline_polyline(m1, p1, m2, p2, max_dist)
     } else if let Some(c1) = g1.as_composite_shape() {
         query::closest_points_composite_shape_shape(m1, c1, m2, g2, max_dist)
     } else if let Some(c2) = g2.as_composite_shape() {
@@ -12,6 +12,7 @@ pub use self::closest_points_line_line::{
 pub use self::closest_points_plane_support_map::{
     closest_points_plane_support_map, closest_points_support_map_plane,
 };
+pub use self::closest_points_polyline_polyline::closest_points_polyline_polyline;
 pub use self::closest_points_segment_segment::{
     closest_points_segment_segment, closest_points_segment_segment_with_locations,
     closest_points_segment_segment_with_locations_nD,
@@ -26,6 +27,7 @@ mod closest_points_ball_ball;
 mod closest_points_composite_shape_shape;
 mod closest_points_line_line;
 mod closest_points_plane_support_map;
+mod closest_points_polyline_polyline;
 mod closest_points_segment_segment;
 mod closest_points_shape_shape;
 mod closest_points_support_map_support_map;
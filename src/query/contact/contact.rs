@@ -1,5 +1,6 @@
 use crate::math::{Point, Vector};
 use crate::query::ContactKinematic;
+use crate::shape::FeatureId;
 use na::{self, RealField, Unit};
 use slotmap::Key;
 use std::mem;
@@ -24,6 +25,12 @@ pub struct Contact<N: RealField> {
 
     /// Penetration depth
     pub depth: N,
+
+    /// How central this contact is within the patch formed by the contacts of its manifold.
+    ///
+    /// This is `1` for contacts not part of a contact manifold. See
+    /// `ContactManifold::update_weights` for details.
+    pub weight: N,
 }
 
 impl<N: RealField> Contact<N> {
@@ -35,6 +42,7 @@ impl<N: RealField> Contact<N> {
             world2,
             normal,
             depth,
+            weight: N::one(),
         }
     }
 
@@ -55,37 +63,61 @@ impl<N: RealField> Contact<N> {
     }
 }
 
+/// User-defined data that can be attached to each contact of a contact manifold.
+pub trait ContactData: Default + Clone + 'static + Send + Sync {}
+
+impl<T: Default + Clone + 'static + Send + Sync> ContactData for T {}
+
 /// A contact combined with contact kinematic information as well as a persistent identifier.
 ///
 /// When ncollide is used to compute contact points between moving solids, it will attempt to
 /// match contact points found at successive frames. Two contact points are said to "match" if
 /// they can be seen as the same contact point that moved in-between frames. Two matching
 /// contact points are given the same `id` here.
+///
+/// The `data` field can be used to store any user-defined information (e.g. accumulated impulses
+/// for warm-starting). It is kept unchanged when this contact is matched with a new one, and set
+/// to `C::default()` for contacts that don't match any previous contact.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
-pub struct TrackedContact<N: RealField> {
+pub struct TrackedContact<N: RealField, C = ()> {
     /// The geometric contact information.
     pub contact: Contact<N>,
     /// The local contact kinematic.
     pub kinematic: ContactKinematic<N>,
     /// The identifier of this contact.
     pub id: ContactId,
+    /// User-defined data attached to this contact.
+    pub data: C,
 }
 
-impl<N: RealField> TrackedContact<N> {
+impl<N: RealField, C: Default> TrackedContact<N, C> {
     /// Creates a new tracked contact.
     pub fn new(contact: Contact<N>, kinematic: ContactKinematic<N>) -> Self {
         TrackedContact {
             contact,
             kinematic,
             id: ContactId::null(),
+            data: C::default(),
         }
     }
 }
 
+impl<N: RealField, C> TrackedContact<N, C> {
+    /// The features of the first and second shapes this contact lies on.
+    ///
+    /// Those are the features reported by the contact generator in `self.kinematic`.
+    #[inline]
+    pub fn features(&self) -> (FeatureId, FeatureId) {
+        (self.kinematic.feature1(), self.kinematic.feature2())
+    }
+}
+
 /// The prediction parameters for contact determination.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContactPrediction<N: RealField> {
     linear: N,
+    breaking_distance: N,
     angular1: N,
     angular2: N,
     cos_angular1: N,
@@ -96,9 +128,12 @@ pub struct ContactPrediction<N: RealField> {
 
 impl<N: RealField> ContactPrediction<N> {
     /// Initialize prediction parameters.
+    ///
+    /// The contact breaking distance is set to `linear`.
     pub fn new(linear: N, angular1: N, angular2: N) -> Self {
         ContactPrediction {
             linear,
+            breaking_distance: linear,
             angular1,
             angular2,
             cos_angular1: angular1.cos(),
@@ -120,6 +155,21 @@ impl<N: RealField> ContactPrediction<N> {
         self.linear = val
     }
 
+    /// The distance beyond which the contacts already tracked by a contact manifold are removed.
+    ///
+    /// Contacts that were not tracked yet are only added to the manifold if they are closer
+    /// than the linear prediction. This is only used by the narrow phase.
+    #[inline]
+    pub fn breaking_distance(&self) -> N {
+        self.breaking_distance
+    }
+
+    /// Sets the contact breaking distance.
+    #[inline]
+    pub fn set_breaking_distance(&mut self, val: N) {
+        self.breaking_distance = val
+    }
+
     /// The angular regularization for the first solid.
     #[inline]
     pub fn angular1(&self) -> N {
@@ -156,3 +206,18 @@ impl<N: RealField> ContactPrediction<N> {
         self.sin_angular2
     }
 }
+
+/// A contact expressed in the local frame of a contact manifold.
+///
+/// See `ContactManifold::in_contact_frame`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct LocalContact<N: RealField> {
+    /// Position of the contact on the first object, expressed in the contact frame.
+    pub point1: Point<N>,
+    /// Position of the contact on the second object, expressed in the contact frame.
+    pub point2: Point<N>,
+    /// Penetration depth.
+    pub depth: N,
+    /// The identifier of the tracked contact this local contact was computed from.
+    pub id: ContactId,
+}
@@ -0,0 +1,162 @@
+use crate::math::{Isometry, Point, Translation, Vector};
+use crate::query::{self, Contact};
+use crate::shape::{Arc, Ball, SupportMap};
+use na::{self, RealField, Unit};
+
+/// The convex hull of the curve of an arc, dilated by half of its thickness.
+struct DilatedArcHull<'a, N: RealField> {
+    arc: &'a Arc<N>,
+}
+
+impl<'a, N: RealField> SupportMap<N> for DilatedArcHull<'a, N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let pt = self.arc.curve_support_point(dir);
+
+        match Unit::try_new(*dir, N::default_epsilon()) {
+            Some(dir) => pt + dir.into_inner() * self.arc.half_thickness(),
+            None => pt,
+        }
+    }
+}
+
+/// Contact between an arc and a support-mapped shape (Cuboid, ConvexPolygon, etc.)
+///
+/// The outer side of the arc is handled with the GJK and EPA algorithms applied to the convex
+/// hull of the arc. The inner side of the arc is handled by searching the point of the
+/// support-mapped shape that is the furthest from the center of the arc. The rounded ends of
+/// the arc are handled like balls. The deepest of those contacts is returned.
+pub fn contact_arc_support_map<N: RealField, G: ?Sized + SupportMap<N>>(
+    marc: &Isometry<N>,
+    arc: &Arc<N>,
+    mother: &Isometry<N>,
+    other: &G,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let half_thickness = arc.half_thickness();
+    let mut best: Option<Contact<N>> = None;
+    let mut keep = |contact: Contact<N>| {
+        let deeper = match &best {
+            Some(best) => contact.depth > best.depth,
+            None => true,
+        };
+
+        if deeper {
+            best = Some(contact);
+        }
+    };
+
+    // The rounded ends.
+    let end_ball = Ball::new(half_thickness);
+
+    for end in &[arc.start_point(), arc.end_point()] {
+        let mend = marc * Translation::from(end.coords);
+
+        if let Some(contact) =
+            query::contact_support_map_support_map(&mend, &end_ball, mother, other, prediction)
+        {
+            keep(contact);
+        }
+    }
+
+    // The outer side. The contact is only valid if it lies on the curved part of the hull, and
+    // if the other shape does not lie inside of the inner side of the arc.
+    let hull = DilatedArcHull { arc };
+
+    if let Some(contact) =
+        query::contact_support_map_support_map(marc, &hull, mother, other, prediction)
+    {
+        let dpt1 = marc.inverse_transform_point(&contact.world1) - arc.center;
+        let dpt2 = marc.inverse_transform_point(&contact.world2) - arc.center;
+
+        if arc.contains_dir(&dpt1)
+            && dpt1.norm() >= arc.radius
+            && dpt2.norm() >= arc.radius - half_thickness
+        {
+            keep(contact);
+        }
+    }
+
+    // The inner side.
+    if arc.radius > half_thickness {
+        if let Some(contact) = contact_inner_side(marc, arc, mother, other, prediction) {
+            keep(contact);
+        }
+    }
+
+    best
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexPolygon, etc.) and an arc.
+pub fn contact_support_map_arc<N: RealField, G: ?Sized + SupportMap<N>>(
+    mother: &Isometry<N>,
+    other: &G,
+    marc: &Isometry<N>,
+    arc: &Arc<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    contact_arc_support_map(marc, arc, mother, other, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
+
+fn contact_inner_side<N: RealField, G: ?Sized + SupportMap<N>>(
+    marc: &Isometry<N>,
+    arc: &Arc<N>,
+    mother: &Isometry<N>,
+    other: &G,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let ls_mother = marc.inverse() * mother;
+    let mid_angle = (arc.start_angle + arc.end_angle) * na::convert(0.5);
+    let mut dir = Unit::try_new(
+        ls_mother.translation.vector - arc.center.coords,
+        N::default_epsilon(),
+    )
+    .unwrap_or_else(|| Unit::new_unchecked(Vector::new(mid_angle.cos(), mid_angle.sin())));
+
+    // Each iteration can only move `furthest` away from the center of the arc, so this
+    // converges to a point of the other shape locally furthest from the center.
+    let mut furthest = other.support_point_toward(&ls_mother, &dir);
+    let mut dist = (furthest - arc.center).dot(&dir);
+    let tolerance = arc.radius * N::default_epsilon().sqrt();
+
+    for _ in 0..MAX_FURTHEST_POINT_ITERATIONS {
+        match Unit::try_new_and_get(furthest - arc.center, N::default_epsilon()) {
+            Some((new_dir, new_dist)) => {
+                let pt = other.support_point_toward(&ls_mother, &new_dir);
+                let pt_dist = (pt - arc.center).dot(&new_dir);
+                dir = new_dir;
+                dist = new_dist;
+
+                if pt_dist <= new_dist + tolerance {
+                    break;
+                }
+
+                furthest = pt;
+                dist = pt_dist;
+            }
+            None => break,
+        }
+    }
+
+    if dist >= arc.radius || !arc.contains_dir(&dir) {
+        return None;
+    }
+
+    let inner_radius = arc.radius - arc.half_thickness();
+    let depth = dist - inner_radius;
+
+    if depth > -prediction {
+        let world1 = marc * (arc.center + dir.into_inner() * inner_radius);
+        let world2 = marc * furthest;
+        let normal = marc * -dir;
+
+        Some(Contact::new(world1, world2, normal, depth))
+    } else {
+        None
+    }
+}
+
+const MAX_FURTHEST_POINT_ITERATIONS: usize = 32;
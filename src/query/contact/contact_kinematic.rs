@@ -4,6 +4,7 @@ use crate::shape::{FeatureId, Shape};
 use na::{self, RealField, Unit};
 
 /// A shape geometry type at the neighborhood of a point.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NeighborhoodGeometry<N: RealField> {
     /// A punctual approximation.
@@ -15,6 +16,7 @@ pub enum NeighborhoodGeometry<N: RealField> {
 }
 
 /// The approximation of a shape on the neighborhood of a point.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
 pub struct LocalShapeApproximation<N: RealField> {
     // XXX: currently, there is no explicit representation
@@ -53,6 +55,7 @@ impl<N: RealField> LocalShapeApproximation<N> {
 /// from one frame to another. To achieve this, the local shape of the solids
 /// around the given points are approximated by either dilated lines (unbounded
 /// cylinders), planes, dilated points (spheres).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
 pub struct ContactKinematic<N: RealField> {
     approx1: LocalShapeApproximation<N>,
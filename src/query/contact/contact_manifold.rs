@@ -1,10 +1,12 @@
-use crate::math::Point;
+use crate::math::{Isometry, Orientation, Point, Rotation, Translation, Vector};
 use crate::query::ContactPreprocessor;
-use crate::query::{Contact, ContactKinematic, TrackedContact};
+use crate::query::{Contact, ContactKinematic, LocalContact, TrackedContact};
 use crate::shape::FeatureId;
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 use slab::Slab;
+use std::cmp::Ordering;
 use std::collections::{hash_map::Entry, HashMap};
+use std::mem;
 
 /// The technique used for contact tracking.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -17,6 +19,47 @@ pub enum ContactTrackingMode<N: RealField> {
     DistanceBased(N),
 }
 
+/// The velocity of one of the two objects of a contact manifold.
+///
+/// See `ContactManifold::set_velocity_hints`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VelocityHint<N: RealField> {
+    /// The world-space linear velocity of the object at `center`.
+    pub linvel: Vector<N>,
+    /// The world-space angular velocity of the object.
+    pub angvel: Orientation<N>,
+    /// The world-space point the angular velocity is applied at.
+    pub center: Point<N>,
+}
+
+impl<N: RealField> VelocityHint<N> {
+    /// Initializes a velocity hint from linear and angular velocities.
+    pub fn new(linvel: Vector<N>, angvel: Orientation<N>, center: Point<N>) -> Self {
+        VelocityHint {
+            linvel,
+            angvel,
+            center,
+        }
+    }
+
+    /// Initializes a purely translational velocity hint.
+    pub fn linear(linvel: Vector<N>) -> Self {
+        VelocityHint::new(linvel, Orientation::zeros(), Point::origin())
+    }
+
+    /// The world-space velocity of the point `pt` moving with the object.
+    pub fn velocity_at_point(&self, pt: &Point<N>) -> Vector<N> {
+        let dpt = pt - self.center;
+
+        #[cfg(feature = "dim2")]
+        return self.linvel + Vector::new(-dpt.y, dpt.x) * self.angvel.x;
+        #[cfg(feature = "dim3")]
+        return self.linvel + self.angvel.cross(&dpt);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 enum ContactCache<N: RealField> {
     FeatureBased(HashMap<(FeatureId, FeatureId), usize>),
@@ -29,16 +72,22 @@ enum ContactCache<N: RealField> {
 /// If the shapes are convex, then the convex hull of those contacts are often interpreted as surface.
 /// This structure is responsible for matching new contacts with old ones in order to perform an
 /// approximate tracking of the contact points.
+///
+/// Each contact can carry some user-defined data of type `C`, which moves with the contact as it
+/// is matched from one frame to the next. See `TrackedContact::data`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
-pub struct ContactManifold<N: RealField> {
+pub struct ContactManifold<N: RealField, C = ()> {
     ncontacts: usize,
     persistence: usize,
     deepest: usize,
-    contacts: Slab<(TrackedContact<N>, usize)>,
+    contacts: Slab<(TrackedContact<N, C>, usize)>,
     cache: ContactCache<N>,
+    velocity_hints: Option<(VelocityHint<N>, VelocityHint<N>)>,
+    canonical_ordering: bool,
 }
 
-impl<N: RealField> ContactManifold<N> {
+impl<N: RealField, C: Default> ContactManifold<N, C> {
     /// Initializes a contact manifold without any contact.
     ///
     /// The default contact tracking mode is set to `ContactTrackingMode::DistanceBased(0.02)`.
@@ -49,6 +98,8 @@ impl<N: RealField> ContactManifold<N> {
             persistence: 1,
             contacts: Slab::new(),
             cache: ContactCache::DistanceBased(Vec::new(), na::convert(0.02)),
+            velocity_hints: None,
+            canonical_ordering: false,
         }
     }
 
@@ -58,7 +109,7 @@ impl<N: RealField> ContactManifold<N> {
     }
 
     /// All the contact tracked by this manifold.
-    pub fn contacts(&self) -> impl Iterator<Item = &TrackedContact<N>> {
+    pub fn contacts(&self) -> impl Iterator<Item = &TrackedContact<N, C>> {
         let persistence = self.persistence;
         self.contacts
             .iter()
@@ -66,7 +117,7 @@ impl<N: RealField> ContactManifold<N> {
     }
 
     /// Mutable reference to all the contact tracked by this manifold.
-    pub fn contacts_mut(&mut self) -> impl Iterator<Item = &mut TrackedContact<N>> {
+    pub fn contacts_mut(&mut self) -> impl Iterator<Item = &mut TrackedContact<N, C>> {
         let persistence = self.persistence;
         self.contacts.iter_mut().filter_map(move |(_, c)| {
             if c.1 == persistence {
@@ -77,8 +128,46 @@ impl<N: RealField> ContactManifold<N> {
         })
     }
 
+    /// Matches the contacts of this manifold with those of `prev` by their pair of features.
+    ///
+    /// Returns the pairs `(i, j)` such that the `i`-th contact of `self.contacts()` and the `j`-th
+    /// contact of `prev.contacts()` lie on the same features of both shapes. Contacts with an
+    /// unknown feature are never matched. If several contacts of `prev` share the same features,
+    /// only the first one is matched.
+    pub fn match_previous<C2: Default>(
+        &self,
+        prev: &ContactManifold<N, C2>,
+    ) -> Vec<(usize, usize)> {
+        let mut prev_features = HashMap::new();
+
+        for (j, c) in prev.contacts().enumerate() {
+            let _ = prev_features.entry(c.features()).or_insert(j);
+        }
+
+        self.contacts()
+            .enumerate()
+            .filter_map(|(i, c)| {
+                let features = c.features();
+
+                if features.0 == FeatureId::Unknown || features.1 == FeatureId::Unknown {
+                    None
+                } else {
+                    prev_features.get(&features).map(|j| (i, *j))
+                }
+            })
+            .collect()
+    }
+
+    /// The contacts of this manifold with a penetration depth strictly greater than `min_depth`.
+    ///
+    /// Unlike the prediction distance used to generate the contacts, this does not change the
+    /// content of this manifold so the filtered contacts are still tracked.
+    pub fn deep_contacts(&self, min_depth: N) -> impl Iterator<Item = &TrackedContact<N, C>> {
+        self.contacts().filter(move |c| c.contact.depth > min_depth)
+    }
+
     /// The contact of this manifold with the deepest penetration depth.
-    pub fn deepest_contact(&self) -> Option<&TrackedContact<N>> {
+    pub fn deepest_contact(&self) -> Option<&TrackedContact<N, C>> {
         if self.len() != 0 {
             Some(&self.contacts[self.deepest].0)
         } else {
@@ -86,6 +175,336 @@ impl<N: RealField> ContactManifold<N> {
         }
     }
 
+    /// Checks whether this manifold contains coincident contacts or spans a patch of near-zero area.
+    ///
+    /// Two contacts are coincident if their contact points on the first shape are closer than
+    /// `tol`. In 3D, a manifold with at least three contacts has a near-zero area if all its
+    /// contact points lie closer than `tol` to a single line.
+    pub fn is_degenerate(&self, tol: N) -> bool {
+        let tol2 = tol * tol;
+
+        for (i, c1) in self.contacts().enumerate() {
+            for c2 in self.contacts().skip(i + 1) {
+                if na::distance_squared(&c1.contact.world1, &c2.contact.world1) <= tol2 {
+                    return true;
+                }
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            if self.len() >= 3 {
+                let p0 = self.contacts().next().unwrap().contact.world1;
+                let mut p1 = p0;
+
+                for c in self.contacts() {
+                    if na::distance_squared(&p0, &c.contact.world1) > na::distance_squared(&p0, &p1)
+                    {
+                        p1 = c.contact.world1;
+                    }
+                }
+
+                // No two contacts are coincident so `p1 - p0` cannot be zero.
+                let dir = (p1 - p0).normalize();
+
+                return self.contacts().all(|c| {
+                    let dpt = c.contact.world1 - p0;
+                    (dpt - dir * dpt.dot(&dir)).norm_squared() <= tol2
+                });
+            }
+        }
+
+        false
+    }
+
+    /// Merges the coincident contacts of this manifold.
+    ///
+    /// Two contacts are coincident if their contact points on the first shape are closer than
+    /// `tol`. Only the deepest contact of such pair is kept. Returns the number of contacts
+    /// removed from this manifold.
+    pub fn sanitize(&mut self, tol: N) -> usize {
+        let persistence = self.persistence;
+        let tol2 = tol * tol;
+        let active: Vec<usize> = self
+            .contacts
+            .iter()
+            .filter(|(_, c)| c.1 == persistence)
+            .map(|(i, _)| i)
+            .collect();
+        let mut nremoved = 0;
+
+        for (k, i) in active.iter().enumerate() {
+            if self.contacts[*i].1 != persistence {
+                // Already merged with a previous contact.
+                continue;
+            }
+
+            for j in &active[k + 1..] {
+                let c1 = &self.contacts[*i];
+                let c2 = &self.contacts[*j];
+
+                if c2.1 != persistence
+                    || na::distance_squared(&c1.0.contact.world1, &c2.0.contact.world1) > tol2
+                {
+                    continue;
+                }
+
+                let removed = if c2.0.contact.depth > c1.0.contact.depth {
+                    *i
+                } else {
+                    *j
+                };
+
+                // The removed contact is purged from the cache by the next call to
+                // `save_cache_and_clear`, so it will not be matched by the next update.
+                self.contacts[removed].1 = persistence - 1;
+                nremoved += 1;
+
+                if removed == *i {
+                    break;
+                }
+            }
+        }
+
+        if nremoved != 0 {
+            self.ncontacts -= nremoved;
+            self.update_deepest();
+        }
+
+        nremoved
+    }
+
+    /// Removes the contacts of this manifold for which `f` returns `false`.
+    ///
+    /// The removed contacts are purged from the cache by the next call to `save_cache_and_clear`,
+    /// so they will not be matched by the next contact update. Returns the number of contacts
+    /// removed from this manifold.
+    pub fn retain(&mut self, mut f: impl FnMut(&TrackedContact<N, C>) -> bool) -> usize {
+        let persistence = self.persistence;
+        let mut nremoved = 0;
+
+        for (_, c) in self.contacts.iter_mut() {
+            if c.1 == persistence && !f(&c.0) {
+                c.1 = persistence - 1;
+                nremoved += 1;
+            }
+        }
+
+        if nremoved != 0 {
+            self.ncontacts -= nremoved;
+            self.update_deepest();
+        }
+
+        nremoved
+    }
+
+    fn update_deepest(&mut self) {
+        let persistence = self.persistence;
+        let mut deepest_depth = -N::max_value();
+        self.deepest = 0;
+
+        for (i, c) in self.contacts.iter() {
+            if c.1 == persistence && c.0.contact.depth > deepest_depth {
+                deepest_depth = c.0.contact.depth;
+                self.deepest = i;
+            }
+        }
+    }
+
+    /// Expresses the contacts of this manifold in a frame aligned with their average normal.
+    ///
+    /// The origin of the returned frame is the centroid of the contacts of this manifold, i.e., the
+    /// average of the midpoints of all the `world1` and `world2` contact points. Its last axis (`z`
+    /// in 3D, `y` in 2D) is the average of the contact normals. If those normals cancel each other
+    /// out, the normal of the deepest contact is used instead. Returns the identity and no contact
+    /// if this manifold is empty.
+    pub fn in_contact_frame(&self) -> (Isometry<N>, Vec<LocalContact<N>>) {
+        let deepest = match self.deepest_contact() {
+            Some(deepest) => deepest,
+            None => return (Isometry::identity(), Vec::new()),
+        };
+
+        let mut centroid = Vector::zeros();
+        let mut normal = Vector::zeros();
+
+        for c in self.contacts() {
+            centroid +=
+                (c.contact.world1.coords + c.contact.world2.coords) * na::convert::<_, N>(0.5);
+            normal += c.contact.normal.into_inner();
+        }
+
+        centroid /= na::convert::<_, N>(self.len() as f64);
+        let normal = Unit::try_new(normal, N::default_epsilon()).unwrap_or(deepest.contact.normal);
+
+        #[cfg(feature = "dim2")]
+        let rotation = Rotation::rotation_between_axis(&Vector::y_axis(), &normal);
+        #[cfg(feature = "dim3")]
+        let rotation = Rotation::rotation_between_axis(&Vector::z_axis(), &normal)
+            .unwrap_or_else(|| Rotation::from_axis_angle(&Vector::x_axis(), N::pi()));
+
+        let frame = Isometry::from_parts(Translation::from(centroid), rotation);
+        let contacts = self
+            .contacts()
+            .map(|c| LocalContact {
+                point1: frame.inverse_transform_point(&c.contact.world1),
+                point2: frame.inverse_transform_point(&c.contact.world2),
+                depth: c.contact.depth,
+                id: c.id,
+            })
+            .collect();
+
+        (frame, contacts)
+    }
+
+    /// Computes the `weight` of each contact of this manifold from its position within the patch.
+    ///
+    /// The centrality of a contact is `1 - d / (2 * dmax)` where `d` is the distance between the
+    /// centroid of the manifold and the midpoint of the contact, and `dmax` is the largest such
+    /// distance. The weights are the centralities rescaled so that their average is `1`. Thus, the
+    /// contacts closest to the centroid get the largest weights, and the weights sum up to the
+    /// number of contacts so they can be used as stiffness multipliers without changing the total
+    /// stiffness of the manifold. All the weights are `1` if the contacts are coincident, e.g., if
+    /// this manifold has a single contact. This is called by the narrow phase each time it updates
+    /// this manifold.
+    pub fn update_weights(&mut self) {
+        // The origin of the contact frame is the centroid of the manifold.
+        let (_, contacts) = self.in_contact_frame();
+        let mut max_dist = N::zero();
+        let dists: Vec<N> = contacts
+            .iter()
+            .map(|c| {
+                let dist = na::center(&c.point1, &c.point2).coords.norm();
+                max_dist = max_dist.max(dist);
+                dist
+            })
+            .collect();
+
+        if max_dist <= N::default_epsilon() {
+            for c in self.contacts_mut() {
+                c.contact.weight = N::one();
+            }
+            return;
+        }
+
+        let _2: N = na::convert(2.0);
+        let mut total = N::zero();
+
+        for dist in &dists {
+            total += N::one() - *dist / (max_dist * _2);
+        }
+
+        let scale = na::convert::<_, N>(dists.len() as f64) / total;
+
+        for (c, dist) in self.contacts_mut().zip(dists.iter()) {
+            c.contact.weight = (N::one() - *dist / (max_dist * _2)) * scale;
+        }
+    }
+
+    /// Enables or disables the canonical ordering of the contacts of this manifold.
+    ///
+    /// If enabled, the narrow phase calls `self.canonicalize()` each time it updates this
+    /// manifold. This is disabled by default.
+    pub fn set_canonical_ordering(&mut self, enabled: bool) {
+        self.canonical_ordering = enabled;
+    }
+
+    /// Whether the narrow phase sorts the contacts of this manifold with `self.canonicalize()`.
+    pub fn canonical_ordering(&self) -> bool {
+        self.canonical_ordering
+    }
+
+    /// Sorts the contacts of this manifold in an order that only depends on their geometry.
+    ///
+    /// After this call, `self.contacts()` yields the contacts by increasing angle around the
+    /// normal of the contact frame returned by `self.in_contact_frame()` (in 3D), or by increasing
+    /// abscissa in this frame (in 2D). Contacts with the same angle are sorted by increasing
+    /// distance to the centroid, and then by increasing depth. Thus, two manifolds containing the
+    /// same contacts yield them in the same order regardless of the order they were pushed in.
+    /// The contact identifiers and the tracking cache are preserved.
+    pub fn canonicalize(&mut self) {
+        let (frame, _) = self.in_contact_frame();
+        let persistence = self.persistence;
+        let mut keys: Vec<_> = self
+            .contacts
+            .iter()
+            .map(|(i, c)| {
+                let mid = na::center(&c.0.contact.world1, &c.0.contact.world2);
+                let local = frame.inverse_transform_point(&mid);
+                #[cfg(feature = "dim2")]
+                let angle = local.x;
+                #[cfg(feature = "dim3")]
+                let angle = local.y.atan2(local.x);
+                let dist = local.coords.norm_squared();
+
+                (i, c.1 != persistence, angle, dist, c.0.contact.depth)
+            })
+            .collect();
+
+        // Contacts only kept in the cache are moved after the active ones, in their current order.
+        keys.sort_by(|a, b| {
+            if a.1 || b.1 {
+                return a.1.cmp(&b.1);
+            }
+
+            a.2.partial_cmp(&b.2)
+                .unwrap_or(Ordering::Equal)
+                .then(a.3.partial_cmp(&b.3).unwrap_or(Ordering::Equal))
+                .then(a.4.partial_cmp(&b.4).unwrap_or(Ordering::Equal))
+        });
+
+        let mut old_contacts = mem::replace(&mut self.contacts, Slab::with_capacity(keys.len()));
+        let mut new_keys = vec![0; old_contacts.capacity()];
+
+        for key in &keys {
+            new_keys[key.0] = self.contacts.insert(old_contacts.remove(key.0));
+        }
+
+        match &mut self.cache {
+            ContactCache::DistanceBased(cache, _) => {
+                for c in cache.iter_mut() {
+                    c.1 = new_keys[c.1];
+                }
+            }
+            ContactCache::FeatureBased(cache) => {
+                for i in cache.values_mut() {
+                    *i = new_keys[*i];
+                }
+            }
+        }
+
+        if self.ncontacts != 0 {
+            self.deepest = new_keys[self.deepest];
+        }
+    }
+
+    /// Sets the velocities of the two objects of this manifold used by `self.approach_speed()`.
+    ///
+    /// Those hints are kept when the contacts of this manifold are updated or cleared.
+    pub fn set_velocity_hints(&mut self, hints: Option<(VelocityHint<N>, VelocityHint<N>)>) {
+        self.velocity_hints = hints;
+    }
+
+    /// The velocities of the two objects of this manifold, if they have been set.
+    pub fn velocity_hints(&self) -> Option<&(VelocityHint<N>, VelocityHint<N>)> {
+        self.velocity_hints.as_ref()
+    }
+
+    /// The relative velocity of the two objects along the normal of the deepest contact.
+    ///
+    /// This is positive if the objects are approaching each other and negative if they are
+    /// separating. The velocity of each object is evaluated at its own contact point. Returns zero
+    /// if this manifold is empty or no velocity hint has been set.
+    pub fn approach_speed(&self) -> N {
+        match (self.deepest_contact(), &self.velocity_hints) {
+            (Some(deepest), Some((hint1, hint2))) => {
+                let vel1 = hint1.velocity_at_point(&deepest.contact.world1);
+                let vel2 = hint2.velocity_at_point(&deepest.contact.world2);
+                (vel1 - vel2).dot(&deepest.contact.normal)
+            }
+            _ => N::zero(),
+        }
+    }
+
     /// Empty the manifold as well as its cache.
     pub fn clear(&mut self) {
         match &mut self.cache {
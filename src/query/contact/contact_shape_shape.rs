@@ -1,8 +1,11 @@
-use na::RealField;
+use na::{RealField, Unit};
 
-use crate::math::{Isometry, Point};
-use crate::query::{self, Contact};
-use crate::shape::{Ball, Plane, Shape};
+use crate::math::{Isometry, Point, Vector};
+use crate::query::algorithms::{gjk::GJKContext, gjk::GJKResult, CSOPoint, VoronoiSimplex};
+use crate::query::{self, contact_support_map_support_map_with_simplex, Contact};
+#[cfg(feature = "dim2")]
+use crate::shape::Arc;
+use crate::shape::{Ball, Plane, Shape, Slab};
 
 /// Computes one contact point between two shapes.
 ///
@@ -17,6 +20,15 @@ pub fn contact<N: RealField>(
     let ball1 = g1.as_shape::<Ball<N>>();
     let ball2 = g2.as_shape::<Ball<N>>();
 
+    #[cfg(feature = "dim2")]
+    {
+        if let (Some(a1), Some(s2)) = (g1.as_shape::<Arc<N>>(), g2.as_support_map()) {
+            return query::contact_arc_support_map(m1, a1, m2, s2, prediction);
+        } else if let (Some(s1), Some(a2)) = (g1.as_support_map(), g2.as_shape::<Arc<N>>()) {
+            return query::contact_support_map_arc(m1, s1, m2, a2, prediction);
+        }
+    }
+
     if let (Some(b1), Some(b2)) = (ball1, ball2) {
         let p1 = Point::from(m1.translation.vector);
         let p2 = Point::from(m2.translation.vector);
@@ -26,6 +38,10 @@ pub fn contact<N: RealField>(
         query::contact_plane_support_map(m1, p1, m2, s2, prediction)
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<N>>()) {
         query::contact_support_map_plane(m1, s1, m2, p2, prediction)
+    } else if let (Some(s1), Some(s2)) = (g1.as_shape::<Slab<N>>(), g2.as_support_map()) {
+        query::contact_slab_support_map(m1, s1, m2, s2, prediction)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_shape::<Slab<N>>()) {
+        query::contact_support_map_slab(m1, s1, m2, s2, prediction)
     } else if let (Some(b1), (Some(_), Some(_))) =
         (ball1, (g2.as_convex_polyhedron(), g2.as_point_query()))
     {
@@ -46,3 +62,109 @@ pub fn contact<N: RealField>(
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes one contact point between two shapes, testing a cached separating axis first.
+///
+/// If `cached_axis` is provided and the two shapes are still separated by more than
+/// `prediction` along it, this returns `None` right away without running the GJK algorithm.
+/// Otherwise, `cached_axis` is used to initialize the GJK algorithm. The returned axis should be
+/// passed as `cached_axis` to the next call for the same pair of shapes. It points from `g1`
+/// toward `g2` and is either the contact normal, or a separating axis if no contact was found.
+/// It is `None` if no contact was found by an algorithm other than GJK (e.g. for planes or
+/// composite shapes).
+pub fn contact_with_cached_axis<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+    cached_axis: Option<Unit<Vector<N>>>,
+) -> (Option<Contact<N>>, Option<Unit<Vector<N>>>) {
+    let balls = g1.is_shape::<Ball<N>>() && g2.is_shape::<Ball<N>>();
+
+    if let (false, Some(s1), Some(s2)) = (balls, g1.as_support_map(), g2.as_support_map()) {
+        if let Some(axis) = cached_axis {
+            let cso_point = CSOPoint::from_shapes_toward(m1, s1, m2, s2, &axis);
+
+            if -axis.dot(&cso_point.point.coords) > prediction {
+                return (None, Some(axis));
+            }
+        }
+
+        match query::contact_support_map_support_map_with_params(
+            m1,
+            s1,
+            m2,
+            s2,
+            prediction,
+            &mut VoronoiSimplex::new(),
+            cached_axis,
+        ) {
+            GJKResult::ClosestPoints(world1, world2, normal) => (
+                Some(Contact::new_wo_depth(world1, world2, normal)),
+                Some(normal),
+            ),
+            GJKResult::NoIntersection(axis) => (None, Some(axis)),
+            GJKResult::Intersection => unreachable!(),
+            GJKResult::Proximity(_) => unreachable!(),
+        }
+    } else {
+        let contact = contact(m1, g1, m2, g2, prediction);
+        let axis = contact.as_ref().map(|c| c.normal);
+        (contact, axis)
+    }
+}
+
+/// Computes one contact point between two shapes, reusing the result of the last call for the
+/// same pair of shapes.
+///
+/// If both shapes are support-mapped (and not two balls), the GJK algorithm is initialized by
+/// `context`, which is then updated for the next call. Otherwise, this is the same as `contact`
+/// and `context` is reset.
+pub fn contact_with_context<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+    context: &mut GJKContext<N>,
+) -> Option<Contact<N>> {
+    let balls = g1.is_shape::<Ball<N>>() && g2.is_shape::<Ball<N>>();
+
+    if let (false, Some(s1), Some(s2)) = (balls, g1.as_support_map(), g2.as_support_map()) {
+        let result = if context.warm_start(m1, m2) {
+            contact_support_map_support_map_with_simplex(
+                m1,
+                s1,
+                m2,
+                s2,
+                prediction,
+                context.simplex_mut(),
+            )
+        } else {
+            query::contact_support_map_support_map_with_params(
+                m1,
+                s1,
+                m2,
+                s2,
+                prediction,
+                context.simplex_mut(),
+                None,
+            )
+        };
+        let contact = match result {
+            GJKResult::ClosestPoints(world1, world2, normal) => {
+                Some(Contact::new_wo_depth(world1, world2, normal))
+            }
+            GJKResult::NoIntersection(_) => None,
+            GJKResult::Intersection => unreachable!(),
+            GJKResult::Proximity(_) => unreachable!(),
+        };
+
+        context.record_simplex(m1, m2);
+        contact
+    } else {
+        context.reset();
+        contact(m1, g1, m2, g2, prediction)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::math::Isometry;
+use crate::query::{self, Contact};
+use crate::shape::{Slab, SupportMap};
+use na::RealField;
+
+/// Contact between a slab and a support-mapped shape (Cuboid, ConvexHull, etc.)
+///
+/// The contact is computed against each of the two planes bounding the slab, and the one with
+/// the smallest penetration depth is returned. Thus, the contact normal is always orthogonal to
+/// the slab. If the shape is thinner than the slab and lies completely inside of it, the contact
+/// is the one moving the shape out of the slab through its closest bounding plane.
+pub fn contact_slab_support_map<N: RealField, G: ?Sized + SupportMap<N>>(
+    mslab: &Isometry<N>,
+    slab: &Slab<N>,
+    mother: &Isometry<N>,
+    other: &G,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let [(m1, plane1), (m2, plane2)] = slab.planes();
+    let c1 = query::contact_plane_support_map(&(mslab * m1), &plane1, mother, other, prediction)?;
+    let c2 = query::contact_plane_support_map(&(mslab * m2), &plane2, mother, other, prediction)?;
+
+    if c1.depth <= c2.depth {
+        Some(c1)
+    } else {
+        Some(c2)
+    }
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexHull, etc.) and a slab.
+pub fn contact_support_map_slab<N: RealField, G: ?Sized + SupportMap<N>>(
+    mother: &Isometry<N>,
+    other: &G,
+    mslab: &Isometry<N>,
+    slab: &Slab<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    contact_slab_support_map(mslab, slab, mother, other, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
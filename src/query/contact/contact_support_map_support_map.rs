@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Vector};
+use crate::math::{Isometry, Vector, DIM};
 use crate::query::algorithms::{gjk, gjk::GJKResult, CSOPoint};
 use crate::query::algorithms::{VoronoiSimplex, EPA};
 use crate::query::Contact;
@@ -61,12 +61,37 @@ where
     };
 
     simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &dir));
+    contact_support_map_support_map_with_simplex(m1, g1, m2, g2, prediction, simplex)
+}
 
+/// Contact between support-mapped shapes, with the GJK algorithm started from `simplex`.
+///
+/// The simplex must be already initialized with at least one point of the CSO of both shapes.
+pub(crate) fn contact_support_map_support_map_with_simplex<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+    simplex: &mut VoronoiSimplex<N>,
+) -> GJKResult<N>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
     let cpts = gjk::closest_points(m1, g1, m2, g2, prediction, true, simplex);
     if cpts != GJKResult::Intersection {
         return cpts;
     }
 
+    // The origin lies on a degenerate simplex when both shapes share the same pose, e.g., when
+    // they are coincident. The GJK direction is then undefined and the EPA result cannot be
+    // trusted. Other degenerate simplices are handled fine by the EPA.
+    if simplex.dimension() < DIM && m1 == m2 {
+        return sampled_penetration(m1, g1, m2, g2);
+    }
+
     // The point is inside of the CSO: use the fallback algorithm
     let mut epa = EPA::new();
     if let Some((p1, p2, n)) = epa.closest_points(m1, g1, m2, g2, simplex) {
@@ -75,7 +100,7 @@ where
     }
 
     // Everything failed
-    GJKResult::NoIntersection(Vector::x_axis())
+    sampled_penetration(m1, g1, m2, g2)
 
     // // When all else fail (e.g. because of roundup errors, default to minkowski sampling.
     // match minkowski_sampling::closest_points(m1, g1, m2, g2, simplex) {
@@ -86,3 +111,62 @@ where
     //     None => GJKResult::NoIntersection(na::zero()), // panic!("Both GJK and fallback algorithm failed.")
     // }
 }
+
+/// Penetration between two support-mapped shapes, computed by sampling a fixed set of directions.
+///
+/// For each direction, the depth is the distance `g2` would have to be translated along it to
+/// separate both shapes. The shallowest direction is returned, the first one sampled being
+/// preferred in case of ties. The sampled directions are the coordinate axes and the diagonals,
+/// so the result is deterministic. This is only an upper bound of the actual
+/// penetration depth, though it is exact for shapes symmetric with regard to the sampled axes.
+fn sampled_penetration<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> GJKResult<N>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let mut best: Option<(N, CSOPoint<N>, Unit<Vector<N>>)> = None;
+    let mut test = |dir: Unit<Vector<N>>| {
+        let cso_point = CSOPoint::from_shapes_toward(m1, g1, m2, g2, &dir);
+        let depth = cso_point.point.coords.dot(&dir);
+
+        let shallower = match &best {
+            Some((best_depth, _, _)) => depth < *best_depth,
+            None => true,
+        };
+
+        if shallower {
+            best = Some((depth, cso_point, dir));
+        }
+    };
+
+    for i in 0..DIM {
+        let mut dir = Vector::zeros();
+        dir[i] = N::one();
+        test(Unit::new_unchecked(dir));
+        test(Unit::new_unchecked(-dir));
+    }
+
+    for signs in 0..(1 << DIM) {
+        let dir = Vector::from_fn(|i, _| {
+            if signs & (1 << i) == 0 {
+                N::one()
+            } else {
+                -N::one()
+            }
+        });
+        test(Unit::new_normalize(dir));
+    }
+
+    match best {
+        Some((_, cso_point, dir)) => {
+            GJKResult::ClosestPoints(cso_point.orig1, cso_point.orig2, dir)
+        }
+        None => GJKResult::NoIntersection(Vector::x_axis()),
+    }
+}
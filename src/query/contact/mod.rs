@@ -1,12 +1,16 @@
 //! Implementation details of the `contact` and `contacts` functions.
 
-pub use self::contact::{Contact, ContactId, ContactPrediction, TrackedContact};
+pub use self::contact::{
+    Contact, ContactData, ContactId, ContactPrediction, LocalContact, TrackedContact,
+};
 pub use self::contact_kinematic::{
     ContactKinematic, LocalShapeApproximation, NeighborhoodGeometry,
 };
-pub use self::contact_manifold::{ContactManifold, ContactTrackingMode};
+pub use self::contact_manifold::{ContactManifold, ContactTrackingMode, VelocityHint};
 pub use self::contact_preprocessor::ContactPreprocessor;
 
+#[cfg(feature = "dim2")]
+pub use self::contact_arc_support_map::{contact_arc_support_map, contact_support_map_arc};
 pub use self::contact_ball_ball::contact_ball_ball;
 pub use self::contact_ball_convex_polyhedron::{
     contact_ball_convex_polyhedron, contact_convex_polyhedron_ball,
@@ -15,11 +19,16 @@ pub use self::contact_composite_shape_shape::{
     contact_composite_shape_shape, contact_shape_composite_shape,
 };
 pub use self::contact_plane_support_map::{contact_plane_support_map, contact_support_map_plane};
-pub use self::contact_shape_shape::contact;
+pub use self::contact_shape_shape::{contact, contact_with_cached_axis, contact_with_context};
+pub use self::contact_slab_support_map::{contact_slab_support_map, contact_support_map_slab};
 pub use self::contact_support_map_support_map::contact_support_map_support_map;
 pub use self::contact_support_map_support_map::contact_support_map_support_map_with_params;
+pub(crate) use self::contact_support_map_support_map::contact_support_map_support_map_with_simplex;
+pub use self::contact_with_similarity::contact_with_similarity;
 
 mod contact;
+#[cfg(feature = "dim2")]
+mod contact_arc_support_map;
 mod contact_ball_ball;
 mod contact_ball_convex_polyhedron;
 mod contact_composite_shape_shape;
@@ -28,4 +37,6 @@ mod contact_manifold;
 mod contact_plane_support_map;
 mod contact_preprocessor;
 mod contact_shape_shape;
+mod contact_slab_support_map;
 mod contact_support_map_support_map;
+mod contact_with_similarity;
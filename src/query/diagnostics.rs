@@ -0,0 +1,164 @@
+//! Diagnostics of the iterative algorithms run by geometric queries.
+//!
+//! Those diagnostics are only collected if the `diagnostics` feature is enabled, since this adds a
+//! thread-local lookup to each run of these algorithms.
+
+use std::cell::RefCell;
+
+use crate::math::{Isometry, Point};
+use crate::partitioning::{self, TraversalStats};
+use crate::query::{
+    self, Contact, PointProjection, PointQuery, Proximity, Ray, RayCast, RayIntersection,
+};
+use crate::shape::Shape;
+use na::RealField;
+
+/// Statistics about the GJK and EPA algorithms run during a geometric query.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryDiagnostics {
+    /// The number of times the GJK algorithm was run.
+    pub gjk_runs: usize,
+    /// The total number of iterations of all the runs of the GJK algorithm.
+    pub gjk_iterations: usize,
+    /// Whether the EPA algorithm was run at least once.
+    pub epa_invoked: bool,
+    /// The total number of iterations of all the runs of the EPA algorithm.
+    pub epa_iterations: usize,
+    /// The largest number of vertices of the polytope built by a run of the EPA algorithm.
+    pub epa_max_polytope_size: usize,
+    /// The number of runs of the EPA algorithm that stopped before the gap between the lower and
+    /// upper bounds of the penetration depth fell below its tolerance.
+    ///
+    /// Those runs either failed, or returned the normal of the best face found so far, which may
+    /// be inaccurate.
+    pub epa_unconverged_runs: usize,
+    /// Whether the GJK algorithm reached its maximum number of iterations, or the EPA algorithm
+    /// failed to compute a penetration depth, at least once.
+    pub convergence_failure: bool,
+}
+
+thread_local! {
+    static DIAGNOSTICS: RefCell<Option<QueryDiagnostics>> = RefCell::new(None);
+}
+
+// Restores the diagnostics being collected before a call to `with_diagnostics`, even if the
+// closure panics.
+struct DiagnosticsGuard {
+    prev: Option<QueryDiagnostics>,
+}
+
+impl Drop for DiagnosticsGuard {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        DIAGNOSTICS.with(|d| *d.borrow_mut() = prev);
+    }
+}
+
+fn record(f: impl FnOnce(&mut QueryDiagnostics)) {
+    DIAGNOSTICS.with(|d| {
+        if let Some(diagnostics) = &mut *d.borrow_mut() {
+            f(diagnostics)
+        }
+    })
+}
+
+pub(crate) fn record_gjk(niter: usize, failed: bool) {
+    record(|d| {
+        d.gjk_runs += 1;
+        d.gjk_iterations += niter;
+        d.convergence_failure |= failed;
+    })
+}
+
+pub(crate) fn record_epa(niter: usize, polytope_size: usize, converged: bool, failed: bool) {
+    record(|d| {
+        d.epa_invoked = true;
+        d.epa_iterations += niter;
+        d.epa_max_polytope_size = d.epa_max_polytope_size.max(polytope_size);
+        d.epa_unconverged_runs += !converged as usize;
+        d.convergence_failure |= failed;
+    })
+}
+
+/// Calls `f` and collects statistics about the GJK and EPA algorithms it runs on this thread.
+///
+/// Calls to `with_diagnostics` can be nested, in which case the statistics collected by the
+/// inner call are also accounted for by the outer one.
+pub fn with_diagnostics<R>(f: impl FnOnce() -> R) -> (R, QueryDiagnostics) {
+    let prev = DIAGNOSTICS.with(|d| d.borrow_mut().replace(QueryDiagnostics::default()));
+    let guard = DiagnosticsGuard { prev };
+    let res = f();
+    let diagnostics = DIAGNOSTICS
+        .with(|d| d.borrow_mut().take())
+        .unwrap_or_default();
+
+    drop(guard);
+    record(|d| {
+        d.gjk_runs += diagnostics.gjk_runs;
+        d.gjk_iterations += diagnostics.gjk_iterations;
+        d.epa_invoked |= diagnostics.epa_invoked;
+        d.epa_iterations += diagnostics.epa_iterations;
+        d.epa_max_polytope_size = d
+            .epa_max_polytope_size
+            .max(diagnostics.epa_max_polytope_size);
+        d.epa_unconverged_runs += diagnostics.epa_unconverged_runs;
+        d.convergence_failure |= diagnostics.convergence_failure;
+    });
+
+    (res, diagnostics)
+}
+
+/// Same as `query::distance` but also returns statistics about the algorithms it ran.
+pub fn distance_with_diagnostics<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> (N, QueryDiagnostics) {
+    with_diagnostics(|| query::distance(m1, g1, m2, g2))
+}
+
+/// Same as `query::contact` but also returns statistics about the algorithms it ran.
+pub fn contact_with_diagnostics<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> (Option<Contact<N>>, QueryDiagnostics) {
+    with_diagnostics(|| query::contact(m1, g1, m2, g2, prediction))
+}
+
+/// Same as `query::proximity` but also returns statistics about the algorithms it ran.
+pub fn proximity_with_diagnostics<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> (Proximity, QueryDiagnostics) {
+    with_diagnostics(|| query::proximity(m1, g1, m2, g2, margin))
+}
+
+/// Same as `shape.toi_and_normal_with_ray` but also returns statistics about the bounding volume
+/// hierarchy traversals it ran.
+pub fn toi_and_normal_with_ray_with_traversal_stats<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn RayCast<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> (Option<RayIntersection<N>>, TraversalStats) {
+    partitioning::with_traversal_stats(|| shape.toi_and_normal_with_ray(m, ray, max_toi, solid))
+}
+
+/// Same as `shape.project_point` but also returns statistics about the bounding volume
+/// hierarchy traversals it ran.
+pub fn project_point_with_traversal_stats<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn PointQuery<N>,
+    point: &Point<N>,
+    solid: bool,
+) -> (PointProjection<N>, TraversalStats) {
+    partitioning::with_traversal_stats(|| shape.project_point(m, point, solid))
+}
@@ -0,0 +1,38 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+use na::{RealField, Unit};
+
+/// Clearance between a support-mapped shape and the tightest of a set of planes.
+///
+/// Each plane is given, in world-space, by one of its points and its normal pointing toward the
+/// free side. The clearance to one plane is the signed distance from this plane to the point of
+/// the shape transformed by `m` that goes the deepest along the opposite of its normal. It is
+/// negative if the shape penetrates the plane. Returns the index of the plane with the smallest
+/// clearance, together with this clearance.
+///
+/// # Panics
+///
+/// Panics if `planes` is empty.
+pub fn clearance_from_planes<N: RealField, G: ?Sized + SupportMap<N>>(
+    m: &Isometry<N>,
+    shape: &G,
+    planes: &[(Point<N>, Unit<Vector<N>>)],
+) -> (usize, N) {
+    assert!(
+        !planes.is_empty(),
+        "The clearance from an empty set of planes is undefined."
+    );
+
+    let mut best = (0, N::max_value());
+
+    for (i, (center, normal)) in planes.iter().enumerate() {
+        let deepest = shape.support_point_toward(m, &-*normal);
+        let clearance = normal.dot(&(deepest - center));
+
+        if clearance < best.1 {
+            best = (i, clearance);
+        }
+    }
+
+    best
+}
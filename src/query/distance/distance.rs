@@ -1,18 +1,36 @@
-use crate::math::{Isometry, Point};
-use crate::query;
-use crate::shape::{Ball, Plane, Shape};
-use na::RealField;
+use crate::math::{Isometry, Point, Vector};
+use crate::query::algorithms::gjk::{self, GJKContext, GJKResult};
+use crate::query::algorithms::CSOPoint;
+use crate::query::{self, Unsupported};
+use crate::shape::{Ball, HeightField, Plane, Shape};
+use na::{self, RealField, Unit};
 
 /// Computes the minimum distance separating two shapes.
 ///
-/// Returns `0.0` if the objects are touching or penetrating.
+/// Returns `0.0` if the objects are touching or penetrating. Panics if no algorithm is known to
+/// compute the distance between the given pair of shapes, e.g., two planes. See `try_distance`
+/// for a fallible version.
 pub fn distance<N: RealField>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
     m2: &Isometry<N>,
     g2: &dyn Shape<N>,
 ) -> N {
-    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
+    try_distance(m1, g1, m2, g2)
+        .expect("No algorithm known to compute the distance between the given pair of shapes.")
+}
+
+/// Computes the minimum distance separating two shapes.
+///
+/// Returns `0.0` if the objects are touching or penetrating, and `Err(Unsupported)` if no
+/// algorithm is known to compute the distance between the given pair of shapes.
+pub fn try_distance<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Result<N, Unsupported> {
+    let dist = if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
         let p1 = Point::from(m1.translation.vector);
         let p2 = Point::from(m2.translation.vector);
 
@@ -27,7 +45,54 @@ pub fn distance<N: RealField>(
         query::distance_composite_shape_shape(m1, c1, m2, g2)
     } else if let Some(c2) = g2.as_composite_shape() {
         query::distance_shape_composite_shape(m1, g1, m2, c2)
+    } else if let Some(h1) = g1.as_shape::<HeightField<N>>() {
+        query::distance_heightfield_shape(m1, h1, m2, g2)
+    } else if let Some(h2) = g2.as_shape::<HeightField<N>>() {
+        query::distance_shape_heightfield(m1, g1, m2, h2)
+    } else {
+        return Err(Unsupported);
+    };
+
+    Ok(dist)
+}
+
+/// Computes the minimum distance separating two shapes, reusing the result of the last call for
+/// the same pair of shapes.
+///
+/// If both shapes are support-mapped (and not two balls), the GJK algorithm is initialized by
+/// `context`, which is then updated for the next call. Otherwise, this is the same as `distance`
+/// and `context` is reset.
+pub fn distance_with_context<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    context: &mut GJKContext<N>,
+) -> N {
+    let balls = g1.is_shape::<Ball<N>>() && g2.is_shape::<Ball<N>>();
+
+    if let (false, Some(s1), Some(s2)) = (balls, g1.as_support_map(), g2.as_support_map()) {
+        if !context.warm_start(m1, m2) {
+            let dir = Unit::try_new(
+                m2.translation.vector - m1.translation.vector,
+                N::default_epsilon(),
+            )
+            .unwrap_or_else(Vector::x_axis);
+            context
+                .simplex_mut()
+                .reset(CSOPoint::from_shapes(m1, s1, m2, s2, &dir));
+        }
+
+        let simplex = context.simplex_mut();
+        let dist = match gjk::closest_points(m1, s1, m2, s2, N::max_value(), true, simplex) {
+            GJKResult::ClosestPoints(p1, p2, _) => na::distance(&p1, &p2),
+            _ => N::zero(),
+        };
+
+        context.record_simplex(m1, m2);
+        dist
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
+        context.reset();
+        distance(m1, g1, m2, g2)
     }
 }
@@ -0,0 +1,50 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::Isometry;
+use crate::query;
+use crate::shape::{HeightField, Shape};
+use na::RealField;
+
+/// Smallest distance between a heightfield and any other shape.
+///
+/// Returns `N::max_value()` if all the elements of the heightfield have been removed.
+pub fn distance_heightfield_shape<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &HeightField<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> N {
+    let ls_aabb2 = g2.aabb(&(m1.inverse() * m2));
+    let mut dist = N::max_value();
+    let mut found = false;
+
+    // The elements facing the AABB of `g2` give an upper bound of the distance.
+    g1.map_elements_in_local_aabb(&ls_aabb2, &mut |_, elt, _| {
+        found = true;
+        dist = dist.min(query::distance(m1, elt, m2, g2))
+    });
+
+    // Any element closer than this upper bound intersects the AABB of `g2` enlarged by it.
+    let aabb = if found {
+        ls_aabb2.loosened(dist)
+    } else {
+        g1.aabb().clone()
+    };
+
+    if !dist.is_zero() {
+        g1.map_elements_in_local_aabb(&aabb, &mut |_, elt, _| {
+            dist = dist.min(query::distance(m1, elt, m2, g2))
+        });
+    }
+
+    dist
+}
+
+/// Smallest distance between a shape and a heightfield.
+pub fn distance_shape_heightfield<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &HeightField<N>,
+) -> N {
+    distance_heightfield_shape(m2, g2, m1, g1)
+}
@@ -1,19 +1,27 @@
 //! Implementation details of the `distance` function.
 
-pub use self::distance::distance;
+pub use self::clearance_from_planes::clearance_from_planes;
+pub use self::distance::{distance, distance_with_context, try_distance};
 pub use self::distance_ball_ball::distance_ball_ball;
 pub use self::distance_composite_shape_shape::{
     distance_composite_shape_shape, distance_shape_composite_shape,
 };
+pub use self::distance_heightfield_shape::{
+    distance_heightfield_shape, distance_shape_heightfield,
+};
 pub use self::distance_plane_support_map::{
     distance_plane_support_map, distance_support_map_plane,
 };
 pub use self::distance_support_map_support_map::{
     distance_support_map_support_map, distance_support_map_support_map_with_params,
 };
+pub use self::distance_with_similarity::distance_with_similarity;
 
+mod clearance_from_planes;
 mod distance;
 mod distance_ball_ball;
 mod distance_composite_shape_shape;
+mod distance_heightfield_shape;
 mod distance_plane_support_map;
 mod distance_support_map_support_map;
+mod distance_with_similarity;
@@ -0,0 +1,177 @@
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::query::{self, algorithms::VoronoiSimplex};
+use crate::shape::{ConvexPolyhedron, SupportMap};
+use na::{self, DMatrix, DVector, RealField, Unit};
+
+/// Computes the largest sphere contained by a convex polyhedron.
+///
+/// This uses a cutting-plane method: the shape is approximated by the intersection of the
+/// half-spaces bounded by some of its supporting planes. The Chebyshev center of this outer
+/// approximation is computed and compared to the actual distance to the shape boundary. If they
+/// don't match, the supporting plane passing through the closest boundary point is added to the
+/// approximation, until all the faces constraining the sphere have been found.
+///
+/// Returns the center (in world-space) and the radius of the inscribed sphere.
+pub fn inscribed_sphere_convex_polyhedron<N, G: ?Sized>(m: &Isometry<N>, g: &G) -> (Point<N>, N)
+where
+    N: RealField,
+    G: ConvexPolyhedron<N>,
+{
+    let max_planes = DIM * 8;
+    let eps = N::default_epsilon().sqrt();
+    let mut simplex = VoronoiSimplex::new();
+    let mut planes = Vec::with_capacity(max_planes);
+
+    // Start with the axis-aligned bounding box of the shape.
+    for i in 0..DIM {
+        for sign in &[N::one(), -N::one()] {
+            let mut dir = Vector::zeros();
+            dir[i] = *sign;
+            let dir = Unit::new_unchecked(dir);
+            let offset = g.local_support_point_toward(&dir).coords.dot(&dir);
+            planes.push((dir, offset));
+        }
+    }
+
+    let mut best = (Point::origin(), -N::max_value());
+    let shift_dir = Vector::from_fn(|i, _| na::convert::<f64, N>(1.0 / (i as f64 + 2.0)));
+
+    while let Some((center, approx_radius)) = chebyshev_center(&planes, eps) {
+        if approx_radius <= eps {
+            // The shape is flat.
+            return (m * center, N::zero());
+        }
+
+        // NOTE: the point projection is ill-conditioned at points equidistant to several faces.
+        // Thus we project a point slightly shifted along an arbitrary direction instead.
+        let shift = shift_dir * (eps * (N::one() + approx_radius));
+        let (normal, radius) = match project_on_boundary(g, &mut simplex, &(center + shift), eps) {
+            Some((normal, radius)) => (normal, radius - shift.norm()),
+            None => {
+                // The center lies on the shape boundary.
+                if best.1 < N::zero() {
+                    best = (center, N::zero());
+                }
+                break;
+            }
+        };
+
+        if radius > best.1 {
+            best = (center, radius);
+        }
+
+        if approx_radius - radius <= eps * (N::one() + approx_radius.abs())
+            || planes.len() >= max_planes
+        {
+            break;
+        }
+
+        let offset = g.local_support_point_toward(&normal).coords.dot(&normal);
+        planes.push((normal, offset));
+    }
+
+    (m * best.0, best.1)
+}
+
+/// The outward normal of the boundary point closest to `pt`, and the signed distance
+/// from `pt` to this boundary (positive if `pt` is inside of the shape).
+fn project_on_boundary<N, G: ?Sized>(
+    g: &G,
+    simplex: &mut VoronoiSimplex<N>,
+    pt: &Point<N>,
+    eps: N,
+) -> Option<(Unit<Vector<N>>, N)>
+where
+    N: RealField,
+    G: SupportMap<N>,
+{
+    let proj = query::point_projection_on_support_map(&Isometry::identity(), g, simplex, pt, false);
+    let (dir, dist) = Unit::try_new_and_get(proj.point - pt, eps)?;
+
+    if proj.is_inside {
+        Some((dir, dist))
+    } else {
+        Some((-dir, -dist))
+    }
+}
+
+/// The center and radius of the largest sphere contained by the intersection of the half-spaces
+/// `{ x | n.dot(x) <= offset }`.
+///
+/// The optimum is searched among the points equidistant to `DIM + 1` planes. If several centers
+/// achieve the same radius, their barycenter is returned.
+fn chebyshev_center<N: RealField>(
+    planes: &[(Unit<Vector<N>>, N)],
+    eps: N,
+) -> Option<(Point<N>, N)> {
+    let k = DIM + 1;
+
+    if planes.len() < k {
+        return None;
+    }
+
+    let mut candidates: Vec<(Point<N>, N)> = Vec::new();
+    let mut best_radius = -N::max_value();
+    let mut subset: Vec<usize> = (0..k).collect();
+
+    loop {
+        let mat = DMatrix::from_fn(k, k, |r, c| {
+            if c < DIM {
+                planes[subset[r]].0[c]
+            } else {
+                N::one()
+            }
+        });
+        let rhs = DVector::from_fn(k, |r, _| planes[subset[r]].1);
+
+        if let Some(sol) = mat.lu().solve(&rhs) {
+            let center = Point::from(Vector::from_fn(|r, _| sol[r]));
+            let radius = sol[DIM];
+            let tol = eps * (N::one() + radius.abs());
+
+            let feasible = radius >= -tol
+                && planes
+                    .iter()
+                    .all(|(n, offset)| n.dot(&center.coords) + radius <= *offset + tol);
+
+            if feasible && radius >= best_radius - tol {
+                if radius > best_radius + tol {
+                    candidates.clear();
+                }
+
+                best_radius = best_radius.max(radius);
+                candidates.push((center, radius));
+            }
+        }
+
+        // Next combination of `k` planes.
+        let mut i = k;
+        while i > 0 && subset[i - 1] == planes.len() - k + i - 1 {
+            i -= 1;
+        }
+
+        if i == 0 {
+            break;
+        }
+
+        subset[i - 1] += 1;
+        for j in i..k {
+            subset[j] = subset[j - 1] + 1;
+        }
+    }
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let mut center = Point::origin();
+    let mut radius = N::max_value();
+
+    for (c, r) in &candidates {
+        center.coords += c.coords;
+        radius = radius.min(*r);
+    }
+
+    center.coords /= na::convert::<f64, N>(candidates.len() as f64);
+    Some((center, radius))
+}
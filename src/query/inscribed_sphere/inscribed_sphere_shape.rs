@@ -0,0 +1,47 @@
+use crate::math::{Isometry, Point};
+use crate::query;
+use crate::shape::{Ball, Capsule, Cuboid, Shape};
+use na::RealField;
+
+/// Computes the largest sphere contained by a convex shape.
+///
+/// Returns the center (in world-space) and the radius of this sphere. The center is also
+/// known as the Chebyshev center of the shape, and the radius as its inradius.
+///
+/// Only balls, capsules, and convex polyhedra are supported.
+pub fn inscribed_sphere<N: RealField>(m: &Isometry<N>, g: &dyn Shape<N>) -> (Point<N>, N) {
+    if let Some(b) = g.as_shape::<Ball<N>>() {
+        inscribed_sphere_ball(m, b)
+    } else if let Some(c) = g.as_shape::<Cuboid<N>>() {
+        inscribed_sphere_cuboid(m, c)
+    } else if let Some(c) = g.as_shape::<Capsule<N>>() {
+        inscribed_sphere_capsule(m, c)
+    } else if let Some(p) = g.as_convex_polyhedron() {
+        query::inscribed_sphere_convex_polyhedron(m, p)
+    } else {
+        panic!("No algorithm known to compute the inscribed sphere of the given shape.")
+    }
+}
+
+/// Computes the largest sphere contained by a ball, i.e., the ball itself.
+#[inline]
+pub fn inscribed_sphere_ball<N: RealField>(m: &Isometry<N>, b: &Ball<N>) -> (Point<N>, N) {
+    (Point::from(m.translation.vector), b.radius)
+}
+
+/// Computes the largest sphere contained by a cuboid.
+///
+/// It is centered at the cuboid center and its radius is the smallest half-extent.
+#[inline]
+pub fn inscribed_sphere_cuboid<N: RealField>(m: &Isometry<N>, c: &Cuboid<N>) -> (Point<N>, N) {
+    (Point::from(m.translation.vector), c.half_extents.min())
+}
+
+/// Computes the largest sphere contained by a capsule.
+///
+/// Any sphere with the capsule radius, centered on its segment, is a solution. This returns
+/// the one centered at the capsule center.
+#[inline]
+pub fn inscribed_sphere_capsule<N: RealField>(m: &Isometry<N>, c: &Capsule<N>) -> (Point<N>, N) {
+    (Point::from(m.translation.vector), c.radius)
+}
@@ -0,0 +1,9 @@
+//! Implementation details of the `inscribed_sphere` function.
+
+pub use self::inscribed_sphere_convex_polyhedron::inscribed_sphere_convex_polyhedron;
+pub use self::inscribed_sphere_shape::{
+    inscribed_sphere, inscribed_sphere_ball, inscribed_sphere_capsule, inscribed_sphere_cuboid,
+};
+
+mod inscribed_sphere_convex_polyhedron;
+mod inscribed_sphere_shape;
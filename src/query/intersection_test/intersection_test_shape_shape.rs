@@ -0,0 +1,27 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query::{self, Proximity};
+use crate::shape::{Ball, Shape};
+
+/// Tests whether two shapes are intersecting.
+///
+/// This is cheaper than `query::proximity` with a zero margin because the underlying algorithms
+/// stop as soon as they can prove that the shapes are intersecting or separated.
+pub fn intersection_test<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> bool {
+    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
+        let p1 = Point::from(m1.translation.vector);
+        let p2 = Point::from(m2.translation.vector);
+
+        query::intersection_test_ball_ball(&p1, b1, &p2, b2)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        query::intersection_test_support_map_support_map(m1, s1, m2, s2)
+    } else {
+        query::proximity(m1, g1, m2, g2, N::zero()) == Proximity::Intersecting
+    }
+}
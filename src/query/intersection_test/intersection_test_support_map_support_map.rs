@@ -0,0 +1,58 @@
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Vector};
+use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
+use crate::shape::SupportMap;
+
+/// Tests whether two support-mapped shapes (`Cuboid`, `ConvexHull`, etc.) are intersecting.
+pub fn intersection_test_support_map_support_map<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> bool
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    intersection_test_support_map_support_map_with_params(
+        m1,
+        g1,
+        m2,
+        g2,
+        &mut VoronoiSimplex::new(),
+        None,
+    )
+}
+
+/// Tests whether two support-mapped shapes (`Cuboid`, `ConvexHull`, etc.) are intersecting.
+///
+/// This allows a more fine grained control over the underlying GJK algorithm.
+pub fn intersection_test_support_map_support_map_with_params<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+    init_dir: Option<Unit<Vector<N>>>,
+) -> bool
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let dir = if let Some(init_dir) = init_dir {
+        init_dir
+    } else if let Some(init_dir) = Unit::try_new(
+        m2.translation.vector - m1.translation.vector,
+        N::default_epsilon(),
+    ) {
+        init_dir
+    } else {
+        Vector::x_axis()
+    };
+
+    simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &dir));
+    gjk::intersection_test(m1, g1, m2, g2, simplex)
+}
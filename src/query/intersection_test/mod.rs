@@ -0,0 +1,12 @@
+//! Implementation details of the `intersection_test` function.
+
+pub use self::intersection_test_ball_ball::intersection_test_ball_ball;
+pub use self::intersection_test_shape_shape::intersection_test;
+pub use self::intersection_test_support_map_support_map::{
+    intersection_test_support_map_support_map,
+    intersection_test_support_map_support_map_with_params,
+};
+
+mod intersection_test_ball_ball;
+mod intersection_test_shape_shape;
+mod intersection_test_support_map_support_map;
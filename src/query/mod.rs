@@ -7,8 +7,11 @@
 //! * [`query::distance()`] to compute the distance between two shapes.
 //! * [`query::contact()`] to compute one pair of contact points between two shapes, including penetrating contact.
 //! * [`query::proximity()`] to determine if two shapes are intersecting or not.
+//! * [`query::intersection_test()`] to quickly determine if two shapes are intersecting, without any distance information.
 //! * [`query::time_of_impact()`] to determine when two shapes undergoing translational motions hit for the first time.
 //! * [`query::nonlinear_time_of_impact()`] to determine when two shapes undergoing continuous rigid motions hit for the first time.
+//! * [`query::contact_at_impact()`] to compute the full contact manifold between two moving shapes when they hit for the first time.
+//! * [`query::inscribed_sphere()`] to compute the largest sphere contained by a convex shape.
 //!
 //! Ray-casting and point-projection can be achieved by importing traits:
 //!
@@ -21,30 +24,89 @@
 //! They are less convenient to use than the most generic version but will be slightly faster due to the lack of dynamic dispatch.
 //! Generally, the specific functions have the form `[operation]_[shape1]_[shape2]()` where:
 //!
-//! * `[operation]` can be `closest_points`, `distance`, `contact`, `proximity` or `time_of_impact`.
+//! * `[operation]` can be `closest_points`, `distance`, `contact`, `proximity`, `intersection_test` or `time_of_impact`.
 //! * `[shape1]` is the type of the first shape passed to the function, e.g., `ball`, or `plane`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 //! * `[shape2]` is the type of the second shape passed to the function, e.g., `ball`, or `plane`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
+//!
+//! # Similarity transforms
+//! The `distance`, `contact`, and `proximity` queries also have `[operation]_with_similarity()` variants
+//! taking shapes transformed by `math::Similarity` (an isometry combined with a uniform scale) instead of
+//! `math::Isometry`. Point projection and ray-casting on a shape transformed by a similarity are
+//! provided by `PointQuery::project_point_with_similarity` and `RayCast::toi_and_normal_with_ray_and_similarity`.
+//!
+//! Similarity transforms are not supported by the collision pipeline: collision objects are always
+//! positioned by an isometry. A collision object can be scaled by giving it a `shape::ScaledShape`
+//! instead.
 
+pub use self::ball_ball_intersection_circle::ball_ball_intersection_circle;
 pub use self::closest_points::*;
 pub use self::contact::*;
+#[cfg(feature = "diagnostics")]
+pub use self::diagnostics::{
+    contact_with_diagnostics, distance_with_diagnostics, project_point_with_traversal_stats,
+    proximity_with_diagnostics, toi_and_normal_with_ray_with_traversal_stats, with_diagnostics,
+    QueryDiagnostics,
+};
 pub use self::distance::*;
 pub use self::error::*;
+#[cfg(feature = "dim3")]
+pub use self::facing_area::{facing_area, swept_frontal_area};
+pub use self::inscribed_sphere::*;
+pub use self::intersection_test::*;
 pub use self::nonlinear_time_of_impact::*;
+pub use self::overlap_volume::shape_aabb_overlap_volume;
 pub use self::point::*;
+#[cfg(feature = "dim3")]
+pub use self::project_to_plane::project_to_plane;
 pub use self::proximity::*;
 pub use self::ray::*;
+#[cfg(feature = "dim3")]
+pub use self::rotational_depenetration::rotational_depenetration;
+pub use self::segment_plane::{segment_plane, SegmentPlaneResult};
+pub use self::surface_distance::surface_distance;
 pub use self::time_of_impact::*;
 pub use self::toi_dispatcher::*;
 
 pub mod algorithms;
+mod ball_ball_intersection_circle;
 mod closest_points;
 mod contact;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+#[cfg(not(feature = "diagnostics"))]
+mod diagnostics {
+    // No-op recorders for the algorithms run by the geometric queries, so that they do not pay for
+    // the diagnostics they do not collect.
+
+    #[inline(always)]
+    pub(crate) fn record_gjk(_niter: usize, _failed: bool) {}
+
+    #[inline(always)]
+    pub(crate) fn record_epa(
+        _niter: usize,
+        _polytope_size: usize,
+        _converged: bool,
+        _failed: bool,
+    ) {
+    }
+}
 mod distance;
 mod error;
+#[cfg(feature = "dim3")]
+mod facing_area;
+mod inscribed_sphere;
+mod intersection_test;
 mod nonlinear_time_of_impact;
+mod overlap_volume;
 mod point;
+#[cfg(feature = "dim3")]
+mod project_to_plane;
 mod proximity;
 mod ray;
+#[cfg(feature = "dim3")]
+mod rotational_depenetration;
+mod segment_plane;
+mod surface_distance;
 mod time_of_impact;
 mod toi_dispatcher;
 pub mod visitors;
@@ -1,8 +1,8 @@
-use na::RealField;
+use na::{self, RealField, Unit};
 
-use crate::interpolation::RigidMotion;
-use crate::math::Isometry;
-use crate::query::{self, ClosestPoints, TOI};
+use crate::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{self, ClosestPoints, TOIStatus, TOI};
 use crate::shape::Ball;
 
 /// Non-linear Time Of Impact of two balls under a rigid motion (translation + rotation).
@@ -41,3 +41,133 @@ pub fn nonlinear_time_of_impact_ball_ball<N: RealField>(
         closest_points,
     )
 }
+
+/// Time Of Impact of two balls rotating about fixed pivots at constant angular velocities.
+///
+/// Each ball rotates about the pivot `motion.start * motion.local_center`, and may also have a
+/// constant translational velocity. This searches the first time in `[0, max_toi]` at which the
+/// distance between the ball centers equals the sum of their radii, using conservative
+/// advancement bounded by the largest speed and acceleration the ball centers can reach.
+///
+/// Returns `None` if the balls never touch before `max_toi`. Since the steps are also bounded by
+/// the acceleration of the ball centers, balls barely missing each other are still found not to
+/// touch in a few iterations. If no conclusion is reached after 100 iterations, the returned TOI
+/// has the `TOIStatus::OutOfIterations` status and the balls may not actually touch at its `toi`.
+pub fn nonlinear_time_of_impact_rotating_ball_ball<N: RealField>(
+    motion1: &ConstantVelocityRigidMotion<N>,
+    b1: &Ball<N>,
+    motion2: &ConstantVelocityRigidMotion<N>,
+    b2: &Ball<N>,
+    max_toi: N,
+) -> Option<TOI<N>> {
+    #[cfg(feature = "dim2")]
+    fn angspeed<N: RealField>(motion: &ConstantVelocityRigidMotion<N>) -> N {
+        motion.angvel.abs()
+    }
+
+    #[cfg(feature = "dim3")]
+    fn angspeed<N: RealField>(motion: &ConstantVelocityRigidMotion<N>) -> N {
+        motion.angvel.norm()
+    }
+
+    // The velocity of the ball center at the time `t`, the ball center being at `center`.
+    fn center_velocity<N: RealField>(
+        motion: &ConstantVelocityRigidMotion<N>,
+        t: N,
+        center: &Vector<N>,
+    ) -> Vector<N> {
+        let pivot = motion.start * motion.local_center + motion.linvel * (t - motion.t0);
+        let arm = center - pivot.coords;
+
+        #[cfg(feature = "dim2")]
+        let rotational = Vector::new(-arm.y, arm.x) * motion.angvel;
+        #[cfg(feature = "dim3")]
+        let rotational = motion.angvel.cross(&arm);
+
+        motion.linvel + rotational
+    }
+
+    let sum_radius = b1.radius + b2.radius;
+    let tol = N::default_epsilon().sqrt() * (N::one() + sum_radius);
+    let max_speed = motion1.linvel.norm()
+        + motion2.linvel.norm()
+        + angspeed(motion1) * motion1.local_center.coords.norm()
+        + angspeed(motion2) * motion2.local_center.coords.norm();
+    // The largest acceleration of the ball centers relative to each other, due to the rotations.
+    let max_accel = angspeed(motion1) * angspeed(motion1) * motion1.local_center.coords.norm()
+        + angspeed(motion2) * angspeed(motion2) * motion2.local_center.coords.norm();
+    // While the balls don't touch, the distance between their centers is at least `sum_radius`,
+    // so the second derivative of this distance is bounded by `max_dist_accel`.
+    let max_dist_accel = if sum_radius.is_zero() {
+        N::zero()
+    } else {
+        max_speed * max_speed / sum_radius + max_accel
+    };
+    let mut toi = N::zero();
+    let mut status = TOIStatus::Converged;
+    let mut niter = 0;
+
+    loop {
+        let center1 = motion1.position_at_time(toi).translation.vector;
+        let center2 = motion2.position_at_time(toi).translation.vector;
+        let dist = (center2 - center1).norm() - sum_radius;
+
+        if dist <= tol {
+            if toi.is_zero() && dist < -tol {
+                status = TOIStatus::Penetrating;
+            }
+
+            break;
+        }
+
+        if max_speed.is_zero() {
+            return None;
+        }
+
+        // The distance cannot decrease faster than `max_speed`.
+        let mut step = dist / max_speed;
+
+        // Nor faster than allowed by its current rate of change and its second derivative. This
+        // gives much larger steps than the speed bound when the balls barely miss each other.
+        if !max_dist_accel.is_zero() {
+            let dir = (center2 - center1).normalize();
+            let rate = dir.dot(
+                &(center_velocity(motion2, toi, &center2)
+                    - center_velocity(motion1, toi, &center1)),
+            );
+            let discr = rate * rate + dist * max_dist_accel * na::convert(2.0);
+            step = step.max((rate + discr.sqrt()) / max_dist_accel);
+        }
+
+        toi += step;
+
+        if toi > max_toi {
+            return None;
+        }
+
+        niter += 1;
+        if niter == 100 {
+            status = TOIStatus::OutOfIterations;
+            break;
+        }
+    }
+
+    let pos1 = motion1.position_at_time(toi);
+    let pos2 = motion2.position_at_time(toi);
+    let dir = Unit::try_new(
+        pos2.translation.vector - pos1.translation.vector,
+        N::default_epsilon(),
+    )
+    .unwrap_or_else(Vector::x_axis);
+    let normal1 = pos1.inverse_transform_unit_vector(&dir);
+    let normal2 = pos2.inverse_transform_unit_vector(&-dir);
+
+    Some(TOI {
+        toi,
+        witness1: Point::from(*normal1 * b1.radius),
+        witness2: Point::from(*normal2 * b2.radius),
+        normal1,
+        normal2,
+        status,
+    })
+}
@@ -1,6 +1,7 @@
-use na::{RealField, Unit};
+use na::{self, RealField, Unit};
 
-use crate::interpolation::RigidMotion;
+use crate::bounding_volume;
+use crate::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
 use crate::math::{Isometry, Point, Vector};
 use crate::query::{self, ClosestPoints, TOIStatus, TOI};
 use crate::shape::SupportMap;
@@ -165,3 +166,115 @@ where
 
     Some(result)
 }
+
+/// Time of impact of two support-mapped shapes moving at constant linear and angular velocities.
+///
+/// This uses conservative advancement: the motion of the point of each shape farthest from its
+/// rotation center `motion.local_center` is bounded by `|linvel| + |angvel| * radius`, so the
+/// shapes can safely be advanced along their screw motions by their current distance divided by
+/// the sum of those bounds projected on the separating direction. Contrary to
+/// `nonlinear_time_of_impact_support_map_support_map`, this never misses an impact happening
+/// before `max_toi`, even for fast-spinning shapes.
+///
+/// Returns `None` if the shapes do not get closer than `target_distance` before `max_toi`. If
+/// the shapes are still not touching after 100 advancement steps, the last (conservative) time
+/// reached is returned with a `TOIStatus::OutOfIterations` status.
+pub fn nonlinear_time_of_impact_rotating_support_map_support_map<N, G1: ?Sized, G2: ?Sized>(
+    motion1: &ConstantVelocityRigidMotion<N>,
+    g1: &G1,
+    motion2: &ConstantVelocityRigidMotion<N>,
+    g2: &G2,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    // The largest distance between a point of `g` and the rotation center of `motion`.
+    fn max_radius<N: RealField, G: ?Sized + SupportMap<N>>(
+        motion: &ConstantVelocityRigidMotion<N>,
+        g: &G,
+    ) -> N {
+        let aabb = bounding_volume::local_support_map_aabb(g);
+        let c = motion.local_center;
+        (aabb.mins - c).abs().sup(&(aabb.maxs - c).abs()).norm()
+    }
+
+    #[cfg(feature = "dim2")]
+    let angspeeds = (motion1.angvel.abs(), motion2.angvel.abs());
+    #[cfg(feature = "dim3")]
+    let angspeeds = (motion1.angvel.norm(), motion2.angvel.norm());
+
+    let angular_bound =
+        angspeeds.0 * max_radius(motion1, g1) + angspeeds.1 * max_radius(motion2, g2);
+    let tol = N::default_epsilon().sqrt() * (N::one() + target_distance);
+    let mut niter = 0;
+    let mut result = TOI {
+        toi: N::zero(),
+        normal1: Vector::x_axis(),
+        normal2: Vector::x_axis(),
+        witness1: Point::origin(),
+        witness2: Point::origin(),
+        status: TOIStatus::Converged,
+    };
+
+    loop {
+        let pos1 = motion1.position_at_time(result.toi);
+        let pos2 = motion2.position_at_time(result.toi);
+
+        match query::closest_points_support_map_support_map(&pos1, g1, &pos2, g2, N::max_value()) {
+            ClosestPoints::Intersecting => {
+                result.status = if result.toi.is_zero() {
+                    TOIStatus::Penetrating
+                } else {
+                    TOIStatus::Failed
+                };
+                break;
+            }
+            ClosestPoints::WithinMargin(p1, p2) => {
+                result.witness1 = pos1.inverse_transform_point(&p1);
+                result.witness2 = pos2.inverse_transform_point(&p2);
+
+                let (dir, dist) = match Unit::try_new_and_get(p2 - p1, N::default_epsilon()) {
+                    Some(res) => res,
+                    None => break,
+                };
+
+                result.normal1 = pos1.inverse_transform_unit_vector(&dir);
+                result.normal2 = pos2.inverse_transform_unit_vector(&-dir);
+
+                if dist - target_distance <= tol {
+                    if result.toi.is_zero() && dist < target_distance - tol {
+                        result.status = TOIStatus::Penetrating;
+                    }
+
+                    break;
+                }
+
+                // The distance cannot decrease faster than this along `dir`.
+                let max_speed = (motion1.linvel - motion2.linvel).dot(&dir) + angular_bound;
+
+                if max_speed <= N::zero() {
+                    return None;
+                }
+
+                result.toi += (dist - target_distance) / max_speed;
+
+                if result.toi > max_toi {
+                    return None;
+                }
+            }
+            ClosestPoints::Disjoint => unreachable!(),
+        }
+
+        niter += 1;
+        if niter == 100 {
+            result.status = TOIStatus::OutOfIterations;
+            break;
+        }
+    }
+
+    Some(result)
+}
@@ -0,0 +1,304 @@
+use na::{self, RealField};
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, DIM};
+use crate::query::PointQuery;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+#[cfg(feature = "dim3")]
+use crate::shape::{
+    ConvexHull, ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, TriMesh, Wedge,
+};
+use crate::shape::{Cuboid, Polyline, Shape};
+
+/// Number of times the cells are subdivided by `shape_aabb_overlap_volume` near the boundary of
+/// the shape.
+const MAX_OVERLAP_VOLUME_DEPTH: usize = 6;
+
+/// Computes the volume of the part of a shape located inside of an AABB.
+///
+/// In 2D, this is the area of this part. Returns zero if the shape does not intersect `cell`.
+///
+/// The result is exact for convex polyhedra, i.e., `Cuboid`, `Wedge` and `ConvexHull` in 3D, and
+/// `Cuboid` and `ConvexPolygon` in 2D: the shape is clipped by each face of `cell`.
+///
+/// For other shapes, the result is only an approximation, computed by recursively subdividing the
+/// intersection of `cell` with the AABB of the shape: sub-boxes entirely inside or outside of the
+/// shape are counted as a whole, and the other ones are subdivided up to a fixed depth where they
+/// are counted as a whole if their center is inside of the shape. So the error is bounded by the
+/// volume of the smallest sub-boxes crossed by the boundary of the shape. Such shapes must have a
+/// `PointQuery` implementation.
+///
+/// Shapes enclosing no volume, i.e., `TriMesh` and `Polyline`, return zero.
+pub fn shape_aabb_overlap_volume<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    cell: &AABB<N>,
+) -> N {
+    let overlap = match shape.aabb(m).intersection(cell) {
+        Some(overlap) => overlap,
+        None => return N::zero(),
+    };
+
+    if is_surface(shape) {
+        return N::zero();
+    }
+
+    if let Some(polytope) = convex_polytope(m, shape) {
+        return clipped_volume(polytope, cell);
+    }
+
+    let point_query = shape
+        .as_point_query()
+        .expect("No PointQuery implementation for the underlying shape.");
+
+    subdivided_volume(m, point_query, &overlap, MAX_OVERLAP_VOLUME_DEPTH)
+}
+
+#[cfg(feature = "dim2")]
+fn is_surface<N: RealField>(shape: &dyn Shape<N>) -> bool {
+    shape.is_shape::<Polyline<N>>()
+}
+
+#[cfg(feature = "dim3")]
+fn is_surface<N: RealField>(shape: &dyn Shape<N>) -> bool {
+    shape.is_shape::<Polyline<N>>() || shape.is_shape::<TriMesh<N>>()
+}
+
+// The vertices of `shape`, in world-space, if it is a convex polygon.
+#[cfg(feature = "dim2")]
+fn convex_polytope<N: RealField>(m: &Isometry<N>, shape: &dyn Shape<N>) -> Option<Vec<Point<N>>> {
+    let vertices = if let Some(cuboid) = shape.as_shape::<Cuboid<N>>() {
+        let he = cuboid.half_extents;
+        vec![
+            Point::new(-he.x, -he.y),
+            Point::new(he.x, -he.y),
+            Point::new(he.x, he.y),
+            Point::new(-he.x, he.y),
+        ]
+    } else if let Some(polygon) = shape.as_shape::<ConvexPolygon<N>>() {
+        polygon.points().to_vec()
+    } else {
+        return None;
+    };
+
+    Some(vertices.iter().map(|pt| m * pt).collect())
+}
+
+// The faces of `shape`, in world-space, if it is a convex polyhedron.
+#[cfg(feature = "dim3")]
+fn convex_polytope<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+) -> Option<Vec<Vec<Point<N>>>> {
+    let (polyhedron, num_faces): (&dyn ConvexPolyhedron<N>, usize) =
+        if let Some(cuboid) = shape.as_shape::<Cuboid<N>>() {
+            (cuboid, 2 * DIM)
+        } else if let Some(wedge) = shape.as_shape::<Wedge<N>>() {
+            (wedge, 5)
+        } else if let Some(hull) = shape.as_shape::<ConvexHull<N>>() {
+            (hull, hull.num_faces())
+        } else {
+            return None;
+        };
+
+    let mut face = ConvexPolygonalFeature::new();
+    let faces = (0..num_faces)
+        .map(|i| {
+            polyhedron.face(FeatureId::Face(i), &mut face);
+            face.vertices.iter().map(|pt| m * pt).collect()
+        })
+        .collect();
+
+    Some(faces)
+}
+
+// Clips the convex polygon `polygon` by the half-space `sign * x[axis] <= offset`.
+//
+// Returns the clipped polygon, and pushes to `section` the points of the clipped polygon lying
+// on the boundary of the half-space.
+fn clip_polygon<N: RealField>(
+    polygon: &[Point<N>],
+    axis: usize,
+    sign: N,
+    offset: N,
+    section: &mut Vec<Point<N>>,
+) -> Vec<Point<N>> {
+    let eps = N::default_epsilon().sqrt();
+    let dist = |pt: &Point<N>| {
+        let d = pt[axis] * sign - offset;
+        if d.abs() <= eps {
+            N::zero()
+        } else {
+            d
+        }
+    };
+    let mut result = Vec::new();
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        let (da, db) = (dist(a), dist(b));
+
+        if da.is_zero() {
+            let mut a = *a;
+            a[axis] = offset * sign;
+            result.push(a);
+            section.push(a);
+        } else if da < N::zero() {
+            result.push(*a);
+        }
+
+        if (da < N::zero() && db > N::zero()) || (da > N::zero() && db < N::zero()) {
+            let mut pt = a + (b - a) * (da / (da - db));
+            pt[axis] = offset * sign;
+            result.push(pt);
+            section.push(pt);
+        }
+    }
+
+    result
+}
+
+// The area of the part of the convex polygon `polygon` located inside of `cell`.
+#[cfg(feature = "dim2")]
+fn clipped_volume<N: RealField>(mut polygon: Vec<Point<N>>, cell: &AABB<N>) -> N {
+    let mut section = Vec::new();
+
+    for axis in 0..DIM {
+        for (sign, offset) in [(N::one(), cell.maxs[axis]), (-N::one(), -cell.mins[axis])].iter() {
+            polygon = clip_polygon(&polygon, axis, *sign, *offset, &mut section);
+        }
+    }
+
+    if polygon.len() < 3 {
+        return N::zero();
+    }
+
+    let mut area = N::zero();
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        area += a.x * b.y - a.y * b.x;
+    }
+
+    area.abs() * na::convert(0.5)
+}
+
+// The volume of the part of the convex polyhedron with the faces `faces` located inside of
+// `cell`.
+#[cfg(feature = "dim3")]
+fn clipped_volume<N: RealField>(mut faces: Vec<Vec<Point<N>>>, cell: &AABB<N>) -> N {
+    for axis in 0..DIM {
+        for (sign, offset) in [(N::one(), cell.maxs[axis]), (-N::one(), -cell.mins[axis])].iter() {
+            // The section of the polyhedron by the clipping plane becomes a new face.
+            let mut section = Vec::new();
+
+            for face in &mut faces {
+                *face = clip_polygon(face, axis, *sign, *offset, &mut section);
+            }
+
+            // Remove the faces lying on the clipping plane, they are replaced by the section.
+            faces.retain(|face| {
+                face.len() >= 3 && face.iter().any(|pt| pt[axis] != *offset * *sign)
+            });
+
+            if section.len() >= 3 {
+                faces.push(sorted_section(section, axis));
+            }
+        }
+    }
+
+    // The polyhedron is the union of the pyramids joining each face to a point inside of it.
+    let mut center = Point::origin();
+    let mut num_points = 0;
+
+    for pt in faces.iter().flatten() {
+        center += pt.coords;
+        num_points += 1;
+    }
+
+    if num_points == 0 {
+        return N::zero();
+    }
+
+    center /= na::convert::<_, N>(num_points as f64);
+    let mut volume = N::zero();
+
+    for face in &faces {
+        for i in 1..face.len() - 1 {
+            let (a, b, c) = (face[0] - center, face[i] - center, face[i + 1] - center);
+            volume += a.dot(&b.cross(&c)).abs();
+        }
+    }
+
+    volume / na::convert(6.0)
+}
+
+// Sorts the points of the convex polygon `section`, lying on a plane orthogonal to `axis`, by
+// angle around their center.
+#[cfg(feature = "dim3")]
+fn sorted_section<N: RealField>(section: Vec<Point<N>>, axis: usize) -> Vec<Point<N>> {
+    let (i, j) = ((axis + 1) % DIM, (axis + 2) % DIM);
+    let mut center = Point::origin();
+
+    for pt in &section {
+        center += pt.coords;
+    }
+
+    center /= na::convert::<_, N>(section.len() as f64);
+    let mut angles: Vec<_> = section
+        .into_iter()
+        .map(|pt| ((pt[j] - center[j]).atan2(pt[i] - center[i]), pt))
+        .collect();
+    angles.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    angles.into_iter().map(|(_, pt)| pt).collect()
+}
+
+fn box_volume<N: RealField>(aabb: &AABB<N>) -> N {
+    aabb.extents()
+        .iter()
+        .fold(N::one(), |volume, e| volume * *e)
+}
+
+fn subdivided_volume<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn PointQuery<N>,
+    aabb: &AABB<N>,
+    depth: usize,
+) -> N {
+    let center = aabb.center();
+    let radius = aabb.half_extents().norm();
+    let dist = shape.distance_to_point(m, &center, false);
+
+    if dist >= radius {
+        // The box is outside of the shape.
+        return N::zero();
+    }
+
+    if dist <= -radius || (depth == 0 && dist <= N::zero()) {
+        // The box is inside of the shape.
+        return box_volume(aabb);
+    }
+
+    if depth == 0 {
+        return N::zero();
+    }
+
+    let mut volume = N::zero();
+
+    for child in 0..1 << DIM {
+        let mut sub_aabb = *aabb;
+
+        for i in 0..DIM {
+            if child & (1 << i) == 0 {
+                sub_aabb.maxs[i] = center[i];
+            } else {
+                sub_aabb.mins[i] = center[i];
+            }
+        }
+
+        volume += subdivided_volume(m, shape, &sub_aabb, depth - 1);
+    }
+
+    volume
+}
@@ -5,6 +5,8 @@ pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation}
 pub use self::point_support_map::point_projection_on_support_map;
 
 mod point_aabb;
+#[cfg(feature = "dim2")]
+mod point_arc;
 mod point_ball;
 mod point_bounding_sphere;
 mod point_capsule;
@@ -15,8 +17,10 @@ mod point_plane;
 mod point_polyline;
 #[doc(hidden)]
 pub mod point_query;
+mod point_scaled_shape;
 mod point_segment;
 mod point_shape;
+mod point_slab;
 mod point_support_map;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
@@ -0,0 +1,37 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{Arc, FeatureId};
+use na::{RealField, Unit};
+
+impl<N: RealField> PointQuery<N> for Arc<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let proj = self.project_on_curve(&ls_pt);
+        let half_thickness = self.half_thickness();
+
+        if let Some((dir, dist)) = Unit::try_new_and_get(ls_pt - proj, N::default_epsilon()) {
+            let inside = dist <= half_thickness;
+            if solid && inside {
+                PointProjection::new(true, *pt)
+            } else {
+                PointProjection::new(inside, m * (proj + dir.into_inner() * half_thickness))
+            }
+        } else if solid {
+            PointProjection::new(true, *pt)
+        } else {
+            // The point lies on the curve: project it on the outer side of the arc.
+            let dir = (proj - self.center).normalize();
+            PointProjection::new(true, m * (proj + dir * half_thickness))
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, pt, false), FeatureId::Face(0))
+    }
+}
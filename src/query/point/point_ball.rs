@@ -1,7 +1,7 @@
-use na;
+use na::{self, Unit};
 use simba::scalar::RealField;
 
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{Ball, FeatureId};
 
@@ -16,7 +16,11 @@ impl<N: RealField> PointQuery<N> for Ball<N> {
         if inside && solid {
             PointProjection::new(true, *pt)
         } else {
-            let ls_proj = Point::from(ls_pt.coords * (self.radius / distance_squared.sqrt()));
+            // The center is equally far from every point of the boundary, pick the same one as
+            // `project_point_with_normal`.
+            let ls_dir =
+                Unit::try_new(ls_pt.coords, N::default_epsilon()).unwrap_or_else(Vector::y_axis);
+            let ls_proj = Point::from(ls_dir.into_inner() * self.radius);
             PointProjection::new(inside, m * ls_proj)
         }
     }
@@ -30,6 +34,19 @@ impl<N: RealField> PointQuery<N> for Ball<N> {
         (self.project_point(m, pt, false), FeatureId::Face(0))
     }
 
+    #[inline]
+    fn project_point_with_normal(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, Unit<Vector<N>>) {
+        let ls_pt = m.inverse_transform_point(pt);
+        let ls_normal =
+            Unit::try_new(ls_pt.coords, N::default_epsilon()).unwrap_or_else(Vector::y_axis);
+
+        (self.project_point(m, pt, false), m * ls_normal)
+    }
+
     #[inline]
     fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
         let dist = m.inverse_transform_point(pt).coords.norm() - self.radius;
@@ -45,4 +62,21 @@ impl<N: RealField> PointQuery<N> for Ball<N> {
     fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
         m.inverse_transform_point(pt).coords.norm_squared() <= self.radius * self.radius
     }
+
+    #[inline]
+    fn contains_points(&self, m: &Isometry<N>, pts: &[Point<N>], out: &mut [bool]) {
+        assert_eq!(
+            pts.len(),
+            out.len(),
+            "The points and results slices must have the same length."
+        );
+
+        // The distance to the center is invariant by rotation so only the translation matters.
+        let center = Point::from(m.translation.vector);
+        let sq_radius = self.radius * self.radius;
+
+        for (pt, res) in pts.iter().zip(out.iter_mut()) {
+            *res = na::distance_squared(pt, &center) <= sq_radius;
+        }
+    }
 }
@@ -1,8 +1,9 @@
 use crate::bounding_volume::AABB;
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
+use crate::query::point::point_query;
 use crate::query::{PointProjection, PointQuery};
-use crate::shape::{Cuboid, FeatureId};
-use na::RealField;
+use crate::shape::{ConvexPolyhedron, Cuboid, FeatureId};
+use na::{RealField, Unit};
 
 impl<N: RealField> PointQuery<N> for Cuboid<N> {
     #[inline]
@@ -23,6 +24,23 @@ impl<N: RealField> PointQuery<N> for Cuboid<N> {
         AABB::new(dl, ur).project_point_with_feature(m, pt)
     }
 
+    #[inline]
+    fn project_point_with_normal(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, Unit<Vector<N>>) {
+        // The normals at edges and vertices are the average of the normals of adjacent faces.
+        let (proj, feature) = self.project_point_with_feature(m, pt);
+
+        let normal = match feature {
+            FeatureId::Unknown => point_query::estimate_normal(self, m, pt, &proj),
+            _ => m * self.feature_normal(feature),
+        };
+
+        (proj, normal)
+    }
+
     #[inline]
     fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
         let dl = Point::origin() + (-self.half_extents);
@@ -36,4 +54,24 @@ impl<N: RealField> PointQuery<N> for Cuboid<N> {
         let ur = Point::origin() + self.half_extents;
         AABB::new(dl, ur).contains_point(m, pt)
     }
+
+    #[inline]
+    fn contains_points(&self, m: &Isometry<N>, pts: &[Point<N>], out: &mut [bool]) {
+        assert_eq!(
+            pts.len(),
+            out.len(),
+            "The points and results slices must have the same length."
+        );
+
+        let inv_m = m.inverse();
+
+        for (pt, res) in pts.iter().zip(out.iter_mut()) {
+            let ls_pt = inv_m * pt;
+            *res = ls_pt
+                .coords
+                .iter()
+                .zip(self.half_extents.iter())
+                .all(|(x, he)| x.abs() <= *he);
+        }
+    }
 }
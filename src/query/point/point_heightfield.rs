@@ -4,16 +4,19 @@ use crate::shape::{FeatureId, HeightField, TrianglePointLocation};
 use na::{self, RealField};
 
 impl<N: RealField> PointQuery<N> for HeightField<N> {
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, _: bool) -> PointProjection<N> {
+        self.project_point_and_cell(m, point).0
+    }
+
+    #[cfg(feature = "dim2")]
     #[inline]
     fn project_point(&self, m: &Isometry<N>, point: &Point<N>, _: bool) -> PointProjection<N> {
         let mut smallest_dist = N::max_value();
         let mut best_proj = PointProjection::new(false, *point);
 
-        #[cfg(feature = "dim2")]
-        let iter = self.segments();
-        #[cfg(feature = "dim3")]
-        let iter = self.triangles();
-        for elt in iter {
+        for elt in self.segments() {
             let proj = elt.project_point(m, point, false);
             let dist = na::distance_squared(point, &proj.point);
 
@@ -1,6 +1,6 @@
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Similarity, Vector, DIM};
 use crate::shape::FeatureId;
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 
 /// Description of the projection of a point on a shape.
 #[derive(Copy, Clone, Debug)]
@@ -45,11 +45,98 @@ pub trait PointQuery<N: RealField> {
         pt: &Point<N>,
     ) -> (PointProjection<N>, FeatureId);
 
+    /// Projects a point on the boundary of `self` transformed by `m` and returns the outward
+    /// normal of the boundary at the projected point.
+    ///
+    /// By default, the normal is the direction between the point and its projection. If the
+    /// point lies on the boundary already, it is estimated from the variations of the signed
+    /// distance to the boundary around the point.
+    #[inline]
+    fn project_point_with_normal(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, Unit<Vector<N>>) {
+        let proj = self.project_point(m, pt, false);
+        let normal = estimate_normal(self, m, pt, &proj);
+        (proj, normal)
+    }
+
     /// Tests if the given point is inside of `self` transformed by `m`.
     #[inline]
     fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
         self.project_point(m, pt, false).is_inside
     }
+
+    /// Tests if each point of `pts` is inside of `self` transformed by `m`.
+    ///
+    /// The result for `pts[i]` is written to `out[i]`. Both slices must have the same length.
+    #[inline]
+    fn contains_points(&self, m: &Isometry<N>, pts: &[Point<N>], out: &mut [bool]) {
+        assert_eq!(
+            pts.len(),
+            out.len(),
+            "The points and results slices must have the same length."
+        );
+
+        for (pt, res) in pts.iter().zip(out.iter_mut()) {
+            *res = self.contains_point(m, pt);
+        }
+    }
+
+    /// Tests if the given point lies on the boundary of `self` transformed by `m`.
+    ///
+    /// This is `true` iff the signed distance between the point and the boundary of the shape
+    /// is at most `tol` in absolute value, i.e., if the point is neither deeper than `tol` inside
+    /// of the shape nor further than `tol` from it.
+    #[inline]
+    fn on_surface(&self, m: &Isometry<N>, pt: &Point<N>, tol: N) -> bool {
+        self.distance_to_point(m, pt, false).abs() <= tol
+    }
+
+    /// Projects a point on `self` transformed by the similarity `m`.
+    #[inline]
+    fn project_point_with_similarity(
+        &self,
+        m: &Similarity<N>,
+        pt: &Point<N>,
+        solid: bool,
+    ) -> PointProjection<N> {
+        let local_pt = m.inverse_transform_point(pt);
+        let proj = self.project_point(&Isometry::identity(), &local_pt, solid);
+        PointProjection::new(proj.is_inside, m * proj.point)
+    }
+}
+
+/// The outward normal of the boundary of `shape` at `proj`, the projection of `pt` on it.
+pub(crate) fn estimate_normal<N: RealField, Q: PointQuery<N> + ?Sized>(
+    shape: &Q,
+    m: &Isometry<N>,
+    pt: &Point<N>,
+    proj: &PointProjection<N>,
+) -> Unit<Vector<N>> {
+    let h = N::default_epsilon().sqrt() * (N::one() + pt.coords.norm());
+    let dir = if proj.is_inside {
+        proj.point - *pt
+    } else {
+        *pt - proj.point
+    };
+
+    if let Some(normal) = Unit::try_new(dir, h) {
+        return normal;
+    }
+
+    // Central differences of the signed distance to the boundary.
+    let mut gradient = Vector::zeros();
+
+    for i in 0..DIM {
+        let mut shift = Vector::zeros();
+        shift[i] = h;
+        gradient[i] = shape.distance_to_point(m, &(*pt + shift), false)
+            - shape.distance_to_point(m, &(*pt - shift), false);
+    }
+
+    Unit::try_new(gradient, N::zero()).unwrap_or_else(|| m * Vector::y_axis())
 }
 
 /// Returns shape-specific info in addition to generic projection information
@@ -0,0 +1,30 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{self, PointProjection, PointQuery};
+use crate::shape::{FeatureId, ScaledShape};
+
+impl<N: RealField> PointQuery<N> for ScaledShape<N> {
+    /// Projects a point on this shape using the GJK algorithm.
+    ///
+    /// Panics if the wrapped shape is not a support map.
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        assert!(
+            self.shape().is_support_map(),
+            "Point queries on a scaled shape require the scaled shape to be a support map."
+        );
+
+        query::point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{FeatureId, Shape};
-use na::RealField;
+use na::{RealField, Unit};
 
 impl<N: RealField> PointQuery<N> for dyn Shape<N> {
     #[inline]
@@ -22,6 +22,17 @@ impl<N: RealField> PointQuery<N> for dyn Shape<N> {
             .project_point_with_feature(m, pt)
     }
 
+    #[inline]
+    fn project_point_with_normal(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, Unit<Vector<N>>) {
+        self.as_point_query()
+            .expect("No PointQuery implementation for the underlying shape.")
+            .project_point_with_normal(m, pt)
+    }
+
     #[inline]
     fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
         self.as_point_query()
@@ -35,4 +46,11 @@ impl<N: RealField> PointQuery<N> for dyn Shape<N> {
             .expect("No PointQuery implementation for the underlying shape.")
             .contains_point(m, pt)
     }
+
+    #[inline]
+    fn contains_points(&self, m: &Isometry<N>, pts: &[Point<N>], out: &mut [bool]) {
+        self.as_point_query()
+            .expect("No PointQuery implementation for the underlying shape.")
+            .contains_points(m, pts, out)
+    }
 }
@@ -0,0 +1,46 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Slab};
+use na::RealField;
+
+impl<N: RealField> PointQuery<N> for Slab<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let (proj, _) = self.project_point_with_feature(m, pt);
+
+        if proj.is_inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            proj
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let ls_pt = m.inverse_transform_point(pt);
+        let h = self.half_thickness();
+        let d = self.normal.dot(&ls_pt.coords) - self.offset;
+        let inside = d.abs() <= h;
+
+        // Project on the closest bounding plane.
+        let (target, feature) = if d >= N::zero() {
+            (h, FeatureId::Face(0))
+        } else {
+            (-h, FeatureId::Face(1))
+        };
+
+        let proj = *pt + m * (*self.normal * (target - d));
+        (PointProjection::new(inside, proj), feature)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        let ls_pt = m.inverse_transform_point(pt);
+
+        (self.normal.dot(&ls_pt.coords) - self.offset).abs() <= self.half_thickness()
+    }
+}
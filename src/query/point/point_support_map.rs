@@ -8,11 +8,11 @@ use crate::query::{PointProjection, PointQuery};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexHull, Cylinder};
-use crate::shape::{ConvexPolyhedron, FeatureId, SupportMap};
+use crate::shape::{Cone, ConvexHull, Cylinder, Wedge};
+use crate::shape::{ConvexPolyhedron, FeatureId, MinkowskiSum, MorphShape, RoundShape, SupportMap};
 
 /// Projects a point on a shape using the GJK algorithm.
-pub fn point_projection_on_support_map<N, G>(
+pub fn point_projection_on_support_map<N, G: ?Sized>(
     m: &Isometry<N>,
     shape: &G,
     simplex: &mut VoronoiSimplex<N>,
@@ -86,8 +86,100 @@ impl<N: RealField> PointQuery<N> for Cone<N> {
     }
 }
 
+impl<N: RealField> PointQuery<N> for MinkowskiSum<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
+impl<N: RealField> PointQuery<N> for MorphShape<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
+impl<N: RealField> PointQuery<N> for RoundShape<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> PointQuery<N> for ConvexHull<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        if !solid {
+            // Exact projection of interior points, which the EPA may fail to compute.
+            let ls_pt = m.inverse_transform_point(point);
+
+            if let Some(ls_proj) = self.project_local_point_from_inside(&ls_pt) {
+                return PointProjection::new(true, m * ls_proj);
+            }
+        }
+
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(point))
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let proj = self.project_point(m, point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside {
+            m.inverse_transform_vector(&-dpt)
+        } else {
+            m.inverse_transform_vector(&dpt)
+        };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, N::default_epsilon()) {
+            let feature = ConvexPolyhedron::<N>::support_feature_id_toward(self, &local_dir);
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> PointQuery<N> for Wedge<N> {
     #[inline]
     fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
         point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
@@ -120,6 +212,15 @@ impl<N: RealField> PointQuery<N> for ConvexHull<N> {
 impl<N: RealField> PointQuery<N> for ConvexPolygon<N> {
     #[inline]
     fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        if !solid {
+            // Exact projection of interior points, which the EPA may fail to compute.
+            let ls_pt = m.inverse_transform_point(point);
+
+            if let Some(ls_proj) = self.project_local_point_from_inside(&ls_pt) {
+                return PointProjection::new(true, m * ls_proj);
+            }
+        }
+
         point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
     }
 
@@ -1,11 +1,12 @@
 use crate::bounding_volume::AABB;
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
+use crate::query::point::point_query;
 use crate::query::{
     visitors::CompositePointContainmentTest, PointProjection, PointQuery, PointQueryWithLocation,
 };
 use crate::shape::{CompositeShape, FeatureId, TriMesh, TrianglePointLocation};
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 
 impl<N: RealField> PointQuery<N> for TriMesh<N> {
     #[inline]
@@ -36,6 +37,22 @@ impl<N: RealField> PointQuery<N> for TriMesh<N> {
         (projection, feature_id)
     }
 
+    #[inline]
+    fn project_point_with_normal(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, Unit<Vector<N>>) {
+        let (projection, (triangle_id, _)) = self.project_point_with_location(m, point, false);
+
+        let normal = match self.faces()[triangle_id].normal {
+            Some(normal) => m * normal,
+            None => point_query::estimate_normal(self, m, point, &projection),
+        };
+
+        (projection, normal)
+    }
+
     // FIXME: implement distance_to_point too?
 
     #[inline]
@@ -0,0 +1,77 @@
+use na::{self, Point2, RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::facing_area::{projection_basis, signed_polygon_area, silhouette_points};
+use crate::shape::{ConvexHull, Cuboid, Polyline, Shape, Wedge};
+use crate::transformation;
+
+/// Computes the outline of the orthographic projection of a convex shape onto a plane.
+///
+/// The plane passes through `plane_point` and is orthogonal to `plane_normal`. The result is a
+/// closed polyline made of the vertices of the convex polygon bounding the projection, given in
+/// counter-clockwise order around `plane_normal` and expressed in the same space as `plane_point`.
+/// The outline is exact for cuboids, wedges, and convex hulls. Other support-mapped shapes, e.g.,
+/// balls, are approximated by projecting their support points along regularly spaced directions of
+/// the plane. The projection of the shape is assumed not to be degenerate, i.e., it must not be a
+/// point or a segment.
+pub fn project_to_plane<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    plane_point: &Point<N>,
+    plane_normal: &Unit<Vector<N>>,
+) -> Polyline<N> {
+    let [u, v] = projection_basis(plane_normal);
+
+    let project = |pt: &Point<N>| {
+        let dpt = m * pt - plane_point;
+        Point2::new(u.dot(&dpt), v.dot(&dpt))
+    };
+
+    let projected: Vec<_> = if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+        (0..8)
+            .map(|i| {
+                let mut corner = c.half_extents;
+
+                for k in 0..3 {
+                    if i & (1 << k) != 0 {
+                        corner[k] = -corner[k];
+                    }
+                }
+
+                project(&Point::from(corner))
+            })
+            .collect()
+    } else if let Some(c) = shape.as_shape::<ConvexHull<N>>() {
+        c.points().iter().map(project).collect()
+    } else if let Some(w) = shape.as_shape::<Wedge<N>>() {
+        w.vertices().iter().map(project).collect()
+    } else if let Some(s) = shape.as_support_map() {
+        let local_basis = [
+            m.inverse_transform_vector(&u),
+            m.inverse_transform_vector(&v),
+        ];
+        silhouette_points(s, &local_basis)
+            .iter()
+            .map(project)
+            .collect()
+    } else {
+        panic!("No algorithm known to compute the projection of the given shape onto a plane.")
+    };
+
+    let mut hull = transformation::convex_hull2_idx(&projected);
+    let hull_points: Vec<_> = hull.iter().map(|i| projected[*i]).collect();
+
+    if signed_polygon_area(&hull_points) < N::zero() {
+        hull.reverse();
+    }
+
+    let outline: Vec<_> = hull
+        .into_iter()
+        .map(|i| plane_point + u * projected[i].x + v * projected[i].y)
+        .collect();
+    let indices = (0..outline.len())
+        .map(|i| Point2::new(i, (i + 1) % outline.len()))
+        .collect();
+
+    Polyline::new(outline, Some(indices))
+}
@@ -8,13 +8,21 @@ pub use self::proximity_composite_shape_shape::{
 pub use self::proximity_plane_support_map::{
     proximity_plane_support_map, proximity_support_map_plane,
 };
-pub use self::proximity_shape_shape::proximity;
+pub use self::proximity_shape_shape::{
+    proximity, proximity_and_direction, proximity_with_cached_axis,
+};
+pub use self::proximity_slab_support_map::{
+    proximity_slab_support_map, proximity_support_map_slab,
+};
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map;
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map_with_params;
+pub use self::proximity_with_similarity::proximity_with_similarity;
 
 mod proximity;
 mod proximity_ball_ball;
 mod proximity_composite_shape_shape;
 mod proximity_plane_support_map;
 mod proximity_shape_shape;
+mod proximity_slab_support_map;
 mod proximity_support_map_support_map;
+mod proximity_with_similarity;
@@ -1,8 +1,9 @@
-use na::RealField;
+use na::{RealField, Unit};
 
-use crate::math::{Isometry, Point};
-use crate::query::{self, Proximity};
-use crate::shape::{Ball, Plane, Shape};
+use crate::math::{Isometry, Point, Vector};
+use crate::query::algorithms::{CSOPoint, VoronoiSimplex};
+use crate::query::{self, ClosestPoints, Proximity};
+use crate::shape::{Ball, Plane, Shape, Slab};
 
 /// Tests whether two shapes are in intersecting or separated by a distance smaller than `margin`.
 pub fn proximity<N: RealField>(
@@ -21,6 +22,10 @@ pub fn proximity<N: RealField>(
         query::proximity_plane_support_map(m1, p1, m2, s2, margin)
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<N>>()) {
         query::proximity_support_map_plane(m1, s1, m2, p2, margin)
+    } else if let (Some(s1), Some(s2)) = (g1.as_shape::<Slab<N>>(), g2.as_support_map()) {
+        query::proximity_slab_support_map(m1, s1, m2, s2, margin)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_shape::<Slab<N>>()) {
+        query::proximity_support_map_slab(m1, s1, m2, s2, margin)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::proximity_support_map_support_map(m1, s1, m2, s2, margin)
     } else if let Some(c1) = g1.as_composite_shape() {
@@ -31,3 +36,75 @@ pub fn proximity<N: RealField>(
         panic!("No algorithm known to compute proximity between the given pair of shapes.")
     }
 }
+
+/// Tests whether two shapes are intersecting or separated by a distance smaller than `margin`,
+/// and computes the direction from `g1` toward `g2`.
+///
+/// If the shapes are not intersecting, the direction goes from the closest point of `g1` to the
+/// closest point of `g2`, even if they are separated by more than `margin`. If they are
+/// intersecting, this is the normal of their penetration contact. The direction is `None` if
+/// it cannot be computed, e.g., if the shapes are exactly touching.
+pub fn proximity_and_direction<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> (Proximity, Option<Unit<Vector<N>>>) {
+    let proximity = proximity(m1, g1, m2, g2, margin);
+    let dir = match proximity {
+        Proximity::Intersecting => query::contact(m1, g1, m2, g2, N::zero()).map(|c| c.normal),
+        Proximity::WithinMargin | Proximity::Disjoint => {
+            match query::closest_points(m1, g1, m2, g2, N::max_value()) {
+                ClosestPoints::WithinMargin(p1, p2) => Unit::try_new(p2 - p1, N::default_epsilon()),
+                ClosestPoints::Intersecting | ClosestPoints::Disjoint => None,
+            }
+        }
+    };
+
+    (proximity, dir)
+}
+
+/// Tests whether two shapes are intersecting or separated by a distance smaller than `margin`,
+/// testing a cached separating axis first.
+///
+/// If `cached_axis` is provided and the two shapes are still separated by more than `margin`
+/// along it, this returns `Proximity::Disjoint` right away without running the GJK algorithm.
+/// Otherwise, `cached_axis` is used to initialize the GJK algorithm. The returned axis should be
+/// passed as `cached_axis` to the next call for the same pair of shapes. It points from `g1`
+/// toward `g2` and is `None` if the proximity was not computed by the GJK algorithm (e.g. for
+/// balls, planes, or composite shapes).
+pub fn proximity_with_cached_axis<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+    cached_axis: Option<Unit<Vector<N>>>,
+) -> (Proximity, Option<Unit<Vector<N>>>) {
+    let balls = g1.is_shape::<Ball<N>>() && g2.is_shape::<Ball<N>>();
+
+    if let (false, Some(s1), Some(s2)) = (balls, g1.as_support_map(), g2.as_support_map()) {
+        if let Some(axis) = cached_axis {
+            let cso_point = CSOPoint::from_shapes_toward(m1, s1, m2, s2, &axis);
+
+            if -axis.dot(&cso_point.point.coords) > margin {
+                return (Proximity::Disjoint, Some(axis));
+            }
+        }
+
+        let (proximity, axis) = query::proximity_support_map_support_map_with_params(
+            m1,
+            s1,
+            m2,
+            s2,
+            margin,
+            &mut VoronoiSimplex::new(),
+            cached_axis,
+        );
+
+        (proximity, Some(axis))
+    } else {
+        (proximity(m1, g1, m2, g2, margin), None)
+    }
+}
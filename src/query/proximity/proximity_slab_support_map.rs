@@ -0,0 +1,38 @@
+use na::RealField;
+
+use crate::math::Isometry;
+use crate::query::{self, Proximity};
+use crate::shape::{Slab, SupportMap};
+
+/// Proximity between a slab and a support-mapped shape (Cuboid, ConvexHull, etc.)
+///
+/// A shape lying completely inside of the slab is intersecting it.
+pub fn proximity_slab_support_map<N: RealField, G: ?Sized + SupportMap<N>>(
+    mslab: &Isometry<N>,
+    slab: &Slab<N>,
+    mother: &Isometry<N>,
+    other: &G,
+    margin: N,
+) -> Proximity {
+    let [(m1, plane1), (m2, plane2)] = slab.planes();
+    let prox1 = query::proximity_plane_support_map(&(mslab * m1), &plane1, mother, other, margin);
+    let prox2 = query::proximity_plane_support_map(&(mslab * m2), &plane2, mother, other, margin);
+
+    // The slab is the intersection of the half-spaces bounded by its two planes.
+    match (prox1, prox2) {
+        (Proximity::Intersecting, Proximity::Intersecting) => Proximity::Intersecting,
+        (Proximity::Disjoint, _) | (_, Proximity::Disjoint) => Proximity::Disjoint,
+        _ => Proximity::WithinMargin,
+    }
+}
+
+/// Proximity between a support-mapped shape (Cuboid, ConvexHull, etc.) and a slab.
+pub fn proximity_support_map_slab<N: RealField, G: ?Sized + SupportMap<N>>(
+    mother: &Isometry<N>,
+    other: &G,
+    mslab: &Isometry<N>,
+    slab: &Slab<N>,
+    margin: N,
+) -> Proximity {
+    proximity_slab_support_map(mslab, slab, mother, other, margin)
+}
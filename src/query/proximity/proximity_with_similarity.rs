@@ -0,0 +1,39 @@
+use na::RealField;
+
+use crate::math::{Point, Similarity};
+use crate::query::{self, algorithms::special_support_maps::UniformlyScaledShape, Proximity};
+use crate::shape::{Ball, Shape};
+
+/// Tests whether two shapes transformed by similarities are intersecting or separated by a
+/// distance smaller than `margin`.
+///
+/// Only balls and support-mapped shapes are supported.
+pub fn proximity_with_similarity<N: RealField>(
+    m1: &Similarity<N>,
+    g1: &dyn Shape<N>,
+    m2: &Similarity<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> Proximity {
+    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
+        let p1 = Point::from(m1.isometry.translation.vector);
+        let p2 = Point::from(m2.isometry.translation.vector);
+        let b1 = Ball::new(b1.radius * m1.scaling());
+        let b2 = Ball::new(b2.radius * m2.scaling());
+
+        query::proximity_ball_ball(&p1, &b1, &p2, &b2, margin)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        let s1 = UniformlyScaledShape {
+            shape: s1,
+            scale: m1.scaling(),
+        };
+        let s2 = UniformlyScaledShape {
+            shape: s2,
+            scale: m2.scaling(),
+        };
+
+        query::proximity_support_map_support_map(&m1.isometry, &s1, &m2.isometry, &s2, margin)
+    } else {
+        panic!("No algorithm known to compute proximity between the given pair of shapes.")
+    }
+}
@@ -3,22 +3,30 @@
 #[doc(inline)]
 pub use self::ray::{Ray, RayCast, RayIntersection};
 pub use self::ray_ball::ray_toi_with_ball;
+pub use self::ray_closest_points::{closest_points_ray_shape, ray_closest_points};
 pub use self::ray_plane::{line_toi_with_plane, ray_toi_with_plane};
 pub use self::ray_support_map::ray_intersection_with_support_map_with_params;
 #[cfg(feature = "dim3")]
 pub use self::ray_triangle::ray_intersection_with_triangle;
+#[cfg(feature = "dim3")]
+pub use self::ray_trimesh::TriMeshRayIntersection;
 
 #[doc(hidden)]
 pub mod ray;
 mod ray_aabb;
+#[cfg(feature = "dim2")]
+mod ray_arc;
 mod ray_ball;
 mod ray_bounding_sphere;
+mod ray_closest_points;
 mod ray_compound;
 mod ray_cuboid;
 mod ray_heightfield;
 mod ray_plane;
 mod ray_polyline;
+mod ray_scaled_shape;
 mod ray_shape;
+mod ray_slab;
 mod ray_support_map;
 #[cfg(feature = "dim3")]
 mod ray_triangle;
@@ -1,6 +1,6 @@
 //! Traits and structure needed to cast rays.
 
-use crate::math::{Isometry, Point, Vector};
+use crate::math::{Isometry, Point, Similarity, Vector};
 use crate::shape::FeatureId;
 #[cfg(feature = "dim3")]
 use na::Point2;
@@ -157,4 +157,27 @@ pub trait RayCast<N: RealField> {
     fn intersects_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N) -> bool {
         self.toi_with_ray(m, ray, max_toi, true).is_some()
     }
+
+    /// Computes the time of impact, and normal between this shape transformed by the similarity
+    /// `m` and a ray.
+    #[inline]
+    fn toi_and_normal_with_ray_and_similarity(
+        &self,
+        m: &Similarity<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        // NOTE: the time of impact is invariant by this change of coordinates.
+        let local_ray = Ray::new(
+            m.inverse_transform_point(&ray.origin),
+            m.inverse_transform_vector(&ray.dir),
+        );
+
+        self.toi_and_normal_with_ray(&Isometry::identity(), &local_ray, max_toi, solid)
+            .map(|mut inter| {
+                inter.normal = m.isometry * inter.normal;
+                inter
+            })
+    }
 }
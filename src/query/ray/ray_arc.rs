@@ -0,0 +1,92 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Arc, FeatureId};
+use na::{self, RealField, Unit};
+
+impl<N: RealField> RayCast<N> for Arc<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let half_thickness = self.half_thickness();
+        let origin_proj = self.project_on_curve(&ls_ray.origin);
+        let origin_dpt = ls_ray.origin - origin_proj;
+        let inside = origin_dpt.norm() <= half_thickness;
+
+        if inside && solid {
+            let normal = Unit::try_new(origin_dpt, N::default_epsilon())
+                .map(|n| -n.into_inner())
+                .unwrap_or_else(na::zero);
+            return Some(RayIntersection::new(
+                N::zero(),
+                m * normal,
+                FeatureId::Face(0),
+            ));
+        }
+
+        // The boundary of the arc is made of parts of the circles bounding its outer and inner
+        // sides, and of the two circles rounding its ends. A point on one of those circles lies
+        // on the boundary iff it is at a distance `half_thickness` from the curve.
+        let circles = [
+            (self.center, self.radius + half_thickness),
+            (self.start_point(), half_thickness),
+            (self.end_point(), half_thickness),
+            (self.center, self.radius - half_thickness),
+        ];
+        let ncircles = if self.radius > half_thickness { 4 } else { 3 };
+        let tolerance = half_thickness * N::default_epsilon().sqrt();
+        let mut best: Option<(N, Unit<Vector<N>>)> = None;
+
+        for (center, radius) in &circles[..ncircles] {
+            for toi in ray_circle_tois(&ls_ray, center, *radius).iter().flatten() {
+                let toi = *toi;
+                let closer = match best {
+                    Some((best_toi, _)) => toi < best_toi,
+                    None => true,
+                };
+
+                if toi < N::zero() || toi > max_toi || !closer {
+                    continue;
+                }
+
+                let pt = ls_ray.point_at(toi);
+                let dpt = pt - self.project_on_curve(&pt);
+
+                if let Some((dir, dist)) = Unit::try_new_and_get(dpt, N::default_epsilon()) {
+                    if (dist - half_thickness).abs() <= tolerance && (!inside || !toi.is_zero()) {
+                        best = Some((toi, dir));
+                    }
+                }
+            }
+        }
+
+        best.map(|(toi, dir)| {
+            let normal = if inside {
+                -dir.into_inner()
+            } else {
+                dir.into_inner()
+            };
+            RayIntersection::new(toi, m * normal, FeatureId::Face(0))
+        })
+    }
+}
+
+/// The parameters of the intersections between the ray line and a circle, if any.
+fn ray_circle_tois<N: RealField>(ray: &Ray<N>, center: &Point<N>, radius: N) -> [Option<N>; 2] {
+    let dcenter = ray.origin - *center;
+    let a = ray.dir.norm_squared();
+    let b = dcenter.dot(&ray.dir);
+    let c = dcenter.norm_squared() - radius * radius;
+    let delta = b * b - a * c;
+
+    if a.is_zero() || delta < N::zero() {
+        [None, None]
+    } else {
+        let sqrt_delta = delta.sqrt();
+        [Some((-b - sqrt_delta) / a), Some((-b + sqrt_delta) / a)]
+    }
+}
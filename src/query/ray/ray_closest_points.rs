@@ -0,0 +1,84 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Point};
+use crate::query::{self, ClosestPoints, Ray, RayCast};
+use crate::shape::{Ball, Segment, Shape};
+
+/// Computes the closest points between a ray limited to `max_toi` and a shape.
+///
+/// Returns the closest point on the ray, the closest point on the shape, and the distance
+/// between them. The ray is considered as the segment joining `ray.origin` and
+/// `ray.point_at(max_toi)`. If the ray hits the shape, both points are equal to the first hit
+/// point along the ray and the distance is zero.
+pub fn ray_closest_points<N: RealField>(
+    ray: &Ray<N>,
+    max_toi: N,
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+) -> (Point<N>, Point<N>, N) {
+    if let Some(ball) = shape.as_shape::<Ball<N>>() {
+        let center = Point::from(m.translation.vector);
+        let dir_norm2 = ray.dir.norm_squared();
+        let t = if dir_norm2.is_zero() {
+            N::zero()
+        } else {
+            na::clamp(
+                (center - ray.origin).dot(&ray.dir) / dir_norm2,
+                N::zero(),
+                max_toi,
+            )
+        };
+        let pt = ray.point_at(t);
+
+        if let Some((dir, dist)) = Unit::try_new_and_get(pt - center, N::zero()) {
+            if dist > ball.radius {
+                return (pt, center + *dir * ball.radius, dist - ball.radius);
+            }
+        }
+
+        let toi = query::ray_toi_with_ball(&center, ball.radius, ray, true)
+            .1
+            .unwrap_or(N::zero());
+        let hit = ray.point_at(toi);
+        return (hit, hit, N::zero());
+    }
+
+    let segment = Segment::new(ray.origin, ray.point_at(max_toi));
+
+    match query::closest_points(&Isometry::identity(), &segment, m, shape, N::max_value()) {
+        ClosestPoints::WithinMargin(pt1, pt2) => (pt1, pt2, na::distance(&pt1, &pt2)),
+        _ => {
+            let toi = shape
+                .toi_with_ray(m, ray, max_toi, true)
+                .unwrap_or(N::zero());
+            let hit = ray.point_at(toi);
+            (hit, hit, N::zero())
+        }
+    }
+}
+
+/// Computes the closest points between an unbounded ray and a shape.
+///
+/// Returns the closest point on the ray, the closest point on the shape, and the squared
+/// distance between them. The ray is a half-line so, if it points away from the shape, the
+/// closest point on the ray is its origin. If the ray origin is inside of the shape, both points
+/// are equal to the ray origin and the distance is zero.
+pub fn closest_points_ray_shape<N: RealField>(
+    ray: &Ray<N>,
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+) -> (Point<N>, Point<N>, N) {
+    // No point of the ray beyond the farthest projection of the bounding sphere on the ray
+    // can be the closest, so the ray can be cut there.
+    let bs = shape.bounding_sphere(m);
+    let dir_norm2 = ray.dir.norm_squared();
+    let max_toi = if dir_norm2.is_zero() {
+        N::zero()
+    } else {
+        let far = (*bs.center() - ray.origin).dot(&ray.dir) + bs.radius() * dir_norm2.sqrt();
+        (far / dir_norm2).max(N::zero())
+    };
+
+    let (pt1, pt2, dist) = ray_closest_points(ray, max_toi, m, shape);
+    (pt1, pt2, dist * dist)
+}
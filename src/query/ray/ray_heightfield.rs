@@ -121,6 +121,11 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
 
 #[cfg(feature = "dim3")]
 impl<N: RealField> RayCast<N> for HeightField<N> {
+    /// Casts a ray on this heightfield by marching through the cells crossed by the ray.
+    ///
+    /// The ray is first clipped to the heightfield AABB. Then, only the cells with a footprint
+    /// crossed by the ray are tested, from the nearest to the farthest, stopping at the first
+    /// cell containing a hit.
     #[inline]
     fn toi_and_normal_with_ray(
         &self,
@@ -132,60 +137,46 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
         let aabb = self.aabb();
         let ls_ray = ray.inverse_transform_by(m);
         let (min_t, mut max_t) = aabb.clip_ray_parameters(&ls_ray)?;
-        max_t = max_t.min(max_toi);
-        let clip_ray_a = ls_ray.point_at(min_t);
-        let mut cell = match self.cell_at_point(&clip_ray_a) {
-            Some(cell) => cell,
-            // None may happen due to slight numerical errors.
-            None => {
-                let i = if ls_ray.origin.z > N::zero() {
-                    self.nrows() - 1
-                } else {
-                    0
-                };
 
-                let j = if ls_ray.origin.x > N::zero() {
-                    self.ncols() - 1
-                } else {
-                    0
-                };
+        if min_t > max_toi {
+            return None;
+        }
 
-                (i, j)
-            }
-        };
+        max_t = max_t.min(max_toi);
+
+        // Slight numerical errors may put the clipped point outside of the heightfield.
+        let mut clip_ray_a = ls_ray.point_at(min_t);
+        clip_ray_a.x = na::clamp(clip_ray_a.x, aabb.mins.x, aabb.maxs.x);
+        clip_ray_a.z = na::clamp(clip_ray_a.z, aabb.mins.z, aabb.maxs.z);
+        let mut cell = self.cell_at_point(&clip_ray_a)?;
 
         loop {
             let tris = self.triangles_at(cell.0, cell.1);
-            let inter1 = tris
-                .0
-                .and_then(|tri| tri.toi_and_normal_with_ray(m, ray, max_toi, solid));
-            let inter2 = tris
-                .1
-                .and_then(|tri| tri.toi_and_normal_with_ray(m, ray, max_toi, solid));
-
-            match (inter1, inter2) {
-                (Some(mut inter1), Some(mut inter2)) => {
+            let inter1 = tris.0.and_then(|tri| {
+                tri.toi_and_normal_with_ray(&Isometry::identity(), &ls_ray, max_toi, solid)
+            });
+            let inter2 = tris.1.and_then(|tri| {
+                tri.toi_and_normal_with_ray(&Isometry::identity(), &ls_ray, max_toi, solid)
+            });
+
+            let hit = match (inter1, inter2) {
+                (Some(inter1), Some(inter2)) => {
                     if inter1.toi < inter2.toi {
-                        inter1.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, true, inter1.feature);
-                        return Some(inter1);
+                        Some((inter1, true))
                     } else {
-                        inter2.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, false, inter2.feature);
-                        return Some(inter2);
+                        Some((inter2, false))
                     }
                 }
-                (Some(mut inter), None) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, true, inter.feature);
-                    return Some(inter);
-                }
-                (None, Some(mut inter)) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, false, inter.feature);
-                    return Some(inter);
-                }
-                (None, None) => {}
+                (Some(inter), None) => Some((inter, true)),
+                (None, Some(inter)) => Some((inter, false)),
+                (None, None) => None,
+            };
+
+            if let Some((mut inter, left)) = hit {
+                inter.normal = m * inter.normal;
+                inter.feature =
+                    self.convert_triangle_feature_id(cell.0, cell.1, left, inter.feature);
+                return Some(inter);
             }
 
             /*
@@ -198,6 +189,7 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
                 let x = self.x_at(cell.1 + 0);
                 ((x - ls_ray.origin.x) / ls_ray.dir.x, false)
             } else {
+                // The ray is parallel to the z axis and never crosses a column boundary.
                 (N::max_value(), false)
             };
 
@@ -208,14 +200,16 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
                 let z = self.z_at(cell.0 + 0);
                 ((z - ls_ray.origin.z) / ls_ray.dir.z, false)
             } else {
+                // The ray is parallel to the x axis and never crosses a row boundary.
                 (N::max_value(), false)
             };
 
             if toi_x > max_t && toi_z > max_t {
+                // The remaining part of the ray is outside of the heightfield AABB.
                 break;
             }
 
-            if toi_x >= N::zero() && toi_x < toi_z {
+            if toi_x < toi_z {
                 if right {
                     cell.1 += 1
                 } else if cell.1 > 0 {
@@ -223,14 +217,10 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
                 } else {
                     break;
                 }
-            } else if toi_z >= N::zero() {
-                if down {
-                    cell.0 += 1
-                } else if cell.0 > 0 {
-                    cell.0 -= 1
-                } else {
-                    break;
-                }
+            } else if down {
+                cell.0 += 1
+            } else if cell.0 > 0 {
+                cell.0 -= 1
             } else {
                 break;
             }
@@ -0,0 +1,34 @@
+use na::RealField;
+
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::ScaledShape;
+
+impl<N: RealField> RayCast<N> for ScaledShape<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        // The scale is linear so it does not change the time of impact of the ray.
+        let unscaled_ray = Ray::new(
+            self.unscale_point(&ls_ray.origin),
+            ls_ray.dir.component_div(self.scale()),
+        );
+
+        let mut res = self.shape().as_ray_cast()?.toi_and_normal_with_ray(
+            &Isometry::identity(),
+            &unscaled_ray,
+            max_toi,
+            solid,
+        )?;
+
+        // Normals are transformed by the inverse transpose of the scale.
+        let normal = res.normal.component_div(self.scale());
+        res.normal = m * normal.try_normalize(N::zero()).unwrap_or(normal);
+        Some(res)
+    }
+}
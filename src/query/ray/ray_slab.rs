@@ -0,0 +1,58 @@
+use na::{self, RealField};
+
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Slab};
+
+impl<N: RealField> RayCast<N> for Slab<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let h = self.half_thickness();
+        let d = self.normal.dot(&ls_ray.origin.coords) - self.offset;
+        let inside = d.abs() <= h;
+
+        if solid && inside {
+            return Some(RayIntersection::new(
+                na::zero(),
+                na::zero(),
+                FeatureId::Face(0),
+            ));
+        }
+
+        let dn = self.normal.dot(&ls_ray.dir);
+
+        if relative_eq!(dn, N::zero()) {
+            // The ray is parallel to the slab.
+            return None;
+        }
+
+        // The exit point of a ray starting inside of the slab is on the plane it moves toward.
+        // The entry point of a ray starting outside is on the plane closest to its origin.
+        let top = if inside {
+            dn > N::zero()
+        } else {
+            d > N::zero()
+        };
+        let toi = if top { (h - d) / dn } else { (-h - d) / dn };
+
+        if toi >= N::zero() && toi <= max_toi {
+            // The normal points toward the side of the plane the ray comes from.
+            let (normal, feature) = if top {
+                (self.normal, FeatureId::Face(0))
+            } else {
+                (-self.normal, FeatureId::Face(1))
+            };
+            let normal = if inside { -normal } else { normal };
+
+            Some(RayIntersection::new(toi, m * *normal, feature))
+        } else {
+            None
+        }
+    }
+}
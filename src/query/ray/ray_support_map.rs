@@ -7,9 +7,9 @@ use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
 use crate::query::{Ray, RayCast, RayIntersection};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
+use crate::shape::{Capsule, FeatureId, MinkowskiSum, MorphShape, RoundShape, Segment, SupportMap};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexHull, Cylinder};
+use crate::shape::{Cone, ConvexHull, Cylinder, Wedge};
 
 /// Cast a ray on a shape using the GJK algorithm.
 pub fn ray_intersection_with_support_map_with_params<N, G: ?Sized>(
@@ -136,6 +136,81 @@ impl<N: RealField> RayCast<N> for Capsule<N> {
     }
 }
 
+impl<N: RealField> RayCast<N> for MinkowskiSum<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
+impl<N: RealField> RayCast<N> for MorphShape<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
+impl<N: RealField> RayCast<N> for RoundShape<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> RayCast<N> for ConvexHull<N> {
     fn toi_and_normal_with_ray(
@@ -162,6 +237,32 @@ impl<N: RealField> RayCast<N> for ConvexHull<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField> RayCast<N> for Wedge<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
 #[cfg(feature = "dim2")]
 impl<N: RealField> RayCast<N> for ConvexPolygon<N> {
     fn toi_and_normal_with_ray(
@@ -1,10 +1,99 @@
 use crate::bounding_volume::AABB;
-use crate::math::Isometry;
-use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
+use crate::math::{Isometry, Vector};
+use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
+use crate::query::visitors::BoundingVolumeInterferencesCollector;
 use crate::query::{self, Ray, RayCast, RayIntersection};
 use crate::shape::{CompositeShape, FeatureId, TriMesh};
 use na::{Point2, RealField, Vector3};
 
+/// The intersection of a ray with a `TriMesh`, along with the location of the hit point on the
+/// triangle hit.
+#[derive(Copy, Clone, Debug)]
+pub struct TriMeshRayIntersection<N: RealField> {
+    /// The intersection of the ray with the mesh.
+    pub intersection: RayIntersection<N>,
+    /// The index of the triangle hit, on `TriMesh::faces()`.
+    pub face: usize,
+    /// The barycentric coordinates of the hit point on the triangle hit.
+    ///
+    /// They are the weights of the vertices `TriMesh::faces()[face].indices` such that their
+    /// weighted sum is the hit point.
+    pub barycentric_coordinates: Vector3<N>,
+}
+
+impl<N: RealField> TriMesh<N> {
+    /// Computes the intersection of this transformed mesh with a ray, along with the index of the
+    /// triangle hit and the barycentric coordinates of the hit point on this triangle.
+    ///
+    /// If the ray hits several triangles at the same time of impact, e.g., at an edge or a vertex
+    /// shared by several triangles, the one with the smallest index is returned.
+    pub fn toi_and_normal_and_barycentric_coordinates_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+    ) -> Option<TriMeshRayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut visitor = TriMeshRayToiAndNormalAndUVsVisitor {
+            mesh: self,
+            ray: &ls_ray,
+            max_toi,
+        };
+
+        let (_, (mut best, mut inter, mut bcoords)) = self.bvh().best_first_search(&mut visitor)?;
+        let eps = N::default_epsilon().sqrt();
+
+        if bcoords.min() <= eps {
+            // The hit point is at the boundary of the triangle, so other triangles containing it
+            // may be hit at the same time of impact.
+            let pt = ls_ray.point_at(inter.toi);
+            let margin = Vector::repeat(eps * (N::one() + pt.coords.amax()));
+            let aabb = AABB::new(pt - margin, pt + margin);
+            let mut candidates = Vec::new();
+            self.bvt()
+                .visit(&mut BoundingVolumeInterferencesCollector::new(
+                    &aabb,
+                    &mut candidates,
+                ));
+
+            for i in candidates {
+                if i < best {
+                    let vs = self.points();
+                    let idx = self.faces()[i].indices;
+
+                    if let Some(other) = query::ray_intersection_with_triangle(
+                        &vs[idx[0]],
+                        &vs[idx[1]],
+                        &vs[idx[2]],
+                        &ls_ray,
+                    ) {
+                        if (other.0.toi - inter.toi).abs() <= eps * (N::one() + inter.toi) {
+                            best = i;
+                            inter = other.0;
+                            bcoords = other.1;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let FeatureId::Face(1) = inter.feature {
+            inter.feature = FeatureId::Face(best + self.faces().len());
+        } else {
+            inter.feature = FeatureId::Face(best);
+        }
+
+        inter.normal = m * inter.normal;
+
+        Some(TriMeshRayIntersection {
+            intersection: inter,
+            face: best,
+            barycentric_coordinates: bcoords,
+        })
+    }
+}
+
 impl<N: RealField> RayCast<N> for TriMesh<N> {
     #[inline]
     fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, _: bool) -> Option<N> {
@@ -60,37 +149,23 @@ impl<N: RealField> RayCast<N> for TriMesh<N> {
             return self.toi_and_normal_with_ray(m, ray, max_toi, solid);
         }
 
-        let ls_ray = ray.inverse_transform_by(m);
+        self.toi_and_normal_and_barycentric_coordinates_with_ray(m, ray, max_toi)
+            .map(|hit| {
+                let idx = &self.faces()[hit.face].indices;
+                let uvs = self.uvs().unwrap();
+                let bcoords = hit.barycentric_coordinates;
 
-        let mut visitor = TriMeshRayToiAndNormalAndUVsVisitor {
-            mesh: self,
-            ray: &ls_ray,
-            max_toi,
-        };
-        let cast = self.bvh().best_first_search(&mut visitor);
-
-        cast.map(|(_, (best, inter, uv))| {
-            let toi = inter.toi;
-            let n = inter.normal;
-
-            let idx = &self.faces()[best].indices;
-            let uvs = self.uvs().unwrap();
+                let uv1 = uvs[idx[0]];
+                let uv2 = uvs[idx[1]];
+                let uv3 = uvs[idx[2]];
 
-            let uv1 = uvs[idx[0]];
-            let uv2 = uvs[idx[1]];
-            let uv3 = uvs[idx[2]];
+                let uvx = uv1.x * bcoords.x + uv2.x * bcoords.y + uv3.x * bcoords.z;
+                let uvy = uv1.y * bcoords.x + uv2.y * bcoords.y + uv3.y * bcoords.z;
 
-            let uvx = uv1.x * uv.x + uv2.x * uv.y + uv3.x * uv.z;
-            let uvy = uv1.y * uv.x + uv2.y * uv.y + uv3.y * uv.z;
-
-            let feature = if let FeatureId::Face(1) = inter.feature {
-                FeatureId::Face(best + self.faces().len())
-            } else {
-                FeatureId::Face(best)
-            };
-
-            RayIntersection::new_with_uvs(toi, m * n, feature, Some(Point2::new(uvx, uvy)))
-        })
+                let mut inter = hit.intersection;
+                inter.uvs = Some(Point2::new(uvx, uvy));
+                inter
+            })
     }
 }
 
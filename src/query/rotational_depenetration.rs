@@ -0,0 +1,90 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Rotation, Vector};
+use crate::query;
+use crate::shape::Shape;
+
+/// Maximum number of steps of the conservative advancement run in each direction by
+/// `rotational_depenetration`, each step rotating the shape by at least `π` divided by this.
+const MAX_DEPENETRATION_ITERATIONS: usize = 1000;
+
+/// Computes the smallest rotation of the first shape about `axis` that separates it from the
+/// second shape.
+///
+/// The rotation axis passes through the origin of the local frame of the first shape, i.e., the
+/// translational part of `m1`, and is oriented by `axis` given in world-space. The result is a
+/// signed angle so the shapes are separated when the first shape is transformed by
+/// `Isometry::from_parts(m1.translation, Rotation::from_axis_angle(axis, angle) * m1.rotation)`.
+/// Returns zero if the shapes are not penetrating, and `None` if no rotation about `axis`
+/// separates them, e.g., if the first shape is a ball centered on the axis.
+///
+/// The angle is found by conservative advancement in both directions: since no point of the
+/// first shape moves faster than the radius of its bounding sphere about the axis, it is
+/// rotated by the penetration depth divided by this radius until the shapes separate. Each step
+/// is at least `π / 1000` so slowly decreasing penetrations don't stall the advancement, and the
+/// separating angle is then refined by bisection within the last step.
+pub fn rotational_depenetration<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    axis: &Unit<Vector<N>>,
+) -> Option<N> {
+    let depth_at = |angle: N| {
+        let m1 = Isometry::from_parts(
+            m1.translation,
+            Rotation::from_axis_angle(axis, angle) * m1.rotation,
+        );
+        query::contact(&m1, g1, m2, g2, N::zero()).map(|c| c.depth)
+    };
+
+    let depth = match depth_at(N::zero()) {
+        Some(depth) => depth,
+        None => return Some(N::zero()),
+    };
+
+    let sphere = g1.local_bounding_sphere();
+    let radius = sphere.center().coords.norm() + sphere.radius();
+    let tolerance = N::default_epsilon().sqrt();
+
+    let min_step = N::pi() / na::convert(MAX_DEPENETRATION_ITERATIONS as f64);
+
+    let advance = |sign: N| {
+        let mut angle = N::zero();
+        let mut depth = depth;
+
+        while angle < N::pi() {
+            let prev_angle = angle;
+            angle = (angle + (depth.max(N::zero()) / radius).max(min_step)).min(N::pi());
+
+            match depth_at(angle * sign) {
+                Some(new_depth) => depth = new_depth,
+                None => {
+                    // The shapes separate somewhere between `prev_angle` and `angle`.
+                    let (mut lo, mut hi) = (prev_angle, angle);
+
+                    while hi - lo > tolerance {
+                        let mid = (lo + hi) * na::convert(0.5);
+
+                        if depth_at(mid * sign).is_some() {
+                            lo = mid;
+                        } else {
+                            hi = mid;
+                        }
+                    }
+
+                    return Some(hi);
+                }
+            }
+        }
+
+        None
+    };
+
+    match (advance(N::one()), advance(-N::one())) {
+        (Some(pos), Some(neg)) => Some(if neg < pos { -neg } else { pos }),
+        (Some(pos), None) => Some(pos),
+        (None, Some(neg)) => Some(-neg),
+        (None, None) => None,
+    }
+}
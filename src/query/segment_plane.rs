@@ -0,0 +1,71 @@
+use na::RealField;
+
+use crate::math::{Point, Vector};
+use crate::shape::Segment;
+
+/// The position of a segment relative to a plane, as computed by `segment_plane`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SegmentPlaneResult<N: RealField> {
+    /// Both endpoints of the segment lie strictly on the side the plane normal points to.
+    InFront,
+    /// Both endpoints of the segment lie strictly on the side opposite to the plane normal.
+    Behind,
+    /// The whole segment lies on the plane.
+    OnPlane,
+    /// The segment crosses the plane, or touches it with one of its endpoints.
+    Crossing {
+        /// The parameter of the crossing point, i.e., `point = seg.a + (seg.b - seg.a) * t`,
+        /// with `t` in `[0, 1]`.
+        t: N,
+        /// The point where the segment crosses the plane.
+        point: Point<N>,
+        /// The portion of the segment lying on or behind the plane.
+        ///
+        /// This is a degenerate segment if the segment only touches the plane with one of its
+        /// endpoints from the front.
+        behind: Segment<N>,
+    },
+}
+
+/// Classifies a segment with regard to a plane described by one of its points and its normal.
+///
+/// The normal does not need to be normalized. The classification only depends on the signs of
+/// the signed distances of the segment endpoints to the plane, so it is exact. Endpoints lying
+/// exactly on the plane are considered as crossing it, unless the whole segment lies on the plane.
+pub fn segment_plane<N: RealField>(
+    seg: &Segment<N>,
+    plane_point: &Point<N>,
+    plane_normal: &Vector<N>,
+) -> SegmentPlaneResult<N> {
+    let da = plane_normal.dot(&(seg.a - *plane_point));
+    let db = plane_normal.dot(&(seg.b - *plane_point));
+
+    if da.is_zero() && db.is_zero() {
+        SegmentPlaneResult::OnPlane
+    } else if da > N::zero() && db > N::zero() {
+        SegmentPlaneResult::InFront
+    } else if da < N::zero() && db < N::zero() {
+        SegmentPlaneResult::Behind
+    } else {
+        // The endpoints are on opposite sides of the plane, or only one of them is on the plane.
+        // Thus `da - db` cannot be zero.
+        let t = da / (da - db);
+        let point = if t.is_zero() {
+            seg.a
+        } else if t == N::one() {
+            seg.b
+        } else {
+            seg.a + (seg.b - seg.a) * t
+        };
+
+        let behind = if da < N::zero() {
+            Segment::new(seg.a, point)
+        } else if db < N::zero() {
+            Segment::new(point, seg.b)
+        } else {
+            Segment::new(point, point)
+        };
+
+        SegmentPlaneResult::Crossing { t, point, behind }
+    }
+}
@@ -0,0 +1,114 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::query::Ray;
+use crate::shape::{Ball, Shape};
+
+/// Number of surface points used to approximate the geodesic between two points of a shape
+/// other than a ball.
+const NUM_SURFACE_DISTANCE_SAMPLES: usize = 64;
+
+/// Computes the approximate geodesic distance between two points on the surface of a convex shape.
+///
+/// Both `a` and `b` are expected to lie on the surface of `shape` transformed by `m`. For a ball,
+/// this is the exact great-circle distance. For other shapes, this is the length of the curve
+/// obtained by intersecting the surface with the plane containing `a`, `b`, and a point inside of
+/// the shape. This curve is sampled at regularly spaced angles and approximated by a polyline.
+/// The result is exact if `a` and `b` lie on the same planar face of a polyhedron. Otherwise it
+/// may overestimate the length of the actual shortest path over the faces of the polyhedron. If
+/// `a` and `b` are opposite with respect to the inner point, the plane is chosen arbitrarily.
+pub fn surface_distance<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    a: &Point<N>,
+    b: &Point<N>,
+) -> N {
+    let la = m.inverse_transform_point(a);
+    let lb = m.inverse_transform_point(b);
+
+    if let Some(ball) = shape.as_shape::<Ball<N>>() {
+        return match arc_between(&Point::origin(), &la, &lb) {
+            Some((_, _, angle)) => ball.radius * angle,
+            None => na::distance(&la, &lb),
+        };
+    }
+
+    let center = inner_point(shape);
+    let ray_cast = shape.as_ray_cast();
+
+    if let (Some((dir_a, ortho, angle)), Some(ray_cast)) =
+        (arc_between(&center, &la, &lb), ray_cast)
+    {
+        let mut length = N::zero();
+        let mut prev = la;
+
+        for i in 1..NUM_SURFACE_DISTANCE_SAMPLES {
+            let t = angle * na::convert(i as f64 / NUM_SURFACE_DISTANCE_SAMPLES as f64);
+            let dir = *dir_a * t.cos() + *ortho * t.sin();
+            let ray = Ray::new(center, dir);
+
+            // Cast from the inside toward the surface.
+            if let Some(toi) =
+                ray_cast.toi_with_ray(&Isometry::identity(), &ray, N::max_value(), false)
+            {
+                let pt = ray.point_at(toi);
+                length += na::distance(&prev, &pt);
+                prev = pt;
+            }
+        }
+
+        length + na::distance(&prev, &lb)
+    } else {
+        na::distance(&la, &lb)
+    }
+}
+
+/// A direction, a unit vector orthogonal to it, and an angle, describing an arc of directions.
+type DirectionArc<N> = (Unit<Vector<N>>, Unit<Vector<N>>, N);
+
+/// The direction from `center` toward `a`, a unit vector orthogonal to it on the plane
+/// containing `center`, `a`, `b`, and the angle between the directions toward `a` and `b`.
+fn arc_between<N: RealField>(
+    center: &Point<N>,
+    a: &Point<N>,
+    b: &Point<N>,
+) -> Option<DirectionArc<N>> {
+    let dir_a = Unit::try_new(*a - *center, N::default_epsilon())?;
+    let dir_b = Unit::try_new(*b - *center, N::default_epsilon())?;
+    let cos = dir_a.dot(&dir_b);
+
+    let ortho = match Unit::try_new(*dir_b - *dir_a * cos, N::default_epsilon()) {
+        Some(ortho) => ortho,
+        None if cos > N::zero() => return None,
+        None => {
+            let mut ortho = Vector::zeros();
+            Vector::orthonormal_subspace_basis(&[dir_a.into_inner()], |v| {
+                ortho = *v;
+                false
+            });
+            Unit::new_unchecked(ortho)
+        }
+    };
+
+    let angle = ortho.dot(&dir_b).atan2(cos);
+    Some((dir_a, ortho, angle))
+}
+
+/// A point inside of a convex shape, roughly at its center.
+fn inner_point<N: RealField>(shape: &dyn Shape<N>) -> Point<N> {
+    match shape.as_support_map() {
+        Some(s) => {
+            // The average of points of the shape lies inside of its convex hull.
+            let mut sum = Vector::zeros();
+
+            for i in 0..DIM {
+                let mut dir = Vector::zeros();
+                dir[i] = N::one();
+                sum += s.local_support_point(&dir).coords + s.local_support_point(&-dir).coords;
+            }
+
+            Point::from(sum / na::convert::<_, N>((2 * DIM) as f64))
+        }
+        None => shape.local_aabb().center(),
+    }
+}
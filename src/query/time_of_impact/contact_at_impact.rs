@@ -0,0 +1,67 @@
+use na::RealField;
+
+use crate::interpolation::{InterpolatedRigidMotion, RigidMotion};
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::ContactDispatcher;
+use crate::query::{ContactData, ContactManifold, ContactPrediction, TOIDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Computes the contact manifold between two shapes at their first time of impact.
+///
+/// Both shapes move from their `start` to their `end` pose, interpolated as by
+/// `InterpolatedRigidMotion`. The time of impact is searched in `[0, 1]`, and the complete
+/// contact manifold, with all the contacts closer than `prediction`, is then computed at the
+/// interpolated poses at this time. For example, a box landing flat on the ground yields
+/// one contact per corner of its face.
+///
+/// Returns the time of impact and the contact manifold, or `None` if the shapes do not touch
+/// during the motion. Fails if the time of impact is not supported by `toi_dispatcher`, or if the
+/// contact manifold is not supported by `contact_dispatcher`.
+pub fn contact_at_impact<N: RealField, C: ContactData>(
+    toi_dispatcher: &dyn TOIDispatcher<N>,
+    contact_dispatcher: &dyn ContactDispatcher<N, C>,
+    m1_start: &Isometry<N>,
+    m1_end: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2_start: &Isometry<N>,
+    m2_end: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: &ContactPrediction<N>,
+) -> Result<Option<(N, ContactManifold<N, C>)>, Unsupported> {
+    let motion1 = InterpolatedRigidMotion::new(*m1_start, *m1_end);
+    let motion2 = InterpolatedRigidMotion::new(*m2_start, *m2_end);
+
+    let toi = match toi_dispatcher.nonlinear_time_of_impact(
+        toi_dispatcher,
+        &motion1,
+        g1,
+        &motion2,
+        g2,
+        N::one(),
+        N::zero(),
+    )? {
+        Some(toi) => toi.toi,
+        None => return Ok(None),
+    };
+
+    let mut generator = contact_dispatcher
+        .get_contact_algorithm(g1, g2)
+        .ok_or(Unsupported)?;
+    let mut manifold = generator.init_manifold();
+    let m1 = motion1.position_at_time(toi);
+    let m2 = motion2.position_at_time(toi);
+
+    let _ = generator.generate_contacts(
+        contact_dispatcher,
+        &m1,
+        g1,
+        None,
+        &m2,
+        g2,
+        None,
+        prediction,
+        &mut manifold,
+    );
+
+    Ok(Some((toi, manifold)))
+}
@@ -1,7 +1,10 @@
 //! Implementation details of the `time_of_impact` function.
 
+pub use self::contact_at_impact::contact_at_impact;
 pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
 pub use self::time_of_impact_ball_ball::time_of_impact_ball_ball;
+pub use self::time_of_impact_capsule_capsule::time_of_impact_capsule_capsule;
+pub use self::time_of_impact_composite_shape_composite_shape::time_of_impact_composite_shape_composite_shape;
 pub use self::time_of_impact_composite_shape_shape::{
     time_of_impact_composite_shape_shape, time_of_impact_shape_composite_shape,
 };
@@ -9,9 +12,14 @@ pub use self::time_of_impact_plane_support_map::{
     time_of_impact_plane_support_map, time_of_impact_support_map_plane,
 };
 pub use self::time_of_impact_support_map_support_map::time_of_impact_support_map_support_map;
+pub use self::time_to_enter_aabb::time_to_enter_aabb;
 
+mod contact_at_impact;
 mod time_of_impact;
 mod time_of_impact_ball_ball;
+mod time_of_impact_capsule_capsule;
+mod time_of_impact_composite_shape_composite_shape;
 mod time_of_impact_composite_shape_shape;
 mod time_of_impact_plane_support_map;
 mod time_of_impact_support_map_support_map;
+mod time_to_enter_aabb;
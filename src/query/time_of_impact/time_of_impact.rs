@@ -2,7 +2,7 @@ use na::{RealField, Unit};
 
 use crate::math::{Isometry, Point, Vector};
 use crate::query::{self, TOIDispatcher, Unsupported};
-use crate::shape::{Ball, Plane, Shape};
+use crate::shape::{Ball, Capsule, Plane, Shape};
 
 /// The status of the time-of-impact computation algorithm.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -92,6 +92,18 @@ pub fn time_of_impact<N: RealField>(
                     }
                 }),
         )
+    } else if let (Some(c1), Some(c2)) = (g1.as_shape::<Capsule<N>>(), g2.as_shape::<Capsule<N>>())
+    {
+        Ok(query::time_of_impact_capsule_capsule(
+            m1,
+            vel1,
+            c1,
+            m2,
+            vel2,
+            c2,
+            max_toi,
+            target_distance,
+        ))
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<N>>(), g2.as_support_map()) {
         Ok(query::time_of_impact_plane_support_map(
             m1,
@@ -125,6 +137,18 @@ pub fn time_of_impact<N: RealField>(
             max_toi,
             target_distance,
         ))
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        Ok(query::time_of_impact_composite_shape_composite_shape(
+            dispatcher,
+            m1,
+            vel1,
+            c1,
+            m2,
+            vel2,
+            c2,
+            max_toi,
+            target_distance,
+        ))
     } else if let Some(c1) = g1.as_composite_shape() {
         Ok(query::time_of_impact_composite_shape_shape(
             dispatcher,
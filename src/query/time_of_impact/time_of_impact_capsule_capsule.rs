@@ -0,0 +1,214 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{self, TOIStatus, TOI};
+use crate::shape::{Ball, Capsule, Segment};
+
+/// Time Of Impact of two capsules under translational movement.
+///
+/// Each capsule is handled as a segment swept by a ball. The first time the cylindrical parts of
+/// the capsules touch is computed in closed form, while the contacts involving the caps are
+/// computed with `time_of_impact_ball_ball`. Thus, unlike the generic support-map algorithm, the
+/// result is exact even if the axes of the capsules are parallel.
+pub fn time_of_impact_capsule_capsule<N: RealField>(
+    m1: &Isometry<N>,
+    vel1: &Vector<N>,
+    c1: &Capsule<N>,
+    m2: &Isometry<N>,
+    vel2: &Vector<N>,
+    c2: &Capsule<N>,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>> {
+    let seg1 = c1.segment().transformed(m1);
+    let seg2 = c2.segment().transformed(m2);
+    let rsum = c1.radius + c2.radius;
+    let radius = rsum + target_distance;
+    // The motion of the second capsule relative to the first one.
+    let vel = *vel2 - *vel1;
+
+    let dist = segment_segment_distance(&seg1.a, &seg1.b, &seg2.a, &seg2.b);
+    let (toi, status) = if dist <= radius {
+        let status = if dist < rsum {
+            TOIStatus::Penetrating
+        } else {
+            TOIStatus::Converged
+        };
+
+        (N::zero(), status)
+    } else {
+        let mut toi = None;
+        let mut candidate = |t: Option<N>| {
+            if let Some(t) = t {
+                if t >= N::zero() && t <= max_toi && toi.map(|toi| t < toi).unwrap_or(true) {
+                    toi = Some(t)
+                }
+            }
+        };
+
+        let ball1 = Ball::new(c1.radius);
+        let ball2 = Ball::new(c2.radius);
+
+        for p1 in &[seg1.a, seg1.b] {
+            for p2 in &[seg2.a, seg2.b] {
+                // Cap-cap contacts.
+                let toi = query::time_of_impact_ball_ball(
+                    p1,
+                    vel1,
+                    &ball1,
+                    p2,
+                    vel2,
+                    &ball2,
+                    max_toi,
+                    target_distance,
+                );
+                candidate(toi.map(|toi| toi.toi));
+            }
+
+            // Cap-cylinder contacts.
+            candidate(point_cylinder_toi(p1, &-vel, &seg2.a, &seg2.b, radius));
+        }
+
+        for p2 in &[seg2.a, seg2.b] {
+            candidate(point_cylinder_toi(p2, &vel, &seg1.a, &seg1.b, radius));
+        }
+
+        // Cylinder-cylinder contacts.
+        #[cfg(feature = "dim3")]
+        candidate(axis_axis_toi(
+            &seg1.a, &seg1.b, &seg2.a, &seg2.b, &vel, radius,
+        ));
+
+        (toi?, TOIStatus::Converged)
+    };
+
+    let p1 = seg1.a + *vel1 * toi;
+    let p2 = seg2.a + *vel2 * toi;
+    let seg1 = Segment::new(p1, p1 + (seg1.b - seg1.a));
+    let seg2 = Segment::new(p2, p2 + (seg2.b - seg2.a));
+    let (loc1, loc2) = query::closest_points_segment_segment_with_locations_nD(
+        (&seg1.a, &seg1.b),
+        (&seg2.a, &seg2.b),
+    );
+    let p1 = seg1.point_at(&loc1);
+    let p2 = seg2.point_at(&loc2);
+    let normal = Unit::try_new(p2 - p1, N::default_epsilon()).unwrap_or_else(Vector::y_axis);
+
+    let m1 = Isometry::from_parts((m1.translation.vector + *vel1 * toi).into(), m1.rotation);
+    let m2 = Isometry::from_parts((m2.translation.vector + *vel2 * toi).into(), m2.rotation);
+
+    Some(TOI {
+        toi,
+        witness1: m1.inverse_transform_point(&(p1 + *normal * c1.radius)),
+        witness2: m2.inverse_transform_point(&(p2 - *normal * c2.radius)),
+        normal1: m1.inverse_transform_unit_vector(&normal),
+        normal2: m2.inverse_transform_unit_vector(&-normal),
+        status,
+    })
+}
+
+fn segment_segment_distance<N: RealField>(
+    a1: &Point<N>,
+    b1: &Point<N>,
+    a2: &Point<N>,
+    b2: &Point<N>,
+) -> N {
+    let (loc1, loc2) = query::closest_points_segment_segment_with_locations_nD((a1, b1), (a2, b2));
+    let p1 = Segment::new(*a1, *b1).point_at(&loc1);
+    let p2 = Segment::new(*a2, *b2).point_at(&loc2);
+    na::distance(&p1, &p2)
+}
+
+// The first time the point `pt` moving at the velocity `vel` reaches the distance `radius` from
+// the segment `[a, b]`, excluding its ends.
+fn point_cylinder_toi<N: RealField>(
+    pt: &Point<N>,
+    vel: &Vector<N>,
+    a: &Point<N>,
+    b: &Point<N>,
+    radius: N,
+) -> Option<N> {
+    let dir = b - a;
+    let sq_len = dir.norm_squared();
+
+    if sq_len <= N::default_epsilon() {
+        return None;
+    }
+
+    let dpt = pt - a;
+    let perp_pt = dpt - dir * (dpt.dot(&dir) / sq_len);
+    let perp_vel = vel - dir * (vel.dot(&dir) / sq_len);
+
+    let qa = perp_vel.norm_squared();
+    let qb = perp_pt.dot(&perp_vel) * na::convert(2.0);
+    let qc = perp_pt.norm_squared() - radius * radius;
+
+    if qa <= N::default_epsilon() {
+        // The point moves parallel to the axis.
+        return None;
+    }
+
+    let disc = qb * qb - qa * qc * na::convert(4.0);
+
+    if disc < N::zero() {
+        return None;
+    }
+
+    let toi = (-qb - disc.sqrt()) / (qa * na::convert(2.0));
+    let s = (dpt + vel * toi).dot(&dir) / sq_len;
+
+    if s >= N::zero() && s <= N::one() {
+        Some(toi)
+    } else {
+        None
+    }
+}
+
+// The first time the segment `[a2, b2]` moving at the velocity `vel` reaches the distance `radius`
+// from the segment `[a1, b1]`, with the closest points of both segments distinct from their ends.
+#[cfg(feature = "dim3")]
+fn axis_axis_toi<N: RealField>(
+    a1: &Point<N>,
+    b1: &Point<N>,
+    a2: &Point<N>,
+    b2: &Point<N>,
+    vel: &Vector<N>,
+    radius: N,
+) -> Option<N> {
+    let dir1 = b1 - a1;
+    let dir2 = b2 - a2;
+    let sq_len1 = dir1.norm_squared();
+    let sq_len2 = dir2.norm_squared();
+    let cross = dir1.cross(&dir2);
+    let sq_sin_scaled = cross.norm_squared();
+
+    // If the axes are parallel, the distance between the segments is reached at one of their
+    // ends so it is already handled by the cap-cylinder contacts.
+    if sq_sin_scaled <= N::default_epsilon().sqrt() * sq_len1 * sq_len2 {
+        return None;
+    }
+
+    let normal = cross / sq_sin_scaled.sqrt();
+    let h0 = normal.dot(&(a2 - a1));
+    let hv = normal.dot(vel);
+
+    if hv.abs() <= N::default_epsilon() {
+        return None;
+    }
+
+    let toi = ((radius - h0) / hv).min((-radius - h0) / hv);
+
+    // Check that the closest points of the axes lie inside of both segments at this time.
+    let dpt = a1 - (a2 + vel * toi);
+    let b = dir1.dot(&dir2);
+    let d = dir1.dot(&dpt);
+    let e = dir2.dot(&dpt);
+    let s = (b * e - sq_len2 * d) / sq_sin_scaled;
+    let u = (sq_len1 * e - b * d) / sq_sin_scaled;
+
+    if s >= N::zero() && s <= N::one() && u >= N::zero() && u <= N::one() {
+        Some(toi)
+    } else {
+        None
+    }
+}
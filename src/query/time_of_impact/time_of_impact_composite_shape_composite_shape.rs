@@ -0,0 +1,197 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::{BVHImpl, WeightedValue, BVH};
+use crate::query::{Ray, RayCast, TOIDispatcher, TOI};
+use crate::shape::CompositeShape;
+use na::RealField;
+use std::collections::BinaryHeap;
+
+/// Time Of Impact of two composite shapes (`TriMesh`, `Compound`, etc.), under translational
+/// movement.
+///
+/// The BVHs of both shapes are traversed simultaneously, visiting first the pairs of nodes with
+/// the smallest time of impact of their swept AABBs. Thus, pairs of nodes that cannot be hit
+/// before `max_toi`, or before the best time of impact found so far, are pruned early. The time
+/// of impact of each pair of leaves reached is computed by `dispatcher`, and the pairs of parts
+/// not supported by `dispatcher` are ignored.
+pub fn time_of_impact_composite_shape_composite_shape<N, G1: ?Sized, G2: ?Sized>(
+    dispatcher: &dyn TOIDispatcher<N>,
+    m1: &Isometry<N>,
+    vel1: &Vector<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    vel2: &Vector<N>,
+    g2: &G2,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>>
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    let search = CompositeShapesTOISearch {
+        dispatcher,
+        ls_m2: m1.inverse() * m2,
+        // The motion of the second shape relative to the first one.
+        ray: Ray::new(
+            Point::origin(),
+            m1.inverse_transform_vector(&(*vel2 - *vel1)),
+        ),
+        m1,
+        vel1,
+        g1,
+        m2,
+        vel2,
+        g2,
+        max_toi,
+        target_distance,
+    };
+
+    // Dispatch on the BVH type of each shape, one after the other.
+    match g2.bvh() {
+        BVHImpl::BVT(bvh2) => search.run_dispatch(bvh2),
+        BVHImpl::DBVT(bvh2) => search.run_dispatch(bvh2),
+    }
+}
+
+struct CompositeShapesTOISearch<'a, N: 'a + RealField, G1: ?Sized + 'a, G2: ?Sized + 'a> {
+    dispatcher: &'a dyn TOIDispatcher<N>,
+    ls_m2: Isometry<N>,
+    ray: Ray<N>,
+
+    m1: &'a Isometry<N>,
+    vel1: &'a Vector<N>,
+    g1: &'a G1,
+    m2: &'a Isometry<N>,
+    vel2: &'a Vector<N>,
+    g2: &'a G2,
+    max_toi: N,
+    target_distance: N,
+}
+
+impl<'a, N, G1: ?Sized, G2: ?Sized> CompositeShapesTOISearch<'a, N, G1, G2>
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    fn run_dispatch(&self, bvh2: &impl BVH<usize, AABB<N>>) -> Option<TOI<N>> {
+        match self.g1.bvh() {
+            BVHImpl::BVT(bvh1) => self.run(bvh1, bvh2),
+            BVHImpl::DBVT(bvh1) => self.run(bvh1, bvh2),
+        }
+    }
+
+    fn run<B1, B2>(&self, bvh1: &B1, bvh2: &B2) -> Option<TOI<N>>
+    where
+        B1: BVH<usize, AABB<N>>,
+        B2: BVH<usize, AABB<N>>,
+    {
+        let mut queue: BinaryHeap<WeightedValue<N, (B1::Node, B2::Node)>> = BinaryHeap::new();
+        let mut best: Option<TOI<N>> = None;
+
+        if let (Some(root1), Some(root2)) = (bvh1.root(), bvh2.root()) {
+            if let Some(toi) = self.swept_aabbs_toi(bvh1.content(root1).0, bvh2.content(root2).0) {
+                queue.push(WeightedValue::new((root1, root2), -toi));
+            }
+        }
+
+        while let Some(entry) = queue.pop() {
+            let best_toi = best.as_ref().map(|toi| toi.toi).unwrap_or(self.max_toi);
+
+            if -entry.cost > best_toi {
+                // No pair left with a smaller time of impact.
+                break;
+            }
+
+            let (node1, node2) = entry.value;
+            let (bv1, data1) = bvh1.content(node1);
+            let (bv2, data2) = bvh2.content(node2);
+
+            match (data1, data2) {
+                (Some(i1), Some(i2)) => {
+                    if let Some(toi) = self.parts_toi(*i1, *i2) {
+                        if toi.toi <= best_toi {
+                            best = Some(toi);
+                        }
+                    }
+                }
+                _ => {
+                    // Descend into the largest node, so both trees are refined evenly.
+                    let descend1 = data2.is_some()
+                        || (data1.is_none()
+                            && bv1.half_extents().norm_squared()
+                                >= bv2.half_extents().norm_squared());
+
+                    if descend1 {
+                        for i in 0..bvh1.num_children(node1) {
+                            let child1 = bvh1.child(i, node1);
+                            let child_bv1 = bvh1.content(child1).0;
+
+                            if let Some(toi) = self.swept_aabbs_toi(child_bv1, bv2) {
+                                if toi <= best_toi {
+                                    queue.push(WeightedValue::new((child1, node2), -toi));
+                                }
+                            }
+                        }
+                    } else {
+                        for i in 0..bvh2.num_children(node2) {
+                            let child2 = bvh2.child(i, node2);
+                            let child_bv2 = bvh2.content(child2).0;
+
+                            if let Some(toi) = self.swept_aabbs_toi(bv1, child_bv2) {
+                                if toi <= best_toi {
+                                    queue.push(WeightedValue::new((node1, child2), -toi));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    // The time of impact of the AABBs `bv1` and `bv2`, given in the local-spaces of their shapes.
+    fn swept_aabbs_toi(&self, bv1: &AABB<N>, bv2: &AABB<N>) -> Option<N> {
+        // Compute the minkowski difference of the two AABBs, in the local-space of `g1`.
+        let ls_bv2 = bv2.transform_by(&self.ls_m2);
+        let margin = Vector::repeat(self.target_distance);
+        let msum = AABB::new(
+            bv1.mins + (-ls_bv2.maxs.coords) - margin,
+            bv1.maxs + (-ls_bv2.mins.coords) + margin,
+        );
+
+        msum.toi_with_ray(&Isometry::identity(), &self.ray, self.max_toi, true)
+    }
+
+    // The time of impact of the parts `i1` and `i2`, or `None` if they don't hit each other
+    // before `max_toi` or if they are not supported by the dispatcher.
+    fn parts_toi(&self, i1: usize, i2: usize) -> Option<TOI<N>> {
+        let mut res = None;
+
+        self.g1.map_part_at(i1, self.m1, &mut |m1, g1| {
+            self.g2.map_part_at(i2, self.m2, &mut |m2, g2| {
+                res = self
+                    .dispatcher
+                    .time_of_impact(
+                        self.dispatcher,
+                        m1,
+                        self.vel1,
+                        g1,
+                        m2,
+                        self.vel2,
+                        g2,
+                        self.max_toi,
+                        self.target_distance,
+                    )
+                    .unwrap_or(None)
+                    .filter(|toi| toi.toi <= self.max_toi);
+            })
+        });
+
+        res
+    }
+}
@@ -0,0 +1,49 @@
+use na::{self, RealField};
+
+use crate::bounding_volume::AABB;
+use crate::interpolation::InterpolatedRigidMotion;
+use crate::math::{Isometry, Translation};
+use crate::pipeline::narrow_phase::{DefaultProximityDispatcher, ProximityDispatcher};
+use crate::query::{DefaultTOIDispatcher, Proximity, TOIDispatcher};
+use crate::shape::{Cuboid, Shape};
+
+/// Computes the first time a moving shape intersects a static region.
+///
+/// The shape moves from `m_start` to `m_end`, interpolated as by `InterpolatedRigidMotion`,
+/// and the time is searched in `[0, 1]`. Returns `Some(0)` if the shape already intersects
+/// `region` at `m_start`, and `None` if it does not intersect it during the whole motion. Also
+/// returns `None` if the proximity between the shape and a cuboid is not supported by the
+/// `DefaultProximityDispatcher`, or if their time of impact is not supported by the
+/// `DefaultTOIDispatcher`.
+pub fn time_to_enter_aabb<N: RealField>(
+    m_start: &Isometry<N>,
+    m_end: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    region: &AABB<N>,
+) -> Option<N> {
+    let cuboid = Cuboid::new(region.half_extents());
+    let region_pos = Isometry::from_parts(Translation::from(region.center().coords), na::one());
+
+    let dispatcher = DefaultProximityDispatcher::new();
+    let mut detector = dispatcher.get_proximity_algorithm(shape, &cuboid)?;
+    let prox = detector.update(&dispatcher, m_start, shape, &region_pos, &cuboid, N::zero())?;
+
+    if prox != Proximity::Disjoint {
+        return Some(N::zero());
+    }
+
+    let motion = InterpolatedRigidMotion::new(*m_start, *m_end);
+    let toi = DefaultTOIDispatcher
+        .nonlinear_time_of_impact(
+            &DefaultTOIDispatcher,
+            &motion,
+            shape,
+            &region_pos,
+            &cuboid,
+            N::one(),
+            N::zero(),
+        )
+        .ok()??;
+
+    Some(toi.toi)
+}
@@ -5,6 +5,7 @@ pub use self::bounding_volume_interferences_collector::BoundingVolumeInterferenc
 pub use self::composite_closest_point_visitor::CompositeClosestPointVisitor;
 pub use self::composite_point_containment_test::CompositePointContainmentTest;
 pub use self::point_interferences_collector::PointInterferencesCollector;
+pub use self::point_projection_cost_fn_visitor::PointProjectionCostFnVisitor;
 pub use self::ray_interferences_collector::RayInterferencesCollector;
 pub use self::ray_intersection_cost_fn_visitor::RayIntersectionCostFnVisitor;
 
@@ -13,5 +14,6 @@ mod bounding_volume_interferences_collector;
 mod composite_closest_point_visitor;
 mod composite_point_containment_test;
 mod point_interferences_collector;
+mod point_projection_cost_fn_visitor;
 mod ray_interferences_collector;
 mod ray_intersection_cost_fn_visitor;
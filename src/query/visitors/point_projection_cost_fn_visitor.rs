@@ -0,0 +1,101 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
+use crate::query::{PointProjection, PointQuery, RayCast};
+use na::{self, RealField, Unit};
+use std::any::Any;
+
+use crate::pipeline::{BroadPhase, BroadPhaseProxyHandle};
+
+/// Bounding Volume Tree visitor searching the object with the boundary closest to a point.
+pub struct PointProjectionCostFnVisitor<'a, 'b, N: 'a + RealField, T, BV>
+where
+    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync,
+{
+    /// Point to be projected.
+    point: &'b Point<N>,
+
+    /// Maximum distance between the point and the objects.
+    max_dist: N,
+
+    /// Used as a lookup to get the underlying data of the tree.
+    broad_phase: &'a dyn BroadPhase<N, BV, T>,
+
+    /// The projection function to apply to each leaf nodes data.
+    cost_fn: &'a dyn Fn(T, &'b Point<N>, N) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)>,
+}
+
+impl<'a, 'b, N: RealField, T, BV> PointProjectionCostFnVisitor<'a, 'b, N, T, BV>
+where
+    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync,
+{
+    /// Creates a new `PointProjectionCostFnVisitor`.
+    #[inline]
+    pub fn new(
+        point: &'b Point<N>,
+        max_dist: N,
+        broad_phase: &'a dyn BroadPhase<N, BV, T>,
+        cost_fn: &'a dyn Fn(T, &'b Point<N>, N) -> Option<(T, PointProjection<N>, Unit<Vector<N>>)>,
+    ) -> PointProjectionCostFnVisitor<'a, 'b, N, T, BV> {
+        PointProjectionCostFnVisitor {
+            point,
+            max_dist,
+            broad_phase,
+            cost_fn,
+        }
+    }
+}
+
+impl<'a, 'b, N, BV, T> BestFirstVisitor<N, BroadPhaseProxyHandle, BV>
+    for PointProjectionCostFnVisitor<'a, 'b, N, T, BV>
+where
+    N: RealField,
+    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync + Clone,
+{
+    type Result = (T, PointProjection<N>, Unit<Vector<N>>);
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best_cost_so_far: N,
+        bv: &BV,
+        data: Option<&BroadPhaseProxyHandle>,
+    ) -> BestFirstVisitStatus<N, Self::Result> {
+        // The distance to the bounding volume is a lower bound of the distance to the boundary
+        // of any object it contains.
+        let rough_dist = bv.distance_to_point(&Isometry::identity(), self.point, true);
+
+        if rough_dist > self.max_dist {
+            return BestFirstVisitStatus::Stop;
+        }
+
+        let mut res = BestFirstVisitStatus::Continue {
+            cost: rough_dist,
+            result: None,
+        };
+
+        if let Some(data_handle) = data {
+            if rough_dist < best_cost_so_far {
+                if let Some((_, leaf_data)) = self.broad_phase.proxy(*data_handle) {
+                    if let Some(result) =
+                        (self.cost_fn)(leaf_data.clone(), self.point, self.max_dist)
+                    {
+                        let dist = na::distance(self.point, &result.1.point);
+
+                        if dist <= self.max_dist {
+                            res = BestFirstVisitStatus::Continue {
+                                cost: dist,
+                                result: Some(result),
+                            };
+                        }
+                    }
+                }
+            }
+        }
+
+        res
+    }
+}
@@ -0,0 +1,156 @@
+//! A thick circular arc.
+
+use na::{self, RealField, Unit};
+
+use crate::math::{Point, Vector};
+use crate::shape::error::{self, InvalidShape};
+
+/// A portion of the boundary of a circle, dilated by half of its thickness.
+///
+/// The arc goes counterclockwise from `start_angle` to `end_angle`. Its ends are rounded:
+/// near each endpoint the arc behaves like the rounded end of a capsule.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Arc<N: RealField> {
+    /// The center of the circle this arc is part of.
+    pub center: Point<N>,
+    /// The radius of the circle this arc is part of.
+    pub radius: N,
+    /// The angle, in radians, of the first endpoint of the arc.
+    pub start_angle: N,
+    /// The angle, in radians, of the last endpoint of the arc.
+    pub end_angle: N,
+    /// The thickness of the arc.
+    pub thickness: N,
+}
+
+impl<N: RealField> Arc<N> {
+    /// Creates a new arc.
+    ///
+    /// # Arguments:
+    /// * `center` - the center of the circle this arc is part of.
+    /// * `radius` - the radius of the circle this arc is part of.
+    /// * `start_angle` - the angle of the first endpoint of the arc.
+    /// * `end_angle` - the angle of the last endpoint of the arc. The angular extent of the arc,
+    ///   `end_angle - start_angle`, is expected to be in `]0, 2pi]`.
+    /// * `thickness` - the thickness of the arc.
+    ///
+    /// The dimensions are not validated. Use `Arc::try_new` to reject invalid dimensions.
+    pub fn new(center: Point<N>, radius: N, start_angle: N, end_angle: N, thickness: N) -> Arc<N> {
+        Arc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            thickness,
+        }
+    }
+
+    /// Creates a new arc, checking its dimensions are finite and positive.
+    ///
+    /// The angular extent `end_angle - start_angle` must be positive as well.
+    pub fn try_new(
+        center: Point<N>,
+        radius: N,
+        start_angle: N,
+        end_angle: N,
+        thickness: N,
+    ) -> Result<Arc<N>, InvalidShape> {
+        error::check_dimension(radius)?;
+        error::check_dimension(thickness)?;
+        error::check_dimension(end_angle - start_angle)?;
+
+        Ok(Arc::new(center, radius, start_angle, end_angle, thickness))
+    }
+
+    /// Half of the thickness of this arc.
+    #[inline]
+    pub fn half_thickness(&self) -> N {
+        self.thickness * na::convert(0.5)
+    }
+
+    /// The angular extent of this arc, i.e., `end_angle - start_angle`.
+    #[inline]
+    pub fn sweep_angle(&self) -> N {
+        self.end_angle - self.start_angle
+    }
+
+    /// The point of the circle of this arc at the given angle.
+    #[inline]
+    pub fn point_at_angle(&self, angle: N) -> Point<N> {
+        self.center + Vector::new(angle.cos(), angle.sin()) * self.radius
+    }
+
+    /// The first endpoint of the circular curve of this arc.
+    #[inline]
+    pub fn start_point(&self) -> Point<N> {
+        self.point_at_angle(self.start_angle)
+    }
+
+    /// The last endpoint of the circular curve of this arc.
+    #[inline]
+    pub fn end_point(&self) -> Point<N> {
+        self.point_at_angle(self.end_angle)
+    }
+
+    /// Tests if the given angle lies between the start and end angles of this arc, modulo `2pi`.
+    #[inline]
+    pub fn contains_angle(&self, angle: N) -> bool {
+        let two_pi = N::two_pi();
+        let mut offset = (angle - self.start_angle) % two_pi;
+
+        if offset < N::zero() {
+            offset += two_pi;
+        }
+
+        offset <= self.sweep_angle()
+    }
+
+    /// Tests if the given direction, starting from the center of this arc, crosses its curve.
+    #[inline]
+    pub fn contains_dir(&self, dir: &Vector<N>) -> bool {
+        self.contains_angle(dir.y.atan2(dir.x))
+    }
+
+    /// The point of the circular curve of this arc (i.e. ignoring its thickness) closest to `pt`.
+    ///
+    /// Both `pt` and the result are expressed in the local-space of this arc.
+    pub fn project_on_curve(&self, pt: &Point<N>) -> Point<N> {
+        let dpt = *pt - self.center;
+
+        if let Some(dir) = Unit::try_new(dpt, N::default_epsilon()) {
+            if self.contains_dir(&dir) {
+                return self.center + dir.into_inner() * self.radius;
+            }
+        }
+
+        let start = self.start_point();
+        let end = self.end_point();
+
+        if na::distance_squared(pt, &start) <= na::distance_squared(pt, &end) {
+            start
+        } else {
+            end
+        }
+    }
+
+    /// The point of the circular curve of this arc that is the furthest along `dir`.
+    ///
+    /// This is the support function of the convex hull of the curve, ignoring the thickness.
+    pub fn curve_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        if let Some(dir) = Unit::try_new(*dir, N::default_epsilon()) {
+            if self.contains_dir(&dir) {
+                return self.center + dir.into_inner() * self.radius;
+            }
+        }
+
+        let start = self.start_point();
+        let end = self.end_point();
+
+        if start.coords.dot(dir) >= end.coords.dot(dir) {
+            start
+        } else {
+            end
+        }
+    }
+}
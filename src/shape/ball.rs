@@ -1,6 +1,7 @@
 use na::{RealField, Unit};
 
 use crate::math::{Isometry, Point, Vector};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::SupportMap;
 
 /// A Ball shape.
@@ -13,11 +14,20 @@ pub struct Ball<N: RealField> {
 
 impl<N: RealField> Ball<N> {
     /// Creates a new ball from its radius and center.
+    ///
+    /// The radius is not validated. Use `Ball::try_new` to reject invalid radii.
     #[inline]
     pub fn new(radius: N) -> Ball<N> {
         Ball { radius }
     }
 
+    /// Creates a new ball from its radius, checking it is finite and positive.
+    #[inline]
+    pub fn try_new(radius: N) -> Result<Ball<N>, InvalidShape> {
+        error::check_dimension(radius)?;
+        Ok(Ball { radius })
+    }
+
     /// The ball radius.
     #[inline]
     #[deprecated(note = "use the `self.radius` public field directly.")]
@@ -4,6 +4,7 @@ use na::{self, RealField, Unit};
 
 use crate::math::{Point, Vector};
 use crate::query::{Contact, ContactKinematic, ContactPreprocessor};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::{FeatureId, Segment, SupportMap};
 
 /// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
@@ -22,6 +23,8 @@ impl<N: RealField> Capsule<N> {
     /// # Arguments:
     /// * `half_height` - the half length of the capsule along the `y` axis.
     /// * `radius` - radius of the rounded part of the capsule.
+    ///
+    /// The dimensions are not validated. Use `Capsule::try_new` to reject invalid dimensions.
     pub fn new(half_height: N, radius: N) -> Capsule<N> {
         Capsule {
             half_height,
@@ -29,6 +32,17 @@ impl<N: RealField> Capsule<N> {
         }
     }
 
+    /// Creates a new capsule, checking its dimensions are finite and positive.
+    pub fn try_new(half_height: N, radius: N) -> Result<Capsule<N>, InvalidShape> {
+        error::check_dimension(half_height)?;
+        error::check_dimension(radius)?;
+
+        Ok(Capsule {
+            half_height,
+            radius,
+        })
+    }
+
     /// The capsule half length along its local `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` public field directly.")]
@@ -4,7 +4,8 @@
 
 use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::Isometry;
-use crate::partitioning::{BVHImpl, BVT};
+use crate::partitioning::{BVHImpl, BVH, BVT};
+use crate::query::visitors::BoundingVolumeInterferencesCollector;
 use crate::query::{Contact, ContactKinematic, ContactPrediction, ContactPreprocessor};
 use crate::shape::{CompositeShape, FeatureId, Shape, ShapeHandle};
 use na::{self, RealField};
@@ -86,6 +87,15 @@ impl<N: RealField> Compound<N> {
         &self.bvs[i]
     }
 
+    /// The indices of the shapes with an AABB intersecting `aabb`, given in the local-space of
+    /// this compound.
+    pub fn intersect_aabb(&self, aabb: &AABB<N>) -> Vec<usize> {
+        let mut res = Vec::new();
+        let mut visitor = BoundingVolumeInterferencesCollector::new(aabb, &mut res);
+        self.bvt.visit(&mut visitor);
+        res
+    }
+
     /// Transforms a FeatureId of this compound into a pair containing the index of the subshape
     /// containing this feature, and the corresponding FeatureId on this subshape.
     pub fn subshape_feature_id(&self, fid: FeatureId) -> (usize, FeatureId) {
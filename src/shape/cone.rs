@@ -1,6 +1,7 @@
 //! Support mapping based Cone shape.
 
 use crate::math::{Point, Vector};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::SupportMap;
 use na::{self, RealField};
 
@@ -20,6 +21,8 @@ impl<N: RealField> Cone<N> {
     /// # Arguments:
     /// * `half_height` - the half length of the cone along the `y` axis.
     /// * `radius` - the length of the cone along all other axis.
+    ///
+    /// The dimensions are not validated. Use `Cone::try_new` to reject invalid dimensions.
     pub fn new(half_height: N, radius: N) -> Cone<N> {
         Cone {
             half_height,
@@ -27,6 +30,17 @@ impl<N: RealField> Cone<N> {
         }
     }
 
+    /// Creates a new cone, checking its dimensions are finite and positive.
+    pub fn try_new(half_height: N, radius: N) -> Result<Cone<N>, InvalidShape> {
+        error::check_dimension(half_height)?;
+        error::check_dimension(radius)?;
+
+        Ok(Cone {
+            half_height,
+            radius,
+        })
+    }
+
     /// The cone half length along the `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` public field directly.")]
@@ -1,4 +1,5 @@
 use crate::math::{Isometry, Point, Vector};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
 use crate::transformation;
 use crate::utils::{self, SortedPair};
@@ -39,6 +40,8 @@ struct Face<N: RealField> {
     first_vertex_or_edge: usize,
     num_vertices_or_edges: usize,
     normal: Unit<Vector<N>>,
+    // The dot product of `normal` with any point of this face.
+    offset: N,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -84,8 +87,10 @@ impl<N: RealField> ConvexHull<N> {
     /// Creates a new 2D convex polyhedron from an arbitrary set of points.
     ///
     /// This explicitly computes the convex hull of the given set of points. Use
-    /// Returns `None` if the convex hull computation failed.
-    pub fn try_from_points(points: &[Point<N>]) -> Option<ConvexHull<N>> {
+    /// Returns `InvalidShape::NonFiniteVertex` if one of the points is not finite, and
+    /// `InvalidShape::Degenerate` if the convex hull computation failed.
+    pub fn try_from_points(points: &[Point<N>]) -> Result<ConvexHull<N>, InvalidShape> {
+        error::check_vertices(points)?;
         let hull = transformation::convex_hull(points);
         let indices: Vec<usize> = hull
             .flat_indices()
@@ -102,11 +107,16 @@ impl<N: RealField> ConvexHull<N> {
     ///
     /// # Return
     ///
-    /// Retruns `None` if:
+    /// Returns `InvalidShape::NonFiniteVertex` if one of the given points has a NaN or infinite
+    /// coordinate, and `InvalidShape::Degenerate` if:
     ///
     ///   1. The given solid does not satisfy the euler characteristic.
     ///   2. The given solid contains degenerate edges/triangles.
-    pub fn try_new(points: Vec<Point<N>>, indices: &[usize]) -> Option<ConvexHull<N>> {
+    pub fn try_new(
+        points: Vec<Point<N>>,
+        indices: &[usize],
+    ) -> Result<ConvexHull<N>, InvalidShape> {
+        error::check_vertices(&points)?;
         let eps = N::default_epsilon().sqrt();
 
         let mut vertices = Vec::new();
@@ -154,7 +164,7 @@ impl<N: RealField> ConvexHull<N> {
                                 deleted: false,
                             })
                         } else {
-                            return None;
+                            return Err(InvalidShape::Degenerate);
                         }
                     }
                 }
@@ -162,7 +172,8 @@ impl<N: RealField> ConvexHull<N> {
 
             let vertices = Point3::new(vtx[0], vtx[1], vtx[2]);
             let normal =
-                utils::ccw_face_normal([&points[vtx[0]], &points[vtx[1]], &points[vtx[2]]])?;
+                utils::ccw_face_normal([&points[vtx[0]], &points[vtx[1]], &points[vtx[2]]])
+                    .ok_or(InvalidShape::Degenerate)?;
             let triangle = Triangle {
                 vertices,
                 edges: edges_id,
@@ -199,6 +210,9 @@ impl<N: RealField> ConvexHull<N> {
                             first_vertex_or_edge: edges_adj_to_face.len(),
                             num_vertices_or_edges: 1,
                             normal: triangles[i].normal,
+                            offset: triangles[i]
+                                .normal
+                                .dot(&points[triangles[i].vertices[j1]].coords),
                         };
 
                         edges_adj_to_face.push(triangles[i].edges[j1]);
@@ -314,7 +328,7 @@ impl<N: RealField> ConvexHull<N> {
 
         // Check that the Euler characteristic is respected.
         if num_valid_vertices + faces.len() - num_valid_edges != 2 {
-            None
+            Err(InvalidShape::Degenerate)
         } else {
             let res = ConvexHull {
                 points,
@@ -330,7 +344,7 @@ impl<N: RealField> ConvexHull<N> {
             // FIXME: for debug.
             // res.check_geometry();
 
-            Some(res)
+            Ok(res)
         }
     }
 
@@ -352,6 +366,135 @@ impl<N: RealField> ConvexHull<N> {
         &self.points[..]
     }
 
+    /// The number of faces of this convex polyhedron, identified by `FeatureId::Face(0)` to
+    /// `FeatureId::Face(self.num_faces() - 1)`.
+    #[inline]
+    pub fn num_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// Tests if the given point, expressed in the local-space of this convex polyhedron, is
+    /// inside of it or on its boundary.
+    ///
+    /// This tests the point against the plane of each face, so this is much cheaper than a
+    /// point projection.
+    #[inline]
+    pub fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        self.faces
+            .iter()
+            .all(|face| face.normal.dot(&pt.coords) <= face.offset)
+    }
+
+    /// Projects a point, expressed in the local-space of this convex polyhedron, on its boundary
+    /// if it is inside of it.
+    ///
+    /// The closest boundary point to an interior point is its orthogonal projection on the plane
+    /// of the closest face. Returns `None` if the point is outside of this convex polyhedron.
+    pub(crate) fn project_local_point_from_inside(&self, pt: &Point<N>) -> Option<Point<N>> {
+        let mut best: Option<(N, &Face<N>)> = None;
+
+        for face in &self.faces {
+            let dist = face.offset - face.normal.dot(&pt.coords);
+
+            if dist < N::zero() {
+                return None;
+            }
+
+            if best.map(|b| dist < b.0) != Some(false) {
+                best = Some((dist, face));
+            }
+        }
+
+        best.map(|(dist, face)| pt + *face.normal * dist)
+    }
+
+    /// Adds a point to this convex polyhedron, updating it in-place so it becomes the convex hull
+    /// of its previous vertices and `p`.
+    ///
+    /// The faces visible from `p` are removed and the hole they leave is closed by a fan of
+    /// triangles connecting `p` to the boundary, i.e., the horizon, of the removed faces. The other
+    /// faces are kept as-is so this is much cheaper than computing the convex hull of all the
+    /// points from scratch. Vertices that are no longer part of the boundary are removed.
+    ///
+    /// Returns `false`, leaving `self` unchanged, if `p` is already inside of this convex
+    /// polyhedron (up to a small tolerance) or if it is not finite.
+    pub fn insert_point(&mut self, p: Point<N>) -> bool {
+        if !p.coords.iter().all(|e| e.is_finite()) {
+            return false;
+        }
+
+        let eps = N::default_epsilon().sqrt();
+        let visible: Vec<bool> = self
+            .faces
+            .iter()
+            .map(|face| {
+                let p0 = self.points[self.vertices_adj_to_face[face.first_vertex_or_edge]];
+                (p - p0).dot(&face.normal) > eps
+            })
+            .collect();
+
+        if !visible.iter().any(|v| *v) {
+            return false;
+        }
+
+        let new_id = self.points.len();
+        let mut indices = Vec::new();
+
+        for (face_id, face) in self.faces.iter().enumerate() {
+            if visible[face_id] {
+                continue;
+            }
+
+            let first_vid = face.first_vertex_or_edge;
+            let last_vid = face.first_vertex_or_edge + face.num_vertices_or_edges;
+            let vertices = &self.vertices_adj_to_face[first_vid..last_vid];
+
+            // Keep the face, triangulated as a fan.
+            for i in 1..vertices.len() - 1 {
+                indices.extend_from_slice(&[vertices[0], vertices[i], vertices[i + 1]]);
+            }
+
+            // Connect the edges of the horizon to the new point.
+            for (i, eid) in self.edges_adj_to_face[first_vid..last_vid]
+                .iter()
+                .enumerate()
+            {
+                if visible[self.edges[*eid].other_triangle(face_id)] {
+                    let next = vertices[(i + 1) % vertices.len()];
+                    indices.extend_from_slice(&[next, vertices[i], new_id]);
+                }
+            }
+        }
+
+        // Only keep the points that are still vertices.
+        let mut points = Vec::new();
+        let mut remap = vec![usize::MAX; new_id + 1];
+
+        for i in &mut indices {
+            if remap[*i] == usize::MAX {
+                remap[*i] = points.len();
+                points.push(if *i == new_id { p } else { self.points[*i] });
+            }
+
+            *i = remap[*i];
+        }
+
+        let updated = Self::try_new(points, &indices).or_else(|_| {
+            // The retriangulation may be degenerate if `p` is almost coplanar with some faces.
+            let mut points = self.points.clone();
+            points.push(p);
+            Self::try_from_points(&points)
+        });
+
+        match updated {
+            Ok(updated) => {
+                *self = updated;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
@@ -1,4 +1,5 @@
 use crate::math::{Isometry, Point, Vector};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
 use crate::transformation;
 use crate::utils;
@@ -17,8 +18,10 @@ impl<N: RealField> ConvexPolygon<N> {
     /// Creates a new 2D convex polygon from an arbitrary set of points.
     ///
     /// This explicitly computes the convex hull of the given set of points. Use
-    /// Returns `None` if the convex hull computation failed.
-    pub fn try_from_points(points: &[Point<N>]) -> Option<Self> {
+    /// Returns `InvalidShape::NonFiniteVertex` if one of the points is not finite, and
+    /// `InvalidShape::Degenerate` if the convex hull computation failed.
+    pub fn try_from_points(points: &[Point<N>]) -> Result<Self, InvalidShape> {
+        error::check_vertices(points)?;
         let hull = transformation::convex_hull(points);
         let mut vertices = hull.unwrap().0;
         vertices.reverse(); // FIXME: it is unfortunate to have to do this reverse.
@@ -29,15 +32,20 @@ impl<N: RealField> ConvexPolygon<N> {
     /// Creates a new 2D convex polygon from a set of points assumed to describe a counter-clockwise convex polyline.
     ///
     /// Convexity of the input polyline is not checked.
-    /// Returns `None` if some consecutive points are identical (or too close to being so).
-    pub fn try_new(mut points: Vec<Point<N>>) -> Option<Self> {
+    /// Returns `InvalidShape::Degenerate` if some consecutive points are identical (or too close
+    /// to being so), and `InvalidShape::NonFiniteVertex` if one of the points is not finite.
+    pub fn try_new(mut points: Vec<Point<N>>) -> Result<Self, InvalidShape> {
+        error::check_vertices(&points)?;
         let eps = N::default_epsilon().sqrt();
         let mut normals = Vec::with_capacity(points.len());
 
         // First, compute all normals.
         for i1 in 0..points.len() {
             let i2 = (i1 + 1) % points.len();
-            normals.push(utils::ccw_face_normal([&points[i1], &points[i2]])?);
+            normals.push(
+                utils::ccw_face_normal([&points[i1], &points[i2]])
+                    .ok_or(InvalidShape::Degenerate)?,
+            );
         }
 
         let mut nremoved = 0;
@@ -64,9 +72,9 @@ impl<N: RealField> ConvexPolygon<N> {
         normals.truncate(new_length);
 
         if points.len() != 0 {
-            Some(ConvexPolygon { points, normals })
+            Ok(ConvexPolygon { points, normals })
         } else {
-            None
+            Err(InvalidShape::Degenerate)
         }
     }
 
@@ -82,6 +90,29 @@ impl<N: RealField> ConvexPolygon<N> {
         &self.normals
     }
 
+    /// Projects a point, expressed in the local-space of this convex polygon, on its boundary if
+    /// it is inside of it.
+    ///
+    /// The closest boundary point to an interior point is its orthogonal projection on the line
+    /// of the closest edge. Returns `None` if the point is outside of this convex polygon.
+    pub(crate) fn project_local_point_from_inside(&self, pt: &Point<N>) -> Option<Point<N>> {
+        let mut best: Option<(N, &Unit<Vector<N>>)> = None;
+
+        for (origin, normal) in self.points.iter().zip(self.normals.iter()) {
+            let dist = normal.dot(&(origin - pt));
+
+            if dist < N::zero() {
+                return None;
+            }
+
+            if best.map(|b| dist < b.0) != Some(false) {
+                best = Some((dist, normal));
+            }
+        }
+
+        best.map(|(dist, normal)| pt + **normal * dist)
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
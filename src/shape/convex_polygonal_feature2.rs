@@ -3,7 +3,7 @@ use na::{self, RealField, Unit};
 use crate::math::{Isometry, Point, Vector};
 use crate::query::ContactPreprocessor;
 use crate::query::{Contact, ContactPrediction};
-use crate::query::{ContactKinematic, ContactManifold, NeighborhoodGeometry};
+use crate::query::{ContactData, ContactKinematic, ContactManifold, NeighborhoodGeometry};
 use crate::shape::{FeatureId, Segment, SegmentPointLocation};
 
 /// A feature (face or vertex) of a 2D convex polygon.
@@ -192,7 +192,7 @@ impl<N: RealField> ConvexPolygonalFeature<N> {
     }
 
     /// Given a contact between two polygonal features, adds it to a contact manifold.
-    pub fn add_contact_to_manifold(
+    pub fn add_contact_to_manifold<C: ContactData>(
         &self,
         other: &Self,
         c: Contact<N>,
@@ -202,7 +202,7 @@ impl<N: RealField> ConvexPolygonalFeature<N> {
         m2: &Isometry<N>,
         f2: FeatureId,
         proc2: Option<&dyn ContactPreprocessor<N>>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) {
         let mut kinematic = ContactKinematic::new();
         let local1 = m1.inverse_transform_point(&c.world1);
@@ -4,7 +4,8 @@ use std::iter;
 use crate::math::{Isometry, Point, Vector};
 use crate::query::ContactPreprocessor;
 use crate::query::{
-    self, Contact, ContactKinematic, ContactManifold, ContactPrediction, NeighborhoodGeometry,
+    self, Contact, ContactData, ContactKinematic, ContactManifold, ContactPrediction,
+    NeighborhoodGeometry,
 };
 use crate::shape::{FeatureId, Segment, SegmentPointLocation};
 use crate::utils;
@@ -338,7 +339,7 @@ impl<N: RealField> ConvexPolygonalFeature<N> {
     }
 
     /// Given a contact between two polygonal features, adds it to a contact manifold.
-    pub fn add_contact_to_manifold(
+    pub fn add_contact_to_manifold<C: ContactData>(
         &self,
         other: &Self,
         c: Contact<N>,
@@ -348,7 +349,7 @@ impl<N: RealField> ConvexPolygonalFeature<N> {
         m2: &Isometry<N>,
         f2: FeatureId,
         proc2: Option<&dyn ContactPreprocessor<N>>,
-        manifold: &mut ContactManifold<N>,
+        manifold: &mut ContactManifold<N, C>,
     ) {
         let mut kinematic = ContactKinematic::new();
         let local1 = m1.inverse_transform_point(&c.world1);
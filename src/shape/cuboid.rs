@@ -1,6 +1,7 @@
 //! Support mapping based Cuboid shape.
 
 use crate::math::{Isometry, Point, Vector, DIM};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
 use na::{self, RealField, Unit};
 use std::f64;
@@ -23,10 +24,22 @@ pub struct Cuboid<N: RealField> {
 impl<N: RealField> Cuboid<N> {
     /// Creates a new box from its half-extents. Half-extents are the box half-width along each
     /// axis. Each half-extent must be positive.
+    ///
+    /// The half-extents are not validated. Use `Cuboid::try_new` to reject invalid half-extents.
     #[inline]
     pub fn new(half_extents: Vector<N>) -> Cuboid<N> {
         Cuboid { half_extents }
     }
+
+    /// Creates a new box from its half-extents, checking they are all finite and positive.
+    #[inline]
+    pub fn try_new(half_extents: Vector<N>) -> Result<Cuboid<N>, InvalidShape> {
+        for he in half_extents.iter() {
+            error::check_dimension(*he)?;
+        }
+
+        Ok(Cuboid { half_extents })
+    }
 }
 
 impl<N: RealField> Cuboid<N> {
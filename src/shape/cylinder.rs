@@ -1,6 +1,7 @@
 //! Support mapping based Cylinder shape.
 
 use crate::math::{Point, Vector};
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::SupportMap;
 use na::{self, RealField};
 
@@ -20,6 +21,9 @@ impl<N: RealField> Cylinder<N> {
     /// # Arguments:
     /// * `half_height` - the half length of the cylinder along the `y` axis.
     /// * `radius` - the length of the cylinder along all other axis.
+    ///
+    /// Panics if one of the dimensions is not positive. Use `Cylinder::try_new` to get an
+    /// error instead.
     pub fn new(half_height: N, radius: N) -> Cylinder<N> {
         assert!(half_height.is_positive() && radius.is_positive());
 
@@ -29,6 +33,17 @@ impl<N: RealField> Cylinder<N> {
         }
     }
 
+    /// Creates a new cylinder, checking its dimensions are finite and positive.
+    pub fn try_new(half_height: N, radius: N) -> Result<Cylinder<N>, InvalidShape> {
+        error::check_dimension(half_height)?;
+        error::check_dimension(radius)?;
+
+        Ok(Cylinder {
+            half_height,
+            radius,
+        })
+    }
+
     /// The cylinder half length along the `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` field directly.")]
@@ -0,0 +1,82 @@
+#[cfg(feature = "dim3")]
+use na::Point3;
+use na::RealField;
+use std::fmt;
+
+use crate::math::Point;
+
+/// Error indicating that a shape cannot be built from the given data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidShape {
+    /// A dimension (radius, half-extent, half-height, etc.) is NaN or infinite.
+    NonFiniteDimension,
+    /// A dimension (radius, half-extent, half-height, etc.) is zero or negative.
+    NonPositiveDimension,
+    /// The vertex with the given index has a NaN or infinite coordinate.
+    NonFiniteVertex(usize),
+    /// The face with the given index references a vertex that does not exist.
+    InvalidIndex(usize),
+    /// The number of texture coordinates differs from the number of vertices.
+    InvalidUVs,
+    /// The shape has degenerate edges or faces, or its topology is not the one of a convex shape.
+    Degenerate,
+    /// The shape was serialized with an unsupported version of its serialized representation.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for InvalidShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidShape::NonFiniteDimension => f.pad("shape dimension is not finite"),
+            InvalidShape::NonPositiveDimension => f.pad("shape dimension is not positive"),
+            InvalidShape::NonFiniteVertex(i) => write!(f, "shape vertex {} is not finite", i),
+            InvalidShape::InvalidIndex(i) => {
+                write!(f, "shape face {} references a missing vertex", i)
+            }
+            InvalidShape::InvalidUVs => f.pad("shape uvs and vertices counts differ"),
+            InvalidShape::Degenerate => f.pad("shape is degenerate"),
+            InvalidShape::UnsupportedVersion(v) => {
+                write!(f, "shape serialization version {} is not supported", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidShape {}
+
+/// Checks that `dim` is finite and strictly positive.
+pub(crate) fn check_dimension<N: RealField>(dim: N) -> Result<(), InvalidShape> {
+    if !dim.is_finite() {
+        Err(InvalidShape::NonFiniteDimension)
+    } else if dim <= N::zero() {
+        Err(InvalidShape::NonPositiveDimension)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that all the coordinates of all the given points are finite.
+pub(crate) fn check_vertices<N: RealField>(points: &[Point<N>]) -> Result<(), InvalidShape> {
+    match points
+        .iter()
+        .position(|pt| pt.coords.iter().any(|x| !x.is_finite()))
+    {
+        Some(i) => Err(InvalidShape::NonFiniteVertex(i)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that all the given faces only reference existing vertices.
+#[cfg(feature = "dim3")]
+pub(crate) fn check_indices(
+    indices: &[Point3<usize>],
+    num_vertices: usize,
+) -> Result<(), InvalidShape> {
+    match indices
+        .iter()
+        .position(|face| face.iter().any(|i| *i >= num_vertices))
+    {
+        Some(i) => Err(InvalidShape::InvalidIndex(i)),
+        None => Ok(()),
+    }
+}
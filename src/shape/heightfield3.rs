@@ -1,9 +1,10 @@
 use na::{DMatrix, Point3, RealField};
 
 use crate::bounding_volume::AABB;
-use crate::math::Vector;
-use crate::query::{Contact, ContactKinematic, ContactPreprocessor};
+use crate::math::{Isometry, Vector};
+use crate::query::{Contact, ContactKinematic, ContactPreprocessor, PointProjection, PointQuery};
 use crate::shape::{FeatureId, Triangle};
+use std::mem;
 
 bitflags! {
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -39,13 +40,7 @@ impl<N: RealField> HeightField<N> {
             heights.nrows() > 1 && heights.ncols() > 1,
             "A heightfield heights must have at least 2 rows and columns."
         );
-        let max = heights.max();
-        let min = heights.min();
-        let hscale = scale * na::convert::<_, N>(0.5);
-        let aabb = AABB::new(
-            Point3::new(-hscale.x, min * scale.y, -hscale.z),
-            Point3::new(hscale.x, max * scale.y, hscale.z),
-        );
+        let aabb = Self::full_aabb(&heights, &scale);
         let num_triangles = (heights.nrows() - 1) * (heights.ncols() - 1) * 2;
         let status = DMatrix::repeat(
             heights.nrows() - 1,
@@ -227,8 +222,23 @@ impl<N: RealField> HeightField<N> {
     }
 
     /// Set the status of the `(i, j)`-th cell.
+    ///
+    /// The AABB of this heightfield is enlarged to enclose the triangles this restores, if any.
+    /// It is not shrunk if this removes triangles, since this would require to traverse all the
+    /// cells: call `self.refit_aabb()` after removing triangles to make it tight again.
     pub fn set_cell_status(&mut self, i: usize, j: usize, status: HeightFieldCellStatus) {
-        self.status[(i, j)] = status
+        let old_status = mem::replace(&mut self.status[(i, j)], status);
+        let restored = old_status & !status & HeightFieldCellStatus::CELL_REMOVED;
+
+        if !restored.is_empty() {
+            let (tri1, tri2) = self.triangles_at(i, j);
+
+            for tri in tri1.iter().chain(tri2.iter()) {
+                for pt in tri.vertices() {
+                    self.aabb.take_point(*pt);
+                }
+            }
+        }
     }
 
     /// The statuses of all the cells of this heightfield.
@@ -237,6 +247,8 @@ impl<N: RealField> HeightField<N> {
     }
 
     /// The mutable statuses of all the cells of this heightfield.
+    ///
+    /// Call `self.refit_aabb()` after removing or restoring triangles this way.
     pub fn cells_statuses_mut(&mut self) -> &mut DMatrix<HeightFieldCellStatus> {
         &mut self.status
     }
@@ -272,10 +284,44 @@ impl<N: RealField> HeightField<N> {
     }
 
     /// The AABB of this heightmap.
+    ///
+    /// It encloses all the triangles that have not been removed. Right after a call to
+    /// `self.refit_aabb()`, it only encloses those triangles, unless they all have been removed in
+    /// which case this is the AABB of the whole heightfield.
     pub fn aabb(&self) -> &AABB<N> {
         &self.aabb
     }
 
+    /// Recomputes the AABB of this heightfield from the triangles that have not been removed.
+    ///
+    /// This traverses all the cells of this heightfield.
+    pub fn refit_aabb(&mut self) {
+        let mut aabb = AABB::new_invalid();
+
+        for tri in self.triangles() {
+            for pt in tri.vertices() {
+                aabb.take_point(*pt);
+            }
+        }
+
+        self.aabb = if aabb.mins.x <= aabb.maxs.x {
+            aabb
+        } else {
+            Self::full_aabb(&self.heights, &self.scale)
+        };
+    }
+
+    fn full_aabb(heights: &DMatrix<N>, scale: &Vector<N>) -> AABB<N> {
+        let max = heights.max();
+        let min = heights.min();
+        let hscale = scale * na::convert::<_, N>(0.5);
+
+        AABB::new(
+            Point3::new(-hscale.x, min * scale.y, -hscale.z),
+            Point3::new(hscale.x, max * scale.y, hscale.z),
+        )
+    }
+
     /// Converts the FeatureID of the left or right triangle at the cell `(i, j)` into a FeatureId
     /// of the whole heightfield.
     pub fn convert_triangle_feature_id(
@@ -453,6 +499,77 @@ impl<N: RealField> HeightField<N> {
             }
         }
     }
+
+    /// Projects a point on this heightfield and returns the index of the cell containing the projection.
+    ///
+    /// This computes the closest point on the heightfield surface, which differs from its vertical
+    /// projection near steep slopes. Cells are visited by increasing distance from the cell
+    /// below the point until they are known to be further than the best projection found so far.
+    /// Returns the point itself and the cell below it if all the triangles have been removed.
+    pub fn project_point_and_cell(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point3<N>,
+    ) -> (PointProjection<N>, (usize, usize)) {
+        let ls_pt = m.inverse_transform_point(pt);
+        let _0_5: N = na::convert(0.5);
+
+        // The cell below the point, clamped to the heightfield bounds.
+        let scaled_pt = ls_pt.coords.component_div(&self.scale);
+        let j0 = self.quantize_floor(
+            na::clamp(scaled_pt.x, -_0_5, _0_5),
+            self.unit_cell_width(),
+            self.ncols(),
+        );
+        let i0 = self.quantize_floor(
+            na::clamp(scaled_pt.z, -_0_5, _0_5),
+            self.unit_cell_height(),
+            self.nrows(),
+        );
+
+        let min_cell_size = self.cell_width().min(self.cell_height());
+        let max_ring = self.nrows().max(self.ncols());
+        let mut best_dist = N::max_value();
+        let mut best = (PointProjection::new(false, ls_pt), (i0, j0));
+
+        for ring in 0..max_ring {
+            if ring > 0 && min_cell_size * na::convert((ring - 1) as f64) > best_dist {
+                // All the remaining cells are further than the best projection.
+                break;
+            }
+
+            let imin = i0.saturating_sub(ring);
+            let jmin = j0.saturating_sub(ring);
+            let imax = (i0 + ring).min(self.nrows() - 1);
+            let jmax = (j0 + ring).min(self.ncols() - 1);
+
+            for i in imin..=imax {
+                for j in jmin..=jmax {
+                    let on_ring =
+                        i + ring == i0 || i == i0 + ring || j + ring == j0 || j == j0 + ring;
+
+                    if !on_ring {
+                        continue;
+                    }
+
+                    let (tri1, tri2) = self.triangles_at(i, j);
+
+                    for tri in tri1.iter().chain(tri2.iter()) {
+                        let proj = tri.project_point(&Isometry::identity(), &ls_pt, false);
+                        let dist = na::distance(&ls_pt, &proj.point);
+
+                        if dist < best_dist {
+                            best_dist = dist;
+                            best = (PointProjection::new(false, proj.point), (i, j));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (proj, cell) = best;
+        (PointProjection::new(false, m * proj.point), cell)
+    }
 }
 
 #[allow(dead_code)]
@@ -0,0 +1,61 @@
+//! Minkowski sum of two support-mapped shapes.
+
+use crate::math::{Point, Vector};
+use crate::shape::{ShapeHandle, SupportMap};
+use na::RealField;
+
+/// The Minkowski sum of two support-mapped shapes.
+///
+/// The support function of this shape is `support1(dir) + support2(dir)`. Both shapes are
+/// expressed in the local-space of the sum, so summing a shape with a `Ball` centered at the
+/// origin inflates it by the radius of the ball, e.g., for conservative collision detection.
+#[derive(Clone)]
+pub struct MinkowskiSum<N: RealField> {
+    shape1: ShapeHandle<N>,
+    shape2: ShapeHandle<N>,
+}
+
+impl<N: RealField> MinkowskiSum<N> {
+    /// Creates the Minkowski sum of `shape1` and `shape2`.
+    ///
+    /// Panics if `shape1` or `shape2` does not implement the `SupportMap` trait.
+    pub fn new(shape1: ShapeHandle<N>, shape2: ShapeHandle<N>) -> MinkowskiSum<N> {
+        assert!(
+            shape1.is_support_map() && shape2.is_support_map(),
+            "A Minkowski sum can only be computed between support-mapped shapes."
+        );
+
+        MinkowskiSum { shape1, shape2 }
+    }
+
+    /// The first shape of this sum.
+    #[inline]
+    pub fn shape1(&self) -> &ShapeHandle<N> {
+        &self.shape1
+    }
+
+    /// The second shape of this sum.
+    #[inline]
+    pub fn shape2(&self) -> &ShapeHandle<N> {
+        &self.shape2
+    }
+}
+
+impl<N: RealField> SupportMap<N> for MinkowskiSum<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The constructor ensures both shapes are support maps.
+        let pt1 = self
+            .shape1
+            .as_support_map()
+            .unwrap()
+            .local_support_point(dir);
+        let pt2 = self
+            .shape2
+            .as_support_map()
+            .unwrap()
+            .local_support_point(dir);
+
+        pt1 + pt2.coords
+    }
+}
@@ -1,5 +1,7 @@
 //! Collision shapes supported by ncollide.
 
+#[cfg(feature = "dim2")]
+pub use self::arc::Arc;
 pub use self::ball::Ball;
 pub use self::capsule::Capsule;
 #[doc(inline)]
@@ -20,17 +22,23 @@ pub use self::cuboid::Cuboid;
 #[cfg(feature = "dim3")]
 pub use self::cylinder::Cylinder;
 pub use self::deformable_shape::{DeformableShape, DeformationsType};
+pub use self::error::InvalidShape;
 //#[cfg(feature = "dim3")]
 //pub use self::deformable_trimesh::DeformableTriMesh;
 #[cfg(feature = "dim2")]
 pub use self::heightfield2::HeightField;
 #[cfg(feature = "dim3")]
 pub use self::heightfield3::{HeightField, HeightFieldCellStatus};
+pub use self::minkowski_sum::MinkowskiSum;
+pub use self::morph_shape::MorphShape;
 pub use self::plane::Plane;
 pub use self::polyline::Polyline;
+pub use self::round_shape::RoundShape;
+pub use self::scaled_shape::ScaledShape;
 pub use self::segment::{Segment, SegmentPointLocation};
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
+pub use self::slab::Slab;
 #[doc(inline)]
 pub use self::support_map::SupportMap;
 #[cfg(feature = "dim3")]
@@ -38,7 +46,11 @@ pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
 pub use self::triangle::{Triangle, TrianglePointLocation};
 #[cfg(feature = "dim3")]
 pub use self::trimesh::{FaceAdjacentToEdge, TriMesh, TriMeshEdge, TriMeshFace, TriMeshVertex};
+#[cfg(feature = "dim3")]
+pub use self::wedge::Wedge;
 
+#[cfg(feature = "dim2")]
+mod arc;
 mod ball;
 mod capsule;
 #[doc(hidden)]
@@ -59,16 +71,22 @@ mod cuboid;
 #[cfg(feature = "dim3")]
 mod cylinder;
 mod deformable_shape;
+mod error;
 #[cfg(feature = "dim2")]
 mod heightfield2;
 #[cfg(feature = "dim3")]
 mod heightfield3;
+mod minkowski_sum;
+mod morph_shape;
 mod plane;
 mod polyline;
+mod round_shape;
+mod scaled_shape;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
 mod shape_impl;
+mod slab;
 #[doc(hidden)]
 pub mod support_map;
 #[cfg(feature = "dim3")]
@@ -76,3 +94,5 @@ mod tetrahedron;
 mod triangle;
 #[cfg(feature = "dim3")]
 mod trimesh;
+#[cfg(feature = "dim3")]
+mod wedge;
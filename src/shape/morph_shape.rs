@@ -0,0 +1,86 @@
+//! Support mapping based interpolation between two shapes.
+
+use crate::math::{Point, Vector};
+use crate::shape::{ShapeHandle, SupportMap};
+use na::RealField;
+
+/// A convex shape interpolating between two support-mapped shapes.
+///
+/// The support function of this shape is `(1 - t) * support1(dir) + t * support2(dir)`, i.e.,
+/// this is the Minkowski sum of the first shape scaled by `1 - t` and of the second shape scaled
+/// by `t`. It is equal to the first shape when `t == 0` and to the second one when `t == 1`.
+#[derive(Clone)]
+pub struct MorphShape<N: RealField> {
+    shape1: ShapeHandle<N>,
+    shape2: ShapeHandle<N>,
+    t: N,
+}
+
+impl<N: RealField> MorphShape<N> {
+    /// Creates a new shape interpolating between `shape1` and `shape2`.
+    ///
+    /// The interpolation parameter `t` is clamped to `[0, 1]`. Panics if `shape1` or `shape2`
+    /// does not implement the `SupportMap` trait.
+    pub fn new(shape1: ShapeHandle<N>, shape2: ShapeHandle<N>, t: N) -> MorphShape<N> {
+        assert!(
+            shape1.is_support_map() && shape2.is_support_map(),
+            "A morph shape can only interpolate between support-mapped shapes."
+        );
+
+        MorphShape {
+            shape1,
+            shape2,
+            t: clamp_parameter(t),
+        }
+    }
+
+    /// The shape this morph shape is equal to when `self.t() == 0`.
+    #[inline]
+    pub fn shape1(&self) -> &ShapeHandle<N> {
+        &self.shape1
+    }
+
+    /// The shape this morph shape is equal to when `self.t() == 1`.
+    #[inline]
+    pub fn shape2(&self) -> &ShapeHandle<N> {
+        &self.shape2
+    }
+
+    /// The interpolation parameter, in `[0, 1]`.
+    #[inline]
+    pub fn t(&self) -> N {
+        self.t
+    }
+
+    /// Sets the interpolation parameter. It is clamped to `[0, 1]`.
+    ///
+    /// If this shape is attached to a collision object, use
+    /// `CollisionObject::set_morph_parameter` instead so its bounding volume gets updated.
+    #[inline]
+    pub fn set_t(&mut self, t: N) {
+        self.t = clamp_parameter(t)
+    }
+}
+
+fn clamp_parameter<N: RealField>(t: N) -> N {
+    na::clamp(t, N::zero(), N::one())
+}
+
+impl<N: RealField> SupportMap<N> for MorphShape<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The constructor ensures both shapes are support maps.
+        let pt1 = self
+            .shape1
+            .as_support_map()
+            .unwrap()
+            .local_support_point(dir);
+        let pt2 = self
+            .shape2
+            .as_support_map()
+            .unwrap()
+            .local_support_point(dir);
+
+        pt1 * (N::one() - self.t) + pt2.coords * self.t
+    }
+}
@@ -298,6 +298,16 @@ impl<N: RealField> Polyline<N> {
         Segment::new(self.points[idx.x], self.points[idx.y])
     }
 
+    /// An iterator through the segments of all the edges of this polyline, ordered like
+    /// `self.edges()`.
+    ///
+    /// A closed polyline has an edge joining its last point to its first one, so this edge is
+    /// yielded as well.
+    #[inline]
+    pub fn segments<'a>(&'a self) -> impl ExactSizeIterator<Item = Segment<N>> + 'a {
+        (0..self.edges.len()).map(move |i| self.segment_at(i))
+    }
+
     /// The optimization structure used by this polyline.
     #[inline]
     pub fn bvt(&self) -> &BVT<usize, AABB<N>> {
@@ -0,0 +1,69 @@
+//! Support-mapped shape inflated by a radius.
+
+use crate::math::{Point, Vector};
+use crate::shape::error::{self, InvalidShape};
+use crate::shape::{ShapeHandle, SupportMap};
+use na::RealField;
+
+/// A support-mapped shape with rounded borders.
+///
+/// This is the set of points at a distance smaller than `radius` from the wrapped shape. A
+/// rounded `Segment` is a capsule with an arbitrary axis, and a rounded `Triangle` is a slab with
+/// a thickness of twice the radius.
+#[derive(Clone)]
+pub struct RoundShape<N: RealField> {
+    shape: ShapeHandle<N>,
+    radius: N,
+}
+
+impl<N: RealField> RoundShape<N> {
+    /// Creates a new shape made of `shape` with its borders rounded by `radius`.
+    ///
+    /// The radius is not validated. Use `RoundShape::try_new` to reject invalid radii.
+    /// Panics if `shape` does not implement the `SupportMap` trait.
+    pub fn new(shape: ShapeHandle<N>, radius: N) -> RoundShape<N> {
+        assert!(
+            shape.is_support_map(),
+            "Only support-mapped shapes can be rounded."
+        );
+
+        RoundShape { shape, radius }
+    }
+
+    /// Creates a new rounded shape, checking its radius is finite and positive.
+    ///
+    /// Panics if `shape` does not implement the `SupportMap` trait.
+    pub fn try_new(shape: ShapeHandle<N>, radius: N) -> Result<RoundShape<N>, InvalidShape> {
+        error::check_dimension(radius)?;
+        Ok(RoundShape::new(shape, radius))
+    }
+
+    /// The shape being rounded.
+    #[inline]
+    pub fn shape(&self) -> &ShapeHandle<N> {
+        &self.shape
+    }
+
+    /// The radius of the rounded borders.
+    #[inline]
+    pub fn radius(&self) -> N {
+        self.radius
+    }
+}
+
+impl<N: RealField> SupportMap<N> for RoundShape<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The constructor ensures the shape is a support map.
+        let pt = self
+            .shape
+            .as_support_map()
+            .unwrap()
+            .local_support_point(dir);
+
+        match dir.try_normalize(N::default_epsilon()) {
+            Some(dir) => pt + dir * self.radius,
+            None => pt,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Shape scaled non-uniformly along the local axes.
+
+use crate::math::{Point, Vector};
+use crate::shape::error::{self, InvalidShape};
+use crate::shape::{ShapeHandle, SupportMap};
+use na::RealField;
+
+/// A shape scaled non-uniformly along the axes of its local space.
+///
+/// The point `pt` of the wrapped shape becomes the point `pt.coords.component_mul(scale)` of the
+/// scaled shape. Support-point queries and ray casts are computed by transforming their input
+/// into the local space of the wrapped shape, so this works with any shape implementing
+/// `SupportMap` (e.g. `ConvexHull`), as well as with the ray casts on any shape supporting them
+/// (e.g. `TriMesh`). Point queries are only supported if the wrapped shape is a support map,
+/// because a non-uniform scale does not preserve distances.
+#[derive(Clone)]
+pub struct ScaledShape<N: RealField> {
+    shape: ShapeHandle<N>,
+    scale: Vector<N>,
+}
+
+impl<N: RealField> ScaledShape<N> {
+    /// Creates a new shape scaling `shape` by the factor `scale[i]` along the `i`-th local axis.
+    ///
+    /// Returns an error if a component of `scale` is not finite or not strictly positive.
+    pub fn new(shape: ShapeHandle<N>, scale: Vector<N>) -> Result<ScaledShape<N>, InvalidShape> {
+        for s in scale.iter() {
+            error::check_dimension(*s)?;
+        }
+
+        Ok(ScaledShape { shape, scale })
+    }
+
+    /// The shape being scaled.
+    #[inline]
+    pub fn shape(&self) -> &ShapeHandle<N> {
+        &self.shape
+    }
+
+    /// The scale factors along each local axis.
+    #[inline]
+    pub fn scale(&self) -> &Vector<N> {
+        &self.scale
+    }
+
+    /// Maps a point from the local space of the wrapped shape to the local space of this shape.
+    #[inline]
+    pub fn scale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_mul(&self.scale))
+    }
+
+    /// Maps a point from the local space of this shape to the local space of the wrapped shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_div(&self.scale))
+    }
+}
+
+impl<N: RealField> SupportMap<N> for ScaledShape<N> {
+    /// The support point of this shape.
+    ///
+    /// Panics if the wrapped shape is not a support map.
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The support point of `scale * shape` toward `dir` is the scaled support point of
+        // `shape` toward `scale * dir`.
+        let pt = self
+            .shape
+            .as_support_map()
+            .expect("The scaled shape is not a support map.")
+            .local_support_point(&dir.component_mul(&self.scale));
+
+        self.scale_point(&pt)
+    }
+}
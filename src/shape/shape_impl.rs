@@ -2,13 +2,14 @@ use crate::bounding_volume::{self, BoundingSphere, AABB};
 use crate::math::{Isometry, Vector};
 use crate::query::{PointQuery, RayCast};
 #[cfg(feature = "dim2")]
-use crate::shape::ConvexPolygon;
+use crate::shape::{Arc, ConvexPolygon};
 use crate::shape::{
     Ball, Capsule, CompositeShape, Compound, ConvexPolyhedron, Cuboid, DeformableShape, FeatureId,
-    HeightField, Plane, Polyline, Segment, Shape, SupportMap,
+    HeightField, MinkowskiSum, MorphShape, Plane, Polyline, RoundShape, ScaledShape, Segment,
+    Shape, Slab, SupportMap,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{ConvexHull, TriMesh, Triangle};
+use crate::shape::{Cone, ConvexHull, Cylinder, TriMesh, Triangle, Wedge};
 use na::{RealField, Unit};
 
 macro_rules! impl_as_convex_polyhedron (
@@ -161,6 +162,44 @@ impl<N: RealField> Shape<N> for Capsule<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Cone<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Cylinder<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> Shape<N> for ConvexHull<N> {
     impl_shape_common!();
@@ -168,6 +207,13 @@ impl<N: RealField> Shape<N> for ConvexHull<N> {
     impl_as_convex_polyhedron!();
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Wedge<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+    impl_as_convex_polyhedron!();
+}
+
 #[cfg(feature = "dim2")]
 impl<N: RealField> Shape<N> for ConvexPolygon<N> {
     impl_shape_common!();
@@ -175,6 +221,125 @@ impl<N: RealField> Shape<N> for ConvexPolygon<N> {
     impl_as_convex_polyhedron!();
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField> Shape<N> for Arc<N> {
+    impl_shape_common!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for MinkowskiSum<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for MorphShape<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for RoundShape<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for ScaledShape<N> {
+    #[inline]
+    fn aabb(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn local_aabb(&self) -> AABB<N> {
+        bounding_volume::local_aabb(self)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&dyn RayCast<N>> {
+        if self.shape().as_ray_cast().is_some() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&dyn PointQuery<N>> {
+        if self.is_support_map() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&dyn SupportMap<N>> {
+        if self.is_support_map() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        self.shape().is_support_map()
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
 impl<N: RealField> Shape<N> for Compound<N> {
     impl_shape_common!();
     impl_as_composite_shape!();
@@ -282,3 +447,23 @@ impl<N: RealField> Shape<N> for Plane<N> {
         dir.dot(&world_normal) <= N::zero()
     }
 }
+
+impl<N: RealField> Shape<N> for Slab<N> {
+    impl_shape_common!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let world_normal = m * self.normal;
+
+        match feature {
+            FeatureId::Face(0) => dir.dot(&world_normal) <= N::zero(),
+            FeatureId::Face(1) => dir.dot(&world_normal) >= N::zero(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! An infinite slab bounded by two parallel planes.
+use crate::math::{Isometry, Translation, Vector};
+use crate::shape::Plane;
+use na::{self, RealField, Unit};
+
+/// The infinite region between two parallel planes.
+///
+/// This is the set of points `x` such that `|normal.dot(x) - offset| <= thickness / 2`, i.e., the
+/// plane orthogonal to `normal` passing at the distance `offset` from the origin, thickened by
+/// `thickness`.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Slab<N: RealField> {
+    /// The normal of the planes bounding this slab.
+    pub normal: Unit<Vector<N>>,
+    /// The signed distance between the origin and the middle plane of this slab.
+    pub offset: N,
+    /// The distance between the two planes bounding this slab.
+    pub thickness: N,
+}
+
+impl<N: RealField> Slab<N> {
+    /// Builds a new slab from its normal, offset and thickness.
+    #[inline]
+    pub fn new(normal: Unit<Vector<N>>, offset: N, thickness: N) -> Slab<N> {
+        Slab {
+            normal,
+            offset,
+            thickness,
+        }
+    }
+
+    /// Half of the thickness of this slab.
+    #[inline]
+    pub fn half_thickness(&self) -> N {
+        self.thickness * na::convert(0.5)
+    }
+
+    /// The two planes bounding this slab, together with their local-space positions.
+    ///
+    /// The first plane has the normal `self.normal` and is identified by `FeatureId::Face(0)`.
+    /// The second plane has the normal `-self.normal` and is identified by `FeatureId::Face(1)`.
+    /// The slab is the intersection of the two half-spaces.
+    pub fn planes(&self) -> [(Isometry<N>, Plane<N>); 2] {
+        let h = self.half_thickness();
+        let top = Translation::from(*self.normal * (self.offset + h));
+        let bottom = Translation::from(*self.normal * (self.offset - h));
+
+        [
+            (
+                Isometry::from_parts(top, na::one()),
+                Plane::new(self.normal),
+            ),
+            (
+                Isometry::from_parts(bottom, na::one()),
+                Plane::new(-self.normal),
+            ),
+        ]
+    }
+}
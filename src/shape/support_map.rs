@@ -1,6 +1,6 @@
 //! Traits for support mapping based shapes.
 
-use crate::math::{Isometry, Point, Vector};
+use crate::math::{Isometry, Point, Similarity, Vector};
 use na::{RealField, Unit};
 
 /// Traits of convex shapes representable by a support mapping function.
@@ -33,4 +33,17 @@ pub trait SupportMap<N: RealField> {
         let local_dir = Unit::new_unchecked(transform.inverse_transform_vector(dir));
         transform * self.local_support_point_toward(&local_dir)
     }
+
+    /// Evaluates the support function of this shape transformed by the similarity `transform`.
+    ///
+    /// Because the scaling factor is positive, this is the local support point toward the
+    /// un-rotated direction, scaled then transformed by the isometric part of `transform`.
+    fn support_point_with_similarity(
+        &self,
+        transform: &Similarity<N>,
+        dir: &Vector<N>,
+    ) -> Point<N> {
+        let local_dir = transform.isometry.inverse_transform_vector(dir);
+        transform * self.local_support_point(&local_dir)
+    }
 }
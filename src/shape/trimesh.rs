@@ -8,6 +8,7 @@ use crate::query::{
     Contact, ContactKinematic, ContactPrediction, ContactPreprocessor, LocalShapeApproximation,
     NeighborhoodGeometry,
 };
+use crate::shape::error::{self, InvalidShape};
 use crate::shape::{
     CompositeShape, DeformableShape, DeformationsType, FeatureId, Segment, Shape, Triangle,
 };
@@ -80,7 +81,16 @@ pub struct TriMeshVertex {
 }
 
 /// A 3d triangle mesh.
+///
+/// A serialized mesh only stores its vertices, faces, uvs, and whether it is oriented. Its
+/// topology and its BVT are rebuilt when it is deserialized, after its data have been validated
+/// like with `TriMesh::try_new`. Thus a deserialized mesh behaves exactly like the mesh
+/// that would be built by `TriMesh::new` from the same data.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "SerializedTriMesh<N>", try_from = "SerializedTriMesh<N>")
+)]
 #[derive(Clone)]
 pub struct TriMesh<N: RealField> {
     bvt: BVT<usize, AABB<N>>,
@@ -95,8 +105,74 @@ pub struct TriMesh<N: RealField> {
     oriented: bool,
 }
 
+/// The version of the serialized representation of a `TriMesh`.
+#[cfg(feature = "serde")]
+const TRIMESH_SERIALIZATION_VERSION: u32 = 1;
+
+/// The serialized representation of a `TriMesh`.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerializedTriMesh<N: RealField> {
+    version: u32,
+    points: Vec<Point<N>>,
+    indices: Vec<Point3<usize>>,
+    uvs: Option<Vec<Point2<N>>>,
+    oriented: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<N: RealField> From<TriMesh<N>> for SerializedTriMesh<N> {
+    fn from(mesh: TriMesh<N>) -> Self {
+        SerializedTriMesh {
+            version: TRIMESH_SERIALIZATION_VERSION,
+            indices: mesh.faces.iter().map(|f| f.indices).collect(),
+            points: mesh.points,
+            uvs: mesh.uvs,
+            oriented: mesh.oriented,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<N: RealField> std::convert::TryFrom<SerializedTriMesh<N>> for TriMesh<N> {
+    type Error = InvalidShape;
+
+    fn try_from(data: SerializedTriMesh<N>) -> Result<Self, InvalidShape> {
+        if data.version != TRIMESH_SERIALIZATION_VERSION {
+            return Err(InvalidShape::UnsupportedVersion(data.version));
+        }
+
+        let mut mesh = TriMesh::try_new(data.points, data.indices, data.uvs)?;
+        mesh.set_oriented(data.oriented);
+        Ok(mesh)
+    }
+}
+
 impl<N: RealField> TriMesh<N> {
+    /// Builds a new mesh, checking that all its vertices are finite and all its faces valid.
+    ///
+    /// Returns `InvalidShape::NonFiniteVertex` if one of the `points` has a NaN or infinite
+    /// coordinate, `InvalidShape::InvalidIndex` if one of the `indices` does not identify
+    /// one of the `points`, and `InvalidShape::InvalidUVs` if there is not exactly one uv per
+    /// point.
+    pub fn try_new(
+        points: Vec<Point<N>>,
+        indices: Vec<Point3<usize>>,
+        uvs: Option<Vec<Point2<N>>>,
+    ) -> Result<TriMesh<N>, InvalidShape> {
+        error::check_vertices(&points)?;
+        error::check_indices(&indices, points.len())?;
+
+        if matches!(&uvs, Some(uvs) if uvs.len() != points.len()) {
+            return Err(InvalidShape::InvalidUVs);
+        }
+
+        Ok(Self::new(points, indices, uvs))
+    }
+
     /// Builds a new mesh.
+    ///
+    /// The vertices are not validated. Use `TriMesh::try_new` to reject non-finite vertices.
     pub fn new(
         points: Vec<Point<N>>,
         indices: Vec<Point3<usize>>,
@@ -798,6 +874,10 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
     }
 
     /// Updates all the degrees of freedom of this shape.
+    ///
+    /// The topology of the BVT is kept: only the AABBs of the triangles with a vertex that moved
+    /// farther than the deformation margin are recomputed, and the AABBs of their ancestors
+    /// are enlarged to contain them.
     fn set_deformations(&mut self, coords: &[N]) {
         assert!(
             coords.len() >= self.points.len() * DIM,
@@ -1024,3 +1104,83 @@ impl<'a, N: RealField> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N>
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::{SerializedTriMesh, TriMesh, TRIMESH_SERIALIZATION_VERSION};
+    use crate::math::{Isometry, Point, Vector};
+    use crate::query::{self, Ray, RayCast};
+    use crate::shape::{Ball, InvalidShape};
+    use na::{Point2, Point3};
+    use std::convert::TryFrom;
+
+    // A pyramid with a square base, open at its base.
+    fn pyramid() -> TriMesh<f64> {
+        let points = vec![
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, 1.0),
+            Point::new(-1.0, 0.0, 1.0),
+            Point::new(0.0, 1.0, 0.0),
+        ];
+        let indices = vec![
+            Point3::new(0, 4, 1),
+            Point3::new(1, 4, 2),
+            Point3::new(2, 4, 3),
+            Point3::new(3, 4, 0),
+        ];
+        let uvs = points.iter().map(|p| Point2::new(p.x, p.z)).collect();
+        let mut mesh = TriMesh::new(points, indices, Some(uvs));
+        mesh.set_oriented(true);
+        mesh
+    }
+
+    #[test]
+    fn serialized_trimesh_round_trip() {
+        let mesh = pyramid();
+        let data = SerializedTriMesh::from(mesh.clone());
+        assert_eq!(data.version, TRIMESH_SERIALIZATION_VERSION);
+        let restored = TriMesh::try_from(data).unwrap();
+
+        assert_eq!(restored.points(), mesh.points());
+        assert_eq!(restored.uvs(), mesh.uvs());
+        assert_eq!(restored.oriented(), mesh.oriented());
+
+        let m = Isometry::new(Vector::new(0.5, -0.2, 0.1), Vector::new(0.1, 0.3, -0.2));
+        let rays = [
+            Ray::new(Point::new(0.2, 5.0, 0.1), -Vector::y()),
+            Ray::new(Point::new(3.0, 0.4, -0.3), -Vector::x()),
+            Ray::new(Point::new(0.0, 0.3, 0.0), Vector::new(1.0, 0.2, 0.5)),
+        ];
+
+        for ray in rays.iter() {
+            let expected = mesh.toi_and_normal_with_ray(&m, ray, 10.0, true);
+            let actual = restored.toi_and_normal_with_ray(&m, ray, 10.0, true);
+            assert_eq!(
+                actual.map(|i| (i.toi, i.normal)),
+                expected.map(|i| (i.toi, i.normal))
+            );
+        }
+
+        let ball = Ball::new(0.5);
+        let m_ball = Isometry::translation(0.4, 1.0, 0.3);
+        assert_eq!(
+            query::contact(&m, &restored, &m_ball, &ball, 0.1),
+            query::contact(&m, &mesh, &m_ball, &ball, 0.1)
+        );
+    }
+
+    #[test]
+    fn serialized_trimesh_with_unsupported_version_is_rejected() {
+        let mut data = SerializedTriMesh::from(pyramid());
+        data.version = TRIMESH_SERIALIZATION_VERSION + 1;
+
+        match TriMesh::try_from(data) {
+            Err(err) => assert_eq!(
+                err,
+                InvalidShape::UnsupportedVersion(TRIMESH_SERIALIZATION_VERSION + 1)
+            ),
+            Ok(_) => panic!("The mesh should have been rejected."),
+        }
+    }
+}
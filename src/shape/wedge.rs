@@ -0,0 +1,298 @@
+//! Support mapping based Wedge shape.
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::error::{self, InvalidShape};
+use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
+use na::{self, RealField, Unit};
+use std::f64;
+
+/// Shape of a ramp, i.e., a right triangular prism.
+///
+/// Its cross-section along the `z` axis is the right triangle with vertices
+/// `(-hx, -hy)`, `(hx, -hy)` and `(-hx, hy)`, where `hx`, `hy` and `hz` are the components of
+/// `half_extents`. So its sloped face starts at the bottom of its `+x` side and reaches its top
+/// on its `-x` side. Its AABB is the box with the same half-extents.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Wedge<N: RealField> {
+    /// The half-extents of the bounding box of the wedge.
+    pub half_extents: Vector<N>,
+}
+
+// NOTE: format of the wedge feature id:
+//
+// FeatureId::Vertex(id): `id % 3` identifies a vertex of the triangular cross-section (in the
+//                        order given in the documentation of `Wedge`), and `id / 3` is 0 for the
+//                        vertices with a negative `z` coordinate, 1 otherwise.
+// FeatureId::Edge(id): index of the edge in `EDGES`.
+// FeatureId::Face(id): index of the face in `FACES`, i.e., bottom, back, slope, then the -z and +z
+//                      triangular faces.
+const EDGES: [[usize; 2]; 9] = [
+    [0, 1],
+    [1, 2],
+    [2, 0],
+    [3, 4],
+    [4, 5],
+    [5, 3],
+    [0, 3],
+    [1, 4],
+    [2, 5],
+];
+
+// The vertices and edges of each face, in counter-clockwise order seen from outside of the wedge.
+// The i-th edge starts at the i-th vertex.
+const FACES: [&[(usize, usize)]; 5] = [
+    &[(0, 0), (1, 7), (4, 3), (3, 6)],
+    &[(0, 6), (3, 5), (5, 8), (2, 2)],
+    &[(1, 1), (2, 8), (5, 4), (4, 7)],
+    &[(0, 2), (2, 1), (1, 0)],
+    &[(3, 3), (4, 4), (5, 5)],
+];
+
+impl<N: RealField> Wedge<N> {
+    /// Creates a new wedge from the half-extents of its bounding box.
+    ///
+    /// The half-extents are not validated. Use `Wedge::try_new` to reject invalid half-extents.
+    #[inline]
+    pub fn new(half_extents: Vector<N>) -> Wedge<N> {
+        Wedge { half_extents }
+    }
+
+    /// Creates a new wedge from the half-extents of its bounding box, checking they are all
+    /// finite and positive.
+    #[inline]
+    pub fn try_new(half_extents: Vector<N>) -> Result<Wedge<N>, InvalidShape> {
+        for he in half_extents.iter() {
+            error::check_dimension(*he)?;
+        }
+
+        Ok(Wedge { half_extents })
+    }
+
+    /// The six vertices of this wedge.
+    pub fn vertices(&self) -> [Point<N>; 6] {
+        let mut res = [Point::origin(); 6];
+
+        for (i, pt) in res.iter_mut().enumerate() {
+            *pt = self.vertex_at(i);
+        }
+
+        res
+    }
+
+    /// The normal of the sloped face of this wedge.
+    pub fn slope_normal(&self) -> Unit<Vector<N>> {
+        let he = self.half_extents;
+        Unit::new_normalize(Vector::new(he.y, he.x, N::zero()))
+    }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_unit_vector(dir);
+
+        match feature {
+            FeatureId::Face(id) => ls_dir.dot(&self.face_normal(id)) <= N::zero(),
+            FeatureId::Edge(id) => (0..FACES.len())
+                .filter(|f| FACES[*f].iter().any(|(_, e)| *e == id))
+                .all(|f| ls_dir.dot(&self.face_normal(f)) <= N::zero()),
+            FeatureId::Vertex(id) => (0..FACES.len())
+                .filter(|f| FACES[*f].iter().any(|(v, _)| *v == id))
+                .all(|f| ls_dir.dot(&self.face_normal(f)) <= N::zero()),
+            FeatureId::Unknown => false,
+        }
+    }
+
+    fn vertex_at(&self, id: usize) -> Point<N> {
+        let he = self.half_extents;
+        let z = if id < 3 { -he.z } else { he.z };
+
+        match id % 3 {
+            0 => Point::new(-he.x, -he.y, z),
+            1 => Point::new(he.x, -he.y, z),
+            _ => Point::new(-he.x, he.y, z),
+        }
+    }
+
+    fn face_normal(&self, id: usize) -> Unit<Vector<N>> {
+        match id {
+            0 => -Vector::y_axis(),
+            1 => -Vector::x_axis(),
+            2 => self.slope_normal(),
+            3 => -Vector::z_axis(),
+            _ => Vector::z_axis(),
+        }
+    }
+
+    fn support_vertex_id(&self, local_dir: &Vector<N>) -> usize {
+        let he = self.half_extents;
+        // Support point of the triangular cross-section.
+        let dots = [
+            -local_dir.x * he.x - local_dir.y * he.y,
+            local_dir.x * he.x - local_dir.y * he.y,
+            -local_dir.x * he.x + local_dir.y * he.y,
+        ];
+        let mut best = 0;
+
+        for i in 1..3 {
+            if dots[i] > dots[best] {
+                best = i;
+            }
+        }
+
+        if local_dir.z >= N::zero() {
+            best + 3
+        } else {
+            best
+        }
+    }
+
+    fn support_feature_id_toward_eps(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
+        let (seps, ceps) = eps.sin_cos();
+        let vid = self.support_vertex_id(local_dir);
+
+        // Check faces.
+        for (fid, face) in FACES.iter().enumerate() {
+            if face.iter().any(|(v, _)| *v == vid) && self.face_normal(fid).dot(local_dir) >= ceps {
+                return FeatureId::Face(fid);
+            }
+        }
+
+        // Check edges.
+        for (eid, edge) in EDGES.iter().enumerate() {
+            if edge[0] == vid || edge[1] == vid {
+                let dir = self.vertex_at(edge[1]) - self.vertex_at(edge[0]);
+
+                if dir.normalize().dot(local_dir).abs() <= seps {
+                    return FeatureId::Edge(eid);
+                }
+            }
+        }
+
+        // The vertex is the support feature.
+        FeatureId::Vertex(vid)
+    }
+}
+
+impl<N: RealField> SupportMap<N> for Wedge<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.vertex_at(self.support_vertex_id(dir))
+    }
+}
+
+impl<N: RealField> ConvexPolyhedron<N> for Wedge<N> {
+    fn vertex(&self, id: FeatureId) -> Point<N> {
+        self.vertex_at(id.unwrap_vertex())
+    }
+
+    fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
+        let edge = EDGES[id.unwrap_edge()];
+
+        (
+            self.vertex_at(edge[0]),
+            self.vertex_at(edge[1]),
+            FeatureId::Vertex(edge[0]),
+            FeatureId::Vertex(edge[1]),
+        )
+    }
+
+    fn face(&self, id: FeatureId, out: &mut ConvexPolygonalFeature<N>) {
+        out.clear();
+
+        let fid = id.unwrap_face();
+
+        for (vid, eid) in FACES[fid] {
+            out.push(self.vertex_at(*vid), FeatureId::Vertex(*vid));
+            out.push_edge_feature_id(FeatureId::Edge(*eid));
+        }
+
+        out.set_normal(self.face_normal(fid));
+        out.set_feature_id(id);
+        out.recompute_edge_normals();
+    }
+
+    fn feature_normal(&self, feature: FeatureId) -> Unit<Vector<N>> {
+        let faces = |f: &dyn Fn(&(usize, usize)) -> bool| {
+            let mut normal = Vector::zeros();
+
+            for (fid, face) in FACES.iter().enumerate() {
+                if face.iter().any(f) {
+                    normal += *self.face_normal(fid);
+                }
+            }
+
+            Unit::new_normalize(normal)
+        };
+
+        match feature {
+            FeatureId::Face(id) => self.face_normal(id),
+            FeatureId::Edge(id) => faces(&|(_, e)| *e == id),
+            FeatureId::Vertex(id) => faces(&|(v, _)| *v == id),
+            FeatureId::Unknown => panic!("Invalid feature ID: {:?}", feature),
+        }
+    }
+
+    fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let mut best_face = 0;
+        let mut max_dot = self.face_normal(0).dot(&ls_dir);
+
+        for i in 1..FACES.len() {
+            let dot = self.face_normal(i).dot(&ls_dir);
+
+            if dot > max_dot {
+                max_dot = dot;
+                best_face = i;
+            }
+        }
+
+        self.face(FeatureId::Face(best_face), out);
+        out.transform_by(m);
+    }
+
+    fn support_feature_toward(
+        &self,
+        transform: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        angle: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        out.clear();
+        let local_dir = transform.inverse_transform_unit_vector(dir);
+        let fid = self.support_feature_id_toward_eps(&local_dir, angle);
+
+        match fid {
+            FeatureId::Vertex(_) => {
+                let v = self.vertex(fid);
+                out.push(v, fid);
+                out.set_feature_id(fid);
+            }
+            FeatureId::Edge(_) => {
+                let edge = self.edge(fid);
+                out.push(edge.0, edge.2);
+                out.push(edge.1, edge.3);
+                out.set_feature_id(fid);
+                out.push_edge_feature_id(fid);
+            }
+            FeatureId::Face(_) => self.face(fid, out),
+            FeatureId::Unknown => unreachable!(),
+        }
+
+        out.transform_by(transform);
+    }
+
+    fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
+        let eps: N = na::convert(f64::consts::PI / 180.0);
+        self.support_feature_id_toward_eps(local_dir, eps)
+    }
+}
@@ -0,0 +1,384 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::procedural;
+use crate::query::{Ray, RayCast};
+use crate::shape::{Compound, ConvexHull, Cuboid, ShapeHandle, TriMesh};
+use crate::transformation;
+use crate::utils;
+use na::{self, Point3, RealField};
+use std::collections::{HashMap, HashSet};
+
+/// Number of voxels along the largest dimension of the AABB of the decomposed mesh.
+const VOXEL_RESOLUTION: usize = 32;
+
+/// Maximum number of splitting planes tested along each axis when a piece is split in two.
+const MAX_SPLITS_PER_AXIS: usize = 8;
+
+/// Maximum perturbation of the voxel corners, relative to the voxel size.
+const CORNER_PERTURBATION: f64 = 1.0e-3;
+
+/// Approximate convex decomposition of a closed triangle mesh into a compound shape.
+///
+/// The volume enclosed by `mesh` is voxelized, with `32` voxels along the largest dimension of
+/// its AABB. The set of voxels is then recursively split in two by axis-aligned planes until
+/// either `max_hulls` pieces are reached, or the concavity of each piece is smaller than
+/// `max_concavity`. The concavity of a piece is the fraction of the volume of its convex hull
+/// not covered by its voxels, so it lies between zero (the piece is convex) and one.
+///
+/// Each part of the returned compound is the convex hull of the voxels of one piece, or the box
+/// enclosing them if this convex hull could not be computed reliably, so the union of the parts
+/// covers the voxelized volume. Returns `None` if `mesh` does not enclose any voxel center, e.g.,
+/// if it is flat or not closed.
+pub fn convex_decomposition<N: RealField>(
+    mesh: &TriMesh<N>,
+    max_hulls: usize,
+    max_concavity: N,
+) -> Option<Compound<N>> {
+    assert!(max_hulls > 0, "At least one convex hull must be allowed.");
+
+    let (origin, voxel_size, voxels) = voxelize(mesh)?;
+    let mut pieces = vec![Piece::new(voxels)];
+
+    while pieces.len() < max_hulls {
+        let (worst, concavity) = pieces
+            .iter()
+            .enumerate()
+            .map(|(i, piece)| (i, piece.concavity()))
+            .fold(
+                (0, N::zero()),
+                |best, curr| {
+                    if curr.1 > best.1 {
+                        curr
+                    } else {
+                        best
+                    }
+                },
+            );
+
+        if concavity <= max_concavity {
+            break;
+        }
+
+        match pieces[worst].split() {
+            Some((left, right)) => {
+                pieces[worst] = left;
+                pieces.push(right);
+            }
+            None => break,
+        }
+    }
+
+    let parts = pieces
+        .into_iter()
+        .map(|piece| {
+            let hull = piece.hull.as_ref().and_then(|hull| {
+                let points: Vec<_> = hull
+                    .coords
+                    .iter()
+                    .map(|pt| origin + pt.coords * voxel_size)
+                    .collect();
+                let indices: Vec<usize> = hull
+                    .flat_indices()
+                    .into_iter()
+                    .map(|i| i as usize)
+                    .collect();
+
+                ConvexHull::try_new(points, &indices).ok()
+            });
+
+            match hull {
+                Some(hull) => (Isometry::identity(), ShapeHandle::new(hull)),
+                None => piece.bounding_box(&origin, voxel_size),
+            }
+        })
+        .collect();
+
+    Some(Compound::new(parts))
+}
+
+// A set of voxels, with the convex hull of their corners in the local-space of the voxel grid.
+//
+// The convex hull is `None` if it could not be computed reliably, in which case the box
+// enclosing the voxels is used instead.
+struct Piece<N: RealField> {
+    voxels: Vec<[usize; 3]>,
+    hull: Option<procedural::TriMesh<N>>,
+    hull_volume: N,
+}
+
+impl<N: RealField> Piece<N> {
+    fn new(voxels: Vec<[usize; 3]>) -> Self {
+        let corners = extreme_corners(&voxels);
+        let hull = transformation::convex_hull(&corners);
+
+        if is_valid_hull(&hull, &corners) {
+            let hull_volume = mesh_volume(&hull);
+
+            Piece {
+                voxels,
+                hull: Some(hull),
+                hull_volume,
+            }
+        } else {
+            let (mins, maxs) = voxels_bounds(&voxels);
+            let hull_volume = (maxs - mins).iter().fold(N::one(), |v, e| v * *e);
+
+            Piece {
+                voxels,
+                hull: None,
+                hull_volume,
+            }
+        }
+    }
+
+    // The volume of the convex hull not covered by the voxels.
+    fn excess_volume(&self) -> N {
+        (self.hull_volume - na::convert(self.voxels.len() as f64)).max(N::zero())
+    }
+
+    // The cuboid enclosing the voxels of this piece, with its world-space position.
+    fn bounding_box(&self, origin: &Point<N>, voxel_size: N) -> (Isometry<N>, ShapeHandle<N>) {
+        let (mins, maxs) = voxels_bounds(&self.voxels);
+        let _0_5: N = na::convert(0.5);
+        let half_extents = (maxs - mins) * (voxel_size * _0_5);
+        let center = origin + (mins + maxs) * (voxel_size * _0_5);
+
+        (
+            Isometry::new(center.coords, na::zero()),
+            ShapeHandle::new(Cuboid::new(half_extents)),
+        )
+    }
+
+    fn concavity(&self) -> N {
+        if self.hull_volume.is_zero() {
+            N::zero()
+        } else {
+            self.excess_volume() / self.hull_volume
+        }
+    }
+
+    // Splits this piece by the axis-aligned plane minimizing the excess volume of both halves.
+    fn split(&self) -> Option<(Self, Self)> {
+        let mut best: Option<(N, Self, Self)> = None;
+
+        for axis in 0..3 {
+            let min = self.voxels.iter().map(|v| v[axis]).min()?;
+            let max = self.voxels.iter().map(|v| v[axis]).max()?;
+            let step = ((max - min) / MAX_SPLITS_PER_AXIS).max(1);
+
+            for cut in (min + 1..=max).step_by(step) {
+                let (left, right): (Vec<_>, Vec<_>) =
+                    self.voxels.iter().partition(|v| v[axis] < cut);
+                let left = Piece::new(left);
+                let right = Piece::new(right);
+                let cost = left.excess_volume() + right.excess_volume();
+
+                if best.as_ref().map(|b| cost < b.0) != Some(false) {
+                    best = Some((cost, left, right))
+                }
+            }
+        }
+
+        best.map(|(_, left, right)| (left, right))
+    }
+}
+
+// The smallest and largest corners of a set of voxels, in the local-space of the voxel grid.
+fn voxels_bounds<N: RealField>(voxels: &[[usize; 3]]) -> (Vector<N>, Vector<N>) {
+    let mut mins = Vector::repeat(N::max_value());
+    let mut maxs = Vector::repeat(-N::max_value());
+
+    for v in voxels {
+        for axis in 0..3 {
+            let coord: N = na::convert(v[axis] as f64);
+            mins[axis] = mins[axis].min(coord);
+            maxs[axis] = maxs[axis].max(coord + N::one());
+        }
+    }
+
+    (mins, maxs)
+}
+
+// Checks that `hull` is a closed mesh with consistently oriented triangles, enclosing `points`.
+//
+// The convex hull computation is not reliable when many points are coplanar, as it is often the
+// case for voxel corners.
+fn is_valid_hull<N: RealField>(hull: &procedural::TriMesh<N>, points: &[Point3<N>]) -> bool {
+    let indices = hull.flat_indices();
+    let mut edges = HashSet::new();
+
+    for tri in indices.chunks(3) {
+        for i in 0..3 {
+            // Each oriented edge must appear exactly once.
+            if !edges.insert((tri[i], tri[(i + 1) % 3])) {
+                return false;
+            }
+        }
+    }
+
+    if edges.iter().any(|(a, b)| !edges.contains(&(*b, *a))) {
+        return false;
+    }
+
+    let tol: N = na::convert(1.0e-6);
+
+    indices.chunks(3).all(|tri| {
+        let a = hull.coords[tri[0] as usize];
+        let b = hull.coords[tri[1] as usize];
+        let c = hull.coords[tri[2] as usize];
+        let normal = (b - a).cross(&(c - a));
+        let max_dist = tol * normal.norm();
+
+        points.iter().all(|pt| (pt - a).dot(&normal) <= max_dist)
+    })
+}
+
+// The corners of the voxels that may be vertices of their convex hull.
+//
+// A vertex of the convex hull must be at an end of the lines of corners passing through it along
+// each axis. So we first keep the corners at both ends of each column of voxels along the `z`
+// axis, and then only the ones also at both ends of their lines along the `x` and `y` axis.
+fn extreme_corners<N: RealField>(voxels: &[[usize; 3]]) -> Vec<Point3<N>> {
+    let mut columns = HashMap::new();
+
+    for v in voxels {
+        let range = columns.entry((v[0], v[1])).or_insert((v[2], v[2]));
+        range.0 = range.0.min(v[2]);
+        range.1 = range.1.max(v[2]);
+    }
+
+    let mut corners = HashSet::new();
+
+    for ((i, j), (kmin, kmax)) in columns {
+        for &di in &[0, 1] {
+            for &dj in &[0, 1] {
+                let _ = corners.insert([i + di, j + dj, kmin]);
+                let _ = corners.insert([i + di, j + dj, kmax + 1]);
+            }
+        }
+    }
+
+    for axis in 0..2 {
+        let mut lines = HashMap::new();
+
+        for c in &corners {
+            let mut key = *c;
+            key[axis] = 0;
+            let range = lines.entry(key).or_insert((c[axis], c[axis]));
+            range.0 = range.0.min(c[axis]);
+            range.1 = range.1.max(c[axis]);
+        }
+
+        corners.retain(|c| {
+            let mut key = *c;
+            key[axis] = 0;
+            let range = lines[&key];
+            c[axis] == range.0 || c[axis] == range.1
+        });
+    }
+
+    // Sorted so the result does not depend on the iteration order of the hash set.
+    let mut corners: Vec<_> = corners.into_iter().collect();
+    corners.sort();
+    corners.into_iter().map(perturbed_corner).collect()
+}
+
+// The position of a voxel corner, slightly perturbed.
+//
+// The convex hull computation is more reliable when the points are not coplanar. So each corner
+// is moved by less than `CORNER_PERTURBATION` along each axis, by an offset depending only on its
+// position so adjacent pieces share the same corners.
+fn perturbed_corner<N: RealField>(c: [usize; 3]) -> Point3<N> {
+    let (a, b, c) = (c[0] as f64, c[1] as f64, c[2] as f64);
+    let (s2, s3, s5) = (2.0f64.sqrt(), 3.0f64.sqrt(), 5.0f64.sqrt());
+    let offset = |seed: f64| (seed.fract() - 0.5) * CORNER_PERTURBATION;
+
+    Point3::new(
+        na::convert(a + offset(a * s2 + b * s3 + c * s5)),
+        na::convert(b + offset(a * s3 + b * s5 + c * s2)),
+        na::convert(c + offset(a * s5 + b * s2 + c * s3)),
+    )
+}
+
+// The volume enclosed by a closed triangle mesh star-shaped with regard to its vertex centroid.
+fn mesh_volume<N: RealField>(mesh: &procedural::TriMesh<N>) -> N {
+    if mesh.coords.is_empty() {
+        return N::zero();
+    }
+
+    let center = utils::center(&mesh.coords);
+
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            utils::tetrahedron_volume(
+                &center,
+                &mesh.coords[t[0] as usize],
+                &mesh.coords[t[1] as usize],
+                &mesh.coords[t[2] as usize],
+            )
+        })
+        .fold(N::zero(), |a, b| a + b)
+}
+
+// The origin and size of the voxel grid, and the voxels with a center inside of `mesh`.
+//
+// A voxel center is inside of the mesh if a ray cast from it in the `-x` direction crosses the
+// mesh boundary an odd number of times. A ray passing exactly through an edge or a vertex of the
+// mesh may find a wrong number of crossings, e.g., when it only grazes the mesh along a ridge.
+// So the rays are slightly offset from the voxel centers, by a distance small enough not to
+// change the result elsewhere, and in a direction unlikely to be aligned with the mesh features.
+fn voxelize<N: RealField>(mesh: &TriMesh<N>) -> Option<(Point<N>, N, Vec<[usize; 3]>)> {
+    let aabb = mesh.aabb();
+    let extents = aabb.extents();
+    let voxel_size = extents.max() / na::convert(VOXEL_RESOLUTION as f64);
+
+    if voxel_size.is_zero() {
+        return None;
+    }
+
+    let mut dims = [0; 3];
+    for d in 0..3 {
+        let n = na::try_convert::<N, f64>((extents[d] / voxel_size).ceil()).unwrap_or(0.0);
+        dims[d] = (n as usize).max(1);
+    }
+
+    let m = Isometry::identity();
+    let eps = voxel_size * na::convert(1.0e-6);
+    let offset_y = voxel_size * na::convert(1.0e-4 * std::f64::consts::SQRT_2);
+    let offset_z = voxel_size * na::convert(1.0e-4 * std::f64::consts::LN_2);
+    let max_toi = extents.x + voxel_size * na::convert(2.0);
+    let mut voxels = Vec::new();
+    let mut crossings = Vec::new();
+
+    for j in 0..dims[1] {
+        for k in 0..dims[2] {
+            let y = aabb.mins.y + na::convert::<f64, N>(j as f64 + 0.5) * voxel_size;
+            let z = aabb.mins.z + na::convert::<f64, N>(k as f64 + 0.5) * voxel_size;
+            let mut ray = Ray::new(
+                Point::new(aabb.mins.x - voxel_size, y + offset_y, z + offset_z),
+                Vector::x(),
+            );
+
+            // The abscissae of all the intersections of this line of voxels with the mesh.
+            crossings.clear();
+            while let Some(toi) = mesh.toi_with_ray(&m, &ray, max_toi, false) {
+                crossings.push(ray.origin.x + toi);
+                ray.origin.x += toi + eps;
+            }
+
+            for i in 0..dims[0] {
+                let x = aabb.mins.x + na::convert::<f64, N>(i as f64 + 0.5) * voxel_size;
+
+                if crossings.iter().filter(|c| **c < x).count() % 2 == 1 {
+                    voxels.push([i, j, k]);
+                }
+            }
+        }
+    }
+
+    if voxels.is_empty() {
+        None
+    } else {
+        Some((aabb.mins, voxel_size, voxels))
+    }
+}
@@ -1,5 +1,7 @@
 //! Transformation, simplification and decomposition of meshes.
 
+#[cfg(feature = "dim3")]
+pub use self::convex_decomposition::convex_decomposition;
 #[cfg(feature = "dim3")]
 pub(crate) use self::convex_hull2::convex_hull2_idx;
 #[cfg(feature = "dim2")]
@@ -14,6 +16,8 @@ pub use self::to_polyline::ToPolyline;
 pub use self::to_trimesh::ToTriMesh;
 // pub use self::triangulate::triangulate;
 
+#[cfg(feature = "dim3")]
+mod convex_decomposition;
 mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;